@@ -107,6 +107,18 @@ macro_rules! struct_file {
             writeln!(structs_priv, "using slint::StandardListViewItem;")?;
             &mut structs_pub
         }};
+        (StandardTreeViewItem) => {{
+            writeln!(structs_priv, "using slint::StandardTreeViewItem;")?;
+            &mut structs_pub
+        }};
+        (Date) => {{
+            writeln!(structs_priv, "using slint::Date;")?;
+            &mut structs_pub
+        }};
+        (Time) => {{
+            writeln!(structs_priv, "using slint::Time;")?;
+            &mut structs_pub
+        }};
         ($_:ident) => {
             &mut structs_priv
         };
@@ -197,7 +209,9 @@ fn default_config() -> cbindgen::Config {
             ("KeyEventArg".into(), "KeyEvent".into()),
             ("PointerEventArg".into(), "PointerEvent".into()),
             ("PointerScrollEventArg".into(), "PointerScrollEvent".into()),
+            ("DropEventArg".into(), "DropEvent".into()),
             ("PointArg".into(), "slint::LogicalPosition".into()),
+            ("StringArg".into(), "SharedString".into()),
             ("FloatArg".into(), "float".into()),
             ("Coord".into(), "float".into()),
         ]
@@ -247,6 +261,8 @@ fn gen_corelib(
     enabled_features: EnabledFeatures,
 ) -> anyhow::Result<()> {
     let mut config = default_config();
+    // `SupportedAccessibilityAction` is defined with the `bitflags!` macro
+    config.macro_expansion.bitflags = true;
 
     let items = [
         "Empty",
@@ -256,9 +272,13 @@ fn gen_corelib(
         "ImageItem",
         "ClippedImage",
         "TouchArea",
+        "GestureArea",
+        "DragArea",
+        "DropArea",
         "FocusScope",
         "Flickable",
         "Text",
+        "StyledText",
         "Path",
         "WindowItem",
         "TextInput",
@@ -267,6 +287,7 @@ fn gen_corelib(
         "Rotate",
         "Opacity",
         "Layer",
+        "TooltipArea",
     ];
 
     config.export.include = [
@@ -290,6 +311,7 @@ fn gen_corelib(
         "PointerEventButton",
         "PointerEvent",
         "PointerScrollEvent",
+        "DropEvent",
         "Rect",
         "SortOrder",
         "BitmapFont",
@@ -309,6 +331,9 @@ fn gen_corelib(
         "GraphicsAPI",
         "CloseRequestResponse",
         "StandardListViewItem",
+        "StandardTreeViewItem",
+        "Date",
+        "Time",
         "Rgb8Pixel",
         "Rgba8Pixel",
     ];
@@ -340,7 +365,9 @@ fn gen_corelib(
         "KeyEventArg",
         "PointerEventArg",
         "PointerScrollEventArg",
+        "DropEventArg",
         "PointArg",
+        "StringArg",
         "Point",
         "slint_color_brighter",
         "slint_color_darker",
@@ -471,7 +498,7 @@ fn gen_corelib(
             ],
             vec!["Color"],
             "slint_image_internal.h",
-            "namespace slint::cbindgen_private { struct ParsedSVG{}; struct HTMLImage{}; using namespace vtable; namespace types{ struct NineSliceImage{}; } }",
+            "namespace slint::cbindgen_private { struct ParsedSVG{}; struct HTMLImage{}; using namespace vtable; namespace types{ struct NineSliceImage{}; struct AnimatedImage{}; } }",
         ),
         (
             vec!["Color", "slint_color_brighter", "slint_color_darker",
@@ -524,6 +551,8 @@ fn gen_corelib(
             "slint_windowrc_set_logical_size",
             "slint_windowrc_set_physical_size",
             "slint_windowrc_dark_color_scheme",
+            "slint_windowrc_tooltip_text",
+            "slint_windowrc_tooltip_position",
             "slint_windowrc_dispatch_pointer_event",
             "slint_windowrc_dispatch_key_event",
             "slint_windowrc_dispatch_event",
@@ -657,7 +686,9 @@ fn gen_corelib(
     );
     config.export.body.insert(
         "EasingCurve".to_owned(),
-        "    constexpr EasingCurve(EasingCurve::Tag tag = Tag::Linear, float a = 0, float b = 0, float c = 1, float d = 1) : tag(tag), cubic_bezier{{a,b,c,d}} {}".into()
+        "    constexpr EasingCurve(EasingCurve::Tag tag = Tag::Linear, float a = 0, float b = 0, float c = 1, float d = 1) : tag(tag), cubic_bezier{{a,b,c,d}} {}
+    constexpr EasingCurve(EasingCurve::Tag tag, uint32_t n) : tag(tag), steps{n} {}
+    static constexpr EasingCurve make_spring(float stiffness, float damping, float mass) { EasingCurve curve(Tag::Spring); curve.spring = {{stiffness, damping, mass}}; return curve; }".into()
     );
     config.export.body.insert(
         "LayoutInfo".to_owned(),
@@ -676,6 +707,14 @@ fn gen_corelib(
         .body
         .insert("Flickable".to_owned(), "    inline Flickable(); inline ~Flickable();".into());
     config.export.pre_body.insert("FlickableDataBox".to_owned(), "struct FlickableData;".into());
+    config
+        .export
+        .body
+        .insert("StyledText".to_owned(), "    inline StyledText(); inline ~StyledText();".into());
+    config
+        .export
+        .pre_body
+        .insert("StyledTextLayoutCacheBox".to_owned(), "struct StyledTextLayoutCache;".into());
 
     cbindgen::Builder::new()
         .with_config(config)
@@ -730,6 +769,8 @@ fn gen_backend_qt(
     dependencies: &mut Vec<PathBuf>,
 ) -> anyhow::Result<()> {
     let mut config = default_config();
+    // `SupportedAccessibilityAction` is defined with the `bitflags!` macro
+    config.macro_expansion.bitflags = true;
 
     let items = [
         "NativeButton",
@@ -746,6 +787,10 @@ fn gen_backend_qt(
         "NativeComboBoxPopup",
         "NativeTabWidget",
         "NativeTab",
+        "NativeMenuItem",
+        "NativeMenuFrame",
+        "NativeMenuBarItem",
+        "NativeTooltip",
         "NativeStyleMetrics",
         "NativePalette",
     ];
@@ -10,6 +10,10 @@ set_property(CACHE DEFAULT_SLINT_EMBED_RESOURCES PROPERTY STRINGS
 # define_property(TARGET PROPERTY SLINT_EMBED_RESOURCES
 #     INITIALIZE_FROM_VARIABLE DEFAULT_SLINT_EMBED_RESOURCES)
 
+# Set up machinery to handle SLINT_EMIT_DEBUG_INFO target property
+option(DEFAULT_SLINT_EMIT_DEBUG_INFO
+    "Generate the id and type name of the elements by default, to find them in tests" OFF)
+
 function(SLINT_TARGET_SOURCES target)
     # Parse the NAMESPACE argument
     cmake_parse_arguments(SLINT_TARGET_SOURCES "" "NAMESPACE" "" ${ARGN})
@@ -33,6 +37,13 @@ function(SLINT_TARGET_SOURCES target)
         set(global_fallback "${DEFAULT_SLINT_EMBED_RESOURCES}")
         set(embed "$<IF:$<STREQUAL:${t_prop},>,${global_fallback},${t_prop}>")
 
+        set(bundle_translations_prop "$<TARGET_PROPERTY:${target},SLINT_BUNDLE_TRANSLATIONS>")
+        set(bundle_translations "$<$<NOT:$<STREQUAL:${bundle_translations_prop},>>:--bundle-translations=${bundle_translations_prop}>")
+
+        set(debug_info_prop "$<TARGET_PROPERTY:${target},SLINT_EMIT_DEBUG_INFO>")
+        set(debug_info_fallback "${DEFAULT_SLINT_EMIT_DEBUG_INFO}")
+        set(debug_info "$<$<BOOL:$<IF:$<STREQUAL:${debug_info_prop},>,${debug_info_fallback},${debug_info_prop}>>:--debug-info>")
+
         add_custom_command(
             OUTPUT ${CMAKE_CURRENT_BINARY_DIR}/${_SLINT_BASE_NAME}.h
             COMMAND Slint::slint-compiler ${_SLINT_ABSOLUTE}
@@ -41,6 +52,8 @@ function(SLINT_TARGET_SOURCES target)
                 --style ${_SLINT_STYLE}
                 --embed-resources=${embed}
                 --translation-domain="${target}"
+                ${bundle_translations}
+                ${debug_info}
                 ${_SLINT_CPP_NAMESPACE_ARG}
             DEPENDS Slint::slint-compiler ${_SLINT_ABSOLUTE}
             COMMENT "Generating ${_SLINT_BASE_NAME}.h"
@@ -23,6 +23,7 @@
 #include <span>
 #include <functional>
 #include <concepts>
+#include <limits>
 
 #ifndef SLINT_FEATURE_FREESTANDING
 #    include <mutex>
@@ -203,6 +204,10 @@ inline bool operator==(const EasingCurve &a, const EasingCurve &b)
         return false;
     } else if (a.tag == EasingCurve::Tag::CubicBezier) {
         return std::equal(a.cubic_bezier._0, a.cubic_bezier._0 + 4, b.cubic_bezier._0);
+    } else if (a.tag == EasingCurve::Tag::Steps) {
+        return a.steps._0 == b.steps._0;
+    } else if (a.tag == EasingCurve::Tag::Spring) {
+        return std::equal(a.spring._0, a.spring._0 + 3, b.spring._0);
     }
     return true;
 }
@@ -210,6 +215,15 @@ inline bool operator==(const EasingCurve &a, const EasingCurve &b)
 
 namespace private_api {
 
+/// A keyframe of a property animation, see `Property::set_animated_binding_with_keyframes`
+template<typename T>
+struct AnimationKeyframe
+{
+    float position;
+    T value;
+    cbindgen_private::EasingCurve easing;
+};
+
 inline static void register_item_tree(const vtable::VRc<ItemTreeVTable> *c,
                                       const std::optional<slint::Window> &maybe_window)
 {
@@ -257,6 +271,44 @@ box_layout_info_ortho(cbindgen_private::Slice<cbindgen_private::BoxLayoutCellDat
     return cbindgen_private::slint_box_layout_info_ortho(cells, &padding);
 }
 
+inline SharedVector<float> solve_flow_layout(const cbindgen_private::FlowLayoutData &data,
+                                             cbindgen_private::Slice<int> repeater_indexes)
+{
+    SharedVector<float> result;
+    cbindgen_private::Slice<uint32_t> ri { reinterpret_cast<uint32_t *>(repeater_indexes.ptr),
+                                           repeater_indexes.len };
+    cbindgen_private::slint_solve_flow_layout(&data, ri, &result);
+    return result;
+}
+
+inline SharedVector<float>
+solve_flow_layout_cross(const cbindgen_private::FlowLayoutData &data,
+                        const cbindgen_private::FlowCrossLayoutData &cross,
+                        cbindgen_private::Slice<int> repeater_indexes)
+{
+    SharedVector<float> result;
+    cbindgen_private::Slice<uint32_t> ri { reinterpret_cast<uint32_t *>(repeater_indexes.ptr),
+                                           repeater_indexes.len };
+    cbindgen_private::slint_solve_flow_layout_cross(&data, &cross, ri, &result);
+    return result;
+}
+
+inline cbindgen_private::LayoutInfo
+flow_layout_info(cbindgen_private::Slice<cbindgen_private::BoxLayoutCellData> cells, float spacing,
+                 const cbindgen_private::Padding &padding,
+                 cbindgen_private::LayoutAlignment alignment, bool wrap)
+{
+    return cbindgen_private::slint_flow_layout_info(cells, spacing, &padding, alignment, wrap);
+}
+
+inline cbindgen_private::LayoutInfo
+flow_layout_info_cross(const cbindgen_private::FlowLayoutData &data,
+                       cbindgen_private::Slice<cbindgen_private::BoxLayoutCellData> cells,
+                       float spacing, const cbindgen_private::Padding &padding)
+{
+    return cbindgen_private::slint_flow_layout_info_cross(&data, cells, spacing, &padding);
+}
+
 /// Access the layout cache of an item within a repeater
 inline float layout_cache_access(const SharedVector<float> &cache, int offset, int repeater_index)
 {
@@ -545,6 +597,29 @@ public:
     }
 };
 
+namespace private_api {
+/// Returns a model with the first \a length rows of \a model followed by the rows of \a tail
+template<typename M, typename T>
+auto model_splice(const std::shared_ptr<M> &model, long int length,
+                  const std::shared_ptr<T> &tail)
+{
+    using ModelData = std::remove_cvref_t<decltype(*model->row_data(0))>;
+    std::vector<ModelData> data;
+    auto append = [&](const auto &m, size_t count) {
+        if (m) {
+            m->track_row_count_changes();
+            for (size_t i = 0; i < std::min(count, m->row_count()); ++i) {
+                data.push_back(*m->row_data(i));
+            }
+        }
+    };
+    append(model, size_t(std::max(length, 0l)));
+    append(tail, std::numeric_limits<size_t>::max());
+    return std::shared_ptr<slint::Model<ModelData>>(
+            std::make_shared<VectorModel<ModelData>>(std::move(data)));
+}
+} // namespace private_api
+
 template<typename ModelData>
 class FilterModel;
 
@@ -1032,6 +1107,275 @@ private:
     std::shared_ptr<private_api::ReverseModelInner<ModelData>> inner;
 };
 
+namespace private_api {
+struct TreeModelChangeListener
+{
+    virtual ~TreeModelChangeListener() = default;
+    virtual void data_changed(std::span<const size_t> path) = 0;
+    virtual void children_changed(std::span<const size_t> path) = 0;
+    virtual void reset() = 0;
+};
+using TreeModelPeer = std::weak_ptr<TreeModelChangeListener>;
+}
+
+/// A TreeModel provides the nodes of a tree, that can be shown in a StandardTreeView through
+/// a FlattenedTreeModel.
+///
+/// A node is identified by its path: the index of its top level ancestor, followed by the
+/// indexes of each of its descendants down to the node itself, among their siblings. The empty
+/// path stands for the root of the tree, whose children are the top level nodes.
+template<typename ModelData>
+class TreeModel
+{
+public:
+    virtual ~TreeModel() = default;
+    TreeModel() = default;
+    TreeModel(const TreeModel &) = delete;
+    TreeModel &operator=(const TreeModel &) = delete;
+
+    /// The amount of children of the node at \a path
+    virtual size_t child_count(std::span<const size_t> path) const = 0;
+    /// Returns the data of the node at \a path.
+    virtual std::optional<ModelData> data(std::span<const size_t> path) const = 0;
+    /// Returns true if the node at \a path has children. Re-implement this function when the
+    /// children are expensive to count, as it's called for every node shown by the view.
+    virtual bool has_children(std::span<const size_t> path) const
+    {
+        return child_count(path) > 0;
+    }
+
+    /// \private
+    /// Internal function called by the view to register itself
+    void attach_peer(private_api::TreeModelPeer p) { peers.push_back(std::move(p)); }
+
+protected:
+    /// Notify the views that the data of the node at \a path was changed
+    void data_changed(std::span<const size_t> path)
+    {
+        for_each_peers([=](auto peer) { peer->data_changed(path); });
+    }
+    /// Notify the views that children were added to or removed from the node at \a path, or
+    /// that its children were changed in any other way
+    void children_changed(std::span<const size_t> path)
+    {
+        for_each_peers([=](auto peer) { peer->children_changed(path); });
+    }
+    /// Notify the views that the tree has been changed and that everything needs to be reloaded
+    void reset()
+    {
+        for_each_peers([=](auto peer) { peer->reset(); });
+    }
+
+private:
+    template<typename F>
+    void for_each_peers(const F &f)
+    {
+        private_api::assert_main_thread();
+        peers.erase(std::remove_if(peers.begin(), peers.end(),
+                                   [&](const auto &p) {
+                                       if (auto pp = p.lock()) {
+                                           f(pp);
+                                           return false;
+                                       }
+                                       return true;
+                                   }),
+                    peers.end());
+    }
+    std::vector<private_api::TreeModelPeer> peers;
+};
+
+class FlattenedTreeModel;
+
+namespace private_api {
+struct FlattenedTreeModelInner : private_api::TreeModelChangeListener
+{
+    FlattenedTreeModelInner(std::shared_ptr<slint::TreeModel<StandardListViewItem>> tree,
+                            slint::FlattenedTreeModel &target_model)
+        : tree(std::move(tree)), target_model(target_model)
+    {
+        rows = child_rows({});
+    }
+
+    struct Row
+    {
+        std::vector<size_t> path;
+        bool expanded = false;
+    };
+
+    std::vector<Row> child_rows(std::span<const size_t> path) const
+    {
+        std::vector<Row> children;
+        for (size_t i = 0, count = tree->child_count(path); i < count; ++i) {
+            Row row { { path.begin(), path.end() } };
+            row.path.push_back(i);
+            children.push_back(std::move(row));
+        }
+        return children;
+    }
+
+    // Returns the row after the last descendant of \a row
+    size_t end_of_descendants(size_t row) const
+    {
+        auto level = rows[row].path.size();
+        auto end = std::find_if(rows.begin() + row + 1, rows.end(),
+                                [&](const Row &r) { return r.path.size() <= level; });
+        return end - rows.begin();
+    }
+
+    std::optional<size_t> row_of(std::span<const size_t> path) const
+    {
+        auto it = std::find_if(rows.begin(), rows.end(), [&](const Row &r) {
+            return std::equal(r.path.begin(), r.path.end(), path.begin(), path.end());
+        });
+        if (it == rows.end())
+            return {};
+        return it - rows.begin();
+    }
+
+    void set_expanded(size_t row, bool expanded);
+    void data_changed(std::span<const size_t> path) override;
+    void children_changed(std::span<const size_t> path) override;
+    void reset() override;
+
+    std::shared_ptr<slint::TreeModel<StandardListViewItem>> tree;
+    // The nodes that are listed, in depth-first order
+    std::vector<Row> rows;
+    slint::FlattenedTreeModel &target_model;
+};
+}
+
+/// The FlattenedTreeModel lists the nodes of a TreeModel in a flat model of
+/// StandardTreeViewItem, so that the tree can be shown in a StandardTreeView.
+///
+/// The top level nodes are listed first, each followed by its descendants when it is expanded.
+/// The children of a node are only queried from the tree when the node is expanded, which
+/// happens when the view sets the row with the `expanded` field set to true, or when
+/// set_expanded() is called.
+class FlattenedTreeModel : public Model<StandardTreeViewItem>
+{
+    friend struct private_api::FlattenedTreeModelInner;
+
+public:
+    /// Constructs a new FlattenedTreeModel listing the top level nodes of \a tree, collapsed.
+    FlattenedTreeModel(std::shared_ptr<TreeModel<StandardListViewItem>> tree)
+        : inner(std::make_shared<private_api::FlattenedTreeModelInner>(std::move(tree), *this))
+    {
+        inner->tree->attach_peer(inner);
+    }
+
+    size_t row_count() const override { return inner->rows.size(); }
+
+    std::optional<StandardTreeViewItem> row_data(size_t i) const override
+    {
+        if (i >= inner->rows.size())
+            return {};
+        const auto &row = inner->rows[i];
+        StandardTreeViewItem item;
+        if (auto data = inner->tree->data(row.path))
+            item.text = data->text;
+        item.level = int(row.path.size()) - 1;
+        item.has_children = inner->tree->has_children(row.path);
+        item.expanded = row.expanded;
+        return item;
+    }
+
+    /// Only the `expanded` field can be changed, which expands or collapses the node.
+    void set_row_data(size_t i, const StandardTreeViewItem &value) override
+    {
+        inner->set_expanded(i, value.expanded);
+    }
+
+    /// Expands or collapses the node listed at \a row.
+    void set_expanded(size_t row, bool expanded) { inner->set_expanded(row, expanded); }
+
+    /// Returns the path in the tree of the node listed at \a row.
+    std::optional<std::vector<size_t>> path(size_t row) const
+    {
+        if (row >= inner->rows.size())
+            return {};
+        return inner->rows[row].path;
+    }
+
+    /// Returns the row at which the node at \a path is listed, or an empty optional if it's
+    /// not listed because one of its ancestors is collapsed.
+    std::optional<size_t> row_of(std::span<const size_t> path) const
+    {
+        return inner->row_of(path);
+    }
+
+    /// Returns the tree model of this flattened model.
+    std::shared_ptr<TreeModel<StandardListViewItem>> source_model() const { return inner->tree; }
+
+private:
+    std::shared_ptr<private_api::FlattenedTreeModelInner> inner;
+};
+
+namespace private_api {
+inline void FlattenedTreeModelInner::set_expanded(size_t row, bool expanded)
+{
+    if (row >= rows.size() || rows[row].expanded == expanded) {
+        return;
+    }
+    if (expanded) {
+        auto children = child_rows(rows[row].path);
+        rows[row].expanded = true;
+        rows.insert(rows.begin() + row + 1, children.begin(), children.end());
+        target_model.row_changed(row);
+        if (!children.empty()) {
+            target_model.row_added(row + 1, children.size());
+        }
+    } else {
+        auto end = end_of_descendants(row);
+        rows[row].expanded = false;
+        rows.erase(rows.begin() + row + 1, rows.begin() + end);
+        target_model.row_changed(row);
+        if (end > row + 1) {
+            target_model.row_removed(row + 1, end - row - 1);
+        }
+    }
+}
+
+inline void FlattenedTreeModelInner::data_changed(std::span<const size_t> path)
+{
+    if (auto row = row_of(path)) {
+        target_model.row_changed(*row);
+    }
+}
+
+inline void FlattenedTreeModelInner::children_changed(std::span<const size_t> path)
+{
+    if (path.empty()) {
+        reset();
+        return;
+    }
+    auto row = row_of(path);
+    if (!row) {
+        return;
+    }
+    if (rows[*row].expanded) {
+        // The descendants are listed again, collapsed, as their paths may have changed.
+        auto end = end_of_descendants(*row);
+        rows.erase(rows.begin() + *row + 1, rows.begin() + end);
+        if (end > *row + 1) {
+            target_model.row_removed(*row + 1, end - *row - 1);
+        }
+        auto children = child_rows(path);
+        rows[*row].expanded = !children.empty();
+        rows.insert(rows.begin() + *row + 1, children.begin(), children.end());
+        if (!children.empty()) {
+            target_model.row_added(*row + 1, children.size());
+        }
+    }
+    target_model.row_changed(*row);
+}
+
+inline void FlattenedTreeModelInner::reset()
+{
+    rows = child_rows({});
+    target_model.reset();
+}
+}
+
 namespace private_api {
 
 template<typename C, typename ModelData>
@@ -1220,6 +1564,59 @@ inline SharedString translate(const SharedString &original, const SharedString &
     return result;
 }
 
+using PluralRule = uintptr_t (*)(int32_t);
+
+/// Convert the strings of bundled translations, where nullptr means untranslated
+inline std::vector<cbindgen_private::Slice<uint8_t>>
+bundled_strings_to_slices(std::span<const char8_t *const> strs)
+{
+    std::vector<cbindgen_private::Slice<uint8_t>> slices;
+    slices.reserve(strs.size());
+    for (auto str : strs) {
+        slices.push_back(string_to_slice(str ? std::string_view(reinterpret_cast<const char *>(str))
+                                             : std::string_view()));
+    }
+    return slices;
+}
+
+inline void set_bundled_languages(std::span<const char8_t *const> languages)
+{
+    auto slices = bundled_strings_to_slices(languages);
+    cbindgen_private::slint_translate_set_bundled_languages({ slices.data(), slices.size() });
+}
+
+inline SharedString translate_from_bundle(std::span<const char8_t *const> strs,
+                                          cbindgen_private::Slice<SharedString> arguments)
+{
+    SharedString result;
+    auto slices = bundled_strings_to_slices(strs);
+    cbindgen_private::slint_translate_from_bundle({ slices.data(), slices.size() }, arguments,
+                                                  &result);
+    return result;
+}
+
+/// `strs` contains the plural forms of each language one after the other, and the forms of
+/// the language `i` are at `indices[i]..indices[i + 1]`
+inline SharedString
+translate_from_bundle_with_plural(std::span<const char8_t *const> strs,
+                                  std::span<const uint32_t> indices,
+                                  std::span<const PluralRule> plural_rules,
+                                  cbindgen_private::Slice<SharedString> arguments, int n)
+{
+    SharedString result;
+    auto slices = bundled_strings_to_slices(strs);
+    cbindgen_private::Slice<uint32_t> indices_slice = {
+        const_cast<uint32_t *>(indices.data()), indices.size()
+    };
+    cbindgen_private::Slice<PluralRule> plural_rules_slice = {
+        const_cast<PluralRule *>(plural_rules.data()), plural_rules.size()
+    };
+    cbindgen_private::slint_translate_from_bundle_with_plural(
+            { slices.data(), slices.size() }, indices_slice, plural_rules_slice, arguments, n,
+            &result);
+    return result;
+}
+
 } // namespace private_api
 
 #if !defined(DOXYGEN)
@@ -1232,6 +1629,15 @@ cbindgen_private::Flickable::~Flickable()
     slint_flickable_data_free(&data);
 }
 
+cbindgen_private::StyledText::StyledText()
+{
+    slint_styled_text_layout_cache_init(&layout_cache);
+}
+cbindgen_private::StyledText::~StyledText()
+{
+    slint_styled_text_layout_cache_free(&layout_cache);
+}
+
 cbindgen_private::NativeStyleMetrics::NativeStyleMetrics(void *)
 {
     slint_native_style_metrics_init(this);
@@ -1261,6 +1667,21 @@ struct [[deprecated]] VersionCheckHelper
 };
 }
 
+/// Selects the language of the translations bundled at compile time (see the
+/// `SLINT_BUNDLE_TRANSLATIONS` CMake variable). All the strings translated with `@tr` are
+/// updated.
+///
+/// The language is a locale name like `"de"` or `"fr_CH"`. If there is no translation for the
+/// full locale name, the translation for the language only (`"fr"`) is used. The empty string
+/// selects the original strings.
+///
+/// Returns false if there is no bundled translation for this language.
+inline bool select_bundled_translation(std::string_view language)
+{
+    return cbindgen_private::slint_translate_select_bundled_translation(
+            private_api::string_to_slice(language));
+}
+
 /// Enum for the event loop mode parameter of the slint::run_event_loop() function.
 /// It is used to determine when the event loop quits.
 enum class EventLoopMode {
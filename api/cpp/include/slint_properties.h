@@ -7,6 +7,7 @@
 
 namespace slint::cbindgen_private {
 struct PropertyAnimation;
+struct EasingCurve;
 }
 
 #include "slint_properties_internal.h"
@@ -20,40 +21,56 @@ inline void slint_property_set_animated_binding_helper(
         const cbindgen_private::PropertyHandleOpaque *handle, void (*binding)(void *, int *),
         void *user_data, void (*drop_user_data)(void *),
         const cbindgen_private::PropertyAnimation *animation_data,
-        cbindgen_private::PropertyAnimation (*transition_data)(void *, uint64_t *))
+        cbindgen_private::PropertyAnimation (*transition_data)(void *, uint64_t *),
+        void (*keyframes)(void *, void *,
+                          void (*)(void *, float, const int *,
+                                   const cbindgen_private::EasingCurve *)) = nullptr)
 {
-    cbindgen_private::slint_property_set_animated_binding_int(
-            handle, binding, user_data, drop_user_data, animation_data, transition_data);
+    cbindgen_private::slint_property_set_animated_binding_int(handle, binding, user_data,
+                                                             drop_user_data, animation_data,
+                                                             transition_data, keyframes);
 }
 
 inline void slint_property_set_animated_binding_helper(
         const cbindgen_private::PropertyHandleOpaque *handle, void (*binding)(void *, float *),
         void *user_data, void (*drop_user_data)(void *),
         const cbindgen_private::PropertyAnimation *animation_data,
-        cbindgen_private::PropertyAnimation (*transition_data)(void *, uint64_t *))
+        cbindgen_private::PropertyAnimation (*transition_data)(void *, uint64_t *),
+        void (*keyframes)(void *, void *,
+                          void (*)(void *, float, const float *,
+                                   const cbindgen_private::EasingCurve *)) = nullptr)
 {
-    cbindgen_private::slint_property_set_animated_binding_float(
-            handle, binding, user_data, drop_user_data, animation_data, transition_data);
+    cbindgen_private::slint_property_set_animated_binding_float(handle, binding, user_data,
+                                                             drop_user_data, animation_data,
+                                                             transition_data, keyframes);
 }
 
 inline void slint_property_set_animated_binding_helper(
         const cbindgen_private::PropertyHandleOpaque *handle, void (*binding)(void *, Color *),
         void *user_data, void (*drop_user_data)(void *),
         const cbindgen_private::PropertyAnimation *animation_data,
-        cbindgen_private::PropertyAnimation (*transition_data)(void *, uint64_t *))
+        cbindgen_private::PropertyAnimation (*transition_data)(void *, uint64_t *),
+        void (*keyframes)(void *, void *,
+                          void (*)(void *, float, const Color *,
+                                   const cbindgen_private::EasingCurve *)) = nullptr)
 {
-    cbindgen_private::slint_property_set_animated_binding_color(
-            handle, binding, user_data, drop_user_data, animation_data, transition_data);
+    cbindgen_private::slint_property_set_animated_binding_color(handle, binding, user_data,
+                                                             drop_user_data, animation_data,
+                                                             transition_data, keyframes);
 }
 
 inline void slint_property_set_animated_binding_helper(
         const cbindgen_private::PropertyHandleOpaque *handle, void (*binding)(void *, Brush *),
         void *user_data, void (*drop_user_data)(void *),
         const cbindgen_private::PropertyAnimation *animation_data,
-        cbindgen_private::PropertyAnimation (*transition_data)(void *, uint64_t *))
+        cbindgen_private::PropertyAnimation (*transition_data)(void *, uint64_t *),
+        void (*keyframes)(void *, void *,
+                          void (*)(void *, float, const Brush *,
+                                   const cbindgen_private::EasingCurve *)) = nullptr)
 {
-    cbindgen_private::slint_property_set_animated_binding_brush(
-            handle, binding, user_data, drop_user_data, animation_data, transition_data);
+    cbindgen_private::slint_property_set_animated_binding_brush(handle, binding, user_data,
+                                                             drop_user_data, animation_data,
+                                                             transition_data, keyframes);
 }
 
 template<typename T>
@@ -136,6 +153,66 @@ struct Property
                 });
     }
 
+    /// Like set_animated_binding, but the animation goes through the keyframes returned by
+    /// `keyframes`, a std::vector of private_api::AnimationKeyframe<T>.
+    template<typename F, typename K>
+    inline void set_animated_binding_with_keyframes(
+            F binding, const cbindgen_private::PropertyAnimation &animation_data,
+            K keyframes) const
+    {
+        struct UserData
+        {
+            F binding;
+            K keyframes;
+        };
+        private_api::slint_property_set_animated_binding_helper(
+                &inner,
+                [](void *user_data, T *value) {
+                    *reinterpret_cast<T *>(value) =
+                            reinterpret_cast<UserData *>(user_data)->binding();
+                },
+                new UserData { binding, keyframes },
+                [](void *user_data) { delete reinterpret_cast<UserData *>(user_data); },
+                &animation_data, nullptr,
+                [](void *user_data, void *sink,
+                   void (*add_keyframe)(void *, float, const T *,
+                                        const cbindgen_private::EasingCurve *)) {
+                    for (const auto &k : reinterpret_cast<UserData *>(user_data)->keyframes()) {
+                        add_keyframe(sink, k.position, &k.value, &k.easing);
+                    }
+                });
+    }
+
+    template<typename F, typename Trans, typename K>
+    inline void set_animated_binding_for_transition_with_keyframes(F binding, Trans animation,
+                                                                   K keyframes) const
+    {
+        struct UserData
+        {
+            F binding;
+            Trans animation;
+            K keyframes;
+        };
+        private_api::slint_property_set_animated_binding_helper(
+                &inner,
+                [](void *user_data, T *value) {
+                    *reinterpret_cast<T *>(value) =
+                            reinterpret_cast<UserData *>(user_data)->binding();
+                },
+                new UserData { binding, animation, keyframes },
+                [](void *user_data) { delete reinterpret_cast<UserData *>(user_data); }, nullptr,
+                [](void *user_data, uint64_t *instant) {
+                    return reinterpret_cast<UserData *>(user_data)->animation(instant);
+                },
+                [](void *user_data, void *sink,
+                   void (*add_keyframe)(void *, float, const T *,
+                                        const cbindgen_private::EasingCurve *)) {
+                    for (const auto &k : reinterpret_cast<UserData *>(user_data)->keyframes()) {
+                        add_keyframe(sink, k.position, &k.value, &k.easing);
+                    }
+                });
+    }
+
     bool is_dirty() const { return cbindgen_private::slint_property_is_dirty(&inner); }
     void mark_dirty() const { cbindgen_private::slint_property_mark_dirty(&inner); }
 
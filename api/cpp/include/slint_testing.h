@@ -18,6 +18,13 @@ inline void mock_elapsed_time(int64_t time_in_ms)
     cbindgen_private::slint_mock_elapsed_time(time_in_ms);
 }
 
+/// Advances the mocked time by the given duration, stopping at the timeout of each timer in
+/// between, so that timers fire in order and repeated timers fire once per elapsed interval.
+inline void advance_time(std::chrono::milliseconds duration)
+{
+    cbindgen_private::slint_mock_advance_time(duration.count());
+}
+
 inline uint64_t get_mocked_time()
 {
     return cbindgen_private::slint_get_mocked_time();
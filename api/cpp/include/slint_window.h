@@ -76,6 +76,19 @@ public:
 
     bool dark_color_scheme() const { return slint_windowrc_dark_color_scheme(&inner); }
 
+    SharedString tooltip_text(bool popup) const
+    {
+        SharedString text;
+        slint_windowrc_tooltip_text(&inner, popup, &text);
+        return text;
+    }
+    LogicalPosition tooltip_position(bool popup) const
+    {
+        LogicalPosition pos;
+        slint_windowrc_tooltip_position(&inner, popup, &pos);
+        return pos;
+    }
+
     bool text_input_focused() const { return slint_windowrc_get_text_input_focused(&inner); }
     void set_text_input_focused(bool value) const
     {
@@ -104,12 +117,14 @@ public:
     }
 
     template<typename Component, typename Parent>
-    void show_popup(const Parent *parent_component, cbindgen_private::Point p, bool close_on_click,
+    void show_popup(const Parent *parent_component, cbindgen_private::Point p,
+                    cbindgen_private::Rect anchor, cbindgen_private::PopupPlacement placement,
+                    cbindgen_private::PopupClosePolicy close_policy,
                     cbindgen_private::ItemRc parent_item) const
     {
         auto popup = Component::create(parent_component).into_dyn();
-        cbindgen_private::slint_windowrc_show_popup(&inner, &popup, p, close_on_click,
-                                                    &parent_item);
+        cbindgen_private::slint_windowrc_show_popup(&inner, &popup, p, anchor, placement,
+                                                    close_policy, &parent_item);
     }
 
     void close_popup() const { cbindgen_private::slint_windowrc_close_popup(&inner); }
@@ -466,6 +481,62 @@ public:
         inner.dispatch_pointer_event(event);
     }
 
+    /// Dispatches a touch press event to the scene.
+    ///
+    /// Use this function when you're implementing your own backend and want to forward touch
+    /// events. The first touch point acts like the mouse. A second touch point in the same
+    /// `GestureArea` starts a pinch and rotation gesture, otherwise it presses the items under it
+    /// on its own.
+    ///
+    /// \a touch_id identifies the touch point until it is released.
+    /// \a pos represents the logical position of the touch point relative to the window.
+    void dispatch_touch_press_event(uint64_t touch_id, LogicalPosition pos)
+    {
+        private_api::assert_main_thread();
+        using slint::cbindgen_private::WindowEvent;
+        WindowEvent event { .touch_pressed = WindowEvent::TouchPressed_Body {
+                                    .tag = WindowEvent::Tag::TouchPressed,
+                                    .touch_id = touch_id,
+                                    .position = { pos.x, pos.y } } };
+        cbindgen_private::slint_windowrc_dispatch_event(&inner.handle(), &event);
+    }
+
+    /// Dispatches a touch move event to the scene.
+    ///
+    /// Use this function when you're implementing your own backend and want to forward touch
+    /// events.
+    ///
+    /// \a touch_id identifies the touch point until it is released.
+    /// \a pos represents the logical position of the touch point relative to the window.
+    void dispatch_touch_move_event(uint64_t touch_id, LogicalPosition pos)
+    {
+        private_api::assert_main_thread();
+        using slint::cbindgen_private::WindowEvent;
+        WindowEvent event { .touch_moved = WindowEvent::TouchMoved_Body {
+                                    .tag = WindowEvent::Tag::TouchMoved,
+                                    .touch_id = touch_id,
+                                    .position = { pos.x, pos.y } } };
+        cbindgen_private::slint_windowrc_dispatch_event(&inner.handle(), &event);
+    }
+
+    /// Dispatches a touch release event to the scene.
+    ///
+    /// Use this function when you're implementing your own backend and want to forward touch
+    /// events.
+    ///
+    /// \a touch_id identifies the touch point until it is released.
+    /// \a pos represents the logical position of the touch point relative to the window.
+    void dispatch_touch_release_event(uint64_t touch_id, LogicalPosition pos)
+    {
+        private_api::assert_main_thread();
+        using slint::cbindgen_private::WindowEvent;
+        WindowEvent event { .touch_released = WindowEvent::TouchReleased_Body {
+                                    .tag = WindowEvent::Tag::TouchReleased,
+                                    .touch_id = touch_id,
+                                    .position = { pos.x, pos.y } } };
+        cbindgen_private::slint_windowrc_dispatch_event(&inner.handle(), &event);
+    }
+
     /// Set the logical size of this window after a resize event
     ///
     /// The backend must send this event to ensure that the `width` and `height` property of the
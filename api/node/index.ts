@@ -388,7 +388,322 @@ export class ArrayModel<T> extends Model<T> {
     }
 }
 
+/**
+ * Represents an item of a `StandardListView`, or the data of a node of a {@link TreeModel}.
+ */
+export interface StandardListViewItem {
+    /**
+     * The text of the item.
+     */
+    text: string;
+}
+
+/**
+ * Represents a node of a tree listed by the {@link FlattenedTreeModel}, as it's shown by the `StandardTreeView`.
+ */
+export interface StandardTreeViewItem {
+    /**
+     * The text of the node.
+     */
+    text: string;
+
+    /**
+     * The depth of the node in the tree: 0 for the top level nodes.
+     */
+    level: number;
+
+    /**
+     * True if the node has children.
+     */
+    has_children: boolean;
+
+    /**
+     * True if the children of the node are listed.
+     */
+    expanded: boolean;
+}
+
+/**
+ * TreeModel is the interface of the hierarchical models that provide the nodes of a tree, which
+ * can be shown in a `StandardTreeView` through a {@link FlattenedTreeModel}.
+ *
+ * A node is identified by its path: the index of its top level ancestor, followed by the index
+ * of each of its descendants down to the node itself, among their siblings. The empty path stands
+ * for the root of the tree, whose children are the top level nodes.
+ *
+ * @template T the type of the tree's nodes.
+ */
+export abstract class TreeModel<T> {
+    /**
+     * @hidden
+     */
+    #listeners: Array<private_api.TreeModelListener> = [];
+
+    /**
+     * Implementations of this function must return the number of children of the node at the specified path.
+     * @param path the path of the node, or an empty array for the top level nodes.
+     */
+    abstract childCount(path: number[]): number;
+
+    /**
+     * Implementations of this function must return the data of the node at the specified path.
+     * @param path the path of the node.
+     * @returns undefined if there is no node at the path, otherwise the data.
+     */
+    abstract data(path: number[]): T | undefined;
+
+    /**
+     * Returns true if the node at the specified path has children. Re-implement this function
+     * when the children are expensive to count, as it's called for every node shown by the view.
+     * @param path the path of the node.
+     */
+    hasChildren(path: number[]): boolean {
+        return this.childCount(path) > 0;
+    }
+
+    /**
+     * @hidden
+     */
+    attachListener(listener: private_api.TreeModelListener): void {
+        this.#listeners.push(listener);
+    }
+
+    /**
+     * Notifies the view that the data of the node at the specified path is changed.
+     * @param path the path of the changed node.
+     */
+    protected notifyDataChanged(path: number[]): void {
+        this.#listeners.forEach((listener) => listener.dataChanged(path));
+    }
+
+    /**
+     * Notifies the view that children are added to or removed from the node at the specified path,
+     * or that its children are changed in any other way.
+     * @param path the path of the node, or an empty array for the top level nodes.
+     */
+    protected notifyChildrenChanged(path: number[]): void {
+        this.#listeners.forEach((listener) => listener.childrenChanged(path));
+    }
+
+    /**
+     * Notifies the view that the complete tree must be reloaded.
+     */
+    protected notifyReset(): void {
+        this.#listeners.forEach((listener) => listener.reset());
+    }
+}
+
+/**
+ * FlattenedTreeModel lists the nodes of a {@link TreeModel} in a flat model of {@link StandardTreeViewItem},
+ * so that the tree can be shown in a `StandardTreeView`.
+ *
+ * The top level nodes are listed first, each followed by its descendants when it is expanded.
+ * The children of a node are only queried from the tree when the node is expanded, which happens
+ * when the view sets the row with the `expanded` field set to true, or when {@link FlattenedTreeModel.setExpanded}
+ * is called.
+ *
+ * ### Example
+ *
+ * ```js
+ * class Chapters extends TreeModel<StandardListViewItem> {
+ *     #chapters = [
+ *         { title: "Introduction", sections: [] },
+ *         { title: "Usage", sections: ["Installation", "Configuration"] },
+ *     ];
+ *
+ *     childCount(path: number[]) {
+ *         if (path.length === 0) {
+ *             return this.#chapters.length;
+ *         }
+ *         return path.length === 1 ? this.#chapters[path[0]].sections.length : 0;
+ *     }
+ *
+ *     data(path: number[]) {
+ *         let chapter = this.#chapters[path[0]];
+ *         return { text: path.length === 1 ? chapter.title : chapter.sections[path[1]] };
+ *     }
+ * }
+ *
+ * const model = new FlattenedTreeModel(new Chapters());
+ * model.setExpanded(1, true);
+ *
+ * // prints "Configuration"
+ * console.log(model.rowData(3)!.text);
+ * ```
+ */
+export class FlattenedTreeModel extends Model<StandardTreeViewItem> {
+    readonly sourceModel: TreeModel<StandardListViewItem>;
+
+    /**
+     * @hidden
+     * The nodes that are listed, in depth-first order
+     */
+    #rows: Array<{ path: number[]; expanded: boolean }>;
+
+    /**
+     * Constructs the FlattenedTreeModel, listing the top level nodes of the tree, collapsed.
+     * @param sourceModel the tree model.
+     */
+    constructor(sourceModel: TreeModel<StandardListViewItem>) {
+        super();
+        this.sourceModel = sourceModel;
+        this.#rows = this.#childRows([]);
+        sourceModel.attachListener({
+            dataChanged: (path) => {
+                let row = this.rowOf(path);
+                if (row !== undefined) {
+                    this.notifyRowDataChanged(row);
+                }
+            },
+            childrenChanged: (path) => this.#childrenChanged(path),
+            reset: () => {
+                this.#rows = this.#childRows([]);
+                this.notifyReset();
+            }
+        });
+    }
+
+    /**
+     * Returns the number of listed nodes.
+     */
+    rowCount(): number {
+        return this.#rows.length;
+    }
+
+    /**
+     * Returns the node listed at the specified row.
+     * @param row index in range 0..(rowCount() - 1).
+     * @returns undefined if row is out of range otherwise the node.
+     */
+    rowData(row: number): StandardTreeViewItem | undefined {
+        let r = this.#rows[row];
+        if (r === undefined) {
+            return undefined;
+        }
+        return {
+            text: this.sourceModel.data(r.path)?.text ?? "",
+            level: r.path.length - 1,
+            has_children: this.sourceModel.hasChildren(r.path),
+            expanded: r.expanded
+        };
+    }
+
+    /**
+     * Expands or collapses the node listed at the specified row, according to the `expanded` field of data.
+     * The other fields are ignored.
+     * @param row index in range 0..(rowCount() - 1).
+     * @param data the node with the new expanded state.
+     */
+    setRowData(row: number, data: StandardTreeViewItem): void {
+        this.setExpanded(row, data.expanded);
+    }
+
+    /**
+     * Expands or collapses the node listed at the specified row.
+     * @param row index in range 0..(rowCount() - 1).
+     * @param expanded true to list the children of the node.
+     */
+    setExpanded(row: number, expanded: boolean): void {
+        let r = this.#rows[row];
+        if (r === undefined || r.expanded === expanded) {
+            return;
+        }
+        if (expanded) {
+            let children = this.#childRows(r.path);
+            r.expanded = true;
+            this.#rows.splice(row + 1, 0, ...children);
+            this.notifyRowDataChanged(row);
+            if (children.length > 0) {
+                this.notifyRowAdded(row + 1, children.length);
+            }
+        } else {
+            let end = this.#endOfDescendants(row);
+            r.expanded = false;
+            this.#rows.splice(row + 1, end - row - 1);
+            this.notifyRowDataChanged(row);
+            if (end > row + 1) {
+                this.notifyRowRemoved(row + 1, end - row - 1);
+            }
+        }
+    }
+
+    /**
+     * Returns the path in the tree of the node listed at the specified row.
+     * @param row index in range 0..(rowCount() - 1).
+     * @returns undefined if row is out of range otherwise the path.
+     */
+    path(row: number): number[] | undefined {
+        return this.#rows[row]?.path.slice();
+    }
+
+    /**
+     * Returns the row at which the node at the specified path is listed.
+     * @param path the path of the node.
+     * @returns undefined if the node isn't listed because one of its ancestors is collapsed.
+     */
+    rowOf(path: number[]): number | undefined {
+        let row = this.#rows.findIndex(
+            (r) => r.path.length === path.length && r.path.every((index, i) => index === path[i])
+        );
+        return row < 0 ? undefined : row;
+    }
+
+    #childRows(path: number[]): Array<{ path: number[]; expanded: boolean }> {
+        let rows = [];
+        for (let i = 0, count = this.sourceModel.childCount(path); i < count; ++i) {
+            rows.push({ path: [...path, i], expanded: false });
+        }
+        return rows;
+    }
+
+    // Returns the row after the last descendant of the specified row
+    #endOfDescendants(row: number): number {
+        let level = this.#rows[row].path.length;
+        let end = row + 1;
+        while (end < this.#rows.length && this.#rows[end].path.length > level) {
+            ++end;
+        }
+        return end;
+    }
+
+    #childrenChanged(path: number[]) {
+        if (path.length === 0) {
+            this.#rows = this.#childRows([]);
+            this.notifyReset();
+            return;
+        }
+        let row = this.rowOf(path);
+        if (row === undefined) {
+            return;
+        }
+        if (this.#rows[row].expanded) {
+            // The descendants are listed again, collapsed, as their paths may have changed.
+            let end = this.#endOfDescendants(row);
+            this.#rows.splice(row + 1, end - row - 1);
+            if (end > row + 1) {
+                this.notifyRowRemoved(row + 1, end - row - 1);
+            }
+            let children = this.#childRows(path);
+            this.#rows[row].expanded = children.length > 0;
+            this.#rows.splice(row + 1, 0, ...children);
+            if (children.length > 0) {
+                this.notifyRowAdded(row + 1, children.length);
+            }
+        }
+        this.notifyRowDataChanged(row);
+    }
+}
+
 export namespace private_api {
+/**
+ * @hidden
+ */
+export interface TreeModelListener {
+    dataChanged(path: number[]): void;
+    childrenChanged(path: number[]): void;
+    reset(): void;
+}
+
 /**
  * Provides rows that are generated by a map function based on the rows of another Model.
  *
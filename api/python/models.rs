@@ -58,6 +58,10 @@ fn notify_row_removed(&self, index: usize, count: usize) {
         self.inner.notify.row_removed(index, count)
     }
 
+    fn notify_reset(&self) {
+        self.inner.notify.reset()
+    }
+
     fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
         if let Some(this) = self.inner.self_ref.borrow().as_ref() {
             visit.call(this)?;
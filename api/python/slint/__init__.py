@@ -243,5 +243,7 @@ Brush = native.PyBrush
 Model = native.PyModelBase
 ListModel = models.ListModel
 Model = models.Model
+TreeModel = models.TreeModel
+FlattenedTreeModel = models.FlattenedTreeModel
 Timer = native.Timer
 TimerMode = native.TimerMode
@@ -2,6 +2,7 @@
 # SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
 
 from . import slint as native
+import weakref
 
 
 class Model(native.PyModelBase):
@@ -58,6 +59,142 @@ class ListModel(Model):
         super().notify_row_added(index, 1)
 
 
+class TreeModel:
+    """The nodes of a tree, to be shown in a StandardTreeView through a FlattenedTreeModel.
+
+    A node is identified by its path: a tuple of the index of its top level ancestor, followed by
+    the index of each of its descendants down to the node itself, among their siblings. The empty
+    tuple stands for the root of the tree, whose children are the top level nodes.
+
+    Sub-classes implement child_count(path) and data(path), which returns a dict with the "text"
+    of the node, and call the notify_* functions when the tree changes.
+    """
+
+    def __init__(self):
+        self._listeners = []
+
+    def has_children(self, path):
+        return self.child_count(path) > 0
+
+    def attach_listener(self, listener):
+        self._listeners.append(weakref.ref(listener))
+
+    def notify_data_changed(self, path):
+        self._for_each_listener(lambda listener: listener._data_changed(tuple(path)))
+
+    def notify_children_changed(self, path):
+        self._for_each_listener(lambda listener: listener._children_changed(tuple(path)))
+
+    def notify_reset(self):
+        self._for_each_listener(lambda listener: listener._reset())
+
+    def _for_each_listener(self, f):
+        self._listeners = [ref for ref in self._listeners if ref() is not None]
+        for ref in list(self._listeners):
+            listener = ref()
+            if listener is not None:
+                f(listener)
+
+
+class FlattenedTreeModel(Model):
+    """Lists the nodes of a TreeModel, each followed by its descendants when it's expanded.
+
+    The children of a node are only queried from the tree when the node is expanded, which
+    happens when the view sets the row with "expanded" set to True, or when set_expanded()
+    is called.
+    """
+
+    def __init__(self, tree):
+        super().__init__()
+        self.tree = tree
+        # The nodes that are listed, in depth-first order, as [path, expanded]
+        self._rows = self._child_rows(())
+        tree.attach_listener(self)
+
+    def row_count(self):
+        return len(self._rows)
+
+    def row_data(self, row):
+        path, expanded = self._rows[row]
+        data = self.tree.data(path)
+        return {
+            "text": data["text"] if data is not None else "",
+            "level": len(path) - 1,
+            "has-children": self.tree.has_children(path),
+            "expanded": expanded,
+        }
+
+    def set_row_data(self, row, data):
+        self.set_expanded(row, data["expanded"])
+
+    def set_expanded(self, row, expanded):
+        if row < 0 or row >= len(self._rows) or self._rows[row][1] == expanded:
+            return
+        path = self._rows[row][0]
+        if expanded:
+            children = self._child_rows(path)
+            self._rows[row][1] = True
+            self._rows[row + 1:row + 1] = children
+            super().notify_row_changed(row)
+            if children:
+                super().notify_row_added(row + 1, len(children))
+        else:
+            end = self._end_of_descendants(row)
+            self._rows[row][1] = False
+            del self._rows[row + 1:end]
+            super().notify_row_changed(row)
+            if end > row + 1:
+                super().notify_row_removed(row + 1, end - row - 1)
+
+    def path(self, row):
+        return self._rows[row][0]
+
+    def row_of(self, path):
+        path = tuple(path)
+        for row, (row_path, _) in enumerate(self._rows):
+            if row_path == path:
+                return row
+        return None
+
+    def _child_rows(self, path):
+        return [[path + (index,), False] for index in range(self.tree.child_count(path))]
+
+    def _end_of_descendants(self, row):
+        level = len(self._rows[row][0])
+        end = row + 1
+        while end < len(self._rows) and len(self._rows[end][0]) > level:
+            end += 1
+        return end
+
+    def _data_changed(self, path):
+        row = self.row_of(path)
+        if row is not None:
+            super().notify_row_changed(row)
+
+    def _children_changed(self, path):
+        if not path:
+            return self._reset()
+        row = self.row_of(path)
+        if row is None:
+            return
+        if self._rows[row][1]:
+            # The descendants are listed again, collapsed, as their paths may have changed.
+            end = self._end_of_descendants(row)
+            del self._rows[row + 1:end]
+            if end > row + 1:
+                super().notify_row_removed(row + 1, end - row - 1)
+            children = self._child_rows(path)
+            self._rows[row][1] = bool(children)
+            self._rows[row + 1:row + 1] = children
+            if children:
+                super().notify_row_added(row + 1, len(children))
+        super().notify_row_changed(row)
+
+    def _reset(self):
+        self._rows = self._child_rows(())
+        super().notify_reset()
+
+
 class ModelIterator:
     def __init__(self, model):
         self.model = model
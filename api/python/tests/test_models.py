@@ -146,3 +146,55 @@ def test_model_writeback():
     assert list(instance.get_property("model")) == [100, 42]
     instance.invoke("write-to-model", 0, 25)
     assert list(instance.get_property("model")) == [25, 42]
+
+
+def test_flattened_tree_model():
+    class Tree(models.TreeModel):
+        def __init__(self):
+            super().__init__()
+            self.nodes = [("a", ["a1", "a2"]), ("b", [])]
+
+        def child_count(self, path):
+            if len(path) == 0:
+                return len(self.nodes)
+            return len(self.nodes[path[0]][1]) if len(path) == 1 else 0
+
+        def data(self, path):
+            node = self.nodes[path[0]]
+            return {"text": node[0] if len(path) == 1 else node[1][path[1]]}
+
+    compiler = native.ComponentCompiler()
+
+    compdef = compiler.build_from_source("""
+  export component App {
+    in-out property<[StandardTreeViewItem]> model;
+    out property<int> count: model.length;
+    callback expand(int);
+    expand(index) => {
+        self.model[index].expanded = true;
+    }
+  }
+    """, "")
+    assert compdef != None
+
+    instance = compdef.create()
+    assert instance != None
+
+    tree = Tree()
+    model = models.FlattenedTreeModel(tree)
+    instance.set_property("model", model)
+    assert instance.get_property("count") == 2
+    assert model.row_data(0)["has-children"]
+
+    instance.invoke("expand", 0)
+    assert [item["text"] for item in model] == ["a", "a1", "a2", "b"]
+    assert model.row_data(2)["level"] == 1
+    assert model.path(2) == (0, 1)
+    assert instance.get_property("count") == 4
+
+    del tree.nodes[0][1][0]
+    tree.notify_children_changed((0,))
+    assert [item["text"] for item in model] == ["a", "a2", "b"]
+
+    model.set_expanded(0, False)
+    assert instance.get_property("count") == 2
@@ -162,6 +162,32 @@ pub fn embed_resources(self, kind: EmbedResourcesKind) -> Self {
         };
         Self { config }
     }
+
+    /// Create a new configuration that bundles the translations in the generated code.
+    ///
+    /// The translations are loaded from the `.po` files in
+    /// `<path>/<language>/LC_MESSAGES/<crate name>.po`. A relative path is relative to the
+    /// directory of the crate's `Cargo.toml`. At runtime, the language is selected with
+    /// `slint::select_bundled_translation()`.
+    #[must_use]
+    pub fn with_bundled_translations(self, path: impl Into<std::path::PathBuf>) -> Self {
+        let mut config = self.config;
+        config.translation_path_bundle = Some(path.into());
+        Self { config }
+    }
+
+    /// Create a new configuration that generates the debug information about the elements:
+    /// their id and type name.
+    ///
+    /// Tests need it to find the elements by id or by type name with the `ElementHandle` of
+    /// the testing backend. It is off by default, unless the `SLINT_EMIT_DEBUG_INFO` environment
+    /// variable is set.
+    #[must_use]
+    pub fn with_debug_info(self, enable: bool) -> Self {
+        let mut config = self.config;
+        config.debug_info = enable;
+        Self { config }
+    }
 }
 
 /// Error returned by the `compile` function
@@ -356,8 +382,10 @@ pub fn compile_with_config(
     path: impl AsRef<std::path::Path>,
     config: CompilerConfiguration,
 ) -> Result<(), CompileError> {
-    let path = Path::new(&env::var_os("CARGO_MANIFEST_DIR").ok_or(CompileError::NotRunViaCargo)?)
-        .join(path.as_ref());
+    let manifest_dir = std::path::PathBuf::from(
+        env::var_os("CARGO_MANIFEST_DIR").ok_or(CompileError::NotRunViaCargo)?,
+    );
+    let path = manifest_dir.join(path.as_ref());
 
     let mut diag = BuildDiagnostics::default();
     let syntax_node = i_slint_compiler::parser::parse_file(&path, &mut diag);
@@ -370,12 +398,19 @@ pub fn compile_with_config(
 
     let mut compiler_config = config.config;
     compiler_config.translation_domain = std::env::var("CARGO_PKG_NAME").ok();
+    if let Some(translation_path) = compiler_config.translation_path_bundle.as_mut() {
+        *translation_path = manifest_dir.join(&*translation_path);
+        println!("cargo:rerun-if-changed={}", translation_path.display());
+    }
 
     let syntax_node = syntax_node.expect("diags contained no compilation errors");
 
     // 'spin_on' is ok here because the compiler in single threaded and does not block if there is no blocking future
-    let (doc, diag, _) =
-        spin_on::spin_on(i_slint_compiler::compile_syntax_node(syntax_node, diag, compiler_config));
+    let (doc, diag, _) = spin_on::spin_on(i_slint_compiler::compile_syntax_node(
+        syntax_node,
+        diag,
+        compiler_config.clone(),
+    ));
 
     if diag.has_error() {
         let vec = diag.to_string_vec();
@@ -393,7 +428,7 @@ pub fn compile_with_config(
 
     let file = std::fs::File::create(&output_file_path).map_err(CompileError::SaveError)?;
     let mut code_formatter = CodeFormatter::new(BufWriter::new(file));
-    let generated = i_slint_compiler::generator::rust::generate(&doc);
+    let generated = i_slint_compiler::generator::rust::generate(&doc, &compiler_config);
 
     for x in &diag.all_loaded_files {
         if x.is_absolute() {
@@ -421,6 +456,8 @@ pub fn compile_with_config(
     println!("cargo:rerun-if-env-changed=SLINT_SCALE_FACTOR");
     println!("cargo:rerun-if-env-changed=SLINT_ASSET_SECTION");
     println!("cargo:rerun-if-env-changed=SLINT_EMBED_RESOURCES");
+    println!("cargo:rerun-if-env-changed=SLINT_BUNDLE_TRANSLATIONS");
+    println!("cargo:rerun-if-env-changed=SLINT_EMIT_DEBUG_INFO");
 
     println!("cargo:rustc-env=SLINT_INCLUDE_GENERATED={}", output_file_path.display());
 
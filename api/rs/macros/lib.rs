@@ -327,6 +327,39 @@ fn extract_compiler_config(
                             _ => break,
                         }
                     }
+                    Some(TokenTree::Ident(bundle_ident))
+                        if bundle_ident.to_string() == "bundle_translations" =>
+                    {
+                        match (attr_stream.next(), attr_stream.next()) {
+                            (
+                                Some(TokenTree::Punct(equal_punct)),
+                                Some(TokenTree::Literal(path)),
+                            ) if equal_punct.as_char() == '=' => {
+                                compiler_config.translation_path_bundle = Some(extract_path(path));
+                            }
+                            _ => break,
+                        }
+                    }
+                    Some(TokenTree::Ident(domain_ident))
+                        if domain_ident.to_string() == "translation_domain" =>
+                    {
+                        match (attr_stream.next(), attr_stream.next()) {
+                            (
+                                Some(TokenTree::Punct(equal_punct)),
+                                Some(TokenTree::Literal(domain)),
+                            ) if equal_punct.as_char() == '=' => {
+                                compiler_config.translation_domain = domain
+                                    .to_string()
+                                    .strip_prefix('\"')
+                                    .unwrap()
+                                    .strip_suffix('\"')
+                                    .unwrap()
+                                    .to_string()
+                                    .into();
+                            }
+                            _ => break,
+                        }
+                    }
                     Some(TokenTree::Ident(style_ident)) if style_ident.to_string() == "style" => {
                         match (attr_stream.next(), attr_stream.next()) {
                             (
@@ -393,15 +426,17 @@ pub fn slint(stream: TokenStream) -> TokenStream {
     }
 
     //println!("{:#?}", syntax_node);
-    compiler_config.translation_domain = std::env::var("CARGO_PKG_NAME").ok();
+    if compiler_config.translation_domain.is_none() {
+        compiler_config.translation_domain = std::env::var("CARGO_PKG_NAME").ok();
+    }
     let (root_component, diag, _) =
-        spin_on::spin_on(compile_syntax_node(syntax_node, diag, compiler_config));
+        spin_on::spin_on(compile_syntax_node(syntax_node, diag, compiler_config.clone()));
     //println!("{:#?}", tree);
     if diag.has_error() {
         return diag.report_macro_diagnostic(&tokens);
     }
 
-    let mut result = generator::rust::generate(&root_component);
+    let mut result = generator::rust::generate(&root_component, &compiler_config);
 
     // Make sure to recompile if any of the external files changes
     let reload = diag
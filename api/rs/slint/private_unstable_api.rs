@@ -97,6 +97,49 @@ pub fn set_animated_property_binding_for_transition<
     )
 }
 
+pub fn set_animated_property_binding_with_keyframes<
+    T: Clone + i_slint_core::properties::InterpolatedPropertyValue + 'static,
+    StrongRef: StrongItemTreeRef + 'static,
+>(
+    property: Pin<&Property<T>>,
+    component_strong: &StrongRef,
+    binding: fn(StrongRef) -> T,
+    animation_data: PropertyAnimation,
+    keyframes: fn(StrongRef) -> SharedVector<AnimationKeyframe<T>>,
+) {
+    let weak_1 = component_strong.to_weak();
+    let weak_2 = weak_1.clone();
+    property.set_animated_binding_with_keyframes(
+        move || binding(<StrongRef as StrongItemTreeRef>::from_weak(&weak_1).unwrap()),
+        animation_data,
+        move || keyframes(<StrongRef as StrongItemTreeRef>::from_weak(&weak_2).unwrap()),
+    )
+}
+
+pub fn set_animated_property_binding_for_transition_with_keyframes<
+    T: Clone + i_slint_core::properties::InterpolatedPropertyValue + 'static,
+    StrongRef: StrongItemTreeRef + 'static,
+>(
+    property: Pin<&Property<T>>,
+    component_strong: &StrongRef,
+    binding: fn(StrongRef) -> T,
+    compute_animation_details: fn(
+        StrongRef,
+    ) -> (PropertyAnimation, i_slint_core::animations::Instant),
+    keyframes: fn(StrongRef) -> SharedVector<AnimationKeyframe<T>>,
+) {
+    let weak_1 = component_strong.to_weak();
+    let weak_2 = weak_1.clone();
+    let weak_3 = weak_1.clone();
+    property.set_animated_binding_for_transition_with_keyframes(
+        move || binding(<StrongRef as StrongItemTreeRef>::from_weak(&weak_1).unwrap()),
+        move || {
+            compute_animation_details(<StrongRef as StrongItemTreeRef>::from_weak(&weak_2).unwrap())
+        },
+        move || keyframes(<StrongRef as StrongItemTreeRef>::from_weak(&weak_3).unwrap()),
+    )
+}
+
 pub fn set_property_state_binding<StrongRef: StrongItemTreeRef + 'static>(
     property: Pin<&Property<StateInfo>>,
     component_strong: &StrongRef,
@@ -160,6 +203,24 @@ pub fn translate(
     i_slint_core::translations::translate(&origin, &context, &domain, args.as_slice(), n, &plural)
 }
 
+pub fn translate_from_bundle(strs: &[Option<&str>], args: Slice<SharedString>) -> SharedString {
+    i_slint_core::translations::translate_from_bundle(strs, args.as_slice())
+}
+
+pub fn translate_from_bundle_with_plural(
+    strs: &[Option<&[&str]>],
+    plural_rules: &[Option<fn(i32) -> usize>],
+    args: Slice<SharedString>,
+    n: i32,
+) -> SharedString {
+    i_slint_core::translations::translate_from_bundle_with_plural(
+        strs,
+        plural_rules,
+        args.as_slice(),
+        n,
+    )
+}
+
 #[cfg(feature = "gettext")]
 pub fn init_translations(domain: &str, dirname: impl Into<std::path::PathBuf>) {
     i_slint_core::translations::gettext_bindtextdomain(domain, dirname.into()).unwrap()
@@ -179,9 +240,12 @@ pub mod re_exports {
     // This one is empty when Qt is not available, which triggers a warning
     #[allow(unused_imports)]
     pub use i_slint_backend_selector::native_widgets::*;
-    pub use i_slint_core::accessibility::AccessibleStringProperty;
+    pub use i_slint_core::accessibility::{
+        AccessibilityAction, AccessibleStringProperty, SupportedAccessibilityAction,
+    };
     pub use i_slint_core::animations::{animation_tick, EasingCurve};
     pub use i_slint_core::callbacks::Callback;
+    pub use i_slint_core::date_time::first_day_of_week;
     pub use i_slint_core::graphics::*;
     pub use i_slint_core::input::{
         key_codes::Key, FocusEvent, InputEventResult, KeyEvent, KeyEventResult, KeyboardModifiers,
@@ -201,8 +265,11 @@ pub mod re_exports {
         logical_position_to_api, LogicalLength, LogicalPoint, LogicalRect,
     };
     pub use i_slint_core::model::*;
-    pub use i_slint_core::properties::{set_state_binding, Property, PropertyTracker, StateInfo};
+    pub use i_slint_core::properties::{
+        set_state_binding, AnimationKeyframe, Property, PropertyTracker, StateInfo,
+    };
     pub use i_slint_core::slice::Slice;
+    pub use i_slint_core::translations::set_bundled_languages;
     pub use i_slint_core::window::{
         InputMethodRequest, WindowAdapter, WindowAdapterRc, WindowInner,
     };
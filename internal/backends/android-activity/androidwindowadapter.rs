@@ -5,7 +5,7 @@
 use crate::javahelper::{print_jni_error, JavaHelper};
 use android_activity::input::{InputEvent, KeyAction, Keycode, MotionAction, MotionEvent};
 use android_activity::{InputStatus, MainEvent, PollEvent};
-use i_slint_core::api::{PhysicalPosition, PhysicalSize, PlatformError, Window};
+use i_slint_core::api::{LogicalPosition, PhysicalPosition, PhysicalSize, PlatformError, Window};
 use i_slint_core::platform::{
     Key, PointerEventButton, WindowAdapter, WindowEvent, WindowProperties,
 };
@@ -222,6 +222,20 @@ pub fn process_event(&self, event: &PollEvent<'_>) -> Result<ControlFlow<()>, Pl
         Ok(ControlFlow::Continue(()))
     }
 
+    /// The id and position of the pointer at `index`, or of the pointer that changed in this
+    /// event if `index` is None
+    fn touch_point(
+        &self,
+        motion_event: &MotionEvent,
+        index: Option<usize>,
+    ) -> (u64, LogicalPosition) {
+        let pointer = motion_event.pointer_at_index(index.unwrap_or(motion_event.pointer_index()));
+        let offset = self.offset.get();
+        let position =
+            PhysicalPosition { x: pointer.x() as i32 - offset.x, y: pointer.y() as i32 - offset.y };
+        (pointer.pointer_id() as u64, position.to_logical(self.window.scale_factor()))
+    }
+
     fn process_inputs(&self) -> Result<(), android_activity::error::AppError> {
         let mut iter = self.app.input_events_iter()?;
 
@@ -235,7 +249,13 @@ fn process_inputs(&self) -> Result<(), android_activity::error::AppError> {
                     None => InputStatus::Unhandled,
                 },
                 InputEvent::MotionEvent(motion_event) => match motion_event.action() {
-                    MotionAction::Down | MotionAction::ButtonPress | MotionAction::PointerDown => {
+                    MotionAction::Down | MotionAction::PointerDown => {
+                        let (touch_id, position) = self.touch_point(motion_event, None);
+                        self.window
+                            .dispatch_event(WindowEvent::TouchPressed { touch_id, position });
+                        InputStatus::Handled
+                    }
+                    MotionAction::ButtonPress => {
                         self.window.dispatch_event(WindowEvent::PointerPressed {
                             position: position_for_event(motion_event, self.offset.get())
                                 .to_logical(self.window.scale_factor()),
@@ -243,7 +263,13 @@ fn process_inputs(&self) -> Result<(), android_activity::error::AppError> {
                         });
                         InputStatus::Handled
                     }
-                    MotionAction::ButtonRelease | MotionAction::PointerUp => {
+                    MotionAction::PointerUp => {
+                        let (touch_id, position) = self.touch_point(motion_event, None);
+                        self.window
+                            .dispatch_event(WindowEvent::TouchReleased { touch_id, position });
+                        InputStatus::Handled
+                    }
+                    MotionAction::ButtonRelease => {
                         self.window.dispatch_event(WindowEvent::PointerReleased {
                             position: position_for_event(motion_event, self.offset.get())
                                 .to_logical(self.window.scale_factor()),
@@ -252,23 +278,38 @@ fn process_inputs(&self) -> Result<(), android_activity::error::AppError> {
                         InputStatus::Handled
                     }
                     MotionAction::Up => {
-                        self.window.dispatch_event(WindowEvent::PointerReleased {
-                            position: position_for_event(motion_event, self.offset.get())
-                                .to_logical(self.window.scale_factor()),
-                            button: PointerEventButton::Left,
-                        });
+                        let (touch_id, position) = self.touch_point(motion_event, None);
+                        self.window
+                            .dispatch_event(WindowEvent::TouchReleased { touch_id, position });
                         // Also send exit to avoid remaining hover state
                         self.window.dispatch_event(WindowEvent::PointerExited);
                         InputStatus::Handled
                     }
-                    MotionAction::Move | MotionAction::HoverMove => {
+                    MotionAction::Move => {
+                        for index in 0..motion_event.pointer_count() {
+                            let (touch_id, position) = self.touch_point(motion_event, Some(index));
+                            self.window
+                                .dispatch_event(WindowEvent::TouchMoved { touch_id, position });
+                        }
+                        InputStatus::Handled
+                    }
+                    MotionAction::HoverMove => {
                         self.window.dispatch_event(WindowEvent::PointerMoved {
                             position: position_for_event(motion_event, self.offset.get())
                                 .to_logical(self.window.scale_factor()),
                         });
                         InputStatus::Handled
                     }
-                    MotionAction::Cancel | MotionAction::Outside => {
+                    MotionAction::Cancel => {
+                        for index in 0..motion_event.pointer_count() {
+                            let (touch_id, position) = self.touch_point(motion_event, Some(index));
+                            self.window
+                                .dispatch_event(WindowEvent::TouchReleased { touch_id, position });
+                        }
+                        self.window.dispatch_event(WindowEvent::PointerExited);
+                        InputStatus::Handled
+                    }
+                    MotionAction::Outside => {
                         self.window.dispatch_event(WindowEvent::PointerExited);
                         InputStatus::Handled
                     }
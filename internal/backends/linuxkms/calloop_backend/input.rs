@@ -24,7 +24,7 @@
 use input::LibinputInterface;
 
 use input::event::keyboard::{KeyState, KeyboardEventTrait};
-use input::event::touch::TouchEventPosition;
+use input::event::touch::{TouchEventPosition, TouchEventSlot};
 use xkbcommon::*;
 
 use crate::fullscreenwindowadapter::FullscreenWindowAdapter;
@@ -115,7 +115,8 @@ pub struct LibInputHandler<'a> {
     libinput: input::Libinput,
     token: Option<calloop::Token>,
     mouse_pos: Pin<Rc<Property<Option<LogicalPosition>>>>,
-    last_touch_pos: LogicalPosition,
+    /// The last position of each touch point, by seat slot
+    touch_positions: HashMap<u32, LogicalPosition>,
     window: &'a RefCell<Option<Rc<FullscreenWindowAdapter>>>,
     keystate: Option<xkb::State>,
 }
@@ -137,7 +138,7 @@ pub fn init<T>(
             libinput,
             token: Default::default(),
             mouse_pos: mouse_pos_property.clone(),
-            last_touch_pos: Default::default(),
+            touch_positions: Default::default(),
             window,
             keystate: Default::default(),
         };
@@ -232,34 +233,50 @@ fn process_events<F>(
                         _ => {}
                     }
                 }
-                input::Event::Touch(touch_event) => {
-                    if let Some(event) = match touch_event {
-                        input::event::TouchEvent::Down(touch_down_event) => {
-                            self.last_touch_pos = LogicalPosition::new(
-                                touch_down_event.x_transformed(screen_size.width as u32) as _,
-                                touch_down_event.y_transformed(screen_size.height as u32) as _,
-                            );
-                            Some(WindowEvent::PointerPressed {
-                                position: self.last_touch_pos,
-                                button: PointerEventButton::Left,
-                            })
+                input::Event::Touch(touch_event) => match touch_event {
+                    input::event::TouchEvent::Down(touch_down_event) => {
+                        let slot = touch_down_event.seat_slot();
+                        let position = LogicalPosition::new(
+                            touch_down_event.x_transformed(screen_size.width as u32) as _,
+                            touch_down_event.y_transformed(screen_size.height as u32) as _,
+                        );
+                        self.touch_positions.insert(slot, position);
+                        window.dispatch_event(WindowEvent::TouchPressed {
+                            touch_id: slot as u64,
+                            position,
+                        });
+                    }
+                    input::event::TouchEvent::Up(touch_up_event) => {
+                        let slot = touch_up_event.seat_slot();
+                        if let Some(position) = self.touch_positions.remove(&slot) {
+                            window.dispatch_event(WindowEvent::TouchReleased {
+                                touch_id: slot as u64,
+                                position,
+                            });
                         }
-                        input::event::TouchEvent::Up(..) => Some(WindowEvent::PointerReleased {
-                            position: self.last_touch_pos,
-                            button: PointerEventButton::Left,
-                        }),
-                        input::event::TouchEvent::Motion(touch_motion_event) => {
-                            self.last_touch_pos = LogicalPosition::new(
-                                touch_motion_event.x_transformed(screen_size.width as u32) as _,
-                                touch_motion_event.y_transformed(screen_size.height as u32) as _,
-                            );
-                            Some(WindowEvent::PointerMoved { position: self.last_touch_pos })
+                    }
+                    input::event::TouchEvent::Motion(touch_motion_event) => {
+                        let slot = touch_motion_event.seat_slot();
+                        let position = LogicalPosition::new(
+                            touch_motion_event.x_transformed(screen_size.width as u32) as _,
+                            touch_motion_event.y_transformed(screen_size.height as u32) as _,
+                        );
+                        self.touch_positions.insert(slot, position);
+                        window.dispatch_event(WindowEvent::TouchMoved {
+                            touch_id: slot as u64,
+                            position,
+                        });
+                    }
+                    input::event::TouchEvent::Cancel(..) => {
+                        for (slot, position) in self.touch_positions.drain() {
+                            window.dispatch_event(WindowEvent::TouchReleased {
+                                touch_id: slot as u64,
+                                position,
+                            });
                         }
-                        _ => None,
-                    } {
-                        window.dispatch_event(event);
                     }
-                }
+                    _ => {}
+                },
                 input::Event::Keyboard(input::event::KeyboardEvent::Key(key_event)) => {
                     // On Linux key codes have a fixed offset of 8: https://docs.rs/xkbcommon/0.6.0/xkbcommon/xkb/struct.Keycode.html
                     let key_code = xkb::Keycode::new(key_event.key() + 8);
@@ -69,7 +69,11 @@ pub extern "C" fn slint_qt_get_widget(
     (qt_widgets::NativeComboBoxPopup,
     (qt_widgets::NativeTabWidget,
     (qt_widgets::NativeTab,
-            ()))))))))))))));
+    (qt_widgets::NativeMenuItem,
+    (qt_widgets::NativeMenuFrame,
+    (qt_widgets::NativeMenuBarItem,
+    (qt_widgets::NativeTooltip,
+            ()))))))))))))))))));
 
 #[cfg(not(no_qt))]
 #[rustfmt::skip]
@@ -307,4 +311,15 @@ fn click_interval(&self) -> core::time::Duration {
         };
         core::time::Duration::from_millis(duration_ms as u64)
     }
+
+    #[cfg(not(no_qt))]
+    fn tooltip_delay(&self) -> core::time::Duration {
+        let duration_ms = unsafe {
+            cpp::cpp! {[] -> u32 as "int" {
+                ensure_initialized();
+                return QApplication::style()->styleHint(QStyle::SH_ToolTip_WakeUpDelay);
+            }}
+        };
+        core::time::Duration::from_millis(duration_ms as u64)
+    }
 }
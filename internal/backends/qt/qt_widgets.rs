@@ -316,9 +316,15 @@ pub unsafe fn new(painter: &QPainterPtr) -> Self {
 mod combobox;
 pub use combobox::*;
 
+mod menu;
+pub use menu::*;
+
 mod tabwidget;
 pub use tabwidget::*;
 
+mod tooltip;
+pub use tooltip::*;
+
 mod stylemetrics;
 pub use stylemetrics::*;
 
@@ -270,6 +270,16 @@ fn input_event(
                 }
             }
             MouseEvent::Wheel { .. } => return InputEventResult::EventIgnored,
+            MouseEvent::DragMove { .. } | MouseEvent::DragDrop { .. } => {
+                return InputEventResult::EventIgnored
+            }
+            MouseEvent::TouchGesture { .. } => {
+                return if was_pressed {
+                    InputEventResult::GrabMouse
+                } else {
+                    InputEventResult::EventIgnored
+                }
+            }
         });
         if let MouseEvent::Released { position, .. } = event {
             let geo = self_rc.geometry();
@@ -0,0 +1,434 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+use i_slint_core::input::FocusEventResult;
+
+use super::*;
+
+cpp! {{
+    QStyleOptionMenuItem create_menu_item_option(
+            QString title, QString shortcut, bool checkable, bool checked,
+            bool has_sub_menu, bool is_separator) {
+        QStyleOptionMenuItem option;
+        option.font = qApp->font("QMenu");
+        option.fontMetrics = QFontMetrics(option.font);
+        if (is_separator) {
+            option.menuItemType = QStyleOptionMenuItem::Separator;
+        } else if (has_sub_menu) {
+            option.menuItemType = QStyleOptionMenuItem::SubMenu;
+        } else {
+            option.menuItemType = QStyleOptionMenuItem::Normal;
+        }
+        option.checkType = checkable ? QStyleOptionMenuItem::NonExclusive : QStyleOptionMenuItem::NotCheckable;
+        option.checked = checked;
+        option.text = title;
+        option.text.replace(QChar('&'), QLatin1String("&&"));
+        if (!shortcut.isEmpty()) {
+            option.text += QLatin1Char('\t') + shortcut;
+            option.reservedShortcutWidth = option.fontMetrics.horizontalAdvance(shortcut);
+        }
+        // Like QMenu, reserve the room of the check mark so that the titles are aligned
+        option.maxIconWidth = qApp->style()->pixelMetric(QStyle::PM_SmallIconSize, nullptr, nullptr) + 4;
+        return option;
+    }
+}}
+
+/// The entry of a menu, drawn like a QMenu entry
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct NativeMenuItem {
+    pub title: Property<SharedString>,
+    pub shortcut: Property<SharedString>,
+    pub checkable: Property<bool>,
+    pub checked: Property<bool>,
+    pub has_sub_menu: Property<bool>,
+    pub is_separator: Property<bool>,
+    pub enabled: Property<bool>,
+    pub has_hover: Property<bool>,
+    pub cached_rendering_data: CachedRenderingData,
+    widget_ptr: std::cell::Cell<SlintTypeErasedWidgetPtr>,
+    animation_tracker: Property<i32>,
+}
+
+impl Item for NativeMenuItem {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {
+        let animation_tracker_property_ptr = Self::FIELD_OFFSETS.animation_tracker.apply_pin(self);
+        self.widget_ptr.set(cpp! { unsafe [animation_tracker_property_ptr as "void*"] -> SlintTypeErasedWidgetPtr as "std::unique_ptr<SlintTypeErasedWidget>"  {
+            return make_unique_animated_widget<QWidget>(animation_tracker_property_ptr);
+        }})
+    }
+
+    fn layout_info(
+        self: Pin<&Self>,
+        orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        let title: qttypes::QString = self.title().as_str().into();
+        let shortcut: qttypes::QString = self.shortcut().as_str().into();
+        let checkable: bool = self.checkable();
+        let has_sub_menu: bool = self.has_sub_menu();
+        let is_separator: bool = self.is_separator();
+
+        let s = cpp!(unsafe [
+            title as "QString",
+            shortcut as "QString",
+            checkable as "bool",
+            has_sub_menu as "bool",
+            is_separator as "bool"
+        ] -> qttypes::QSize as "QSize" {
+            ensure_initialized();
+            auto option = create_menu_item_option(title, shortcut, checkable, false, has_sub_menu, is_separator);
+            // Same as QMenu: the size of the text is the contents, the style adds its margins
+            QSize contents(option.fontMetrics.horizontalAdvance(title) + option.reservedShortcutWidth,
+                           option.fontMetrics.height());
+            if (is_separator)
+                contents = QSize();
+            return qApp->style()->sizeFromContents(QStyle::CT_MenuItem, &option, contents, nullptr);
+        });
+        let min = match orientation {
+            Orientation::Horizontal => s.width,
+            Orientation::Vertical => s.height,
+        } as f32;
+        LayoutInfo { min, preferred: min, ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _event: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &i_slint_core::items::ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn_render! { this dpr size painter widget initial_state =>
+        let title: qttypes::QString = this.title().as_str().into();
+        let shortcut: qttypes::QString = this.shortcut().as_str().into();
+        let checkable: bool = this.checkable();
+        let checked: bool = this.checked();
+        let has_sub_menu: bool = this.has_sub_menu();
+        let is_separator: bool = this.is_separator();
+        let enabled: bool = this.enabled();
+        let has_hover: bool = this.has_hover();
+        cpp!(unsafe [
+            painter as "QPainterPtr*",
+            widget as "QWidget*",
+            size as "QSize",
+            dpr as "float",
+            title as "QString",
+            shortcut as "QString",
+            checkable as "bool",
+            checked as "bool",
+            has_sub_menu as "bool",
+            is_separator as "bool",
+            enabled as "bool",
+            has_hover as "bool",
+            initial_state as "int"
+        ] {
+            auto option = create_menu_item_option(title, shortcut, checkable, checked, has_sub_menu, is_separator);
+            option.styleObject = widget;
+            option.state |= QStyle::State(initial_state);
+            option.rect = QRect(QPoint(), size / dpr);
+            option.menuRect = option.rect;
+            if (enabled) {
+                option.state |= QStyle::State_Enabled;
+                if (has_hover)
+                    option.state |= QStyle::State_Selected;
+            } else {
+                option.palette.setCurrentColorGroup(QPalette::Disabled);
+            }
+            qApp->style()->drawControl(QStyle::CE_MenuItem, &option, painter->get(), widget);
+        });
+    }
+}
+
+impl ItemConsts for NativeMenuItem {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+declare_item_vtable! {
+fn slint_get_NativeMenuItemVTable() -> NativeMenuItemVTable for NativeMenuItem
+}
+
+/// The panel and frame of an open menu, drawn like a QMenu
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct NativeMenuFrame {
+    pub cached_rendering_data: CachedRenderingData,
+    pub native_padding_x: Property<LogicalLength>,
+    pub native_padding_y: Property<LogicalLength>,
+    widget_ptr: std::cell::Cell<SlintTypeErasedWidgetPtr>,
+    animation_tracker: Property<i32>,
+}
+
+impl Item for NativeMenuFrame {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {
+        let animation_tracker_property_ptr = Self::FIELD_OFFSETS.animation_tracker.apply_pin(self);
+        self.widget_ptr.set(cpp! { unsafe [animation_tracker_property_ptr as "void*"] -> SlintTypeErasedWidgetPtr as "std::unique_ptr<SlintTypeErasedWidget>"  {
+            return make_unique_animated_widget<QWidget>(animation_tracker_property_ptr);
+        }});
+
+        let padding = cpp!(unsafe [] -> qttypes::QSize as "QSize" {
+            ensure_initialized();
+            auto style = qApp->style();
+            int fw = style->pixelMetric(QStyle::PM_MenuPanelWidth, nullptr, nullptr);
+            return QSize(fw + style->pixelMetric(QStyle::PM_MenuHMargin, nullptr, nullptr),
+                         fw + style->pixelMetric(QStyle::PM_MenuVMargin, nullptr, nullptr));
+        });
+        self.native_padding_x.set(LogicalLength::new(padding.width as _));
+        self.native_padding_y.set(LogicalLength::new(padding.height as _));
+    }
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        Default::default()
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &i_slint_core::items::ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn_render! { _this dpr size painter widget initial_state =>
+        cpp!(unsafe [
+            painter as "QPainterPtr*",
+            widget as "QWidget*",
+            size as "QSize",
+            dpr as "float",
+            initial_state as "int"
+        ] {
+            auto style = qApp->style();
+            QStyleOptionMenuItem option;
+            option.styleObject = widget;
+            option.state |= QStyle::State(initial_state);
+            option.rect = QRect(QPoint(), size / dpr);
+            option.menuRect = option.rect;
+            option.checkType = QStyleOptionMenuItem::NotCheckable;
+            option.maxIconWidth = 0;
+            option.reservedShortcutWidth = 0;
+            style->drawPrimitive(QStyle::PE_PanelMenu, &option, painter->get(), widget);
+
+            int fw = style->pixelMetric(QStyle::PM_MenuPanelWidth, nullptr, widget);
+            option.rect = option.rect.adjusted(fw, fw, -fw, -fw);
+            style->drawControl(QStyle::CE_MenuEmptyArea, &option, painter->get(), widget);
+
+            if (fw) {
+                QStyleOptionFrame frame;
+                frame.styleObject = widget;
+                frame.rect = QRect(QPoint(), size / dpr);
+                frame.state = QStyle::State(initial_state);
+                frame.lineWidth = fw;
+                frame.midLineWidth = 0;
+                style->drawPrimitive(QStyle::PE_FrameMenu, &frame, painter->get(), widget);
+            }
+        });
+    }
+}
+
+impl ItemConsts for NativeMenuFrame {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+declare_item_vtable! {
+fn slint_get_NativeMenuFrameVTable() -> NativeMenuFrameVTable for NativeMenuFrame
+}
+
+/// The button of a top level entry of a menu bar, drawn like a QMenuBar entry
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct NativeMenuBarItem {
+    pub title: Property<SharedString>,
+    pub enabled: Property<bool>,
+    pub has_hover: Property<bool>,
+    pub pressed: Property<bool>,
+    pub cached_rendering_data: CachedRenderingData,
+    widget_ptr: std::cell::Cell<SlintTypeErasedWidgetPtr>,
+    animation_tracker: Property<i32>,
+}
+
+impl Item for NativeMenuBarItem {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {
+        let animation_tracker_property_ptr = Self::FIELD_OFFSETS.animation_tracker.apply_pin(self);
+        self.widget_ptr.set(cpp! { unsafe [animation_tracker_property_ptr as "void*"] -> SlintTypeErasedWidgetPtr as "std::unique_ptr<SlintTypeErasedWidget>"  {
+            return make_unique_animated_widget<QWidget>(animation_tracker_property_ptr);
+        }})
+    }
+
+    fn layout_info(
+        self: Pin<&Self>,
+        orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        let title: qttypes::QString = self.title().as_str().into();
+
+        let s = cpp!(unsafe [title as "QString"] -> qttypes::QSize as "QSize" {
+            ensure_initialized();
+            QStyleOptionMenuItem option;
+            option.font = qApp->font("QMenuBar");
+            option.fontMetrics = QFontMetrics(option.font);
+            option.menuItemType = QStyleOptionMenuItem::Normal;
+            option.checkType = QStyleOptionMenuItem::NotCheckable;
+            option.text = title;
+            option.text.replace(QChar('&'), QLatin1String("&&"));
+            // Same as QMenuBar
+            QSize contents = qApp->style()->itemTextRect(option.fontMetrics, QRect(), Qt::TextShowMnemonic, true, option.text).size();
+            return qApp->style()->sizeFromContents(QStyle::CT_MenuBarItem, &option, contents, nullptr);
+        });
+        let min = match orientation {
+            Orientation::Horizontal => s.width,
+            Orientation::Vertical => s.height,
+        } as f32;
+        LayoutInfo { min, preferred: min, ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _event: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &i_slint_core::items::ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn_render! { this dpr size painter widget initial_state =>
+        let title: qttypes::QString = this.title().as_str().into();
+        let enabled: bool = this.enabled();
+        let has_hover: bool = this.has_hover();
+        let pressed: bool = this.pressed();
+        cpp!(unsafe [
+            painter as "QPainterPtr*",
+            widget as "QWidget*",
+            size as "QSize",
+            dpr as "float",
+            title as "QString",
+            enabled as "bool",
+            has_hover as "bool",
+            pressed as "bool",
+            initial_state as "int"
+        ] {
+            QStyleOptionMenuItem option;
+            option.styleObject = widget;
+            option.state |= QStyle::State(initial_state);
+            option.font = qApp->font("QMenuBar");
+            option.fontMetrics = QFontMetrics(option.font);
+            option.rect = QRect(QPoint(), size / dpr);
+            option.menuRect = option.rect;
+            option.menuItemType = QStyleOptionMenuItem::Normal;
+            option.checkType = QStyleOptionMenuItem::NotCheckable;
+            option.text = title;
+            option.text.replace(QChar('&'), QLatin1String("&&"));
+            if (enabled) {
+                option.state |= QStyle::State_Enabled;
+                if (has_hover || pressed)
+                    option.state |= QStyle::State_Selected;
+                if (pressed)
+                    option.state |= QStyle::State_Sunken;
+            } else {
+                option.palette.setCurrentColorGroup(QPalette::Disabled);
+            }
+            qApp->style()->drawControl(QStyle::CE_MenuBarItem, &option, painter->get(), widget);
+        });
+    }
+}
+
+impl ItemConsts for NativeMenuBarItem {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+declare_item_vtable! {
+fn slint_get_NativeMenuBarItemVTable() -> NativeMenuBarItemVTable for NativeMenuBarItem
+}
@@ -221,6 +221,16 @@ fn input_event(
                     }
                     InputEventResult::EventAccepted
                 }
+                MouseEvent::TouchGesture { .. } => {
+                    if data.pressed != 0 {
+                        InputEventResult::GrabMouse
+                    } else {
+                        InputEventResult::EventIgnored
+                    }
+                }
+                MouseEvent::DragMove { .. } | MouseEvent::DragDrop { .. } => {
+                    InputEventResult::EventIgnored
+                }
             };
             self.data.set(data);
             result
@@ -238,6 +238,16 @@ fn input_event(
                 debug_assert_ne!(button, PointerEventButton::Left);
                 InputEventResult::EventIgnored
             }
+            MouseEvent::TouchGesture { .. } => {
+                if data.pressed != 0 {
+                    InputEventResult::GrabMouse
+                } else {
+                    InputEventResult::EventIgnored
+                }
+            }
+            MouseEvent::DragMove { .. } | MouseEvent::DragDrop { .. } => {
+                InputEventResult::EventIgnored
+            }
         };
         data.active_controls = new_control;
 
@@ -190,7 +190,10 @@ fn input_event(
                     }
                     true
                 }
-                MouseEvent::Moved { .. } => false,
+                MouseEvent::Moved { .. }
+                | MouseEvent::TouchGesture { .. }
+                | MouseEvent::DragMove { .. }
+                | MouseEvent::DragDrop { .. } => false,
                 MouseEvent::Wheel { delta_y, .. } => {
                     if delta_y < 0. {
                         let v = self.value();
@@ -0,0 +1,116 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+use i_slint_core::input::FocusEventResult;
+
+use super::*;
+
+cpp! {{
+    #include <QtWidgets/QToolTip>
+}}
+
+/// The panel of a tooltip, drawn like a QToolTip
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct NativeTooltip {
+    pub cached_rendering_data: CachedRenderingData,
+    pub native_padding_x: Property<LogicalLength>,
+    pub native_padding_y: Property<LogicalLength>,
+    pub native_text_color: Property<Color>,
+    widget_ptr: std::cell::Cell<SlintTypeErasedWidgetPtr>,
+    animation_tracker: Property<i32>,
+}
+
+impl Item for NativeTooltip {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {
+        let animation_tracker_property_ptr = Self::FIELD_OFFSETS.animation_tracker.apply_pin(self);
+        self.widget_ptr.set(cpp! { unsafe [animation_tracker_property_ptr as "void*"] -> SlintTypeErasedWidgetPtr as "std::unique_ptr<SlintTypeErasedWidget>"  {
+            return make_unique_animated_widget<QWidget>(animation_tracker_property_ptr);
+        }});
+
+        let padding = cpp!(unsafe [] -> i32 as "int" {
+            ensure_initialized();
+            // Same margin as the label of QToolTip
+            return 1 + qApp->style()->pixelMetric(QStyle::PM_ToolTipLabelFrameWidth, nullptr, nullptr);
+        });
+        self.native_padding_x.set(LogicalLength::new(padding as _));
+        self.native_padding_y.set(LogicalLength::new(padding as _));
+
+        let text_color = cpp!(unsafe [] -> u32 as "QRgb" {
+            return QToolTip::palette().color(QPalette::Inactive, QPalette::ToolTipText).rgba();
+        });
+        self.native_text_color.set(Color::from_argb_encoded(text_color));
+    }
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        Default::default()
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &i_slint_core::items::ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn_render! { _this dpr size painter widget initial_state =>
+        cpp!(unsafe [
+            painter as "QPainterPtr*",
+            widget as "QWidget*",
+            size as "QSize",
+            dpr as "float",
+            initial_state as "int"
+        ] {
+            // Same as QTipLabel::paintEvent
+            QStyleOptionFrame option;
+            option.styleObject = widget;
+            option.state |= QStyle::State(initial_state);
+            option.rect = QRect(QPoint(), size / dpr);
+            option.palette = QToolTip::palette();
+            qApp->style()->drawPrimitive(QStyle::PE_PanelTipLabel, &option, painter->get(), widget);
+        });
+    }
+}
+
+impl ItemConsts for NativeTooltip {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+declare_item_vtable! {
+fn slint_get_NativeTooltipVTable() -> NativeTooltipVTable for NativeTooltip
+}
@@ -50,6 +50,8 @@
     #include <QtGui/QWindow>
     #include <QtGui/QResizeEvent>
     #include <QtGui/QTextLayout>
+    #include <QtGui/QGlyphRun>
+    #include <QtGui/QRawFont>
     #include <QtGui/QImageReader>
     #include <QtGui/QCursor>
     #include <QtCore/QBasicTimer>
@@ -391,6 +393,22 @@ struct SlintWidget : QWidget {
         return -1;
     }
 
+    // Stroke the outline of the glyphs of the layout drawn at `position`. The pen is twice as wide
+    // as the stroke because the inner half is covered by the text drawn afterwards.
+    static void stroke_text_layout(QPainter *painter, const QTextLayout &layout, const QPointF &position, const QBrush &brush, qreal stroke_width) {
+        QPainterPath outline;
+        for (const auto &run : layout.glyphRuns()) {
+            auto raw_font = run.rawFont();
+            auto indexes = run.glyphIndexes();
+            auto positions = run.positions();
+            for (int i = 0; i < indexes.size(); ++i) {
+                outline.addPath(raw_font.pathForGlyph(indexes[i]).translated(positions[i]));
+            }
+        }
+        painter->strokePath(outline.translated(position + layout.position()),
+                            QPen(brush, stroke_width * 2, Qt::SolidLine, Qt::RoundCap, Qt::RoundJoin));
+    }
+
     QPainterPath to_painter_path(const QRectF &rect, qreal top_left_radius, qreal top_right_radius, qreal bottom_right_radius, qreal bottom_left_radius) {
         QPainterPath path;
         if (qFuzzyCompare(top_left_radius, top_right_radius) && qFuzzyCompare(top_left_radius, bottom_right_radius) && qFuzzyCompare(top_left_radius, bottom_left_radius)) {
@@ -662,10 +680,14 @@ fn draw_text(&mut self, text: std::pin::Pin<&items::Text>, _: &ItemRc, size: Log
         let fill_brush: qttypes::QBrush = into_qbrush(text.color(), rect.width, rect.height);
         let mut string: qttypes::QString = text.text().as_str().into();
         let font: QFont = get_font(text.font_request(WindowInner::from_pub(self.window)));
-        let flags = match text.horizontal_alignment() {
-            TextHorizontalAlignment::Left => key_generated::Qt_AlignmentFlag_AlignLeft,
+        let flags = match text.resolved_horizontal_alignment(WindowInner::from_pub(self.window)) {
+            TextHorizontalAlignment::Left | TextHorizontalAlignment::Start => {
+                key_generated::Qt_AlignmentFlag_AlignLeft
+            }
             TextHorizontalAlignment::Center => key_generated::Qt_AlignmentFlag_AlignHCenter,
-            TextHorizontalAlignment::Right => key_generated::Qt_AlignmentFlag_AlignRight,
+            TextHorizontalAlignment::Right | TextHorizontalAlignment::End => {
+                key_generated::Qt_AlignmentFlag_AlignRight
+            }
         } | match text.vertical_alignment() {
             TextVerticalAlignment::Top => key_generated::Qt_AlignmentFlag_AlignTop,
             TextVerticalAlignment::Center => key_generated::Qt_AlignmentFlag_AlignVCenter,
@@ -675,13 +697,30 @@ fn draw_text(&mut self, text: std::pin::Pin<&items::Text>, _: &ItemRc, size: Log
             TextWrap::WordWrap => key_generated::Qt_TextFlag_TextWordWrap,
         };
         let elide = text.overflow() == TextOverflow::Elide;
+        let (underline, strikethrough) = (text.font_underline(), text.font_strikethrough());
+        let stroke_brush: qttypes::QBrush = into_qbrush(text.stroke(), rect.width, rect.height);
+        let stroke_width: f32 =
+            if text.stroke().is_transparent() { 0. } else { text.stroke_width().get() };
         let painter: &mut QPainterPtr = &mut self.painter;
-        cpp! { unsafe [painter as "QPainterPtr*", rect as "QRectF", fill_brush as "QBrush", mut string as "QString", flags as "int", font as "QFont", elide as "bool"] {
+        cpp! { unsafe [painter as "QPainterPtr*", rect as "QRectF", fill_brush as "QBrush", mut string as "QString", flags as "int", mut font as "QFont", elide as "bool", underline as "bool", strikethrough as "bool", stroke_brush as "QBrush", stroke_width as "float"] {
+            font.setUnderline(underline);
+            font.setStrikeOut(strikethrough);
             (*painter)->setFont(font);
-            (*painter)->setPen(QPen(fill_brush, 0));
             (*painter)->setBrush(Qt::NoBrush);
+            auto draw_text = [&](const QString &text) {
+                if (stroke_width > 0) {
+                    // drawText doesn't give access to the glyphs, so lay the text out the same way
+                    QString laid_out = text;
+                    laid_out.replace(QChar('\n'), QChar::LineSeparator);
+                    QTextLayout layout(laid_out, font);
+                    do_text_layout(layout, flags, rect);
+                    stroke_text_layout(painter->get(), layout, rect.topLeft(), stroke_brush, stroke_width);
+                }
+                (*painter)->setPen(QPen(fill_brush, 0));
+                (*painter)->drawText(rect, flags, text);
+            };
             if (!elide) {
-                (*painter)->drawText(rect, flags, string);
+                draw_text(string);
             } else if (!(flags & Qt::TextWordWrap)) {
                 QString elided;
                 QFontMetrics fm(font);
@@ -696,7 +735,7 @@ fn draw_text(&mut self, text: std::pin::Pin<&items::Text>, _: &ItemRc, size: Log
                     elided += '\n';
                     string = string.mid(pos + 1);
                 }
-                (*painter)->drawText(rect, flags, elided);
+                draw_text(elided);
             } else {
                 // elide and word wrap: we need to add the ellipsis manually on the last line
                 string.replace(QChar('\n'), QChar::LineSeparator);
@@ -730,7 +769,7 @@ fn draw_text(&mut self, text: std::pin::Pin<&items::Text>, _: &ItemRc, size: Log
                     QString to_elide = QStringView(string).mid(last_line_begin, last_line_size).trimmed() % QStringView(QT_UNICODE_LITERAL("…"));
                     elided += fm.elidedText(to_elide, Qt::ElideRight, rect.width());
                 }
-                (*painter)->drawText(rect, flags, elided);
+                draw_text(elided);
             }
         }}
     }
@@ -746,10 +785,16 @@ fn draw_text_input(
 
         let font: QFont =
             get_font(text_input.font_request(&WindowInner::from_pub(self.window).window_adapter()));
-        let flags = match text_input.horizontal_alignment() {
-            TextHorizontalAlignment::Left => key_generated::Qt_AlignmentFlag_AlignLeft,
+        let flags = match text_input
+            .resolved_horizontal_alignment(&WindowInner::from_pub(self.window).window_adapter())
+        {
+            TextHorizontalAlignment::Left | TextHorizontalAlignment::Start => {
+                key_generated::Qt_AlignmentFlag_AlignLeft
+            }
             TextHorizontalAlignment::Center => key_generated::Qt_AlignmentFlag_AlignHCenter,
-            TextHorizontalAlignment::Right => key_generated::Qt_AlignmentFlag_AlignRight,
+            TextHorizontalAlignment::Right | TextHorizontalAlignment::End => {
+                key_generated::Qt_AlignmentFlag_AlignRight
+            }
         } | match text_input.vertical_alignment() {
             TextVerticalAlignment::Top => key_generated::Qt_AlignmentFlag_AlignTop,
             TextVerticalAlignment::Center => key_generated::Qt_AlignmentFlag_AlignVCenter,
@@ -813,19 +858,29 @@ fn draw_text_input(
             };
 
         let single_line: bool = text_input.single_line();
+        let (underline, strikethrough) =
+            (text_input.font_underline(), text_input.font_strikethrough());
+        let stroke_brush: qttypes::QBrush =
+            into_qbrush(text_input.stroke(), rect.width, rect.height);
+        let stroke_width: f32 =
+            if text_input.stroke().is_transparent() { 0. } else { text_input.stroke_width().get() };
 
         let painter: &mut QPainterPtr = &mut self.painter;
         cpp! { unsafe [
                 painter as "QPainterPtr*",
                 rect as "QRectF",
                 fill_brush as "QBrush",
+                stroke_brush as "QBrush",
+                stroke_width as "float",
+                underline as "bool",
+                strikethrough as "bool",
                 selection_foreground_color as "QRgb",
                 selection_background_color as "QRgb",
                 underline_selection as "bool",
                 mut string as "QString",
                 flags as "int",
                 single_line as "bool",
-                font as "QFont",
+                mut font as "QFont",
                 selection_start_position as "int",
                 selection_end_position as "int",
                 cursor_position as "int",
@@ -833,8 +888,13 @@ fn draw_text_input(
             if (!single_line) {
                 string.replace(QChar('\n'), QChar::LineSeparator);
             }
+            font.setUnderline(underline);
+            font.setStrikeOut(strikethrough);
             QTextLayout layout(string, font);
             do_text_layout(layout, flags, rect);
+            if (stroke_width > 0) {
+                stroke_text_layout(painter->get(), layout, rect.topLeft(), stroke_brush, stroke_width);
+            }
             (*painter)->setPen(QPen(fill_brush, 0));
             QVector<QTextLayout::FormatRange> selections;
             if (selection_end_position != selection_start_position) {
@@ -2044,11 +2104,20 @@ fn text_size(
         get_font(font_request).text_size(text, max_width.map(|logical_width| logical_width.get()))
     }
 
+    fn font_metrics(
+        &self,
+        font_request: FontRequest,
+        _scale_factor: ScaleFactor,
+    ) -> i_slint_core::graphics::FontMetrics {
+        get_font(font_request).font_metrics()
+    }
+
     fn text_input_byte_offset_for_position(
         &self,
         text_input: Pin<&i_slint_core::items::TextInput>,
         pos: LogicalPoint,
         font_request: FontRequest,
+        horizontal_alignment: i_slint_core::items::TextHorizontalAlignment,
         _scale_factor: ScaleFactor,
     ) -> usize {
         if pos.y < 0. {
@@ -2063,10 +2132,14 @@ fn text_input_byte_offset_for_position(
 
         let string = qttypes::QString::from(visual_representation.text.as_str());
 
-        let flags = match text_input.horizontal_alignment() {
-            TextHorizontalAlignment::Left => key_generated::Qt_AlignmentFlag_AlignLeft,
+        let flags = match horizontal_alignment {
+            TextHorizontalAlignment::Left | TextHorizontalAlignment::Start => {
+                key_generated::Qt_AlignmentFlag_AlignLeft
+            }
             TextHorizontalAlignment::Center => key_generated::Qt_AlignmentFlag_AlignHCenter,
-            TextHorizontalAlignment::Right => key_generated::Qt_AlignmentFlag_AlignRight,
+            TextHorizontalAlignment::Right | TextHorizontalAlignment::End => {
+                key_generated::Qt_AlignmentFlag_AlignRight
+            }
         } | match text_input.vertical_alignment() {
             TextVerticalAlignment::Top => key_generated::Qt_AlignmentFlag_AlignTop,
             TextVerticalAlignment::Center => key_generated::Qt_AlignmentFlag_AlignVCenter,
@@ -2112,6 +2185,7 @@ fn text_input_cursor_rect_for_byte_offset(
         text_input: Pin<&i_slint_core::items::TextInput>,
         byte_offset: usize,
         font_request: FontRequest,
+        horizontal_alignment: i_slint_core::items::TextHorizontalAlignment,
         _scale_factor: ScaleFactor,
     ) -> LogicalRect {
         let size = LogicalSize::new(text_input.width().get(), text_input.height().get());
@@ -2120,10 +2194,14 @@ fn text_input_cursor_rect_for_byte_offset(
         let text = text_input.text();
         let mut string = qttypes::QString::from(text.as_str());
         let offset: u32 = utf8_byte_offset_to_utf16_units(text.as_str(), byte_offset) as _;
-        let flags = match text_input.horizontal_alignment() {
-            TextHorizontalAlignment::Left => key_generated::Qt_AlignmentFlag_AlignLeft,
+        let flags = match horizontal_alignment {
+            TextHorizontalAlignment::Left | TextHorizontalAlignment::Start => {
+                key_generated::Qt_AlignmentFlag_AlignLeft
+            }
             TextHorizontalAlignment::Center => key_generated::Qt_AlignmentFlag_AlignHCenter,
-            TextHorizontalAlignment::Right => key_generated::Qt_AlignmentFlag_AlignRight,
+            TextHorizontalAlignment::Right | TextHorizontalAlignment::End => {
+                key_generated::Qt_AlignmentFlag_AlignRight
+            }
         } | match text_input.vertical_alignment() {
             TextVerticalAlignment::Top => key_generated::Qt_AlignmentFlag_AlignTop,
             TextVerticalAlignment::Center => key_generated::Qt_AlignmentFlag_AlignVCenter,
@@ -2273,6 +2351,16 @@ fn text_size(&self, text: &str, max_width: Option<f32>) -> LogicalSize {
         }};
         LogicalSize::new(size.width as _, size.height as _)
     }
+
+    fn font_metrics(&self) -> i_slint_core::graphics::FontMetrics {
+        let ascent = cpp! { unsafe [self as "const QFont*"] -> f32 as "float" {
+            return QFontMetricsF(*self).ascent();
+        }};
+        let descent = cpp! { unsafe [self as "const QFont*"] -> f32 as "float" {
+            return QFontMetricsF(*self).descent();
+        }};
+        i_slint_core::graphics::FontMetrics { ascent, descent }
+    }
 }
 
 thread_local! {
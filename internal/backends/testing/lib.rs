@@ -10,6 +10,8 @@
 use i_slint_core::lengths::{LogicalLength, LogicalPoint, LogicalRect, LogicalSize, ScaleFactor};
 use i_slint_core::platform::PlatformError;
 use i_slint_core::renderer::{Renderer, RendererSealed};
+#[cfg(feature = "snapshot")]
+use i_slint_core::software_renderer::{RepaintBufferType, SoftwareRenderer};
 use i_slint_core::window::{InputMethodRequest, WindowAdapter, WindowAdapterInternal};
 
 use std::cell::{Cell, RefCell};
@@ -17,9 +19,18 @@
 use std::rc::Rc;
 use std::sync::Mutex;
 
+mod search_api;
+pub use search_api::*;
+#[cfg(feature = "snapshot")]
+mod snapshot;
+#[cfg(feature = "snapshot")]
+pub use snapshot::*;
+
 pub struct TestingBackend {
     clipboard: Mutex<Option<String>>,
     queue: Option<Queue>,
+    #[cfg(feature = "snapshot")]
+    software_renderer: bool,
 }
 
 impl TestingBackend {
@@ -31,7 +42,19 @@ pub fn new() -> Self {
     }
 
     pub fn new_no_thread() -> Self {
-        Self { clipboard: Mutex::default(), queue: None }
+        Self {
+            clipboard: Mutex::default(),
+            queue: None,
+            #[cfg(feature = "snapshot")]
+            software_renderer: false,
+        }
+    }
+
+    /// Creates a backend whose windows render with the [`SoftwareRenderer`], so that
+    /// [`TestingWindow::take_snapshot()`] can be used.
+    #[cfg(feature = "snapshot")]
+    pub fn new_with_software_renderer() -> Self {
+        Self { software_renderer: true, ..Self::new_no_thread() }
     }
 }
 
@@ -44,6 +67,10 @@ fn create_window_adapter(
             size: Default::default(),
             ime_requests: Default::default(),
             mouse_cursor: Default::default(),
+            #[cfg(feature = "snapshot")]
+            software_renderer: self.software_renderer.then(|| {
+                SoftwareRenderer::new_with_repaint_buffer_type(RepaintBufferType::NewBuffer)
+            }),
         }))
     }
 
@@ -94,6 +121,8 @@ pub struct TestingWindow {
     size: Cell<PhysicalSize>,
     pub ime_requests: RefCell<Vec<InputMethodRequest>>,
     pub mouse_cursor: Cell<i_slint_core::items::MouseCursor>,
+    #[cfg(feature = "snapshot")]
+    software_renderer: Option<SoftwareRenderer>,
 }
 
 impl WindowAdapterInternal for TestingWindow {
@@ -131,6 +160,10 @@ fn set_size(&self, size: i_slint_core::api::WindowSize) {
     }
 
     fn renderer(&self) -> &dyn Renderer {
+        #[cfg(feature = "snapshot")]
+        if let Some(renderer) = &self.software_renderer {
+            return renderer;
+        }
         self
     }
 
@@ -157,12 +190,21 @@ fn text_size(
         LogicalSize::new(text.len() as f32 * 10., 10.)
     }
 
+    fn font_metrics(
+        &self,
+        _font_request: i_slint_core::graphics::FontRequest,
+        _scale_factor: ScaleFactor,
+    ) -> i_slint_core::graphics::FontMetrics {
+        i_slint_core::graphics::FontMetrics { ascent: 7., descent: 3. }
+    }
+
     // this works only for single line text
     fn text_input_byte_offset_for_position(
         &self,
         text_input: Pin<&i_slint_core::items::TextInput>,
         pos: LogicalPoint,
         _font_request: FontRequest,
+        _horizontal_alignment: i_slint_core::items::TextHorizontalAlignment,
         _scale_factor: ScaleFactor,
     ) -> usize {
         let text_len = text_input.text().len();
@@ -176,6 +218,7 @@ fn text_input_cursor_rect_for_byte_offset(
         _text_input: Pin<&i_slint_core::items::TextInput>,
         byte_offset: usize,
         _font_request: FontRequest,
+        _horizontal_alignment: i_slint_core::items::TextHorizontalAlignment,
         _scale_factor: ScaleFactor,
     ) -> LogicalRect {
         LogicalRect::new(Point2D::new(byte_offset as f32 * 10., 0.), Size2D::new(1., 10.))
@@ -239,6 +282,17 @@ pub fn init() {
         .expect("platform already initialized");
 }
 
+/// Initialize the testing backend with windows that render with the software renderer.
+/// Use [`TestingWindow::take_snapshot()`] or [`take_snapshot()`] to get the rendered image.
+/// Must be called before any call that would otherwise initialize the rendering backend.
+///
+/// This requires the `snapshot` feature.
+#[cfg(feature = "snapshot")]
+pub fn init_with_software_renderer() {
+    i_slint_core::platform::set_platform(Box::new(TestingBackend::new_with_software_renderer()))
+        .expect("platform already initialized");
+}
+
 /// Initialize the testing backend with support for simple event loop.
 /// This function can only be called once per process, so make sure to use integration
 /// tests with one `#[test]` function.
@@ -256,6 +310,15 @@ mod for_unit_test {
     use i_slint_core::window::WindowInner;
     use i_slint_core::SharedString;
 
+    /// Advance the mocked time by `duration`, stopping at the timeout of each timer in
+    /// between: timers fire in order and repeated timers fire once per elapsed interval.
+    ///
+    /// Unlike [`mock_elapsed_time()`], which jumps straight to the new time and fires a repeated
+    /// timer at most once, this makes tests depending on timers and animations deterministic.
+    pub fn advance_time(duration: core::time::Duration) {
+        i_slint_core::tests::slint_mock_advance_time(duration.as_millis() as u64);
+    }
+
     /// Simulate a mouse click
     pub fn send_mouse_click<
         X: vtable::HasStaticVTable<i_slint_core::item_tree::ItemTreeVTable> + 'static,
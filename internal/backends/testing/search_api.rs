@@ -0,0 +1,318 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! API to find the elements of a component and to interact with them in tests,
+//! without hard-coding their position in the window.
+
+use core::ops::ControlFlow;
+use i_slint_core::accessibility::{AccessibilityAction, AccessibleStringProperty};
+use i_slint_core::api::{ComponentHandle, LogicalPosition, LogicalSize};
+use i_slint_core::item_tree::{ItemRc, ItemWeak};
+use i_slint_core::items::{AccessibleRole, PointerEventButton, Text, TextInput};
+use i_slint_core::platform::WindowEvent;
+use i_slint_core::window::{WindowAdapter, WindowInner};
+use i_slint_core::SharedString;
+use std::rc::Rc;
+
+/// A handle to an element of a component, obtained by searching the component with one
+/// of the `find_by_*` functions or with [`ElementHandle::visit_elements()`].
+///
+/// The handle doesn't keep the element alive: use [`Self::is_valid()`] to check that the
+/// element still exists. The functions of an invalid handle return `None` or do nothing.
+///
+/// The element ids and type names are only known if the debug information was generated.
+/// The interpreter always provides it. The Rust and C++ code generators only generate it when
+/// enabled with `slint_build::CompilerConfiguration::with_debug_info(true)`, with the
+/// `SLINT_EMIT_DEBUG_INFO` property of the CMake target, or when the `SLINT_EMIT_DEBUG_INFO`
+/// environment variable is set at compile time. Without it, [`Self::find_by_element_id()`] and
+/// [`Self::find_by_element_type_name()`] find nothing and print a warning.
+///
+/// Several elements can be merged into the same item when the components are inlined (for
+/// example `ok := Button {}` and the root of the `Button` component). These elements have
+/// their own handle, but they share the same geometry and accessible properties.
+#[derive(Clone)]
+pub struct ElementHandle {
+    item: ItemWeak,
+    element_index: usize,
+}
+
+impl ElementHandle {
+    /// Calls `visitor` for each element of the component, until the visitor returns
+    /// `ControlFlow::Break`, in which case the value of the break is returned.
+    pub fn visit_elements<R>(
+        component: &impl ComponentHandle,
+        mut visitor: impl FnMut(ElementHandle) -> ControlFlow<R>,
+    ) -> Option<R> {
+        let root = ItemRc::new(WindowInner::from_pub(component.window()).component(), 0);
+        match visit_item(&root, &mut visitor) {
+            ControlFlow::Break(r) => Some(r),
+            ControlFlow::Continue(()) => None,
+        }
+    }
+
+    /// Returns all the elements with the given qualified id, in the form `ComponentName::element-id`.
+    /// The id of the root element of a component is `root`.
+    pub fn find_by_element_id(component: &impl ComponentHandle, id: &str) -> Vec<ElementHandle> {
+        Self::find_all_with_debug_info(component, "find_by_element_id", |e| {
+            e.id().as_deref() == Some(id)
+        })
+    }
+
+    /// Returns all the elements of the given type, for example `Button` or `Rectangle`.
+    pub fn find_by_element_type_name(
+        component: &impl ComponentHandle,
+        type_name: &str,
+    ) -> Vec<ElementHandle> {
+        Self::find_all_with_debug_info(component, "find_by_element_type_name", |e| {
+            e.type_name().as_deref() == Some(type_name)
+        })
+    }
+
+    /// Returns all the elements whose `accessible-label` is `label`.
+    pub fn find_by_accessible_label(
+        component: &impl ComponentHandle,
+        label: &str,
+    ) -> Vec<ElementHandle> {
+        Self::find_all(component, |e| {
+            e.element_index == 0 && e.accessible_label().as_deref() == Some(label)
+        })
+    }
+
+    /// Returns all the elements whose `accessible-role` is `role`.
+    pub fn find_by_accessible_role(
+        component: &impl ComponentHandle,
+        role: AccessibleRole,
+    ) -> Vec<ElementHandle> {
+        Self::find_all(component, |e| e.element_index == 0 && e.accessible_role() == Some(role))
+    }
+
+    fn find_all(
+        component: &impl ComponentHandle,
+        mut filter: impl FnMut(&ElementHandle) -> bool,
+    ) -> Vec<ElementHandle> {
+        let mut result = Vec::new();
+        Self::visit_elements(component, |e| {
+            if filter(&e) {
+                result.push(e);
+            }
+            ControlFlow::<()>::Continue(())
+        });
+        result
+    }
+
+    /// Same as `find_all`, but warns when none of the elements has debug information, as the
+    /// filter can't match anything then.
+    fn find_all_with_debug_info(
+        component: &impl ComponentHandle,
+        function_name: &str,
+        mut filter: impl FnMut(&ElementHandle) -> bool,
+    ) -> Vec<ElementHandle> {
+        let mut has_debug_info = false;
+        let result = Self::find_all(component, |e| {
+            has_debug_info |= e.element_info().is_some();
+            filter(e)
+        });
+        if !has_debug_info {
+            i_slint_core::debug_log!(
+                "ElementHandle::{function_name}(): the debug information about the elements wasn't \
+                 generated. Enable it with slint_build::CompilerConfiguration::with_debug_info(true), \
+                 the SLINT_EMIT_DEBUG_INFO property of the CMake target, or by setting the \
+                 SLINT_EMIT_DEBUG_INFO environment variable when compiling the .slint files."
+            );
+        }
+        result
+    }
+
+    /// Returns true if the element still exists.
+    pub fn is_valid(&self) -> bool {
+        self.item.upgrade().is_some()
+    }
+
+    /// Returns the qualified id of the element (`ComponentName::element-id`), or None if the
+    /// element doesn't have an id or if the debug information isn't available.
+    pub fn id(&self) -> Option<SharedString> {
+        self.element_info().and_then(|(id, _)| (!id.is_empty()).then(|| id.into()))
+    }
+
+    /// Returns the type name of the element, or None if the debug information isn't available.
+    pub fn type_name(&self) -> Option<SharedString> {
+        self.element_info().map(|(_, type_name)| type_name.into())
+    }
+
+    fn element_info(&self) -> Option<(String, String)> {
+        let infos = self.item.upgrade()?.element_infos()?;
+        let (id, type_name) = infos.lines().nth(self.element_index)?.split_once(',')?;
+        Some((id.into(), type_name.into()))
+    }
+
+    /// Returns the `accessible-role` of the element.
+    pub fn accessible_role(&self) -> Option<AccessibleRole> {
+        self.item.upgrade().map(|item| item.accessible_role())
+    }
+
+    /// Returns the `accessible-label` of the element, if set.
+    pub fn accessible_label(&self) -> Option<SharedString> {
+        self.accessible_string_property(AccessibleStringProperty::Label)
+    }
+
+    /// Returns the `accessible-description` of the element, if set.
+    pub fn accessible_description(&self) -> Option<SharedString> {
+        self.accessible_string_property(AccessibleStringProperty::Description)
+    }
+
+    /// Returns the `accessible-value` of the element, if set.
+    pub fn accessible_value(&self) -> Option<SharedString> {
+        self.accessible_string_property(AccessibleStringProperty::Value)
+    }
+
+    /// Returns the `accessible-value-minimum` of the element, if set.
+    pub fn accessible_value_minimum(&self) -> Option<f32> {
+        self.accessible_string_property(AccessibleStringProperty::ValueMinimum)
+            .and_then(|v| v.parse().ok())
+    }
+
+    /// Returns the `accessible-value-maximum` of the element, if set.
+    pub fn accessible_value_maximum(&self) -> Option<f32> {
+        self.accessible_string_property(AccessibleStringProperty::ValueMaximum)
+            .and_then(|v| v.parse().ok())
+    }
+
+    /// Returns the `accessible-checked` of the element, if set.
+    pub fn accessible_checked(&self) -> Option<bool> {
+        self.accessible_string_property(AccessibleStringProperty::Checked).map(|v| v == "true")
+    }
+
+    fn accessible_string_property(&self, what: AccessibleStringProperty) -> Option<SharedString> {
+        self.item
+            .upgrade()
+            .map(|item| item.accessible_string_property(what))
+            .filter(|value| !value.is_empty())
+    }
+
+    /// Sets the value of the element, by invoking its `accessible-action-set-value` callback.
+    pub fn set_accessible_value(&self, value: impl Into<SharedString>) {
+        self.accessible_action(&AccessibilityAction::SetValue(value.into()));
+    }
+
+    /// Invokes the `accessible-action-default` callback of the element.
+    pub fn invoke_accessible_default_action(&self) {
+        self.accessible_action(&AccessibilityAction::Default);
+    }
+
+    /// Invokes the `accessible-action-increment` callback of the element.
+    pub fn invoke_accessible_increment_action(&self) {
+        self.accessible_action(&AccessibilityAction::Increment);
+    }
+
+    /// Invokes the `accessible-action-decrement` callback of the element.
+    pub fn invoke_accessible_decrement_action(&self) {
+        self.accessible_action(&AccessibilityAction::Decrement);
+    }
+
+    fn accessible_action(&self, action: &AccessibilityAction) {
+        if let Some(item) = self.item.upgrade() {
+            item.accessible_action(action);
+        }
+    }
+
+    /// Returns the text of the element if it's a `Text` or a `TextInput`.
+    pub fn text(&self) -> Option<SharedString> {
+        let item = self.item.upgrade()?;
+        if let Some(text) = item.downcast::<Text>() {
+            Some(text.as_pin_ref().text())
+        } else {
+            item.downcast::<TextInput>().map(|text_input| text_input.as_pin_ref().text())
+        }
+    }
+
+    /// Returns the size of the element.
+    pub fn size(&self) -> LogicalSize {
+        self.item
+            .upgrade()
+            .map(|item| {
+                let size = item.geometry().size;
+                LogicalSize::new(size.width, size.height)
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the position of the element within the window.
+    pub fn absolute_position(&self) -> LogicalPosition {
+        self.item
+            .upgrade()
+            .map(|item| {
+                let position = item.map_to_window(item.geometry().origin);
+                LogicalPosition::new(position.x, position.y)
+            })
+            .unwrap_or_default()
+    }
+
+    fn center(&self) -> LogicalPosition {
+        let position = self.absolute_position();
+        let size = self.size();
+        LogicalPosition::new(position.x + size.width / 2., position.y + size.height / 2.)
+    }
+
+    fn window_adapter(&self) -> Option<Rc<dyn WindowAdapter>> {
+        let item = self.item.upgrade()?;
+        let mut window_adapter = None;
+        vtable::VRc::borrow_pin(item.item_tree())
+            .as_ref()
+            .window_adapter(false, &mut window_adapter);
+        window_adapter
+    }
+
+    /// Simulates a click with the given button in the middle of the element.
+    pub fn single_click(&self, button: PointerEventButton) {
+        let Some(window_adapter) = self.window_adapter() else { return };
+        let position = self.center();
+        let window = window_adapter.window();
+        window.dispatch_event(WindowEvent::PointerMoved { position });
+        window.dispatch_event(WindowEvent::PointerPressed { position, button });
+        i_slint_core::tests::slint_mock_elapsed_time(50);
+        window.dispatch_event(WindowEvent::PointerReleased { position, button });
+    }
+
+    /// Simulates a scroll of the mouse wheel in the middle of the element, by the given
+    /// amount of logical pixels.
+    pub fn scroll(&self, delta_x: f32, delta_y: f32) {
+        let Some(window_adapter) = self.window_adapter() else { return };
+        let position = self.center();
+        let window = window_adapter.window();
+        window.dispatch_event(WindowEvent::PointerMoved { position });
+        window.dispatch_event(WindowEvent::PointerScrolled { position, delta_x, delta_y });
+    }
+
+    /// Gives the focus to the element, or to the next element that accepts the focus.
+    pub fn focus(&self) {
+        let (Some(item), Some(window_adapter)) = (self.item.upgrade(), self.window_adapter())
+        else {
+            return;
+        };
+        WindowInner::from_pub(window_adapter.window()).set_focus_item(&item);
+    }
+
+    /// Gives the focus to the element and simulates typing the given text with the keyboard.
+    pub fn type_text(&self, text: &str) {
+        self.focus();
+        if let Some(window_adapter) = self.window_adapter() {
+            i_slint_core::tests::send_keyboard_string_sequence(&text.into(), &window_adapter);
+        }
+    }
+}
+
+fn visit_item<R>(
+    item: &ItemRc,
+    visitor: &mut impl FnMut(ElementHandle) -> ControlFlow<R>,
+) -> ControlFlow<R> {
+    let element_count = item.element_infos().map_or(1, |infos| infos.lines().count().max(1));
+    for element_index in 0..element_count {
+        visitor(ElementHandle { item: item.downgrade(), element_index })?;
+    }
+    let mut child = item.first_child();
+    while let Some(c) = child {
+        visit_item(&c, visitor)?;
+        child = c.next_sibling();
+    }
+    ControlFlow::Continue(())
+}
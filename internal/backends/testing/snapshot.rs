@@ -0,0 +1,229 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! API to capture the rendered frame of a window and to compare it with a reference image.
+
+use crate::TestingWindow;
+use i_slint_core::graphics::{euclid, Rgba8Pixel, SharedPixelBuffer};
+use i_slint_core::lengths::{LogicalSize, ScaleFactor};
+use i_slint_core::platform::PlatformError;
+use i_slint_core::software_renderer::PremultipliedRgbaColor;
+use i_slint_core::window::WindowInner;
+use std::path::{Path, PathBuf};
+
+impl TestingWindow {
+    /// Renders the window with the software renderer and returns the resulting image.
+    ///
+    /// This requires the testing backend to be initialized with
+    /// [`init_with_software_renderer()`](crate::init_with_software_renderer).
+    pub fn take_snapshot(&self) -> Result<SharedPixelBuffer<Rgba8Pixel>, PlatformError> {
+        let Some(renderer) = self.software_renderer.as_ref() else {
+            return Err(PlatformError::Other(
+                "take_snapshot requires the testing backend to use the software renderer".into(),
+            ));
+        };
+
+        let window_inner = WindowInner::from_pub(&self.window);
+        let size = match window_inner.window_item() {
+            Some(window_item) => {
+                let window_item = window_item.as_pin_ref();
+                (LogicalSize::from_lengths(window_item.width(), window_item.height())
+                    * ScaleFactor::new(window_inner.scale_factor()))
+                .ceil()
+                .cast::<u32>()
+            }
+            None => {
+                let size = i_slint_core::window::WindowAdapter::size(self);
+                euclid::size2(size.width, size.height)
+            }
+        };
+
+        let mut buffer =
+            vec![PremultipliedRgbaColor::default(); size.width as usize * size.height as usize];
+        renderer.render(&mut buffer, size.width as usize);
+
+        let mut snapshot = SharedPixelBuffer::<Rgba8Pixel>::new(size.width, size.height);
+        for (pixel, color) in snapshot.make_mut_slice().iter_mut().zip(buffer) {
+            *pixel = unpremultiply(color);
+        }
+        Ok(snapshot)
+    }
+}
+
+/// Renders the given window, which must be a window of the testing backend initialized with
+/// [`init_with_software_renderer()`](crate::init_with_software_renderer), and returns the
+/// resulting image.
+pub fn take_snapshot(
+    window: &i_slint_core::api::Window,
+) -> Result<SharedPixelBuffer<Rgba8Pixel>, PlatformError> {
+    crate::access_testing_window(window, |window| window.take_snapshot())
+}
+
+fn unpremultiply(color: PremultipliedRgbaColor) -> Rgba8Pixel {
+    if color.alpha == 0 {
+        return Rgba8Pixel::new(0, 0, 0, 0);
+    }
+    let unpremultiply = |c: u8| (c as u16 * 255 / color.alpha as u16).min(255) as u8;
+    Rgba8Pixel::new(
+        unpremultiply(color.red),
+        unpremultiply(color.green),
+        unpremultiply(color.blue),
+        color.alpha,
+    )
+}
+
+/// Options for [`compare_with_reference()`]
+#[derive(Clone, Debug)]
+pub struct ImageComparisonOptions {
+    /// The maximum distance between the colors of a pixel in the two images, as the euclidean
+    /// distance of their RGBA components, for the pixel to be considered equal.
+    pub color_tolerance: f32,
+    /// The maximum percentage of pixels that may differ for the images to be considered equal.
+    pub max_different_pixels_percentage: f32,
+    /// When set, an image highlighting the pixels that differ is written to this path if the
+    /// comparison fails.
+    pub diff_image_path: Option<PathBuf>,
+}
+
+impl Default for ImageComparisonOptions {
+    fn default() -> Self {
+        Self { color_tolerance: 0., max_different_pixels_percentage: 0., diff_image_path: None }
+    }
+}
+
+/// The error returned by [`compare_with_reference()`]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ImageComparisonError {
+    /// The reference image could not be loaded, or the diff image could not be saved
+    Image(image::ImageError),
+    /// The images don't have the same size
+    SizeMismatch { reference_size: (u32, u32), snapshot_size: (u32, u32) },
+    /// Too many pixels differ between the two images
+    PixelsMismatch { different_pixels_percentage: f32, max_color_difference: f32 },
+}
+
+impl core::fmt::Display for ImageComparisonError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Image(err) => write!(f, "image error: {err}"),
+            Self::SizeMismatch { reference_size, snapshot_size } => write!(
+                f,
+                "image sizes don't match. reference size {reference_size:?} rendered size {snapshot_size:?}"
+            ),
+            Self::PixelsMismatch { different_pixels_percentage, max_color_difference } => write!(
+                f,
+                "images are not equal. Percentage of pixels that are different: {different_pixels_percentage}. Maximum color difference: {max_color_difference}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ImageComparisonError {}
+
+impl From<image::ImageError> for ImageComparisonError {
+    fn from(err: image::ImageError) -> Self {
+        Self::Image(err)
+    }
+}
+
+fn color_difference(lhs: &Rgba8Pixel, rhs: &Rgba8Pixel) -> f32 {
+    ((rhs.r as f32 - lhs.r as f32).powi(2)
+        + (rhs.g as f32 - lhs.g as f32).powi(2)
+        + (rhs.b as f32 - lhs.b as f32).powi(2)
+        + (rhs.a as f32 - lhs.a as f32).powi(2))
+    .sqrt()
+}
+
+/// Compares a snapshot, as returned by [`take_snapshot()`], with the reference PNG image at the
+/// given path.
+///
+/// If the `SLINT_CREATE_SCREENSHOTS` environment variable is set to `1` and the comparison fails,
+/// the snapshot is saved as the new reference image.
+pub fn compare_with_reference(
+    snapshot: &SharedPixelBuffer<Rgba8Pixel>,
+    reference_path: impl AsRef<Path>,
+    options: &ImageComparisonOptions,
+) -> Result<(), ImageComparisonError> {
+    let reference_path = reference_path.as_ref();
+    let result = compare(snapshot, reference_path, options);
+    if result.is_err() && std::env::var("SLINT_CREATE_SCREENSHOTS").map_or(false, |var| var == "1")
+    {
+        save_snapshot(snapshot, reference_path)?;
+    }
+    result
+}
+
+fn compare(
+    snapshot: &SharedPixelBuffer<Rgba8Pixel>,
+    reference_path: &Path,
+    options: &ImageComparisonOptions,
+) -> Result<(), ImageComparisonError> {
+    let reference = image::open(reference_path)?.into_rgba8();
+    let reference = SharedPixelBuffer::<Rgba8Pixel>::clone_from_slice(
+        reference.as_raw(),
+        reference.width(),
+        reference.height(),
+    );
+
+    if reference.size() != snapshot.size() {
+        return Err(ImageComparisonError::SizeMismatch {
+            reference_size: (reference.width(), reference.height()),
+            snapshot_size: (snapshot.width(), snapshot.height()),
+        });
+    }
+
+    let differences = reference
+        .as_slice()
+        .iter()
+        .zip(snapshot.as_slice())
+        .map(|(reference_pixel, snapshot_pixel)| color_difference(reference_pixel, snapshot_pixel))
+        .collect::<Vec<_>>();
+    let different_pixels = differences.iter().filter(|d| **d > options.color_tolerance).count();
+    let different_pixels_percentage =
+        different_pixels as f32 * 100. / differences.len().max(1) as f32;
+    if different_pixels_percentage <= options.max_different_pixels_percentage {
+        return Ok(());
+    }
+
+    if let Some(diff_image_path) = &options.diff_image_path {
+        let mut diff = SharedPixelBuffer::<Rgba8Pixel>::new(reference.width(), reference.height());
+        for ((pixel, reference_pixel), difference) in
+            diff.make_mut_slice().iter_mut().zip(reference.as_slice()).zip(&differences)
+        {
+            *pixel = if *difference > options.color_tolerance {
+                Rgba8Pixel::new(255, 0, 0, 255)
+            } else {
+                // Fade the pixels that are equal so that the differences stand out
+                let fade = |c: u8| (c as u16 / 4 + 191) as u8;
+                Rgba8Pixel::new(
+                    fade(reference_pixel.r),
+                    fade(reference_pixel.g),
+                    fade(reference_pixel.b),
+                    255,
+                )
+            };
+        }
+        save_snapshot(&diff, diff_image_path)?;
+    }
+
+    Err(ImageComparisonError::PixelsMismatch {
+        different_pixels_percentage,
+        max_color_difference: differences.into_iter().fold(0., f32::max),
+    })
+}
+
+/// Saves a snapshot, as returned by [`take_snapshot()`], as a PNG image, for example to create
+/// the reference image used by [`compare_with_reference()`].
+pub fn save_snapshot(
+    buffer: &SharedPixelBuffer<Rgba8Pixel>,
+    path: impl AsRef<Path>,
+) -> Result<(), image::ImageError> {
+    image::save_buffer(
+        path.as_ref(),
+        buffer.as_bytes(),
+        buffer.width(),
+        buffer.height(),
+        image::ColorType::Rgba8,
+    )
+}
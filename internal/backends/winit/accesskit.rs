@@ -9,9 +9,11 @@
 use std::sync::{Arc, Condvar, Mutex};
 
 use accesskit::{
-    Action, ActionRequest, Checked, Node, NodeBuilder, NodeId, Role, Tree, TreeUpdate,
+    Action, ActionData, ActionRequest, Checked, Node, NodeBuilder, NodeId, Role, Tree, TreeUpdate,
+};
+use i_slint_core::accessibility::{
+    AccessibilityAction, AccessibleStringProperty, SupportedAccessibilityAction,
 };
-use i_slint_core::accessibility::AccessibleStringProperty;
 use i_slint_core::item_tree::{ItemTreeRc, ItemTreeRef, ItemTreeWeak};
 use i_slint_core::items::{ItemRc, WindowItem};
 use i_slint_core::lengths::ScaleFactor;
@@ -119,10 +121,23 @@ fn focus_node(&self) -> NodeId {
 
     fn handle_request(&self, request: ActionRequest) {
         let Some(window_adapter) = self.window_adapter_weak.upgrade() else { return };
-        if request.action == Action::Focus {
-            if let Some(item) = self.item_rc_for_node_id(request.target) {
+        let Some(item) = self.item_rc_for_node_id(request.target) else { return };
+        match request.action {
+            Action::Focus => {
                 WindowInner::from_pub(window_adapter.window()).set_focus_item(&item);
             }
+            Action::Default => item.accessible_action(&AccessibilityAction::Default),
+            Action::Decrement => item.accessible_action(&AccessibilityAction::Decrement),
+            Action::Increment => item.accessible_action(&AccessibilityAction::Increment),
+            Action::SetValue => {
+                let value = match request.data {
+                    Some(ActionData::Value(value)) => value.as_ref().into(),
+                    Some(ActionData::NumericValue(value)) => value.to_string().into(),
+                    _ => return,
+                };
+                item.accessible_action(&AccessibilityAction::SetValue(value));
+            }
+            _ => {}
         }
     }
 
@@ -364,6 +379,16 @@ fn build_node_without_children(&self, item: &ItemRc, scale_factor: ScaleFactor)
                     i_slint_core::items::AccessibleRole::ProgressIndicator => {
                         Role::ProgressIndicator
                     }
+                    i_slint_core::items::AccessibleRole::List => Role::List,
+                    i_slint_core::items::AccessibleRole::ListItem => Role::ListItem,
+                    i_slint_core::items::AccessibleRole::Table => Role::Table,
+                    i_slint_core::items::AccessibleRole::Tree => Role::Tree,
+                    i_slint_core::items::AccessibleRole::Menu => Role::Menu,
+                    i_slint_core::items::AccessibleRole::MenuItem => Role::MenuItem,
+                    i_slint_core::items::AccessibleRole::TextInput => Role::TextInput,
+                    i_slint_core::items::AccessibleRole::Image => Role::Image,
+                    i_slint_core::items::AccessibleRole::Group => Role::Group,
+                    i_slint_core::items::AccessibleRole::Dialog => Role::Dialog,
                 },
                 item.accessible_string_property(
                     i_slint_core::accessibility::AccessibleStringProperty::Label,
@@ -413,6 +438,20 @@ fn build_node_without_children(&self, item: &ItemRc, scale_factor: ScaleFactor)
             builder.add_action(Action::Focus);
         }
 
+        let supported = item.supported_accessibility_actions();
+        if supported.contains(SupportedAccessibilityAction::Default) {
+            builder.add_action(Action::Default);
+        }
+        if supported.contains(SupportedAccessibilityAction::Decrement) {
+            builder.add_action(Action::Decrement);
+        }
+        if supported.contains(SupportedAccessibilityAction::Increment) {
+            builder.add_action(Action::Increment);
+        }
+        if supported.contains(SupportedAccessibilityAction::SetValue) {
+            builder.add_action(Action::SetValue);
+        }
+
         let min = item.accessible_string_property(AccessibleStringProperty::ValueMinimum);
         let max = item.accessible_string_property(AccessibleStringProperty::ValueMaximum);
         let step = item.accessible_string_property(AccessibleStringProperty::ValueStep);
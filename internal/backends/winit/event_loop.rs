@@ -245,11 +245,53 @@ pub struct EventLoopState {
     // last seen cursor position
     cursor_pos: LogicalPoint,
     pressed: bool,
+    // The `file://` URIs of the files currently dragged over, or dropped on, the window
+    hovered_files: Vec<String>,
+    // winit sends one event per file. The window for which HoveredFile (false) or
+    // DroppedFile (true) events were received and not dispatched yet.
+    pending_file_event: Option<(Weak<WinitWindowAdapter>, bool)>,
 
     loop_error: Option<PlatformError>,
 }
 
+/// Returns the `file://` URI of the given path, as used in the `text/uri-list` MIME type
+fn file_uri(path: &std::path::Path) -> String {
+    let path = path.to_string_lossy();
+    #[cfg(windows)]
+    let path = path.replace('\\', "/");
+    let mut uri = String::from(if path.starts_with('/') { "file://" } else { "file:///" });
+    for byte in path.bytes() {
+        if byte.is_ascii_alphanumeric() || b"/-_.~:".contains(&byte) {
+            uri.push(byte as char);
+        } else {
+            uri.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    uri
+}
+
 impl EventLoopState {
+    /// Dispatch the files collected from the last HoveredFile or DroppedFile events as
+    /// one `text/uri-list` drag or drop event.
+    fn flush_file_event(&mut self) {
+        let Some((window, dropped)) = self.pending_file_event.take() else { return };
+        let Some(window) = window.upgrade() else {
+            self.hovered_files.clear();
+            return;
+        };
+        // winit doesn't report the position of the file drag and drop, so use the last
+        // position reported with a CursorMoved event
+        let position = corelib::api::LogicalPosition::new(self.cursor_pos.x, self.cursor_pos.y);
+        let mime_type = "text/uri-list".into();
+        let data = self.hovered_files.join("\r\n").into();
+        window.window().dispatch_event(if dropped {
+            self.hovered_files.clear();
+            corelib::platform::WindowEvent::DragDropped { position, mime_type, data }
+        } else {
+            corelib::platform::WindowEvent::DragMoved { position, mime_type, data }
+        });
+    }
+
     fn process_window_event(&mut self, window: Rc<WinitWindowAdapter>, event: WindowEvent) {
         let runtime_window = WindowInner::from_pub(window.window());
         match event {
@@ -345,6 +387,11 @@ macro_rules! winit_key_to_char {
                 let position = position.to_logical(runtime_window.scale_factor() as f64);
                 self.cursor_pos = euclid::point2(position.x, position.y);
                 runtime_window.process_mouse_input(MouseEvent::Moved { position: self.cursor_pos });
+                // Some platforms keep reporting the cursor while files are dragged over the window
+                if !self.hovered_files.is_empty() {
+                    self.pending_file_event = Some((Rc::downgrade(&window), false));
+                    self.flush_file_event();
+                }
             }
             WindowEvent::CursorLeft { .. } => {
                 // On the html canvas, we don't get the mouse move or release event when outside the canvas. So we have no choice but canceling the event
@@ -390,27 +437,22 @@ macro_rules! winit_key_to_char {
             }
             WindowEvent::Touch(touch) => {
                 let location = touch.location.to_logical(runtime_window.scale_factor() as f64);
-                let position = euclid::point2(location.x, location.y);
+                let position = corelib::api::LogicalPosition::new(location.x, location.y);
+                let touch_id = touch.id;
                 let ev = match touch.phase {
                     winit::event::TouchPhase::Started => {
                         self.pressed = true;
-                        MouseEvent::Pressed {
-                            position,
-                            button: PointerEventButton::Left,
-                            click_count: 0,
-                        }
+                        corelib::platform::WindowEvent::TouchPressed { touch_id, position }
                     }
                     winit::event::TouchPhase::Ended | winit::event::TouchPhase::Cancelled => {
                         self.pressed = false;
-                        MouseEvent::Released {
-                            position,
-                            button: PointerEventButton::Left,
-                            click_count: 0,
-                        }
+                        corelib::platform::WindowEvent::TouchReleased { touch_id, position }
+                    }
+                    winit::event::TouchPhase::Moved => {
+                        corelib::platform::WindowEvent::TouchMoved { touch_id, position }
                     }
-                    winit::event::TouchPhase::Moved => MouseEvent::Moved { position },
                 };
-                runtime_window.process_mouse_input(ev);
+                window.window().dispatch_event(ev);
             }
             WindowEvent::ScaleFactorChanged { scale_factor, inner_size_writer: _ } => {
                 if std::env::var("SLINT_SCALE_FACTOR").is_err() {
@@ -423,6 +465,29 @@ macro_rules! winit_key_to_char {
                     //window.resize_event(inner_size_writer.???)?;
                 }
             }
+            WindowEvent::HoveredFile(path) => {
+                self.hovered_files.push(file_uri(&path));
+                self.pending_file_event = Some((Rc::downgrade(&window), false));
+            }
+            WindowEvent::HoveredFileCancelled => {
+                self.pending_file_event = None;
+                self.hovered_files.clear();
+                window.window().dispatch_event(corelib::platform::WindowEvent::DragExited);
+            }
+            WindowEvent::DroppedFile(path) => {
+                let window_weak = Rc::downgrade(&window);
+                let is_same_drop = matches!(
+                    &self.pending_file_event,
+                    Some((w, true)) if Weak::ptr_eq(w, &window_weak)
+                );
+                if !is_same_drop {
+                    // The first dropped file replaces the list of the hovered files
+                    self.flush_file_event();
+                    self.hovered_files.clear();
+                }
+                self.hovered_files.push(file_uri(&path));
+                self.pending_file_event = Some((window_weak, true));
+            }
             WindowEvent::ThemeChanged(theme) => {
                 window.set_dark_color_scheme(theme == winit::window::Theme::Dark)
             }
@@ -443,6 +508,18 @@ fn process_event(
     ) {
         use winit::event_loop::ControlFlow;
 
+        // The events for the files of a drag and drop come one after the other, so dispatch
+        // them all at once before handling any other event.
+        if !matches!(
+            event,
+            Event::WindowEvent {
+                event: WindowEvent::HoveredFile(_) | WindowEvent::DroppedFile(_),
+                ..
+            }
+        ) {
+            self.flush_file_event();
+        }
+
         match event {
             Event::WindowEvent { event, window_id } => {
                 if let Some(window) = window_by_id(window_id) {
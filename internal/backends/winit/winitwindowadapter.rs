@@ -68,6 +68,7 @@ fn icon_to_winit(icon: corelib::graphics::Image) -> Option<winit::window::Icon>
 
     let pixel_buffer = match image_inner {
         ImageInner::EmbeddedImage { buffer, .. } => buffer.clone(),
+        ImageInner::Animated(animated) => animated.frames[0].buffer.clone(),
         _ => return None,
     };
 
@@ -86,6 +86,19 @@ struct PointerScrollEvent {
                 }
             }
 
+            /// This structure is passed to the `can-drop` and `dropped` callbacks of the `DropArea` element.
+            struct DropEvent {
+                @name = "slint::private_api::DropEvent"
+                export {
+                    /// The MIME type of the data, for example `text/plain` or `text/uri-list`
+                    mime_type: SharedString,
+                    /// The data being dragged
+                    data: SharedString,
+                }
+                private {
+                }
+            }
+
             /// This structure is generated and passed to the key press and release callbacks of the `FocusScope` element.
             struct KeyEvent {
                 @name = "slint::private_api::KeyEvent"
@@ -127,6 +140,56 @@ struct StandardListViewItem {
                 }
             }
 
+            /// Represents an item in a StandardTreeView: a node of a tree, listed with its expanded descendants.
+            #[non_exhaustive]
+            struct StandardTreeViewItem {
+                @name = "slint::StandardTreeViewItem"
+                export {
+                    /// The text content of the item
+                    text: SharedString,
+                    /// The depth of the item in the tree, 0 for the top level items
+                    level: i32,
+                    /// Whether the item has children, which are listed after it when it's expanded
+                    has_children: bool,
+                    /// Whether the children of the item are shown
+                    expanded: bool,
+                }
+                private {
+                }
+            }
+
+            /// Represents a date of the Gregorian calendar, as used by the DatePicker.
+            #[derive(Copy, Eq)]
+            struct Date {
+                @name = "slint::Date"
+                export {
+                    /// The year, for example 2024
+                    year: i32,
+                    /// The month, from 1 for January to 12 for December
+                    month: i32,
+                    /// The day of the month, starting at 1
+                    day: i32,
+                }
+                private {
+                }
+            }
+
+            /// Represents a time of the day, as used by the TimePicker.
+            #[derive(Copy, Eq)]
+            struct Time {
+                @name = "slint::Time"
+                export {
+                    /// The hour, from 0 to 23
+                    hour: i32,
+                    /// The minute, from 0 to 59
+                    minute: i32,
+                    /// The second, from 0 to 59
+                    second: i32,
+                }
+                private {
+                }
+            }
+
             /// This is used to define the column and the column header of a TableView
             #[non_exhaustive]
             struct TableColumn {
@@ -28,6 +28,12 @@ enum TextHorizontalAlignment {
                 Center,
                 /// The text will be aligned to the right of the containing box.
                 Right,
+                /// The text will be aligned with the start edge of the containing box: the left edge, or the right edge
+                /// when the `layout-direction` of the window is `right-to-left`.
+                Start,
+                /// The text will be aligned with the end edge of the containing box: the right edge, or the left edge
+                /// when the `layout-direction` of the window is `right-to-left`.
+                End,
             }
 
             /// This enum describes the different types of alignment of text along the vertical axis of a [`Text`](elements.md#text) element.
@@ -307,6 +313,29 @@ enum LayoutAlignment {
                 SpaceAround,
             }
 
+            /// Enum representing the `cross-alignment` property of a [`FlowLayout`](elements.md#flowlayout).
+            /// It describes how the elements are placed within their row, or their column for a vertical flow.
+            enum LayoutCrossAlignment {
+                /// Resize the elements to the size of the row, within their minimum and maximum size.
+                Stretch,
+                /// Use the preferred size of the elements and place them at the start of the row.
+                Start,
+                /// Use the preferred size of the elements and center them within the row.
+                Center,
+                /// Use the preferred size of the elements and place them at the end of the row.
+                End,
+            }
+
+            /// This enum describes the direction in which the content of a [`Window`](elements.md#window) is laid out.
+            /// It's the type of the window's `layout-direction` property.
+            enum LayoutDirection {
+                /// Horizontal layouts place their first element on the left.
+                LeftToRight,
+                /// Horizontal layouts are mirrored and place their first element on the right, as needed for
+                /// right-to-left languages such as Arabic or Hebrew.
+                RightToLeft,
+            }
+
             /// PathEvent is a low-level data structure describing the composition of a path. Typically it is
             /// generated at compile time from a higher-level description, such as SVG commands.
             enum PathEvent {
@@ -345,6 +374,26 @@ enum AccessibleRole {
                 Text,
                 /// The element is a [`ProgressIndicator`](../widgets/progressindicator.md) or behaves like one.
                 ProgressIndicator,
+                /// The element is a list, such as a [`ListView`](../widgets/listview.md), or behaves like one.
+                List,
+                /// The element is an item in a list.
+                ListItem,
+                /// The element is a table, such as a [`StandardTableView`](../widgets/standardtableview.md), or behaves like one.
+                Table,
+                /// The element is a tree of items that can be expanded and collapsed.
+                Tree,
+                /// The element is a menu or a menu bar.
+                Menu,
+                /// The element is an entry in a menu.
+                MenuItem,
+                /// The element is a [`LineEdit`](../widgets/lineedit.md), a [`TextEdit`](../widgets/textedit.md), or another element where text can be entered.
+                TextInput,
+                /// The element is an [`Image`](elements.md#image) that conveys information.
+                Image,
+                /// The element groups other elements, such as a [`GroupBox`](../widgets/groupbox.md).
+                Group,
+                /// The element is a [`Dialog`](elements.md#dialog) or behaves like one.
+                Dialog,
             }
 
             /// This enum represents the different values of the `sort-order` property.
@@ -367,6 +416,70 @@ enum Orientation {
                 /// Element is oriented vertically.
                 Vertical,
             }
+
+            /// This enum describes the direction in which a [property animation](../syntax/animations.md) plays its iterations.
+            enum AnimationDirection {
+                /// Every iteration plays forward.
+                Normal,
+                /// Every iteration plays backward.
+                Reverse,
+                /// The first iteration plays forward, then every other iteration plays backward.
+                Alternate,
+                /// The first iteration plays backward, then every other iteration plays forward.
+                AlternateReverse,
+            }
+
+            /// This enum describes when a [`PopupWindow`](elements.md#popupwindow) closes automatically.
+            enum PopupClosePolicy {
+                /// Closes the `PopupWindow` when the user clicks, inside or outside of the popup.
+                CloseOnClick,
+                /// Closes the `PopupWindow` only when the user clicks outside of the popup.
+                CloseOnClickOutside,
+                /// Does not close the `PopupWindow` automatically. Call `close()` to close it.
+                NoAutoClose,
+            }
+
+            /// This enum describes where a [`PopupWindow`](elements.md#popupwindow) is placed relative to its anchor.
+            /// When the popup doesn't fit in the window on the preferred side of the anchor, it's placed on the opposite side,
+            /// and it's then moved so that it stays inside the window.
+            /// The start of the anchor is its left side, or its right side when the `layout-direction` of the window is `right-to-left`.
+            enum PopupPlacement {
+                /// The popup is placed at its `x` and `y` coordinates, relative to its parent element.
+                Fixed,
+                /// The popup is placed below the anchor, aligned with its start.
+                Below,
+                /// The popup is placed above the anchor, aligned with its start.
+                Above,
+                /// The popup is placed before the start of the anchor, aligned with its top.
+                Start,
+                /// The popup is placed after the end of the anchor, aligned with its top.
+                End,
+            }
+
+            /// This enum describes the platform independent keyboard shortcuts, such as the one to copy the selection.
+            /// The keys of a shortcut depend on the platform, for example <kbd>Ctrl</kbd>+<kbd>C</kbd> or <kbd>⌘</kbd>+<kbd>C</kbd> for `copy`.
+            enum StandardShortcut {
+                /// Copy something.
+                Copy,
+                /// Cut something.
+                Cut,
+                /// Paste something.
+                Paste,
+                /// Select all.
+                SelectAll,
+                /// Find or search something.
+                Find,
+                /// Save something.
+                Save,
+                /// Print something.
+                Print,
+                /// Undo the last action.
+                Undo,
+                /// Redo the last undone action.
+                Redo,
+                /// Refresh.
+                Refresh,
+            }
         ];
     };
 }
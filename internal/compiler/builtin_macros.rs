@@ -62,10 +62,68 @@ pub fn lower_macro(
 
             expr
         }
+        BuiltinMacroFunction::Steps => steps_macro(n, sub_expr.collect(), diag),
+        BuiltinMacroFunction::Spring => spring_macro(n, sub_expr.collect(), diag),
         BuiltinMacroFunction::Rgb => rgb_macro(n, sub_expr.collect(), diag),
+        BuiltinMacroFunction::ArraySplice => array_splice_macro(n, sub_expr.collect(), diag),
     }
 }
 
+/// Returns the value of a (possibly negated) number literal without unit
+fn number_literal(expr: &Expression) -> Option<f64> {
+    match expr {
+        Expression::NumberLiteral(val, Unit::None) => Some(*val),
+        Expression::UnaryOp { sub, op: '-' } => number_literal(sub).map(|val| -val),
+        _ => None,
+    }
+}
+
+fn steps_macro(
+    node: Option<NodeOrToken>,
+    args: Vec<(Expression, Option<NodeOrToken>)>,
+    diag: &mut BuildDiagnostics,
+) -> Expression {
+    if args.len() != 1 {
+        diag.push_error("Needs 1 argument".into(), &node);
+        return Expression::Invalid;
+    }
+    match number_literal(&args[0].0) {
+        Some(steps) if steps >= 1. && steps.fract() == 0. && steps <= u32::MAX as f64 => {
+            Expression::EasingCurve(EasingCurve::Steps(steps as u32))
+        }
+        _ => {
+            diag.push_error(
+                "Argument to steps must be a positive integer literal".into(),
+                &args[0].1,
+            );
+            Expression::Invalid
+        }
+    }
+}
+
+fn spring_macro(
+    node: Option<NodeOrToken>,
+    args: Vec<(Expression, Option<NodeOrToken>)>,
+    diag: &mut BuildDiagnostics,
+) -> Expression {
+    if args.len() != 2 && args.len() != 3 {
+        diag.push_error("Needs 2 or 3 arguments (stiffness, damping, and mass)".into(), &node);
+        return Expression::Invalid;
+    }
+    let mut params = [0., 0., 1.];
+    for (param, (expr, n)) in params.iter_mut().zip(args.iter()) {
+        match number_literal(expr) {
+            Some(val) if val > 0. => *param = val as f32,
+            _ => {
+                diag.push_error("Arguments to spring must be positive number literals".into(), n);
+                return Expression::Invalid;
+            }
+        }
+    }
+    let [stiffness, damping, mass] = params;
+    Expression::EasingCurve(EasingCurve::Spring(stiffness, damping, mass))
+}
+
 fn min_max_macro(
     node: Option<NodeOrToken>,
     op: MinMaxOp,
@@ -255,6 +313,38 @@ fn debug_macro(
     }
 }
 
+fn array_splice_macro(
+    node: Option<NodeOrToken>,
+    args: Vec<(Expression, Option<NodeOrToken>)>,
+    diag: &mut BuildDiagnostics,
+) -> Expression {
+    let [(array, _), (length, length_node), (tail, tail_node)]: [_; 3] = match args.try_into() {
+        Ok(args) => args,
+        Err(_) => {
+            diag.push_error("Needs 3 arguments (array, length, and tail)".into(), &node);
+            return Expression::Invalid;
+        }
+    };
+    let array_ty = array.ty();
+    if !matches!(array_ty, Type::Array(_)) {
+        diag.push_error("The first argument must be an array".into(), &node);
+        return Expression::Invalid;
+    }
+    let sl = node.map(|node| node.to_source_location());
+    Expression::FunctionCall {
+        function: Box::new(Expression::BuiltinFunctionReference(
+            BuiltinFunction::ArraySplice,
+            sl.clone(),
+        )),
+        arguments: vec![
+            array,
+            length.maybe_convert_to(Type::Int32, &length_node, diag),
+            tail.maybe_convert_to(array_ty, &tail_node, diag),
+        ],
+        source_location: sl,
+    }
+}
+
 fn to_debug_string(
     expr: Expression,
     node: Option<NodeOrToken>,
@@ -30,6 +30,11 @@ pub struct Texture {
     pub rect: Rect,
     pub data: Vec<u8>,
     pub format: PixelFormat,
+    /// The frames of an animated image, empty if the image is not animated.
+    /// The texture itself is the first frame.
+    pub frames: Vec<Texture>,
+    /// How long the texture is shown as a frame of an animated image, in milliseconds
+    pub frame_duration: u32,
 }
 
 #[cfg(feature = "software-renderer")]
@@ -41,6 +46,8 @@ pub fn new_empty() -> Self {
             rect: Rect::from_xywh(0, 0, 1, 1).unwrap(),
             data: vec![0, 0, 0, 0],
             format: PixelFormat::Rgba,
+            frames: Vec::new(),
+            frame_duration: 0,
         }
     }
 }
@@ -21,6 +21,7 @@
 pub enum BuiltinFunction {
     GetWindowScaleFactor,
     GetWindowDefaultFontSize,
+    GetWindowLayoutDirection,
     AnimationTick,
     Debug,
     Mod,
@@ -54,10 +55,18 @@ pub enum BuiltinFunction {
     ColorMix,
     ColorWithAlpha,
     ImageSize,
+    /// the `event.matches-shortcut(StandardShortcut.copy)` of a KeyEvent
+    KeyEventMatchesShortcut,
     ArrayLength,
+    /// `SlintInternal.array-splice(array, length, tail)`: the first `length` rows of the array
+    /// followed by the rows of `tail`. The result has the type of the first argument.
+    ArraySplice,
     Rgb,
     DarkColorScheme,
+    FirstDayOfWeek,
     TextInputFocused,
+    TooltipText,
+    TooltipPosition,
     SetTextInputFocused,
     ImplicitLayoutInfo(Orientation),
     ItemAbsolutePosition,
@@ -83,11 +92,18 @@ pub enum BuiltinMacroFunction {
     /// Add the right conversion operations so that the return type is the same as the argument type
     Mod,
     CubicBezier,
+    /// Transforms `steps(n)` into a staircase easing curve with n steps
+    Steps,
+    /// Transforms `spring(stiffness, damping)` or `spring(stiffness, damping, mass)` into a spring easing curve
+    Spring,
     /// The argument can be r,g,b,a or r,g,b and they can be percentages or integer.
     /// transform the argument so it is always rgb(r, g, b, a) with r, g, b between 0 and 255.
     Rgb,
     /// transform `debug(a, b, c)` into debug `a + " " + b + " " + c`
     Debug,
+    /// Converts the last argument of `SlintInternal.array-splice(array, length, tail)` to the
+    /// type of the array
+    ArraySplice,
 }
 
 impl BuiltinFunction {
@@ -100,6 +116,12 @@ pub fn ty(&self) -> Type {
             BuiltinFunction::GetWindowDefaultFontSize => {
                 Type::Function { return_type: Box::new(Type::LogicalLength), args: vec![] }
             }
+            BuiltinFunction::GetWindowLayoutDirection => Type::Function {
+                return_type: Box::new(Type::Enumeration(
+                    crate::typeregister::BUILTIN_ENUMS.with(|e| e.LayoutDirection.clone()),
+                )),
+                args: vec![],
+            },
             BuiltinFunction::AnimationTick => {
                 Type::Function { return_type: Type::Duration.into(), args: vec![] }
             }
@@ -202,9 +224,23 @@ pub fn ty(&self) -> Type {
                 }),
                 args: vec![Type::Image],
             },
+            BuiltinFunction::KeyEventMatchesShortcut => Type::Function {
+                return_type: Box::new(Type::Bool),
+                args: vec![
+                    crate::typeregister::key_event_type(),
+                    Type::Enumeration(
+                        crate::typeregister::BUILTIN_ENUMS.with(|e| e.StandardShortcut.clone()),
+                    ),
+                ],
+            },
             BuiltinFunction::ArrayLength => {
                 Type::Function { return_type: Box::new(Type::Int32), args: vec![Type::Model] }
             }
+            // The actual return type is the type of the first argument, see Expression::ty
+            BuiltinFunction::ArraySplice => Type::Function {
+                return_type: Box::new(Type::Model),
+                args: vec![Type::Model, Type::Int32, Type::Model],
+            },
             BuiltinFunction::Rgb => Type::Function {
                 return_type: Box::new(Type::Color),
                 args: vec![Type::Int32, Type::Int32, Type::Int32, Type::Float32],
@@ -212,9 +248,19 @@ pub fn ty(&self) -> Type {
             BuiltinFunction::DarkColorScheme => {
                 Type::Function { return_type: Box::new(Type::Bool), args: vec![] }
             }
+            BuiltinFunction::FirstDayOfWeek => {
+                Type::Function { return_type: Box::new(Type::Int32), args: vec![] }
+            }
             BuiltinFunction::TextInputFocused => {
                 Type::Function { return_type: Box::new(Type::Bool), args: vec![] }
             }
+            BuiltinFunction::TooltipText => {
+                Type::Function { return_type: Box::new(Type::String), args: vec![Type::Bool] }
+            }
+            BuiltinFunction::TooltipPosition => Type::Function {
+                return_type: Box::new(crate::typeregister::logical_point_type()),
+                args: vec![Type::Bool],
+            },
             BuiltinFunction::SetTextInputFocused => {
                 Type::Function { return_type: Box::new(Type::Void), args: vec![Type::Bool] }
             }
@@ -249,8 +295,10 @@ fn is_const(&self) -> bool {
         match self {
             BuiltinFunction::GetWindowScaleFactor => false,
             BuiltinFunction::GetWindowDefaultFontSize => false,
+            BuiltinFunction::GetWindowLayoutDirection => false,
             BuiltinFunction::AnimationTick => false,
             BuiltinFunction::DarkColorScheme => false,
+            BuiltinFunction::FirstDayOfWeek => false,
             // Even if it is not pure, we optimize it away anyway
             BuiltinFunction::Debug => true,
             BuiltinFunction::Mod
@@ -286,10 +334,13 @@ fn is_const(&self) -> bool {
             BuiltinFunction::ImageSize => true,
             #[cfg(target_arch = "wasm32")]
             BuiltinFunction::ImageSize => false,
+            BuiltinFunction::KeyEventMatchesShortcut => true,
             BuiltinFunction::ArrayLength => true,
+            BuiltinFunction::ArraySplice => true,
             BuiltinFunction::Rgb => true,
             BuiltinFunction::SetTextInputFocused => false,
             BuiltinFunction::TextInputFocused => false,
+            BuiltinFunction::TooltipText | BuiltinFunction::TooltipPosition => false,
             BuiltinFunction::ImplicitLayoutInfo(_) => false,
             BuiltinFunction::ItemAbsolutePosition => true,
             BuiltinFunction::RegisterCustomFontByPath
@@ -304,8 +355,10 @@ pub fn is_pure(&self) -> bool {
         match self {
             BuiltinFunction::GetWindowScaleFactor => true,
             BuiltinFunction::GetWindowDefaultFontSize => true,
+            BuiltinFunction::GetWindowLayoutDirection => true,
             BuiltinFunction::AnimationTick => true,
             BuiltinFunction::DarkColorScheme => true,
+            BuiltinFunction::FirstDayOfWeek => true,
             // Even if it has technically side effect, we still consider it as pure for our purpose
             BuiltinFunction::Debug => true,
             BuiltinFunction::Mod
@@ -334,12 +387,15 @@ pub fn is_pure(&self) -> bool {
             | BuiltinFunction::ColorMix
             | BuiltinFunction::ColorWithAlpha => true,
             BuiltinFunction::ImageSize => true,
+            BuiltinFunction::KeyEventMatchesShortcut => true,
             BuiltinFunction::ArrayLength => true,
+            BuiltinFunction::ArraySplice => true,
             BuiltinFunction::Rgb => true,
             BuiltinFunction::ImplicitLayoutInfo(_) => true,
             BuiltinFunction::ItemAbsolutePosition => true,
             BuiltinFunction::SetTextInputFocused => false,
             BuiltinFunction::TextInputFocused => true,
+            BuiltinFunction::TooltipText | BuiltinFunction::TooltipPosition => true,
             BuiltinFunction::RegisterCustomFontByPath
             | BuiltinFunction::RegisterCustomFontByMemory
             | BuiltinFunction::RegisterBitmapFont => false,
@@ -692,6 +748,14 @@ pub fn ty(&self) -> Type {
             },
             Expression::Cast { to, .. } => to.clone(),
             Expression::CodeBlock(sub) => sub.last().map_or(Type::Void, |e| e.ty()),
+            Expression::FunctionCall { function, arguments, .. }
+                if matches!(
+                    **function,
+                    Expression::BuiltinFunctionReference(BuiltinFunction::ArraySplice, _)
+                ) =>
+            {
+                arguments.first().map_or(Type::Invalid, |array| array.ty())
+            }
             Expression::FunctionCall { function, .. } => match function.ty() {
                 Type::Function { return_type, .. } => *return_type,
                 Type::Callback { return_type, .. } => return_type.map_or(Type::Void, |x| *x),
@@ -1518,8 +1582,10 @@ pub enum EasingCurve {
     EaseInBounce,
     EaseOutBounce,
     EaseInOutBounce,
+    Steps(u32),
+    /// stiffness, damping and mass
+    Spring(f32, f32, f32),
     // CubicBezierNonConst([Box<Expression>; 4]),
-    // Custom(Box<dyn Fn(f32)->f32>),
 }
 
 // The compiler generates ResourceReference::AbsolutePath for all references like @image-url("foo.png")
@@ -332,10 +332,11 @@ pub fn escape_string(str: &str) -> String {
 };
 use crate::object_tree::Document;
 use crate::parser::syntax_nodes;
+use crate::CompilerConfiguration;
 use cpp_ast::*;
 use itertools::{Either, Itertools};
 use std::cell::Cell;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::num::NonZeroUsize;
 
 #[derive(Default)]
@@ -502,26 +503,44 @@ fn handle_property_init(
             if binding_expression.is_state_info {
                 format!("slint::private_api::set_state_binding({}, {});", prop_access, binding_code)
             } else {
+                let keyframes_code = binding_expression.keyframes.as_ref().map(|keyframes| {
+                    format!(
+                        "[this]() {{
+                            [[maybe_unused]] auto self = this;
+                            return {};
+                        }}",
+                        compile_keyframes(keyframes, ctx)
+                    )
+                });
                 match &binding_expression.animation {
                     Some(llr::Animation::Static(anim)) => {
                         let anim = compile_expression(anim, ctx);
-                        format!("{}.set_animated_binding({}, {});", prop_access, binding_code, anim)
+                        if let Some(keyframes_code) = keyframes_code {
+                            format!(
+                                "{}.set_animated_binding_with_keyframes({}, {}, {});",
+                                prop_access, binding_code, anim, keyframes_code
+                            )
+                        } else {
+                            format!("{}.set_animated_binding({}, {});", prop_access, binding_code, anim)
+                        }
                     }
                     Some(llr::Animation::Transition (
                         anim
                     )) => {
                         let anim = compile_expression(anim, ctx);
                         format!(
-                            "{}.set_animated_binding_for_transition({},
+                            "{}.set_animated_binding_for_transition{}({},
                             [this](uint64_t *start_time) -> slint::cbindgen_private::PropertyAnimation {{
                                 [[maybe_unused]] auto self = this;
                                 auto [anim, time] = {};
                                 *start_time = time;
                                 return anim;
-                            }});",
+                            }}{});",
                             prop_access,
+                            if keyframes_code.is_some() { "_with_keyframes" } else { "" },
                             binding_code,
                             anim,
+                            keyframes_code.map(|k| format!(", {k}")).unwrap_or_default(),
                         )
                     }
                     None => format!("{}.set_binding({});", prop_access, binding_code),
@@ -532,7 +551,11 @@ fn handle_property_init(
 }
 
 /// Returns the text of the C++ code produced by the given root component
-pub fn generate(doc: &Document, config: Config) -> impl std::fmt::Display {
+pub fn generate(
+    doc: &Document,
+    config: Config,
+    compiler_config: &CompilerConfiguration,
+) -> impl std::fmt::Display {
     let mut file = File { namespace: config.namespace.clone(), ..Default::default() };
 
     file.includes.push("<array>".into());
@@ -568,58 +591,41 @@ pub fn generate(doc: &Document, config: Config) -> impl std::fmt::Display {
                 }));
             }
             #[cfg(feature = "software-renderer")]
-            crate::embedded_resources::EmbeddedResourcesKind::TextureData(
-                crate::embedded_resources::Texture {
-                    data,
-                    format,
-                    rect,
-                    total_size: crate::embedded_resources::Size { width, height },
-                    original_size:
-                        crate::embedded_resources::Size {
-                            width: unscaled_width,
-                            height: unscaled_height,
-                        },
-                },
-            ) => {
-                let (r_x, r_y, r_w, r_h) = (rect.x(), rect.y(), rect.width(), rect.height());
-                let color =
-                    if let crate::embedded_resources::PixelFormat::AlphaMap([r, g, b]) = format {
-                        format!("slint::Color::from_rgb_uint8({r}, {g}, {b})")
-                    } else {
-                        "slint::Color{}".to_string()
-                    };
-                let count = data.len();
-                let data = data.iter().map(ToString::to_string).join(", ");
-                let data_name = format!("slint_embedded_resource_{}_data", er.id);
-                file.declarations.push(Declaration::Var(Var {
-                    ty: "inline const uint8_t".into(),
-                    name: data_name.clone(),
-                    array_size: Some(count),
-                    init: Some(format!("{{ {data} }}")),
-                }));
-                let texture_name = format!("slint_embedded_resource_{}_texture", er.id);
-                file.declarations.push(Declaration::Var(Var {
-                    ty: "inline const slint::cbindgen_private::types::StaticTexture".into(),
-                    name: texture_name.clone(),
-                    array_size: None,
-                    init: Some(format!(
-                        "{{
-                            .rect = {{ {r_x}, {r_y}, {r_w}, {r_h} }},
-                            .format = slint::cbindgen_private::types::PixelFormat::{format},
-                            .color = {color},
-                            .index = 0,
-                            }}"
-                    )),
-                }));
-                let init = format!("slint::cbindgen_private::types::StaticTextures {{
-                        .size = {{ {width}, {height} }},
-                        .original_size = {{ {unscaled_width}, {unscaled_height} }},
-                        .data = slint::cbindgen_private::Slice<uint8_t>{{  {data_name} , {count} }},
-                        .textures = slint::cbindgen_private::Slice<slint::cbindgen_private::types::StaticTexture>{{ &{texture_name}, 1 }}
-                    }}");
+            crate::embedded_resources::EmbeddedResourcesKind::TextureData(texture) => {
+                let name = format!("slint_embedded_resource_{}", er.id);
+                let no_frames =
+                    "slint::cbindgen_private::Slice<slint::cbindgen_private::types::StaticTextures>{ nullptr, 0 }";
+                let init = if texture.frames.is_empty() {
+                    generate_texture_data(&mut file, texture, &name);
+                    generate_static_textures(texture, &name, no_frames)
+                } else {
+                    let frames = texture
+                        .frames
+                        .iter()
+                        .enumerate()
+                        .map(|(i, frame)| {
+                            let frame_name = format!("{name}_frame_{i}");
+                            generate_texture_data(&mut file, frame, &frame_name);
+                            generate_static_textures(frame, &frame_name, no_frames)
+                        })
+                        .join(", ");
+                    let frames_name = format!("{name}_frames");
+                    file.declarations.push(Declaration::Var(Var {
+                        ty: "inline const slint::cbindgen_private::types::StaticTextures".into(),
+                        name: frames_name.clone(),
+                        array_size: Some(texture.frames.len()),
+                        init: Some(format!("{{ {frames} }}")),
+                    }));
+                    // The texture of an animated image is its first frame, so it uses the data of that frame
+                    generate_static_textures(
+                        texture,
+                        &format!("{name}_frame_0"),
+                        &format!("slint::cbindgen_private::Slice<slint::cbindgen_private::types::StaticTextures>{{ {frames_name}, {} }}", texture.frames.len()),
+                    )
+                };
                 file.declarations.push(Declaration::Var(Var {
                     ty: "inline const slint::cbindgen_private::types::StaticTextures".into(),
-                    name: format!("slint_embedded_resource_{}", er.id),
+                    name,
                     array_size: None,
                     init: Some(init),
                 }))
@@ -762,7 +768,7 @@ pub fn generate(doc: &Document, config: Config) -> impl std::fmt::Display {
         return file;
     }
 
-    let llr = llr::lower_to_item_tree::lower_to_item_tree(&doc.root_component);
+    let llr = llr::lower_to_item_tree::lower_to_item_tree(doc, compiler_config);
 
     // Forward-declare the root so that sub-components can access singletons, the window, etc.
     file.declarations.push(Declaration::Struct(Struct {
@@ -772,6 +778,10 @@ pub fn generate(doc: &Document, config: Config) -> impl std::fmt::Display {
 
     let conditional_includes = ConditionalIncludes::default();
 
+    if let Some(translations) = &llr.translations {
+        generate_translations(translations, &llr, &mut file);
+    }
+
     for sub_compo in &llr.sub_components {
         let sub_compo_id = ident(&sub_compo.name);
         let mut sub_compo_struct = Struct { name: sub_compo_id.clone(), ..Default::default() };
@@ -823,6 +833,73 @@ pub fn generate(doc: &Document, config: Config) -> impl std::fmt::Display {
     file
 }
 
+/// Declares the `{name}_data` array with the pixels of an embedded texture, and the `{name}_texture`
+/// that refers to them.
+#[cfg(feature = "software-renderer")]
+fn generate_texture_data(
+    file: &mut File,
+    texture: &crate::embedded_resources::Texture,
+    name: &str,
+) {
+    let crate::embedded_resources::Texture { data, format, rect, .. } = texture;
+    let (r_x, r_y, r_w, r_h) = (rect.x(), rect.y(), rect.width(), rect.height());
+    let color = if let crate::embedded_resources::PixelFormat::AlphaMap([r, g, b]) = format {
+        format!("slint::Color::from_rgb_uint8({r}, {g}, {b})")
+    } else {
+        "slint::Color{}".to_string()
+    };
+    let count = data.len();
+    let data = data.iter().map(ToString::to_string).join(", ");
+    file.declarations.push(Declaration::Var(Var {
+        ty: "inline const uint8_t".into(),
+        name: format!("{name}_data"),
+        array_size: Some(count),
+        init: Some(format!("{{ {data} }}")),
+    }));
+    file.declarations.push(Declaration::Var(Var {
+        ty: "inline const slint::cbindgen_private::types::StaticTexture".into(),
+        name: format!("{name}_texture"),
+        array_size: None,
+        init: Some(format!(
+            "{{
+                .rect = {{ {r_x}, {r_y}, {r_w}, {r_h} }},
+                .format = slint::cbindgen_private::types::PixelFormat::{format},
+                .color = {color},
+                .index = 0,
+                }}"
+        )),
+    }));
+}
+
+/// Returns the initializer of the `StaticTextures` for an embedded texture whose data was declared
+/// by `generate_texture_data` with the given `name`.
+#[cfg(feature = "software-renderer")]
+fn generate_static_textures(
+    texture: &crate::embedded_resources::Texture,
+    name: &str,
+    frames: &str,
+) -> String {
+    let crate::embedded_resources::Texture {
+        data,
+        total_size: crate::embedded_resources::Size { width, height },
+        original_size:
+            crate::embedded_resources::Size { width: unscaled_width, height: unscaled_height },
+        frame_duration,
+        ..
+    } = texture;
+    let count = data.len();
+    format!(
+        "slint::cbindgen_private::types::StaticTextures {{
+            .size = {{ {width}, {height} }},
+            .original_size = {{ {unscaled_width}, {unscaled_height} }},
+            .data = slint::cbindgen_private::Slice<uint8_t>{{  {name}_data , {count} }},
+            .textures = slint::cbindgen_private::Slice<slint::cbindgen_private::types::StaticTexture>{{ &{name}_texture, 1 }},
+            .frames = {frames},
+            .frame_duration = {frame_duration}
+        }}"
+    )
+}
+
 fn generate_struct(
     file: &mut File,
     name: &str,
@@ -1366,6 +1443,57 @@ fn generate_item_tree(
         }),
     ));
 
+    target_struct.members.push((
+        Access::Private,
+        Declaration::Function(Function {
+            name: "accessibility_action".into(),
+            signature:
+                "([[maybe_unused]] slint::private_api::ItemTreeRef component, uint32_t index, const slint::cbindgen_private::AccessibilityAction *action) -> void"
+                    .into(),
+            is_static: true,
+            statements: Some(vec![format!(
+                "reinterpret_cast<const {}*>(component.instance)->accessibility_action(index, *action);",
+                item_tree_class_name
+            )]),
+            ..Default::default()
+        }),
+    ));
+
+    target_struct.members.push((
+        Access::Private,
+        Declaration::Function(Function {
+            name: "supported_accessibility_actions".into(),
+            signature:
+                "([[maybe_unused]] slint::private_api::ItemTreeRef component, uint32_t index) -> slint::cbindgen_private::SupportedAccessibilityAction"
+                    .into(),
+            is_static: true,
+            statements: Some(vec![format!(
+                "return reinterpret_cast<const {}*>(component.instance)->supported_accessibility_actions(index);",
+                item_tree_class_name
+            )]),
+            ..Default::default()
+        }),
+    ));
+
+    target_struct.members.push((
+        Access::Private,
+        Declaration::Function(Function {
+            name: "item_element_infos".into(),
+            signature:
+                "([[maybe_unused]] slint::private_api::ItemTreeRef component, uint32_t index, slint::SharedString *result) -> bool"
+                    .into(),
+            is_static: true,
+            statements: Some(vec![
+                format!("if (auto infos = reinterpret_cast<const {}*>(component.instance)->item_element_infos(index)) {{", item_tree_class_name),
+                "    *result = *infos;".into(),
+                "    return true;".into(),
+                "}".into(),
+                "return false;".into(),
+            ]),
+            ..Default::default()
+        }),
+    ));
+
     target_struct.members.push((
         Access::Private,
         Declaration::Function(Function {
@@ -1396,7 +1524,8 @@ fn generate_item_tree(
         init: Some(format!(
             "{{ visit_children, get_item_ref, get_subtree_range, get_subtree, \
                 get_item_tree, parent_node, embed_component, subtree_index, layout_info, \
-                item_geometry, accessible_role, accessible_string_property, window_adapter, \
+                item_geometry, accessible_role, accessible_string_property, accessibility_action, \
+                supported_accessibility_actions, item_element_infos, window_adapter, \
                 slint::private_api::drop_in_place<{}>, slint::private_api::dealloc }}",
             item_tree_class_name
         )),
@@ -1425,6 +1554,12 @@ fn generate_item_tree(
 
     if parent_ctx.is_none() {
         create_code.push("slint::cbindgen_private::slint_ensure_backend();".into());
+        if root.translations.is_some() {
+            create_code.push(format!(
+                "slint::private_api::set_bundled_languages({});",
+                translations_var_name("languages", root)
+            ));
+        }
     }
 
     let root_access = if parent_ctx.is_some() { "parent->root" } else { "self" };
@@ -1864,7 +1999,11 @@ fn generate_sub_component(
             }
             else_ = "} else ";
         }
-        code.push(format!("{else_}return {{}};"));
+        if signature.ends_with("-> void") {
+            code.push(format!("{else_}return;"));
+        } else {
+            code.push(format!("{else_}return {{}};"));
+        }
         target_struct.members.push((
             field_access,
             Declaration::Function(Function {
@@ -1901,16 +2040,41 @@ fn generate_sub_component(
 
     let mut accessible_role_cases = vec!["switch (index) {".into()];
     let mut accessible_string_cases = vec!["switch ((index << 8) | uintptr_t(what)) {".into()];
+    let mut accessibility_action_cases =
+        vec!["switch ((index << 8) | uintptr_t(action.tag)) {".into()];
+    let mut supported_accessibility_actions = BTreeMap::<u32, BTreeSet<_>>::new();
     for ((index, what), expr) in &component.accessible_prop {
+        let has_args = matches!(&*expr.borrow(), llr::Expression::CallBackCall { arguments, .. } if !arguments.is_empty());
         let expr = compile_expression(&expr.borrow(), &ctx);
         if what == "Role" {
             accessible_role_cases.push(format!("    case {index}: return {expr};"));
+        } else if let Some(what) = what.strip_prefix("Action") {
+            let args = if has_args {
+                format!(
+                    "auto arg_0 = action.{}._0; ",
+                    crate::generator::to_kebab_case(what).replace('-', "_")
+                )
+            } else {
+                String::new()
+            };
+            accessibility_action_cases.push(format!("    case ({index} << 8) | uintptr_t(slint::cbindgen_private::AccessibilityAction::Tag::{what}): {{ {args}{expr}; return; }}"));
+            supported_accessibility_actions
+                .entry(*index)
+                .or_default()
+                .insert(format!("slint::cbindgen_private::SupportedAccessibilityAction_{what}"));
         } else {
             accessible_string_cases.push(format!("    case ({index} << 8) | uintptr_t(slint::cbindgen_private::AccessibleStringProperty::{what}): return {expr};"));
         }
     }
     accessible_role_cases.push("}".into());
     accessible_string_cases.push("}".into());
+    accessibility_action_cases.push("}".into());
+
+    let mut supported_accessibility_actions_cases = vec!["switch (index) {".into()];
+    supported_accessibility_actions_cases.extend(supported_accessibility_actions.into_iter().map(
+        |(index, values)| format!("    case {index}: return {};", values.into_iter().join("|")),
+    ));
+    supported_accessibility_actions_cases.push("}".into());
 
     dispatch_item_function(
         "accessible_role",
@@ -1925,6 +2089,41 @@ fn generate_sub_component(
         accessible_string_cases,
     );
 
+    dispatch_item_function(
+        "accessibility_action",
+        "(uint32_t index, const slint::cbindgen_private::AccessibilityAction &action) const -> void",
+        ", action",
+        accessibility_action_cases,
+    );
+
+    dispatch_item_function(
+        "supported_accessibility_actions",
+        "(uint32_t index) const -> slint::cbindgen_private::SupportedAccessibilityAction",
+        "",
+        supported_accessibility_actions_cases,
+    );
+
+    let mut element_infos_cases = vec!["switch (index) {".to_string()];
+    element_infos_cases.extend(component.element_infos.iter().map(|(index, infos)| {
+        let infos = format!(r#"slint::SharedString(u8"{}")"#, escape_string(infos));
+        // The root of the sub-component is also the element of this component
+        match component.sub_components.iter().find(|sub| sub.index_in_tree == *index) {
+            Some(sub) => format!(
+                "    case {index}: {{ auto infos = {infos}; if (auto sub_infos = self->{}.item_element_infos(0)) {{ infos += \"\\n\"; infos += *sub_infos; }} return infos; }}",
+                ident(&sub.name)
+            ),
+            None => format!("    case {index}: return {infos};"),
+        }
+    }));
+    element_infos_cases.push("}".into());
+
+    dispatch_item_function(
+        "item_element_infos",
+        "(uint32_t index) const -> std::optional<slint::SharedString>",
+        "",
+        element_infos_cases,
+    );
+
     if !children_visitor_cases.is_empty() {
         target_struct.members.push((
             field_access,
@@ -2533,6 +2732,51 @@ fn native_item<'a>(
     }
 }
 
+/// Compile the keyframes of an animation to an expression of type
+/// `std::vector<slint::private_api::AnimationKeyframe<T>>`
+fn compile_keyframes(expr: &llr::Expression, ctx: &EvaluationContext) -> String {
+    use llr::Expression;
+    match expr {
+        Expression::Array { element_ty, values, .. } => {
+            let value_ty = match element_ty {
+                Type::Struct { fields, .. } => fields["value"].cpp_type().unwrap(),
+                _ => panic!("keyframe is not a struct"),
+            };
+            let mut keyframes = values.iter().map(|keyframe| {
+                let Expression::Struct { values, .. } = keyframe else {
+                    panic!("keyframe is not a struct")
+                };
+                format!(
+                    "{{ float({}), {}({}), {} }}",
+                    compile_expression(&values["position"], ctx),
+                    value_ty,
+                    compile_expression(&values["value"], ctx),
+                    compile_expression(&values["easing"], ctx),
+                )
+            });
+            format!(
+                "std::vector<slint::private_api::AnimationKeyframe<{value_ty}>>{{ {} }}",
+                keyframes.join(", ")
+            )
+        }
+        Expression::Condition { condition, true_expr, false_expr } => format!(
+            "({}) ? {} : {}",
+            compile_expression(condition, ctx),
+            compile_keyframes(true_expr, ctx),
+            compile_keyframes(false_expr, ctx),
+        ),
+        Expression::CodeBlock(sub) => {
+            let (last, statements) = sub.split_last().expect("empty keyframes code block");
+            format!(
+                "[&]{{ {} return {}; }}()",
+                statements.iter().map(|e| compile_expression(e, ctx) + ";").join(" "),
+                compile_keyframes(last, ctx)
+            )
+        }
+        _ => panic!("Unexpected expression for keyframes: {expr:?}"),
+    }
+}
+
 fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String {
     use llr::Expression;
     match expr {
@@ -2851,8 +3095,16 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
         Expression::EasingCurve(EasingCurve::EaseOutElastic) => "slint::cbindgen_private::EasingCurve::Tag::EaseOutElastic".into(),
         Expression::EasingCurve(EasingCurve::EaseInOutElastic) => "slint::cbindgen_private::EasingCurve::Tag::EaseInOutElastic".into(),
         Expression::EasingCurve(EasingCurve::EaseInBounce) => "slint::cbindgen_private::EasingCurve::Tag::EaseInBounce".into(),
-        Expression::EasingCurve(EasingCurve::EaseOutBounce) => "slint::cbindgen_private::EasingCurve::Tag::EaseOutElastic".into(),
-        Expression::EasingCurve(EasingCurve::EaseInOutBounce) => "slint::cbindgen_private::EasingCurve::Tag::EaseInOutElastic".into(),
+        Expression::EasingCurve(EasingCurve::EaseOutBounce) => "slint::cbindgen_private::EasingCurve::Tag::EaseOutBounce".into(),
+        Expression::EasingCurve(EasingCurve::EaseInOutBounce) => "slint::cbindgen_private::EasingCurve::Tag::EaseInOutBounce".into(),
+        Expression::EasingCurve(EasingCurve::Steps(steps)) => format!(
+            "slint::cbindgen_private::EasingCurve(slint::cbindgen_private::EasingCurve::Tag::Steps, {}u)",
+            steps
+        ),
+        Expression::EasingCurve(EasingCurve::Spring(stiffness, damping, mass)) => format!(
+            "slint::cbindgen_private::EasingCurve::make_spring({}, {}, {})",
+            stiffness, damping, mass
+        ),
         Expression::LinearGradient{angle, stops} => {
             let angle = compile_expression(angle, ctx);
             let mut stops_it = stops.iter().map(|(color, stop)| {
@@ -2938,6 +3190,124 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
                 rhs_code = rhs_code
             )
         }
+        Expression::TranslationReference { format_args, string_index, plural } => {
+            let args = compile_expression(format_args, ctx);
+            let root = ctx.public_component;
+            let language_count =
+                root.translations.as_ref().map_or(0, |translations| translations.languages.len());
+            match plural {
+                Some(plural) => {
+                    let plural = compile_expression(plural, ctx);
+                    format!(
+                        "slint::private_api::translate_from_bundle_with_plural({strings}, std::span({indices} + {offset}, {count}), {rules}, {args}, {plural})",
+                        strings = translations_var_name("plurals", root),
+                        indices = translations_var_name("plurals_indices", root),
+                        offset = string_index * (language_count + 1),
+                        count = language_count + 1,
+                        rules = translations_var_name("plural_rules", root),
+                    )
+                }
+                None => format!(
+                    "slint::private_api::translate_from_bundle(std::span({strings} + {offset}, {language_count}), {args})",
+                    strings = translations_var_name("strings", root),
+                    offset = string_index * language_count,
+                ),
+            }
+        }
+    }
+}
+
+/// The name of the variables declared by [`generate_translations`]
+fn translations_var_name(name: &str, root: &llr::PublicComponent) -> String {
+    format!("slint_translated_{name}_{}", ident(&root.item_tree.root.name))
+}
+
+fn generate_translations(
+    translations: &crate::translations::Translations,
+    root: &llr::PublicComponent,
+    file: &mut File,
+) {
+    let to_cpp_string = |s: Option<&String>| match s {
+        Some(s) => format!(r#"u8"{}""#, escape_string(s)),
+        None => "nullptr".to_string(),
+    };
+    let mut declare_array = |ty: &str, name: &str, values: Vec<String>| {
+        file.declarations.push(Declaration::Var(Var {
+            ty: ty.into(),
+            name: translations_var_name(name, root),
+            array_size: Some(values.len()),
+            init: Some(format!("{{ {} }}", values.join(", "))),
+        }));
+    };
+
+    // The translations of each string, one language after the other
+    let mut strings = translations
+        .strings
+        .iter()
+        .flatten()
+        .map(|s| to_cpp_string(s.as_ref()))
+        .collect::<Vec<_>>();
+    // An array must not be empty
+    if strings.is_empty() {
+        strings.push("nullptr".into());
+    }
+    declare_array("const inline char8_t *const", "strings", strings);
+
+    // The plural forms of all the strings and languages, and for each string, the index of the
+    // forms of each language in that array.
+    let mut plurals = Vec::new();
+    let mut indices = Vec::new();
+    for forms_for_each_language in &translations.plurals {
+        for forms in forms_for_each_language {
+            indices.push(plurals.len().to_string());
+            plurals.extend(forms.iter().flatten().map(|form| to_cpp_string(Some(form))));
+        }
+        indices.push(plurals.len().to_string());
+    }
+    if plurals.is_empty() {
+        plurals.push("nullptr".into());
+    }
+    if indices.is_empty() {
+        indices.push("0".into());
+    }
+    declare_array("const inline char8_t *const", "plurals", plurals);
+    declare_array("const inline uint32_t", "plurals_indices", indices);
+
+    let plural_rules = translations.plural_rules.iter().map(|rule| match rule {
+        Some(rule) => {
+            format!("[](int32_t n) -> uintptr_t {{ return {}; }}", compile_plural_expression(rule))
+        }
+        None => "nullptr".into(),
+    });
+    declare_array(
+        "const inline slint::private_api::PluralRule",
+        "plural_rules",
+        plural_rules.collect(),
+    );
+
+    let languages = translations.languages.iter().map(|l| to_cpp_string(Some(l)));
+    declare_array("const inline char8_t *const", "languages", languages.collect());
+}
+
+/// Compile the formula of the `Plural-Forms` header to a C++ expression of type `int`.
+/// The formula is itself C code, so only the parentheses need to be added.
+fn compile_plural_expression(expr: &crate::translations::PluralExpression) -> String {
+    use crate::translations::PluralExpression;
+    match expr {
+        PluralExpression::N => "n".into(),
+        PluralExpression::NumberLiteral(x) => x.to_string(),
+        PluralExpression::UnaryOp { op, sub } => {
+            format!("({op}{})", compile_plural_expression(sub))
+        }
+        PluralExpression::BinaryExpression { lhs, rhs, op } => {
+            format!("({} {op} {})", compile_plural_expression(lhs), compile_plural_expression(rhs))
+        }
+        PluralExpression::Condition { condition, true_expr, false_expr } => format!(
+            "({} ? {} : {})",
+            compile_plural_expression(condition),
+            compile_plural_expression(true_expr),
+            compile_plural_expression(false_expr)
+        ),
     }
 }
 
@@ -2961,6 +3331,13 @@ fn compile_builtin_function_call(
                 ctx.generator_state.root_access, window_item_name
             )
         }
+        BuiltinFunction::GetWindowLayoutDirection => {
+            let window_item_name = ident(&ctx.public_component.item_tree.root.items[0].name);
+            format!(
+                "{}->{}.layout_direction.get()",
+                ctx.generator_state.root_access, window_item_name
+            )
+        }
         BuiltinFunction::AnimationTick => "slint::cbindgen_private::slint_animation_tick()".into(),
         BuiltinFunction::Debug => {
             ctx.generator_state.conditional_includes.iostream.set(true);
@@ -3069,9 +3446,20 @@ fn compile_builtin_function_call(
         BuiltinFunction::ImageSize => {
             format!("{}.size()", a.next().unwrap())
         }
+        BuiltinFunction::KeyEventMatchesShortcut => {
+            format!("[](const auto &event, auto shortcut) {{ return slint::cbindgen_private::slint_key_event_matches_shortcut(&event, shortcut); }}({}, {})", a.next().unwrap(), a.next().unwrap())
+        }
         BuiltinFunction::ArrayLength => {
             format!("slint::private_api::model_length({})", a.next().unwrap())
         }
+        BuiltinFunction::ArraySplice => {
+            format!(
+                "slint::private_api::model_splice({}, {}, {})",
+                a.next().unwrap(),
+                a.next().unwrap(),
+                a.next().unwrap()
+            )
+        }
         BuiltinFunction::Rgb => {
             format!("slint::Color::from_argb_uint8(std::clamp(static_cast<float>({a}) * 255., 0., 255.), std::clamp(static_cast<int>({r}), 0, 255), std::clamp(static_cast<int>({g}), 0, 255), std::clamp(static_cast<int>({b}), 0, 255))",
                 r = a.next().unwrap(),
@@ -3083,14 +3471,23 @@ fn compile_builtin_function_call(
         BuiltinFunction::DarkColorScheme => {
             format!("{}.dark_color_scheme()", access_window_field(ctx))
         }
+        BuiltinFunction::FirstDayOfWeek => {
+            "slint::cbindgen_private::slint_date_time_first_day_of_week()".into()
+        }
         BuiltinFunction::SetTextInputFocused => {
             format!("{}.set_text_input_focused({})", access_window_field(ctx), a.next().unwrap())
         }
         BuiltinFunction::TextInputFocused => {
             format!("{}.text_input_focused()", access_window_field(ctx))
         }
+        BuiltinFunction::TooltipText => {
+            format!("{}.tooltip_text({})", access_window_field(ctx), a.next().unwrap())
+        }
+        BuiltinFunction::TooltipPosition => {
+            format!("{}.tooltip_position({})", access_window_field(ctx), a.next().unwrap())
+        }
         BuiltinFunction::ShowPopupWindow => {
-            if let [llr::Expression::NumberLiteral(popup_index), x, y, close_on_click, llr::Expression::PropertyReference(parent_ref)] =
+            if let [llr::Expression::NumberLiteral(popup_index), x, y, anchor, placement, close_policy, llr::Expression::PropertyReference(parent_ref)] =
                 arguments
             {
                 let mut parent_ctx = ctx;
@@ -3110,9 +3507,11 @@ fn compile_builtin_function_call(
                 let parent_component = access_item_rc(parent_ref, ctx);
                 let x = compile_expression(x, ctx);
                 let y = compile_expression(y, ctx);
-                let close_on_click = compile_expression(close_on_click, ctx);
+                let anchor = compile_expression(anchor, ctx);
+                let placement = compile_expression(placement, ctx);
+                let close_policy = compile_expression(close_policy, ctx);
                 format!(
-                    "{window}.show_popup<{popup_window_id}>({component_access}, {{ static_cast<float>({x}), static_cast<float>({y}) }}, {close_on_click}, {{ {parent_component} }})"
+                    "{window}.show_popup<{popup_window_id}>({component_access}, {{ static_cast<float>({x}), static_cast<float>({y}) }}, slint::private_api::convert_anonymous_rect({anchor}), {placement}, {close_policy}, {{ {parent_component} }})"
                 )
             } else {
                 panic!("internal error: invalid args to ShowPopupWindow {:?}", arguments)
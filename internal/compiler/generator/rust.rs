@@ -20,10 +20,11 @@
     TypeResolutionContext as _,
 };
 use crate::object_tree::Document;
+use crate::CompilerConfiguration;
 use itertools::Either;
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::num::NonZeroUsize;
 use std::str::FromStr;
 
@@ -138,7 +139,7 @@ fn set_primitive_property_value(ty: &Type, value_expression: TokenStream) -> Tok
 }
 
 /// Generate the rust code for the given component.
-pub fn generate(doc: &Document) -> TokenStream {
+pub fn generate(doc: &Document, compiler_config: &CompilerConfiguration) -> TokenStream {
     let (structs_and_enums_ids, structs_and_enum_def): (Vec<_>, Vec<_>) = doc
         .root_component
         .used_types
@@ -162,7 +163,7 @@ pub fn generate(doc: &Document) -> TokenStream {
         return TokenStream::default();
     }
 
-    let llr = crate::llr::lower_to_item_tree::lower_to_item_tree(&doc.root_component);
+    let llr = crate::llr::lower_to_item_tree::lower_to_item_tree(doc, compiler_config);
 
     let sub_compos = llr
         .sub_components
@@ -188,6 +189,7 @@ pub fn generate(doc: &Document) -> TokenStream {
 
     let resource_symbols = generate_resources(doc);
     let named_exports = generate_named_exports(doc);
+    let translations = llr.translations.as_ref().map(generate_translations);
 
     quote! {
         #[allow(non_snake_case)]
@@ -212,6 +214,7 @@ mod #compo_module {
             #(#sub_compos)*
             #compo
             #(#resource_symbols)*
+            #translations
             const _THE_SAME_VERSION_MUST_BE_USED_FOR_THE_COMPILER_AND_THE_RUNTIME : slint::#version_check = slint::#version_check;
         }
         #[allow(unused_imports)]
@@ -253,6 +256,11 @@ fn generate_public_component(llr: &llr::PublicComponent) -> TokenStream {
         &ctx,
     );
 
+    let init_bundle_translations = llr
+        .translations
+        .as_ref()
+        .map(|_| quote!(sp::set_bundled_languages(_SLINT_BUNDLED_LANGUAGES);));
+
     let global_names =
         llr.globals.iter().map(|g| format_ident!("global_{}", ident(&g.name))).collect::<Vec<_>>();
     let global_types = llr.globals.iter().map(global_inner_name).collect::<Vec<_>>();
@@ -263,6 +271,7 @@ fn generate_public_component(llr: &llr::PublicComponent) -> TokenStream {
 
         impl #public_component_id {
             pub fn new() -> core::result::Result<Self, slint::PlatformError> {
+                #init_bundle_translations
                 let inner = #inner_component_id::new()?;
                 #(inner.globals.#global_names.clone().init(&inner);)*
                 #inner_component_id::user_init(sp::VRc::map(inner.clone(), |x| x));
@@ -450,6 +459,19 @@ fn handle_property_init(
                 } }
             } else {
                 match &binding_expression.animation {
+                    Some(llr::Animation::Static(anim)) if binding_expression.keyframes.is_some() => {
+                        let anim = compile_expression(anim, ctx);
+                        let keyframes = compile_keyframes(binding_expression.keyframes.as_ref().unwrap(), ctx);
+                        quote! { {
+                            #init_self_pin_ref
+                            slint::private_unstable_api::set_animated_property_binding_with_keyframes(
+                                #rust_property, &self_rc, #binding_tokens, #anim, move |self_rc| {
+                                    #init_self_pin_ref
+                                    #keyframes
+                                }
+                            );
+                        } }
+                    }
                     Some(llr::Animation::Static(anim)) => {
                         let anim = compile_expression(anim, ctx);
                         quote! { {
@@ -457,6 +479,21 @@ fn handle_property_init(
                             slint::private_unstable_api::set_animated_property_binding(#rust_property, &self_rc, #binding_tokens, #anim);
                         } }
                     }
+                    Some(llr::Animation::Transition(anim)) if binding_expression.keyframes.is_some() => {
+                        let anim = compile_expression(anim, ctx);
+                        let keyframes = compile_keyframes(binding_expression.keyframes.as_ref().unwrap(), ctx);
+                        quote! {
+                            slint::private_unstable_api::set_animated_property_binding_for_transition_with_keyframes(
+                                #rust_property, &self_rc, #binding_tokens, move |self_rc| {
+                                    #init_self_pin_ref
+                                    #anim
+                                }, move |self_rc| {
+                                    #init_self_pin_ref
+                                    #keyframes
+                                }
+                            );
+                        }
+                    }
                     Some(llr::Animation::Transition(anim)) => {
                         let anim = compile_expression(anim, ctx);
                         quote! {
@@ -788,16 +825,34 @@ fn generate_sub_component(
 
     let mut accessible_role_branch = vec![];
     let mut accessible_string_property_branch = vec![];
+    let mut accessibility_action_branch = vec![];
+    let mut supported_accessibility_actions = BTreeMap::<u32, BTreeSet<_>>::new();
     for ((index, what), expr) in &component.accessible_prop {
-        let expr = compile_expression(&expr.borrow(), &ctx);
+        let e = compile_expression(&expr.borrow(), &ctx);
         if what == "Role" {
-            accessible_role_branch.push(quote!(#index => #expr,));
+            accessible_role_branch.push(quote!(#index => #e,));
+        } else if let Some(what) = what.strip_prefix("Action") {
+            let what = ident(what);
+            let has_args = matches!(&*expr.borrow(), Expression::CallBackCall { arguments, .. } if !arguments.is_empty());
+            accessibility_action_branch.push(if has_args {
+                quote!((#index, sp::AccessibilityAction::#what(args)) => { let args = (args,); #e })
+            } else {
+                quote!((#index, sp::AccessibilityAction::#what) => { #e })
+            });
+            supported_accessibility_actions.entry(*index).or_default().insert(what);
         } else {
             let what = ident(what);
             accessible_string_property_branch
-                .push(quote!((#index, sp::AccessibleStringProperty::#what) => #expr,));
+                .push(quote!((#index, sp::AccessibleStringProperty::#what) => #e,));
         }
     }
+    let mut supported_accessibility_actions_branch = supported_accessibility_actions
+        .into_iter()
+        .map(|(index, values)| quote!(#index => #(sp::SupportedAccessibilityAction::#values)|*,))
+        .collect::<Vec<_>>();
+
+    let mut element_infos = component.element_infos.clone();
+    let mut item_element_infos_branch = vec![];
 
     let mut item_geometry_branch = component
         .geometries
@@ -875,6 +930,28 @@ fn generate_sub_component(
         accessible_string_property_branch.push(quote!(
             (#local_tree_index, _) => #sub_compo_field.apply_pin(_self).accessible_string_property(0, what),
         ));
+        accessibility_action_branch.push(quote!(
+            (#local_tree_index, _) => #sub_compo_field.apply_pin(_self).accessibility_action(0, action),
+        ));
+        supported_accessibility_actions_branch.push(quote!(
+            #local_tree_index => #sub_compo_field.apply_pin(_self).supported_accessibility_actions(0),
+        ));
+        // The root of the sub-component is also the element of this component
+        item_element_infos_branch.push(match element_infos.remove(&local_tree_index) {
+            Some(infos) => quote!(
+                #local_tree_index => {
+                    let mut infos = sp::SharedString::from(#infos);
+                    if let sp::Some(sub_infos) = #sub_compo_field.apply_pin(_self).item_element_infos(0) {
+                        infos.push_str("\n");
+                        infos.push_str(&sub_infos);
+                    }
+                    sp::Some(infos)
+                }
+            ),
+            None => quote!(
+                #local_tree_index => #sub_compo_field.apply_pin(_self).item_element_infos(0),
+            ),
+        });
         if sub_items_count > 1 {
             let range_begin = local_index_of_first_child;
             let range_end = range_begin + sub_items_count - 2 + sub.ty.repeater_count();
@@ -884,15 +961,28 @@ fn generate_sub_component(
             accessible_string_property_branch.push(quote!(
                 (#range_begin..=#range_end, _) => #sub_compo_field.apply_pin(_self).accessible_string_property(index - #range_begin + 1, what),
             ));
+            accessibility_action_branch.push(quote!(
+                (#range_begin..=#range_end, _) => #sub_compo_field.apply_pin(_self).accessibility_action(index - #range_begin + 1, action),
+            ));
+            supported_accessibility_actions_branch.push(quote!(
+                #range_begin..=#range_end => #sub_compo_field.apply_pin(_self).supported_accessibility_actions(index - #range_begin + 1),
+            ));
             item_geometry_branch.push(quote!(
                 #range_begin..=#range_end => return #sub_compo_field.apply_pin(_self).item_geometry(index - #range_begin + 1),
             ));
+            item_element_infos_branch.push(quote!(
+                #range_begin..=#range_end => #sub_compo_field.apply_pin(_self).item_element_infos(index - #range_begin + 1),
+            ));
         }
 
         sub_component_names.push(field_name);
         sub_component_types.push(sub_component_id);
     }
 
+    item_element_infos_branch.extend(
+        element_infos.into_iter().map(|(index, infos)| quote!(#index => sp::Some(#infos.into()),)),
+    );
+
     for (prop1, prop2) in &component.two_way_bindings {
         let p1 = access_member(prop1, &ctx);
         let p2 = access_member(prop2, &ctx);
@@ -1072,6 +1162,33 @@ fn accessible_string_property(
                 }
             }
 
+            fn accessibility_action(self: ::core::pin::Pin<&Self>, index: u32, action: &sp::AccessibilityAction) {
+                #![allow(unused)]
+                let _self = self;
+                match (index, action) {
+                    #(#accessibility_action_branch)*
+                    _ => (),
+                }
+            }
+
+            fn supported_accessibility_actions(self: ::core::pin::Pin<&Self>, index: u32) -> sp::SupportedAccessibilityAction {
+                #![allow(unused)]
+                let _self = self;
+                match index {
+                    #(#supported_accessibility_actions_branch)*
+                    _ => ::core::default::Default::default(),
+                }
+            }
+
+            fn item_element_infos(self: ::core::pin::Pin<&Self>, index: u32) -> sp::Option<sp::SharedString> {
+                #![allow(unused)]
+                let _self = self;
+                match index {
+                    #(#item_element_infos_branch)*
+                    _ => sp::None,
+                }
+            }
+
             #(#declared_functions)*
         }
 
@@ -1516,6 +1633,24 @@ fn accessible_string_property(
                 *result = self.accessible_string_property(index, what);
             }
 
+            fn accessibility_action(self: ::core::pin::Pin<&Self>, index: u32, action: &sp::AccessibilityAction) {
+                self.accessibility_action(index, action);
+            }
+
+            fn supported_accessibility_actions(self: ::core::pin::Pin<&Self>, index: u32) -> sp::SupportedAccessibilityAction {
+                self.supported_accessibility_actions(index)
+            }
+
+            fn item_element_infos(self: ::core::pin::Pin<&Self>, index: u32, result: &mut sp::SharedString) -> bool {
+                match self.item_element_infos(index) {
+                    sp::Some(infos) => {
+                        *result = infos;
+                        true
+                    }
+                    sp::None => false,
+                }
+            }
+
             fn window_adapter(
                 self: ::core::pin::Pin<&Self>,
                 do_create: bool,
@@ -2233,6 +2368,12 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
         Expression::EasingCurve(EasingCurve::EaseInOutBounce) => {
             quote!(sp::EasingCurve::EaseInOutBounce)
         }
+        Expression::EasingCurve(EasingCurve::Steps(steps)) => {
+            quote!(sp::EasingCurve::Steps(#steps))
+        }
+        Expression::EasingCurve(EasingCurve::Spring(stiffness, damping, mass)) => {
+            quote!(sp::EasingCurve::Spring([#stiffness, #damping, #mass]))
+        }
         Expression::LinearGradient { angle, stops } => {
             let angle = compile_expression(angle, ctx);
             let stops = stops.iter().map(|(color, stop)| {
@@ -2321,6 +2462,24 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
                 }
             }
         }
+        Expression::TranslationReference { format_args, string_index, plural } => {
+            let args = compile_expression(format_args, ctx);
+            match plural {
+                Some(plural) => {
+                    let plural = compile_expression(plural, ctx);
+                    quote!(slint::private_unstable_api::translate_from_bundle_with_plural(
+                        &self::_SLINT_TRANSLATED_STRINGS_PLURALS[#string_index],
+                        &self::_SLINT_TRANSLATED_PLURAL_RULES,
+                        #args,
+                        #plural as _
+                    ))
+                }
+                None => quote!(slint::private_unstable_api::translate_from_bundle(
+                    &self::_SLINT_TRANSLATED_STRINGS[#string_index],
+                    #args
+                )),
+            }
+        }
     }
 }
 
@@ -2343,7 +2502,7 @@ fn compile_builtin_function_call(
             }
         }
         BuiltinFunction::ShowPopupWindow => {
-            if let [Expression::NumberLiteral(popup_index), x, y, close_on_click, Expression::PropertyReference(parent_ref)] =
+            if let [Expression::NumberLiteral(popup_index), x, y, anchor, placement, close_policy, Expression::PropertyReference(parent_ref)] =
                 arguments
             {
                 let mut parent_ctx = ctx;
@@ -2362,7 +2521,9 @@ fn compile_builtin_function_call(
                 let parent_component = access_item_rc(parent_ref, ctx);
                 let x = compile_expression(x, ctx);
                 let y = compile_expression(y, ctx);
-                let close_on_click = compile_expression(close_on_click, ctx);
+                let anchor = compile_expression(anchor, ctx);
+                let placement = compile_expression(placement, ctx);
+                let close_policy = compile_expression(close_policy, ctx);
                 let window_adapter_tokens = access_window_adapter_field(ctx);
                 quote!(
                     sp::WindowInner::from_pub(#window_adapter_tokens.window()).show_popup(
@@ -2372,7 +2533,13 @@ fn compile_builtin_function_call(
                             instance.into()
                         }),
                         sp::Point::new(#x as sp::Coord, #y as sp::Coord),
-                        #close_on_click,
+                        {
+                            // The anchor is an anonymous struct, `{height: length, width: length, x: length, y: length}`
+                            let (h, w, x, y) = #anchor;
+                            sp::euclid::rect(x as sp::Coord, y as sp::Coord, w as sp::Coord, h as sp::Coord)
+                        },
+                        #placement,
+                        #close_policy,
                         #parent_component
                     )
                 )
@@ -2464,6 +2631,13 @@ fn compile_builtin_function_call(
             let item_field = access_component_field_offset(&root_component_id, &window_item_name);
             quote!((#item_field + sp::WindowItem::FIELD_OFFSETS.default_font_size).apply_pin(#root_access.as_pin_ref()).get().get())
         }
+        BuiltinFunction::GetWindowLayoutDirection => {
+            let window_item_name = ident(&ctx.public_component.item_tree.root.items[0].name);
+            let root_access = &ctx.generator_state;
+            let root_component_id = inner_component_id(&ctx.public_component.item_tree.root);
+            let item_field = access_component_field_offset(&root_component_id, &window_item_name);
+            quote!((#item_field + sp::WindowItem::FIELD_OFFSETS.layout_direction).apply_pin(#root_access.as_pin_ref()).get())
+        }
         BuiltinFunction::AnimationTick => {
             quote!(sp::animation_tick())
         }
@@ -2520,12 +2694,27 @@ fn compile_builtin_function_call(
             quote!(#x.with_alpha(#alpha as f32))
         }
         BuiltinFunction::ImageSize => quote!( #(#a)*.size()),
+        BuiltinFunction::KeyEventMatchesShortcut => {
+            let event = a.next().unwrap();
+            let shortcut = a.next().unwrap();
+            quote!((#event.shortcut() == Some(#shortcut)))
+        }
         BuiltinFunction::ArrayLength => {
             quote!(match &#(#a)* { x => {
                 x.model_tracker().track_row_count_changes();
                 x.row_count() as i32
             }})
         }
+        BuiltinFunction::ArraySplice => {
+            let (array, length, tail) = (a.next().unwrap(), a.next().unwrap(), a.next().unwrap());
+            quote!(match (&#array, #length as usize, &#tail) { (array, length, tail) => {
+                array.model_tracker().track_row_count_changes();
+                tail.model_tracker().track_row_count_changes();
+                sp::ModelRc::new(sp::VecModel::from(
+                    array.iter().take(length).chain(tail.iter()).collect::<sp::Vec<_>>()
+                ))
+            }})
+        }
 
         BuiltinFunction::Rgb => {
             let (r, g, b, a) =
@@ -2542,10 +2731,21 @@ fn compile_builtin_function_call(
             let window_adapter_tokens = access_window_adapter_field(ctx);
             quote!(sp::WindowInner::from_pub(#window_adapter_tokens.window()).dark_color_scheme())
         }
+        BuiltinFunction::FirstDayOfWeek => quote!(sp::first_day_of_week()),
         BuiltinFunction::TextInputFocused => {
             let window_adapter_tokens = access_window_adapter_field(ctx);
             quote!(sp::WindowInner::from_pub(#window_adapter_tokens.window()).text_input_focused())
         }
+        BuiltinFunction::TooltipText => {
+            let window_adapter_tokens = access_window_adapter_field(ctx);
+            quote!(sp::WindowInner::from_pub(#window_adapter_tokens.window()).tooltip_text(#(#a)*))
+        }
+        BuiltinFunction::TooltipPosition => {
+            let window_adapter_tokens = access_window_adapter_field(ctx);
+            quote!(sp::logical_position_to_api(
+                sp::WindowInner::from_pub(#window_adapter_tokens.window()).tooltip_position(#(#a)*)
+            ))
+        }
         BuiltinFunction::SetTextInputFocused => {
             let window_adapter_tokens = access_window_adapter_field(ctx);
             quote!(sp::WindowInner::from_pub(#window_adapter_tokens.window()).set_text_input_focused(#(#a)*))
@@ -2566,6 +2766,44 @@ fn compile_builtin_function_call(
     }
 }
 
+/// Compile the keyframes of an animation to an expression of type `SharedVector<AnimationKeyframe<_>>`
+fn compile_keyframes(expr: &Expression, ctx: &EvaluationContext) -> TokenStream {
+    match expr {
+        Expression::Array { values, .. } => {
+            let keyframes = values.iter().map(|keyframe| {
+                let Expression::Struct { values, .. } = keyframe else {
+                    panic!("keyframe is not a struct")
+                };
+                let position = compile_expression(&values["position"], ctx);
+                let value = set_primitive_property_value(
+                    &values["value"].ty(ctx),
+                    compile_expression(&values["value"], ctx),
+                );
+                let easing = compile_expression(&values["easing"], ctx);
+                quote!(sp::AnimationKeyframe {
+                    position: #position as _,
+                    value: (#value) as _,
+                    easing: #easing,
+                })
+            });
+            quote!(sp::SharedVector::from_slice(&[#(#keyframes),*]))
+        }
+        Expression::Condition { condition, true_expr, false_expr } => {
+            let condition = compile_expression(condition, ctx);
+            let true_code = compile_keyframes(true_expr, ctx);
+            let false_code = compile_keyframes(false_expr, ctx);
+            quote!(if #condition { #true_code } else { #false_code })
+        }
+        Expression::CodeBlock(sub) => {
+            let (last, statements) = sub.split_last().expect("empty keyframes code block");
+            let statements = statements.iter().map(|e| compile_expression(e, ctx));
+            let last = compile_keyframes(last, ctx);
+            quote!({ #(#statements;)* #last })
+        }
+        _ => panic!("Unexpected expression for keyframes: {expr:?}"),
+    }
+}
+
 /// Return a TokenStream for a name (as in [`Type::Struct::name`])
 fn struct_name_to_tokens(name: &str) -> TokenStream {
     // the name match the C++ signature so we need to change that to the rust namespace
@@ -2658,6 +2896,120 @@ fn embedded_file_tokens(path: &str) -> TokenStream {
     }
 }
 
+fn generate_translations(translations: &crate::translations::Translations) -> TokenStream {
+    let to_option = |x: Option<TokenStream>| match x {
+        Some(x) => quote!(::core::option::Option::Some(#x)),
+        None => quote!(::core::option::Option::None),
+    };
+    let strings = translations.strings.iter().map(|strings| {
+        let array = strings.iter().map(|s| to_option(s.as_ref().map(|s| quote!(#s))));
+        quote!(&[#(#array),*])
+    });
+    let plurals = translations.plurals.iter().map(|plurals| {
+        let array = plurals.iter().map(|p| to_option(p.as_ref().map(|p| quote!(&[#(#p),*]))));
+        quote!(&[#(#array),*])
+    });
+    let plural_rules = translations.plural_rules.iter().map(|rule| {
+        to_option(rule.as_ref().map(|rule| {
+            let rule = compile_plural_expression(rule);
+            quote!(|n: i32| { (#rule) as usize })
+        }))
+    });
+    let languages = &translations.languages;
+    quote!(
+        #[allow(dead_code)]
+        const _SLINT_TRANSLATED_STRINGS: &[&[::core::option::Option<&str>]] = &[#(#strings),*];
+        #[allow(dead_code)]
+        const _SLINT_TRANSLATED_STRINGS_PLURALS: &[&[::core::option::Option<&[&str]>]] = &[#(#plurals),*];
+        #[allow(dead_code)]
+        const _SLINT_TRANSLATED_PLURAL_RULES: &[::core::option::Option<fn(i32) -> usize>] = &[#(#plural_rules),*];
+        const _SLINT_BUNDLED_LANGUAGES: &[&str] = &[#(#languages),*];
+    )
+}
+
+/// Compile the formula of the `Plural-Forms` header to an expression of type `i32`, following
+/// the C semantic of the operators
+fn compile_plural_expression(expr: &crate::translations::PluralExpression) -> TokenStream {
+    use crate::translations::PluralExpression;
+    match expr {
+        PluralExpression::N => quote!(n),
+        PluralExpression::NumberLiteral(x) => quote!(#x),
+        PluralExpression::UnaryOp { op: '!', sub } => {
+            let sub = compile_plural_expression(sub);
+            quote!(((#sub == 0) as i32))
+        }
+        PluralExpression::UnaryOp { op, sub } => {
+            let sub = compile_plural_expression(sub);
+            let op: TokenStream = op.to_string().parse().unwrap();
+            quote!((#op #sub))
+        }
+        PluralExpression::BinaryExpression { lhs, rhs, op } => {
+            let lhs = compile_plural_expression(lhs);
+            let rhs = compile_plural_expression(rhs);
+            match *op {
+                "&&" | "||" => {
+                    let op: TokenStream = op.parse().unwrap();
+                    quote!((((#lhs != 0) #op (#rhs != 0)) as i32))
+                }
+                "==" | "!=" | "<" | "<=" | ">" | ">=" => {
+                    let op: TokenStream = op.parse().unwrap();
+                    quote!(((#lhs #op #rhs) as i32))
+                }
+                _ => {
+                    let op: TokenStream = op.parse().unwrap();
+                    quote!((#lhs #op #rhs))
+                }
+            }
+        }
+        PluralExpression::Condition { condition, true_expr, false_expr } => {
+            let condition = compile_plural_expression(condition);
+            let true_expr = compile_plural_expression(true_expr);
+            let false_expr = compile_plural_expression(false_expr);
+            quote!((if #condition != 0 { #true_expr } else { #false_expr }))
+        }
+    }
+}
+
+/// Returns the `StaticTextures` for the texture of an embedded image, which holds its pixels in the
+/// static `data` array.
+#[cfg(feature = "software-renderer")]
+fn generate_static_textures(
+    texture: &crate::embedded_resources::Texture,
+    data: &proc_macro2::Ident,
+    frames: TokenStream,
+) -> TokenStream {
+    let crate::embedded_resources::Texture {
+        format,
+        rect,
+        total_size: crate::embedded_resources::Size { width, height },
+        original_size:
+            crate::embedded_resources::Size { width: unscaled_width, height: unscaled_height },
+        frame_duration,
+        ..
+    } = texture;
+    let (r_x, r_y, r_w, r_h) = (rect.x(), rect.y(), rect.width(), rect.height());
+    let color = if let crate::embedded_resources::PixelFormat::AlphaMap([r, g, b]) = format {
+        quote!(sp::Color::from_rgb_u8(#r, #g, #b))
+    } else {
+        quote!(sp::Color::from_argb_encoded(0))
+    };
+    quote!(sp::StaticTextures {
+        size: sp::IntSize::new(#width as _, #height as _),
+        original_size: sp::IntSize::new(#unscaled_width as _, #unscaled_height as _),
+        data: sp::Slice::from_slice(&#data),
+        textures: sp::Slice::from_slice(&[
+            sp::StaticTexture {
+                rect: sp::euclid::rect(#r_x as _, #r_y as _, #r_w as _, #r_h as _),
+                format: #format,
+                color: #color,
+                index: 0,
+            }
+        ]),
+        frames: #frames,
+        frame_duration: #frame_duration,
+    })
+}
+
 fn generate_resources(doc: &Document) -> Vec<TokenStream> {
     #[cfg(feature = "software-renderer")]
     let link_section =
@@ -2675,36 +3027,47 @@ fn generate_resources(doc: &Document) -> Vec<TokenStream> {
                     quote!(static #symbol: &'static [u8] = #data;)
                 }
                 #[cfg(feature = "software-renderer")]
-                crate::embedded_resources::EmbeddedResourcesKind::TextureData(crate::embedded_resources::Texture {
-                    data, format, rect,
-                    total_size: crate::embedded_resources::Size{width, height},
-                    original_size: crate::embedded_resources::Size{width: unscaled_width, height: unscaled_height},
-                }) => {
-                    let (r_x, r_y, r_w, r_h) = (rect.x(), rect.y(), rect.width(), rect.height());
-                    let color = if let crate::embedded_resources::PixelFormat::AlphaMap([r, g, b]) = format {
-                        quote!(sp::Color::from_rgb_u8(#r, #g, #b))
+                crate::embedded_resources::EmbeddedResourcesKind::TextureData(texture) => {
+                    let symbol_data = format_ident!("SLINT_EMBEDDED_RESOURCE_{}_DATA", er.id);
+                    let symbol_frames = format_ident!("SLINT_EMBEDDED_RESOURCE_{}_FRAMES", er.id);
+                    let frame_count = texture.frames.len();
+                    let frames = texture.frames.iter().enumerate().map(|(i, frame)| {
+                        generate_static_textures(frame, &format_ident!("SLINT_EMBEDDED_RESOURCE_{}_FRAME_{}_DATA", er.id, i), quote!(sp::Slice::from_slice(&[])))
+                    });
+                    let frames_data = texture.frames.iter().enumerate().map(|(i, frame)| {
+                        let symbol_frame_data = format_ident!("SLINT_EMBEDDED_RESOURCE_{}_FRAME_{}_DATA", er.id, i);
+                        let data = &frame.data;
+                        let data_size = data.len();
+                        quote!(
+                            #link_section
+                            static #symbol_frame_data : [u8; #data_size]= [#(#data),*];
+                        )
+                    });
+                    // The texture of an animated image is its first frame, so it uses the data of that frame
+                    let (data, textures) = if frame_count == 0 {
+                        let data = &texture.data;
+                        let data_size = data.len();
+                        (
+                            quote!(
+                                #link_section
+                                static #symbol_data : [u8; #data_size]= [#(#data),*];
+                            ),
+                            generate_static_textures(texture, &symbol_data, quote!(sp::Slice::from_slice(&[]))),
+                        )
                     } else {
-                        quote!(sp::Color::from_argb_encoded(0))
+                        (
+                            quote!(
+                                #link_section
+                                static #symbol_frames : [sp::StaticTextures; #frame_count] = [#(#frames),*];
+                            ),
+                            generate_static_textures(texture, &format_ident!("SLINT_EMBEDDED_RESOURCE_{}_FRAME_0_DATA", er.id), quote!(sp::Slice::from_slice(&#symbol_frames))),
+                        )
                     };
-                    let symbol_data = format_ident!("SLINT_EMBEDDED_RESOURCE_{}_DATA", er.id);
-                    let data_size = data.len();
                     quote!(
+                        #(#frames_data)*
+                        #data
                         #link_section
-                        static #symbol_data : [u8; #data_size]= [#(#data),*];
-                        #link_section
-                        static #symbol: sp::StaticTextures = sp::StaticTextures{
-                            size: sp::IntSize::new(#width as _, #height as _),
-                            original_size: sp::IntSize::new(#unscaled_width as _, #unscaled_height as _),
-                            data: sp::Slice::from_slice(&#symbol_data),
-                            textures: sp::Slice::from_slice(&[
-                                sp::StaticTexture {
-                                    rect: sp::euclid::rect(#r_x as _, #r_y as _, #r_w as _, #r_h as _),
-                                    format: #format,
-                                    color: #color,
-                                    index: 0,
-                                }
-                            ])
-                        };
+                        static #symbol: sp::StaticTextures = #textures;
                     )
                 },
                 #[cfg(feature = "software-renderer")]
@@ -21,6 +21,7 @@ pub enum Orientation {
 pub enum Layout {
     GridLayout(GridLayout),
     BoxLayout(BoxLayout),
+    FlowLayout(FlowLayout),
 }
 
 impl Layout {
@@ -28,18 +29,21 @@ pub fn rect(&self) -> &LayoutRect {
         match self {
             Layout::GridLayout(g) => &g.geometry.rect,
             Layout::BoxLayout(g) => &g.geometry.rect,
+            Layout::FlowLayout(g) => &g.geometry.rect,
         }
     }
     pub fn rect_mut(&mut self) -> &mut LayoutRect {
         match self {
             Layout::GridLayout(g) => &mut g.geometry.rect,
             Layout::BoxLayout(g) => &mut g.geometry.rect,
+            Layout::FlowLayout(g) => &mut g.geometry.rect,
         }
     }
     pub fn geometry(&self) -> &LayoutGeometry {
         match self {
             Layout::GridLayout(l) => &l.geometry,
             Layout::BoxLayout(l) => &l.geometry,
+            Layout::FlowLayout(l) => &l.geometry,
         }
     }
 }
@@ -50,6 +54,7 @@ pub fn visit_named_references(&mut self, visitor: &mut impl FnMut(&mut NamedRefe
         match self {
             Layout::GridLayout(grid) => grid.visit_named_references(visitor),
             Layout::BoxLayout(l) => l.visit_named_references(visitor),
+            Layout::FlowLayout(l) => l.visit_named_references(visitor),
         }
     }
 }
@@ -416,7 +421,7 @@ fn find_binding<R>(
 }
 
 /// Return a named reference to a property if a binding is set on that property
-fn binding_reference(element: &ElementRc, name: &str) -> Option<NamedReference> {
+pub fn binding_reference(element: &ElementRc, name: &str) -> Option<NamedReference> {
     find_binding(element, name, |_, _, _| NamedReference::new(element, name))
 }
 
@@ -481,6 +486,33 @@ fn visit_named_references(&mut self, visitor: &mut impl FnMut(&mut NamedReferenc
     }
 }
 
+/// Internal representation of a FlowLayout
+#[derive(Debug, Clone)]
+pub struct FlowLayout {
+    /// The direction of the flow: Horizontal if the elements are wrapped into rows,
+    /// Vertical if they are wrapped into columns
+    pub orientation: Orientation,
+    pub elems: Vec<LayoutItem>,
+    pub geometry: LayoutGeometry,
+    pub wrap: Option<NamedReference>,
+    pub cross_alignment: Option<NamedReference>,
+}
+
+impl FlowLayout {
+    fn visit_named_references(&mut self, visitor: &mut impl FnMut(&mut NamedReference)) {
+        for cell in &mut self.elems {
+            cell.constraints.visit_named_references(visitor);
+        }
+        self.geometry.visit_named_references(visitor);
+        if let Some(e) = self.wrap.as_mut() {
+            visitor(&mut *e);
+        }
+        if let Some(e) = self.cross_alignment.as_mut() {
+            visitor(&mut *e);
+        }
+    }
+}
+
 /// The [`Type`] for a runtime LayoutInfo structure
 pub fn layout_info_type() -> Type {
     Type::Struct {
@@ -524,9 +556,13 @@ pub fn implicit_layout_info_call(elem: &ElementRc, orientation: Orientation) ->
                     "Rectangle"
                         | "Empty"
                         | "TouchArea"
+                        | "GestureArea"
+                        | "DragArea"
+                        | "DropArea"
                         | "FocusScope"
                         | "Opacity"
                         | "Layer"
+                        | "TooltipArea"
                         | "BoxShadow"
                         | "Clip"
                 ) =>
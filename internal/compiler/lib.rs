@@ -36,6 +36,7 @@
 pub mod typeregister;
 
 pub mod passes;
+pub mod translations;
 
 use std::path::Path;
 
@@ -95,8 +96,16 @@ pub struct CompilerConfiguration {
     /// The domain used as one of the parameter to the translate function
     pub translation_domain: Option<String>,
 
+    /// When set, the translations are loaded from the `.po` files in
+    /// `<path>/<language>/LC_MESSAGES/<domain>.po` and bundled in the generated code.
+    pub translation_path_bundle: Option<std::path::PathBuf>,
+
     /// C++ namespace
     pub cpp_namespace: Option<String>,
+
+    /// Generate the debug information about the elements (their id and type name), which
+    /// is used to find elements in tests.
+    pub debug_info: bool,
 }
 
 impl CompilerConfiguration {
@@ -145,6 +154,14 @@ pub fn new(output_format: crate::generator::OutputFormat) -> Self {
 
         let enable_component_containers = enable_experimental_features;
 
+        let debug_info = std::env::var_os("SLINT_EMIT_DEBUG_INFO").is_some();
+
+        // The interpreter uses the translations from gettext at runtime
+        let translation_path_bundle = match output_format {
+            crate::generator::OutputFormat::Interpreter => None,
+            _ => std::env::var_os("SLINT_BUNDLE_TRANSLATIONS").map(std::path::PathBuf::from),
+        };
+
         let cpp_namespace = match output_format {
             #[cfg(feature = "cpp")]
             crate::generator::OutputFormat::Cpp(config) => match config.namespace {
@@ -169,7 +186,9 @@ pub fn new(output_format: crate::generator::OutputFormat) -> Self {
             accessibility: true,
             enable_component_containers,
             translation_domain: None,
+            translation_path_bundle,
             cpp_namespace,
+            debug_info,
         }
     }
 }
@@ -212,7 +231,7 @@ pub async fn compile_syntax_node(
     let (foreign_imports, reexports) =
         loader.load_dependencies_recursively(&doc_node, &mut diagnostics, &type_registry).await;
 
-    let doc = crate::object_tree::Document::from_node(
+    let mut doc = crate::object_tree::Document::from_node(
         doc_node,
         foreign_imports,
         reexports,
@@ -227,6 +246,22 @@ pub async fn compile_syntax_node(
 
     if !diagnostics.has_error() {
         passes::run_passes(&doc, &mut loader, &mut diagnostics).await;
+        if let Some(path) = &loader.compiler_config.translation_path_bundle {
+            match &loader.compiler_config.translation_domain {
+                Some(domain) => {
+                    doc.translation_builder =
+                        Some(translations::TranslationsBuilder::load_translations(
+                            path,
+                            domain,
+                            &mut diagnostics,
+                        ))
+                }
+                None => diagnostics.push_error_with_span(
+                    "Bundling translations requires a translation domain".into(),
+                    Default::default(),
+                ),
+            }
+        }
     } else {
         // Don't run all the passes in case of errors because because some invariants are not met.
         passes::run_import_passes(&doc, &loader, &mut diagnostics);
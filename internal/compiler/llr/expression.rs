@@ -187,6 +187,17 @@ pub enum Expression {
         lhs: Box<Expression>,
         rhs: Box<Expression>,
     },
+
+    /// A string translated with `@tr` whose translations are bundled in the generated code
+    TranslationReference {
+        /// An expression of type array of strings
+        format_args: Box<Expression>,
+        /// The index in [`crate::translations::Translations::strings`], or in
+        /// [`crate::translations::Translations::plurals`] if `plural` is set
+        string_index: usize,
+        /// The `n` used to select the plural form
+        plural: Option<Box<Expression>>,
+    },
 }
 
 impl Expression {
@@ -263,6 +274,9 @@ pub fn ty(&self, ctx: &dyn TypeResolutionContext) -> Type {
             },
             Self::Cast { to, .. } => to.clone(),
             Self::CodeBlock(sub) => sub.last().map_or(Type::Void, |e| e.ty(ctx)),
+            Self::BuiltinFunctionCall { function: BuiltinFunction::ArraySplice, arguments } => {
+                arguments[0].ty(ctx)
+            }
             Self::BuiltinFunctionCall { function, .. } => match function.ty() {
                 Type::Function { return_type, .. } => *return_type,
                 _ => unreachable!(),
@@ -301,6 +315,7 @@ pub fn ty(&self, ctx: &dyn TypeResolutionContext) -> Type {
                 Type::Array(super::lower_expression::grid_layout_cell_data_ty().into())
             }
             Self::MinMax { ty, .. } => ty.clone(),
+            Self::TranslationReference { .. } => Type::String,
         }
     }
 }
@@ -382,6 +397,12 @@ macro_rules! visit_impl {
                 $visitor(lhs);
                 $visitor(rhs);
             }
+            Expression::TranslationReference { format_args, plural, .. } => {
+                $visitor(format_args);
+                if let Some(plural) = plural {
+                    $visitor(plural);
+                }
+            }
         }
     };
 }
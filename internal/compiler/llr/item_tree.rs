@@ -40,6 +40,9 @@ pub enum Animation {
 pub struct BindingExpression {
     pub expression: MutExpression,
     pub animation: Option<Animation>,
+    /// The keyframes of the animation: an expression evaluating to an array of
+    /// `slint::private_api::AnimationKeyframe` (possibly depending on the state for transitions)
+    pub keyframes: Option<Expression>,
     /// When true, we can initialize the property with `set` otherwise, `set_binding` must be used
     pub is_constant: bool,
     /// When true, the expression is a "state binding".  Despite the type of the expression being a integer
@@ -252,6 +255,10 @@ pub struct SubComponent {
     /// Maps (item_index, property) to an expression
     pub accessible_prop: BTreeMap<(u32, String), MutExpression>,
 
+    /// Maps item index to the debug information of the elements lowered to that item
+    /// (only when the debug information is enabled)
+    pub element_infos: BTreeMap<u32, String>,
+
     pub prop_analysis: HashMap<PropertyReference, PropAnalysis>,
 }
 
@@ -320,6 +327,8 @@ pub struct PublicComponent {
     pub item_tree: ItemTree,
     pub sub_components: Vec<Rc<SubComponent>>,
     pub globals: Vec<GlobalComponent>,
+    /// The translations bundled in the generated code
+    pub translations: Option<crate::translations::Translations>,
 }
 
 impl PublicComponent {
@@ -130,6 +130,9 @@ pub fn lower_expression(
                     if let llr_Expression::Array { as_model, .. } = &mut arguments[3] {
                         *as_model = false;
                     }
+                    if let Some(translation_builder) = ctx.state.translation_builder.as_ref() {
+                        return lower_translation(&mut translation_builder.borrow_mut(), arguments);
+                    }
                 }
                 llr_Expression::BuiltinFunctionCall { function: f.clone(), arguments }
             }
@@ -351,6 +354,33 @@ fn repeater_special_property(
     llr_Expression::PropertyReference(r)
 }
 
+/// Lower a call to `@tr` to a reference to the translations bundled in the generated code.
+/// The arguments are the string, the context, the domain, the format arguments, `n` and the plural
+fn lower_translation(
+    translation_builder: &mut crate::translations::TranslationsBuilder,
+    arguments: Vec<llr_Expression>,
+) -> llr_Expression {
+    let Ok(
+        [llr_Expression::StringLiteral(string), llr_Expression::StringLiteral(context), _domain, format_args, n, llr_Expression::StringLiteral(plural)],
+    ) = <[_; 6]>::try_from(arguments)
+    else {
+        panic!("internal error: invalid arguments to Translate")
+    };
+    if plural.is_empty() {
+        llr_Expression::TranslationReference {
+            format_args: Box::new(format_args),
+            string_index: translation_builder.collect_string(&string, &context),
+            plural: None,
+        }
+    } else {
+        llr_Expression::TranslationReference {
+            format_args: Box::new(format_args),
+            string_index: translation_builder.collect_plural(&string, &plural, &context),
+            plural: Some(Box::new(n)),
+        }
+    }
+}
+
 fn lower_show_popup(args: &[tree_Expression], ctx: &ExpressionContext) -> llr_Expression {
     if let [tree_Expression::ElementReference(e)] = args {
         let popup_window = e.upgrade().unwrap();
@@ -371,6 +401,7 @@ fn lower_show_popup(args: &[tree_Expression], ctx: &ExpressionContext) -> llr_Ex
             .unwrap();
         let x = llr_Expression::PropertyReference(ctx.map_property_reference(&popup.x));
         let y = llr_Expression::PropertyReference(ctx.map_property_reference(&popup.y));
+        let anchor = llr_Expression::PropertyReference(ctx.map_property_reference(&popup.anchor));
         let item_ref = lower_expression(
             &tree_Expression::ElementReference(Rc::downgrade(&popup.parent_element)),
             ctx,
@@ -381,7 +412,9 @@ fn lower_show_popup(args: &[tree_Expression], ctx: &ExpressionContext) -> llr_Ex
                 llr_Expression::NumberLiteral(popup_index as _),
                 x,
                 y,
-                llr_Expression::BoolLiteral(popup.close_on_click),
+                anchor,
+                llr_Expression::EnumerationValue(popup.placement.clone()),
+                llr_Expression::EnumerationValue(popup.close_policy.clone()),
                 item_ref,
             ],
         }
@@ -412,6 +445,12 @@ fn animation_fields() -> impl Iterator<Item = (String, Type)> {
             ("iteration-count".to_string(), Type::Float32),
             ("easing".to_string(), Type::Easing),
             ("delay".to_string(), Type::Int32),
+            (
+                "direction".to_string(),
+                Type::Enumeration(
+                    crate::typeregister::BUILTIN_ENUMS.with(|e| e.AnimationDirection.clone()),
+                ),
+            ),
         ])
     }
 
@@ -481,6 +520,56 @@ fn animation_ty() -> Type {
     }
 }
 
+/// Lower the keyframes of an animation to an expression evaluating to an array of
+/// `slint::private_api::AnimationKeyframe`, or None if the animation has no keyframes.
+pub fn lower_animation_keyframes(
+    a: &PropertyAnimation,
+    ctx: &ExpressionContext<'_>,
+) -> Option<llr_Expression> {
+    fn keyframes_binding(a: &ElementRc) -> Option<tree_Expression> {
+        a.borrow().bindings.get("keyframes").map(|b| b.borrow().expression.clone())
+    }
+
+    match a {
+        PropertyAnimation::Static(a) => keyframes_binding(a).map(|k| lower_expression(&k, ctx)),
+        PropertyAnimation::Transition { state_ref, animations } => {
+            let element_ty =
+                animations.iter().find_map(|tr| match keyframes_binding(&tr.animation)?.ty() {
+                    Type::Array(ty) => Some(*ty),
+                    _ => None,
+                })?;
+            let empty = || llr_Expression::Array {
+                element_ty: element_ty.clone(),
+                values: vec![],
+                as_model: false,
+            };
+            let set_state = llr_Expression::StoreLocalVariable {
+                name: "state".into(),
+                value: Box::new(lower_expression(state_ref, ctx)),
+            };
+            let mut get_keyframes = empty();
+            for tr in animations.iter().rev() {
+                let condition = lower_expression(
+                    &tr.condition(tree_Expression::ReadLocalVariable {
+                        name: "state".into(),
+                        ty: state_ref.ty(),
+                    }),
+                    ctx,
+                );
+                get_keyframes = llr_Expression::Condition {
+                    condition: Box::new(condition),
+                    true_expr: Box::new(
+                        keyframes_binding(&tr.animation)
+                            .map_or_else(empty, |k| lower_expression(&k, ctx)),
+                    ),
+                    false_expr: Box::new(get_keyframes),
+                }
+            }
+            Some(llr_Expression::CodeBlock(vec![set_state, get_keyframes]))
+        }
+    }
+}
+
 fn compute_layout_info(
     l: &crate::layout::Layout,
     o: Orientation,
@@ -523,6 +612,36 @@ fn compute_layout_info(
                 None => sub_expression,
             }
         }
+        crate::layout::Layout::FlowLayout(layout) => {
+            let (padding, spacing) = generate_layout_padding_and_spacing(&layout.geometry, o, ctx);
+            if o == layout.orientation {
+                let (cells, compute_cells) = box_layout_cells(&layout.elems, o, ctx, "cells");
+                let sub_expression = llr_Expression::ExtraBuiltinFunctionCall {
+                    function: "flow_layout_info".into(),
+                    arguments: vec![
+                        cells,
+                        spacing,
+                        padding,
+                        flow_layout_alignment(layout, ctx),
+                        flow_layout_wrap(layout, ctx),
+                    ],
+                    return_ty: crate::layout::layout_info_type(),
+                };
+                with_box_layout_cells(compute_cells, None, o, sub_expression)
+            } else {
+                let (data, compute_cells) = flow_layout_data(layout, ctx);
+                let (cross_cells, compute_cross_cells) =
+                    box_layout_cells(&layout.elems, o, ctx, "cross_cells");
+                let sub_expression = llr_Expression::ExtraBuiltinFunctionCall {
+                    function: "flow_layout_info_cross".into(),
+                    arguments: vec![data, cross_cells, spacing, padding],
+                    return_ty: crate::layout::layout_info_type(),
+                };
+                let sub_expression =
+                    with_box_layout_cells(compute_cross_cells, None, o, sub_expression);
+                with_box_layout_cells(compute_cells, None, layout.orientation, sub_expression)
+            }
+        }
     }
 }
 
@@ -568,6 +687,7 @@ fn solve_layout(
                                 ("size", Type::Float32, size),
                                 ("spacing", Type::Float32, spacing),
                                 ("padding", padding.ty(ctx), padding),
+                                ("direction", layout_direction_ty(), layout_direction(o)),
                                 (
                                     "cells",
                                     cells_ty.clone(),
@@ -590,6 +710,7 @@ fn solve_layout(
                             ("size", Type::Float32, size),
                             ("spacing", Type::Float32, spacing),
                             ("padding", padding.ty(ctx), padding),
+                            ("direction", layout_direction_ty(), layout_direction(o)),
                             ("cells", cells.ty(ctx), cells),
                         ],
                     )],
@@ -613,6 +734,7 @@ fn solve_layout(
                             .with(|e| Type::Enumeration(e.LayoutAlignment.clone())),
                         bld.alignment,
                     ),
+                    ("direction", layout_direction_ty(), layout_direction(o)),
                     ("cells", bld.cells.ty(ctx), bld.cells),
                 ],
             );
@@ -648,6 +770,170 @@ fn solve_layout(
                 },
             }
         }
+        crate::layout::Layout::FlowLayout(layout) => {
+            let (data, compute_cells) = flow_layout_data(layout, ctx);
+            let repeater_indices = if compute_cells.is_some() {
+                llr_Expression::ReadLocalVariable {
+                    name: "repeated_indices".into(),
+                    ty: Type::Array(Type::Int32.into()),
+                }
+            } else {
+                llr_Expression::Array { element_ty: Type::Int32, values: vec![], as_model: false }
+            };
+            if o == layout.orientation {
+                let sub_expression = llr_Expression::ExtraBuiltinFunctionCall {
+                    function: "solve_flow_layout".into(),
+                    arguments: vec![data, repeater_indices],
+                    return_ty: Type::LayoutCache,
+                };
+                with_box_layout_cells(compute_cells, Some("repeated_indices"), o, sub_expression)
+            } else {
+                let (padding, spacing) =
+                    generate_layout_padding_and_spacing(&layout.geometry, o, ctx);
+                let (cross_cells, compute_cross_cells) =
+                    box_layout_cells(&layout.elems, o, ctx, "cross_cells");
+                let cross_alignment = match &layout.cross_alignment {
+                    Some(expr) => {
+                        llr_Expression::PropertyReference(ctx.map_property_reference(expr))
+                    }
+                    None => {
+                        let e = crate::typeregister::BUILTIN_ENUMS
+                            .with(|e| e.LayoutCrossAlignment.clone());
+                        llr_Expression::EnumerationValue(EnumerationValue {
+                            value: e.default_value,
+                            enumeration: e,
+                        })
+                    }
+                };
+                let cross = make_struct(
+                    "FlowCrossLayoutData",
+                    [
+                        (
+                            "size",
+                            Type::Float32,
+                            layout_geometry_size(&layout.geometry.rect, o, ctx),
+                        ),
+                        ("spacing", Type::Float32, spacing),
+                        ("padding", padding.ty(ctx), padding),
+                        (
+                            "alignment",
+                            crate::typeregister::BUILTIN_ENUMS
+                                .with(|e| Type::Enumeration(e.LayoutCrossAlignment.clone())),
+                            cross_alignment,
+                        ),
+                        ("cells", cross_cells.ty(ctx), cross_cells),
+                    ],
+                );
+                let sub_expression = llr_Expression::ExtraBuiltinFunctionCall {
+                    function: "solve_flow_layout_cross".into(),
+                    arguments: vec![data, cross, repeater_indices],
+                    return_ty: Type::LayoutCache,
+                };
+                let sub_expression = with_box_layout_cells(
+                    compute_cross_cells,
+                    Some("repeated_indices"),
+                    o,
+                    sub_expression,
+                );
+                with_box_layout_cells(compute_cells, None, layout.orientation, sub_expression)
+            }
+        }
+    }
+}
+
+/// Wrap the sub_expression in a BoxLayoutFunction that computes the cells, if needed
+fn with_box_layout_cells(
+    compute_cells: Option<(String, Vec<Either<llr_Expression, u32>>)>,
+    repeater_indices: Option<&str>,
+    orientation: Orientation,
+    sub_expression: llr_Expression,
+) -> llr_Expression {
+    match compute_cells {
+        Some((cells_variable, elements)) => llr_Expression::BoxLayoutFunction {
+            cells_variable,
+            repeater_indices: repeater_indices.map(Into::into),
+            elements,
+            orientation,
+            sub_expression: Box::new(sub_expression),
+        },
+        None => sub_expression,
+    }
+}
+
+/// Return the expression for the FlowLayoutData of a FlowLayout, and the elements to compute
+/// the cells if there are repeater involved (see [`box_layout_cells`])
+#[allow(clippy::type_complexity)]
+fn flow_layout_data(
+    layout: &crate::layout::FlowLayout,
+    ctx: &ExpressionContext,
+) -> (llr_Expression, Option<(String, Vec<Either<llr_Expression, u32>>)>) {
+    let o = layout.orientation;
+    let (padding, spacing) = generate_layout_padding_and_spacing(&layout.geometry, o, ctx);
+    let (cells, compute_cells) = box_layout_cells(&layout.elems, o, ctx, "cells");
+    let alignment = flow_layout_alignment(layout, ctx);
+    let data = make_struct(
+        "FlowLayoutData",
+        [
+            ("size", Type::Float32, layout_geometry_size(&layout.geometry.rect, o, ctx)),
+            ("spacing", Type::Float32, spacing),
+            ("padding", padding.ty(ctx), padding),
+            (
+                "alignment",
+                crate::typeregister::BUILTIN_ENUMS
+                    .with(|e| Type::Enumeration(e.LayoutAlignment.clone())),
+                alignment,
+            ),
+            ("wrap", Type::Bool, flow_layout_wrap(layout, ctx)),
+            ("direction", layout_direction_ty(), layout_direction(o)),
+            ("cells", cells.ty(ctx), cells),
+        ],
+    );
+    (data, compute_cells)
+}
+
+fn layout_direction_ty() -> Type {
+    crate::typeregister::BUILTIN_ENUMS.with(|e| Type::Enumeration(e.LayoutDirection.clone()))
+}
+
+/// The direction of a layout in the given orientation: only the horizontal direction follows the
+/// `layout-direction` of the window.
+fn layout_direction(o: Orientation) -> llr_Expression {
+    match o {
+        Orientation::Horizontal => llr_Expression::BuiltinFunctionCall {
+            function: BuiltinFunction::GetWindowLayoutDirection,
+            arguments: vec![],
+        },
+        Orientation::Vertical => {
+            let e = crate::typeregister::BUILTIN_ENUMS.with(|e| e.LayoutDirection.clone());
+            llr_Expression::EnumerationValue(EnumerationValue {
+                value: e.values.iter().position(|v| v == "left-to-right").unwrap(),
+                enumeration: e,
+            })
+        }
+    }
+}
+
+/// The alignment of a FlowLayout, which defaults to `start` (unlike for other layouts)
+fn flow_layout_alignment(
+    layout: &crate::layout::FlowLayout,
+    ctx: &ExpressionContext,
+) -> llr_Expression {
+    match &layout.geometry.alignment {
+        Some(expr) => llr_Expression::PropertyReference(ctx.map_property_reference(expr)),
+        None => {
+            let e = crate::typeregister::BUILTIN_ENUMS.with(|e| e.LayoutAlignment.clone());
+            llr_Expression::EnumerationValue(EnumerationValue {
+                value: e.values.iter().position(|v| v == "start").unwrap(),
+                enumeration: e,
+            })
+        }
+    }
+}
+
+fn flow_layout_wrap(layout: &crate::layout::FlowLayout, ctx: &ExpressionContext) -> llr_Expression {
+    match &layout.wrap {
+        Some(expr) => llr_Expression::PropertyReference(ctx.map_property_reference(expr)),
+        None => llr_Expression::BoolLiteral(true),
     }
 }
 
@@ -674,8 +960,21 @@ fn box_layout_data(
         })
     };
 
-    let repeater_count =
-        layout.elems.iter().filter(|i| i.element.borrow().repeated.is_some()).count();
+    let (cells, compute_cells) = box_layout_cells(&layout.elems, orientation, ctx, "cells");
+    BoxLayoutDataResult { alignment, cells, compute_cells }
+}
+
+/// Return the expression for the array of BoxLayoutCellData for the given elements.
+/// When there are repeater involved, the expression reads the `cells_variable`, and the
+/// second member of the tuple contains the elements to compute it with a BoxLayoutFunction.
+#[allow(clippy::type_complexity)]
+fn box_layout_cells(
+    elems: &[crate::layout::LayoutItem],
+    orientation: Orientation,
+    ctx: &ExpressionContext,
+    cells_variable: &str,
+) -> (llr_Expression, Option<(String, Vec<Either<llr_Expression, u32>>)>) {
+    let repeater_count = elems.iter().filter(|i| i.element.borrow().repeated.is_some()).count();
 
     let element_ty = Type::Struct {
         fields: IntoIterator::into_iter([(
@@ -690,8 +989,7 @@ fn box_layout_data(
 
     if repeater_count == 0 {
         let cells = llr_Expression::Array {
-            values: layout
-                .elems
+            values: elems
                 .iter()
                 .map(|li| {
                     let layout_info =
@@ -705,10 +1003,10 @@ fn box_layout_data(
             element_ty,
             as_model: false,
         };
-        BoxLayoutDataResult { alignment, cells, compute_cells: None }
+        (cells, None)
     } else {
         let mut elements = vec![];
-        for item in &layout.elems {
+        for item in elems {
             if item.element.borrow().repeated.is_some() {
                 let repeater_index =
                     match ctx.mapping.element_mapping.get(&item.element.clone().into()).unwrap() {
@@ -726,10 +1024,10 @@ fn box_layout_data(
             }
         }
         let cells = llr_Expression::ReadLocalVariable {
-            name: "cells".into(),
+            name: cells_variable.into(),
             ty: Type::Array(Box::new(crate::layout::layout_info_type())),
         };
-        BoxLayoutDataResult { alignment, cells, compute_cells: Some(("cells".into(), elements)) }
+        (cells, Some((cells_variable.into(), elements)))
     }
 }
 
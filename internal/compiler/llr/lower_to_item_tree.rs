@@ -8,12 +8,22 @@
 use crate::langtype::{ElementType, Type};
 use crate::llr::item_tree::*;
 use crate::namedreference::NamedReference;
-use crate::object_tree::{Component, ElementRc, PropertyVisibility};
+use crate::object_tree::{Component, Document, ElementRc, PropertyVisibility};
+use crate::CompilerConfiguration;
+use std::cell::RefCell;
 use std::collections::{BTreeMap, HashMap};
 use std::rc::Rc;
 
-pub fn lower_to_item_tree(component: &Rc<Component>) -> PublicComponent {
-    let mut state = LoweringState::default();
+pub fn lower_to_item_tree(
+    document: &Document,
+    compiler_config: &CompilerConfiguration,
+) -> PublicComponent {
+    let component = &document.root_component;
+    let mut state = LoweringState {
+        debug_info: compiler_config.debug_info,
+        translation_builder: document.translation_builder.clone().map(RefCell::new),
+        ..Default::default()
+    };
 
     let mut globals = Vec::new();
     for g in &component.used_types.borrow().globals {
@@ -46,6 +56,7 @@ pub fn lower_to_item_tree(component: &Rc<Component>) -> PublicComponent {
             .collect(),
         public_properties,
         private_properties: component.private_properties.borrow().clone(),
+        translations: state.translation_builder.take().map(|x| x.into_inner().result()),
     };
     super::optim_passes::run_passes(&root);
     root
@@ -55,6 +66,8 @@ pub fn lower_to_item_tree(component: &Rc<Component>) -> PublicComponent {
 pub struct LoweringState {
     global_properties: HashMap<NamedReference, PropertyReference>,
     sub_components: HashMap<ByAddress<Rc<Component>>, LoweredSubComponent>,
+    debug_info: bool,
+    pub translation_builder: Option<RefCell<crate::translations::TranslationsBuilder>>,
 }
 
 #[derive(Debug, Clone)]
@@ -200,6 +213,7 @@ fn lower_sub_component(
         layout_info_h: super::Expression::BoolLiteral(false).into(),
         layout_info_v: super::Expression::BoolLiteral(false).into(),
         accessible_prop: Default::default(),
+        element_infos: Default::default(),
         prop_analysis: Default::default(),
     };
     let mut mapping = LoweredSubComponentMapping::default();
@@ -312,6 +326,12 @@ fn lower_sub_component(
             }
             _ => unreachable!(),
         };
+        if state.debug_info {
+            let element_infos = elem.element_infos();
+            if !element_infos.is_empty() {
+                sub_component.element_infos.insert(*elem.item_index.get().unwrap(), element_infos);
+            }
+        }
         for (key, nr) in &elem.accessibility_props.0 {
             // TODO: we also want to split by type (role/string/...)
             let enum_value =
@@ -349,6 +369,11 @@ fn lower_sub_component(
                 .as_ref()
                 .filter(|_| !is_constant)
                 .map(|a| super::lower_expression::lower_animation(a, &ctx));
+            let keyframes = binding
+                .animation
+                .as_ref()
+                .filter(|_| !is_constant)
+                .and_then(|a| super::lower_expression::lower_animation_keyframes(a, &ctx));
 
             sub_component.prop_analysis.insert(
                 prop.clone(),
@@ -368,6 +393,7 @@ fn lower_sub_component(
                 BindingExpression {
                     expression,
                     animation,
+                    keyframes,
                     is_constant,
                     is_state_info,
                     use_count: 0.into(),
@@ -457,6 +483,14 @@ fn lower_sub_component(
                 }
                 Type::String => {}
                 Type::Enumeration(e) if e.name == "AccessibleRole" => {}
+                Type::Callback { args, .. } => {
+                    expr = super::Expression::CallBackCall {
+                        callback: ctx.map_property_reference(&nr),
+                        arguments: (0..args.len())
+                            .map(|index| super::Expression::FunctionParameterReference { index })
+                            .collect(),
+                    };
+                }
                 _ => panic!("Invalid type for accessible property"),
             }
 
@@ -691,6 +725,7 @@ fn lower_global(
         init_values[property_index] = Some(BindingExpression {
             expression: expression.into(),
             animation: None,
+            keyframes: None,
             is_constant,
             is_state_info: false,
             use_count: 0.into(),
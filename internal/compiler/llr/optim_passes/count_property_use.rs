@@ -144,6 +144,9 @@ fn visit_binding_expression(binding: &BindingExpression, ctx: &EvaluationContext
         }
         None => (),
     }
+    if let Some(keyframes) = &binding.keyframes {
+        keyframes.visit_recursive(&mut |e| visit_expression(e, ctx))
+    }
 }
 
 fn visit_expression(expr: &Expression, ctx: &EvaluationContext) {
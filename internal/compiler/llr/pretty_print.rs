@@ -265,6 +265,14 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result {
                 MinMaxOp::Min => write!(f, "min({}, {})", e(lhs), e(rhs)),
                 MinMaxOp::Max => write!(f, "max({}, {})", e(lhs), e(rhs)),
             },
+            Expression::TranslationReference { format_args, string_index, plural } => {
+                match plural {
+                    Some(plural) => {
+                        write!(f, "@tr({:?} % {}, {})", string_index, e(plural), e(format_args))
+                    }
+                    None => write!(f, "@tr({:?}, {})", string_index, e(format_args)),
+                }
+            }
         }
     }
 }
@@ -34,6 +34,9 @@ pub struct LookupCtx<'a> {
     /// The name of the arguments of the callback or function
     pub arguments: Vec<String>,
 
+    /// The local variables declared with `let`, for each of the enclosing code blocks
+    pub local_variables: Vec<Vec<(String, Type)>>,
+
     /// The type register in which to look for Globals
     pub type_register: &'a TypeRegister,
 
@@ -54,6 +57,7 @@ pub fn empty_context(type_register: &'a TypeRegister, diag: &'a mut BuildDiagnos
             component_scope: Default::default(),
             diag,
             arguments: Default::default(),
+            local_variables: Default::default(),
             type_register,
             type_loader: None,
             current_token: None,
@@ -185,6 +189,32 @@ fn lookup(&self, ctx: &LookupCtx, name: &str) -> Option<LookupResult> {
     }
 }
 
+/// The name of the local variable in the generated code for a variable declared
+/// with `let` in a code block
+pub fn local_variable_name(name: &str) -> String {
+    format!("local_{name}")
+}
+
+struct LocalVariableLookup;
+impl LookupObject for LocalVariableLookup {
+    fn for_each_entry<R>(
+        &self,
+        ctx: &LookupCtx,
+        f: &mut impl FnMut(&str, LookupResult) -> Option<R>,
+    ) -> Option<R> {
+        for (name, ty) in ctx.local_variables.iter().rev().flat_map(|scope| scope.iter().rev()) {
+            if let Some(r) = f(
+                name,
+                Expression::ReadLocalVariable { name: local_variable_name(name), ty: ty.clone() }
+                    .into(),
+            ) {
+                return Some(r);
+            }
+        }
+        None
+    }
+}
+
 struct ArgumentsLookup;
 impl LookupObject for ArgumentsLookup {
     fn for_each_entry<R>(
@@ -604,6 +634,24 @@ fn for_each_entry<R>(
                     Expression::EasingCurve(CubicBezier(0.45, 0.0, 0.55, 1.0)).into(),
                 )
             })
+            .or_else(|| {
+                f(
+                    "ease-in-cubic",
+                    Expression::EasingCurve(CubicBezier(0.32, 0.0, 0.67, 0.0)).into(),
+                )
+            })
+            .or_else(|| {
+                f(
+                    "ease-out-cubic",
+                    Expression::EasingCurve(CubicBezier(0.33, 1.0, 0.68, 1.0)).into(),
+                )
+            })
+            .or_else(|| {
+                f(
+                    "ease-in-out-cubic",
+                    Expression::EasingCurve(CubicBezier(0.65, 0.0, 0.35, 1.0)).into(),
+                )
+            })
             .or_else(|| {
                 f("ease", Expression::EasingCurve(CubicBezier(0.25, 0.1, 0.25, 1.0)).into())
             })
@@ -719,6 +767,26 @@ fn for_each_entry<R>(
                     .into(),
                 )
             })
+            .or_else(|| {
+                f(
+                    "steps",
+                    Expression::BuiltinMacroReference(
+                        BuiltinMacroFunction::Steps,
+                        ctx.current_token.clone(),
+                    )
+                    .into(),
+                )
+            })
+            .or_else(|| {
+                f(
+                    "spring",
+                    Expression::BuiltinMacroReference(
+                        BuiltinMacroFunction::Spring,
+                        ctx.current_token.clone(),
+                    )
+                    .into(),
+                )
+            })
             .or_else(|| {
                 f("ease-in-elastic", Expression::EasingCurve(EasingCurve::EaseInElastic).into())
             })
@@ -803,19 +871,34 @@ fn for_each_entry<R>(
         ctx: &LookupCtx,
         f: &mut impl FnMut(&str, LookupResult) -> Option<R>,
     ) -> Option<R> {
-        f(
-            "dark-color-scheme",
-            Expression::FunctionCall {
-                function: Expression::BuiltinFunctionReference(
-                    BuiltinFunction::DarkColorScheme,
-                    None,
+        let t = &ctx.current_token;
+        let sl = || t.as_ref().map(|t| t.to_source_location());
+        let mut f = |n, e: Expression| f(n, e.into());
+        let call = |function| Expression::FunctionCall {
+            function: Expression::BuiltinFunctionReference(function, None).into(),
+            arguments: vec![],
+            source_location: sl(),
+        };
+        None.or_else(|| f("dark-color-scheme", call(BuiltinFunction::DarkColorScheme)))
+            .or_else(|| f("first-day-of-week", call(BuiltinFunction::FirstDayOfWeek)))
+            .or_else(|| {
+                f(
+                    "tooltip-text",
+                    Expression::BuiltinFunctionReference(BuiltinFunction::TooltipText, sl()),
                 )
-                .into(),
-                arguments: vec![],
-                source_location: ctx.current_token.as_ref().map(|t| t.to_source_location()),
-            }
-            .into(),
-        )
+            })
+            .or_else(|| {
+                f(
+                    "tooltip-position",
+                    Expression::BuiltinFunctionReference(BuiltinFunction::TooltipPosition, sl()),
+                )
+            })
+            .or_else(|| {
+                f(
+                    "array-splice",
+                    Expression::BuiltinMacroReference(BuiltinMacroFunction::ArraySplice, t.clone()),
+                )
+            })
     }
 }
 
@@ -888,16 +971,22 @@ fn for_each_entry<R>(
 
 pub fn global_lookup() -> impl LookupObject {
     (
-        ArgumentsLookup,
+        LocalVariableLookup,
         (
-            SpecialIdLookup,
+            ArgumentsLookup,
             (
-                IdLookup,
+                SpecialIdLookup,
                 (
-                    InScopeLookup,
+                    IdLookup,
                     (
-                        LookupType,
-                        (BuiltinNamespaceLookup, (ReturnTypeSpecificLookup, BuiltinFunctionLookup)),
+                        InScopeLookup,
+                        (
+                            LookupType,
+                            (
+                                BuiltinNamespaceLookup,
+                                (ReturnTypeSpecificLookup, BuiltinFunctionLookup),
+                            ),
+                        ),
                     ),
                 ),
             ),
@@ -914,7 +1003,7 @@ fn for_each_entry<R>(
         match self {
             Expression::ElementReference(e) => e.upgrade().unwrap().for_each_entry(ctx, f),
             _ => match self.ty() {
-                Type::Struct { fields, .. } => {
+                Type::Struct { fields, name, .. } => {
                     for name in fields.keys() {
                         if let Some(r) = f(
                             name,
@@ -927,6 +1016,9 @@ fn for_each_entry<R>(
                             return Some(r);
                         }
                     }
+                    if name.as_deref() == Some(KEY_EVENT_STRUCT_NAME) {
+                        return KeyEventExpression(self).for_each_entry(ctx, f);
+                    }
                     None
                 }
                 Type::String => StringExpression(self).for_each_entry(ctx, f),
@@ -942,12 +1034,18 @@ fn lookup(&self, ctx: &LookupCtx, name: &str) -> Option<LookupResult> {
         match self {
             Expression::ElementReference(e) => e.upgrade().unwrap().lookup(ctx, name),
             _ => match self.ty() {
-                Type::Struct { fields, .. } => fields.contains_key(name).then(|| {
-                    LookupResult::from(Expression::StructFieldAccess {
-                        base: Box::new(self.clone()),
-                        name: name.to_string(),
-                    })
-                }),
+                Type::Struct { fields, name: struct_name, .. } => {
+                    if fields.contains_key(name) {
+                        Some(LookupResult::from(Expression::StructFieldAccess {
+                            base: Box::new(self.clone()),
+                            name: name.to_string(),
+                        }))
+                    } else if struct_name.as_deref() == Some(KEY_EVENT_STRUCT_NAME) {
+                        KeyEventExpression(self).lookup(ctx, name)
+                    } else {
+                        None
+                    }
+                }
                 Type::String => StringExpression(self).lookup(ctx, name),
                 Type::Brush | Type::Color => ColorExpression(self).lookup(ctx, name),
                 Type::Image => ImageExpression(self).lookup(ctx, name),
@@ -979,6 +1077,28 @@ fn for_each_entry<R>(
             .or_else(|| f("to-float", member_function(BuiltinFunction::StringToFloat)))
     }
 }
+const KEY_EVENT_STRUCT_NAME: &str = "slint::private_api::KeyEvent";
+
+struct KeyEventExpression<'a>(&'a Expression);
+impl<'a> LookupObject for KeyEventExpression<'a> {
+    fn for_each_entry<R>(
+        &self,
+        ctx: &LookupCtx,
+        f: &mut impl FnMut(&str, LookupResult) -> Option<R>,
+    ) -> Option<R> {
+        let member_function = |f: BuiltinFunction| {
+            LookupResult::from(Expression::MemberFunction {
+                base: Box::new(self.0.clone()),
+                base_node: ctx.current_token.clone(), // Note that this is not the base_node, but the function's node
+                member: Box::new(Expression::BuiltinFunctionReference(
+                    f,
+                    ctx.current_token.as_ref().map(|t| t.to_source_location()),
+                )),
+            })
+        };
+        f("matches-shortcut", member_function(BuiltinFunction::KeyEventMatchesShortcut))
+    }
+}
 struct ColorExpression<'a>(&'a Expression);
 impl<'a> LookupObject for ColorExpression<'a> {
     fn for_each_entry<R>(
@@ -9,7 +9,7 @@
 
 use crate::diagnostics::{BuildDiagnostics, SourceLocation, Spanned};
 use crate::expression_tree::{self, BindingExpression, Expression, Unit};
-use crate::langtype::{BuiltinElement, Enumeration, NativeClass, Type};
+use crate::langtype::{BuiltinElement, Enumeration, EnumerationValue, NativeClass, Type};
 use crate::langtype::{ElementType, PropertyLookupResult};
 use crate::layout::{LayoutConstraints, Orientation};
 use crate::namedreference::NamedReference;
@@ -50,6 +50,9 @@ pub struct Document {
     /// startup for custom font use.
     pub custom_fonts: Vec<(String, crate::parser::SyntaxToken)>,
     pub exports: Exports,
+    /// The translations to bundle in the generated code, when
+    /// [`CompilerConfiguration::translation_path_bundle`](crate::CompilerConfiguration::translation_path_bundle) is set
+    pub translation_builder: Option<crate::translations::TranslationsBuilder>,
 }
 
 impl Document {
@@ -245,6 +248,7 @@ pub fn from_node(
             local_registry,
             custom_fonts,
             exports,
+            translation_builder: None,
         }
     }
 }
@@ -254,7 +258,11 @@ pub struct PopupWindow {
     pub component: Rc<Component>,
     pub x: NamedReference,
     pub y: NamedReference,
-    pub close_on_click: bool,
+    /// A property of the parent element with the geometry of the anchor (a struct with
+    /// x, y, width and height), relative to the parent element
+    pub anchor: NamedReference,
+    pub placement: EnumerationValue,
+    pub close_policy: EnumerationValue,
     pub parent_element: ElementRc,
 }
 
@@ -529,11 +537,11 @@ fn clone(&self) -> Self {
         fn deep_clone(e: &ElementRc) -> ElementRc {
             let e = e.borrow();
             debug_assert!(e.children.is_empty());
-            debug_assert!(e.property_declarations.is_empty());
             debug_assert!(e.states.is_empty() && e.transitions.is_empty());
             Rc::new(RefCell::new(Element {
                 id: e.id.clone(),
                 base_type: e.base_type.clone(),
+                property_declarations: e.property_declarations.clone(),
                 bindings: e.bindings.clone(),
                 property_analysis: e.property_analysis.clone(),
                 enclosing_component: e.enclosing_component.clone(),
@@ -1641,6 +1649,48 @@ pub fn original_name(&self) -> String {
             .unwrap_or_else(|| self.id.clone())
     }
 
+    /// Returns the qualified id (`Component::id`) and the type name of the elements that were
+    /// merged into this element, one per line and separated by a comma.
+    ///
+    /// This is the debug information exposed by the `item_element_infos` function of the ItemTree.
+    pub fn element_infos(&self) -> String {
+        self.debug
+            .iter()
+            .map(|(node, _)| {
+                let source_file = node.source_file.clone();
+                let id = match node.node.parent() {
+                    Some(p) if p.kind() == SyntaxKind::SubElement => {
+                        parser::identifier_text(&SyntaxNode {
+                            node: p,
+                            source_file: source_file.clone(),
+                        })
+                    }
+                    Some(p) if p.kind() == SyntaxKind::Component => Some("root".into()),
+                    _ => None,
+                };
+                let component = node
+                    .node
+                    .ancestors()
+                    .find(|n| n.kind() == SyntaxKind::Component)
+                    .map(|c| {
+                        syntax_nodes::Component::from(SyntaxNode { node: c, source_file })
+                            .DeclaredIdentifier()
+                    })
+                    .and_then(|c| parser::identifier_text(&c));
+                let qualified_id = match (component, id) {
+                    (Some(component), Some(id)) => format!("{component}::{id}"),
+                    _ => String::new(),
+                };
+                let type_name = node.QualifiedName().map_or_else(
+                    || "Empty".into(),
+                    |q| QualifiedTypeName::from_node(q).to_string(),
+                );
+                format!("{qualified_id},{type_name}")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
     /// Return true if the binding is set, either on this element or in a base
     ///
     /// If `need_explicit` is true, then only consider binding set in the code, not the ones set
@@ -1768,7 +1818,7 @@ fn animation_element_from_node(
     diag: &mut BuildDiagnostics,
     tr: &TypeRegister,
 ) -> Option<ElementRc> {
-    let anim_type = tr.property_animation_type_for_property(prop_type);
+    let anim_type = tr.property_animation_type_for_property(prop_type.clone());
     if !matches!(anim_type, ElementType::Builtin(..)) {
         diag.push_error(
             format!(
@@ -1789,6 +1839,27 @@ fn animation_element_from_node(
             diag,
         );
 
+        let mut keyframes = anim.Keyframes();
+        if let Some(k) = keyframes.next() {
+            // The keyframes are resolved into an array of AnimationKeyframe by the resolving pass
+            anim_element.property_declarations.insert(
+                "keyframes".into(),
+                PropertyDeclaration {
+                    property_type: Type::Array(Box::new(
+                        crate::typeregister::animation_keyframe_type(prop_type),
+                    )),
+                    node: Some(k.clone().into()),
+                    ..Default::default()
+                },
+            );
+            anim_element
+                .bindings
+                .insert("keyframes".into(), BindingExpression::new_uncompiled(k.into()).into());
+        }
+        for k in keyframes {
+            diag.push_error("Duplicated keyframes".into(), &k);
+        }
+
         apply_default_type_properties(&mut anim_element);
 
         Some(Rc::new(RefCell::new(anim_element)))
@@ -2188,6 +2259,7 @@ pub fn visit_all_named_references(
                 compo.popup_windows.borrow_mut().iter_mut().for_each(|p| {
                     vis(&mut p.x);
                     vis(&mut p.y);
+                    vis(&mut p.anchor);
                 });
             }
             compo
@@ -346,7 +346,11 @@ fn source_file(&self) -> Option<&SourceFile> {
         /// Declaration of a property.
         PropertyDeclaration-> [ ?Type , DeclaredIdentifier, ?BindingExpression, ?TwoWayBinding ],
         /// QualifiedName are the properties name
-        PropertyAnimation-> [ *QualifiedName, *Binding ],
+        PropertyAnimation-> [ *QualifiedName, *Binding, *Keyframes ],
+        /// `keyframes { 50% { value: ...; } }` in a PropertyAnimation
+        Keyframes -> [ *Keyframe ],
+        /// `50% { value: ...; easing: ...; }`
+        Keyframe -> [ *Binding ],
         /// wraps Identifiers, like `Rectangle` or `SomeModule.SomeType`
         QualifiedName-> [],
         /// Wraps single identifier (to disambiguate when there are other identifier in the production)
@@ -358,8 +362,10 @@ fn source_file(&self) -> Option<&SourceFile> {
         /// the right-hand-side of a binding
         // Fixme: the test should be a or
         BindingExpression-> [ ?CodeBlock, ?Expression ],
-        CodeBlock-> [ *Expression, *ReturnStatement ],
+        CodeBlock-> [ *Expression, *ReturnStatement, *LetStatement ],
         ReturnStatement -> [ ?Expression ],
+        /// `let foo: type = expression;` (the type is optional)
+        LetStatement -> [ DeclaredIdentifier, ?Type, Expression ],
         // FIXME: the test should test that as alternative rather than several of them (but it can also be a literal)
         Expression-> [ ?Expression, ?FunctionCallExpression, ?IndexExpression, ?SelfAssignment,
                        ?ConditionalExpression, ?QualifiedName, ?BinaryExpression, ?Array, ?ObjectLiteral,
@@ -399,6 +399,7 @@ fn parse_property_declaration(p: &mut impl Parser) {
 /// animate x { duration: 1000; }
 /// animate x, foo.y {  }
 /// animate * {  }
+/// animate x { duration: 1s; keyframes { 50% { value: 10px; } } }
 /// ```
 fn parse_property_animation(p: &mut impl Parser) {
     debug_assert_eq!(p.peek().as_str(), "animate");
@@ -424,6 +425,7 @@ fn parse_property_animation(p: &mut impl Parser) {
             SyntaxKind::Eof => return,
             SyntaxKind::Identifier => match p.nth(1).kind() {
                 SyntaxKind::Colon => parse_property_binding(&mut *p),
+                SyntaxKind::LBrace if p.peek().as_str() == "keyframes" => parse_keyframes(&mut *p),
                 _ => {
                     p.consume();
                     p.error("Only bindings are allowed in animations");
@@ -437,6 +439,65 @@ fn parse_property_animation(p: &mut impl Parser) {
     }
 }
 
+#[cfg_attr(test, parser_test)]
+/// ```test,Keyframes
+/// keyframes { }
+/// keyframes { 0% { value: 0; } 50% { value: 1; easing: ease-in; } 100% { value: 0; } }
+/// ```
+fn parse_keyframes(p: &mut impl Parser) {
+    debug_assert_eq!(p.peek().as_str(), "keyframes");
+    let mut p = p.start_node(SyntaxKind::Keyframes);
+    p.expect(SyntaxKind::Identifier); // "keyframes"
+    p.expect(SyntaxKind::LBrace);
+    loop {
+        match p.nth(0).kind() {
+            SyntaxKind::RBrace => {
+                p.consume();
+                return;
+            }
+            SyntaxKind::Eof => return,
+            SyntaxKind::NumberLiteral => parse_keyframe(&mut *p),
+            _ if p.nth(1).kind() == SyntaxKind::LBrace => parse_keyframe(&mut *p),
+            _ => {
+                p.consume();
+                p.error("Expected a percentage to declare a keyframe");
+            }
+        }
+    }
+}
+
+#[cfg_attr(test, parser_test)]
+/// ```test,Keyframe
+/// 50% { }
+/// 25% { value: 10px; easing: ease-out; }
+/// ```
+fn parse_keyframe(p: &mut impl Parser) {
+    let mut p = p.start_node(SyntaxKind::Keyframe);
+    if p.nth(0).kind() != SyntaxKind::NumberLiteral {
+        p.error("Expected a percentage to declare a keyframe");
+    }
+    p.consume();
+    if !p.expect(SyntaxKind::LBrace) {
+        return;
+    }
+    loop {
+        match p.nth(0).kind() {
+            SyntaxKind::RBrace => {
+                p.consume();
+                return;
+            }
+            SyntaxKind::Eof => return,
+            SyntaxKind::Identifier if p.nth(1).kind() == SyntaxKind::Colon => {
+                parse_property_binding(&mut *p)
+            }
+            _ => {
+                p.consume();
+                p.error("Only bindings are allowed in keyframes");
+            }
+        }
+    }
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test,States
 /// states []
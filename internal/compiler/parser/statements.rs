@@ -4,6 +4,7 @@
 use super::element::parse_code_block;
 use super::expressions::parse_expression;
 use super::prelude::*;
+use super::r#type::parse_type;
 
 #[cfg_attr(test, parser_test)]
 /// ```test
@@ -14,6 +15,8 @@
 /// if (true) { foo = bar; } else { bar = foo;  }
 /// return;
 /// if (true) { return 42; }
+/// let foo = 42;
+/// let bar: string = "hello";
 /// ```
 pub fn parse_statement(p: &mut impl Parser) -> bool {
     if p.nth(0).kind() == SyntaxKind::RBrace {
@@ -40,6 +43,11 @@ pub fn parse_statement(p: &mut impl Parser) -> bool {
         return true;
     }
 
+    if p.peek().as_str() == "let" && p.nth(1).kind() == SyntaxKind::Identifier {
+        parse_let_statement(&mut *p);
+        return true;
+    }
+
     if p.peek().as_str() == "return" {
         let mut p = p.start_node_at(checkpoint, SyntaxKind::ReturnStatement);
         p.expect(SyntaxKind::Identifier); // "return"
@@ -67,6 +75,29 @@ pub fn parse_statement(p: &mut impl Parser) -> bool {
     p.test(SyntaxKind::Semicolon)
 }
 
+#[cfg_attr(test, parser_test)]
+/// ```test,LetStatement
+/// let foo = 42;
+/// let foo: int = 42;
+/// let some-struct: { a: int, b: [string] } = { a: 4, b: [] };
+/// let foo = bar + baz(45);
+/// ```
+fn parse_let_statement(p: &mut impl Parser) {
+    let mut p = p.start_node(SyntaxKind::LetStatement);
+    debug_assert_eq!(p.peek().as_str(), "let");
+    p.expect(SyntaxKind::Identifier);
+    {
+        let mut p = p.start_node(SyntaxKind::DeclaredIdentifier);
+        p.expect(SyntaxKind::Identifier);
+    }
+    if p.test(SyntaxKind::Colon) {
+        parse_type(&mut *p);
+    }
+    p.expect(SyntaxKind::Equal);
+    parse_expression(&mut *p);
+    p.expect(SyntaxKind::Semicolon);
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test,ConditionalExpression
 /// if (true) { foo = bar; } else { bar = foo;  }
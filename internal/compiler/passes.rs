@@ -36,6 +36,7 @@
 mod lower_states;
 mod lower_tabwidget;
 mod lower_text_input_interface;
+mod lower_tooltips;
 pub mod materialize_fake_properties;
 pub mod move_declarations;
 mod optimize_useless_rectangles;
@@ -85,12 +86,7 @@ pub async fn run_passes(
     for component in (root_component.used_types.borrow().sub_components.iter())
         .chain(std::iter::once(root_component))
     {
-        compile_paths::compile_paths(
-            component,
-            &doc.local_registry,
-            type_loader.compiler_config.embed_resources,
-            diag,
-        );
+        compile_paths::compile_paths(component, &doc.local_registry, diag);
         lower_tabwidget::lower_tabwidget(component, type_loader, diag).await;
         apply_default_properties_from_style::apply_default_properties_from_style(
             component,
@@ -107,6 +103,8 @@ pub async fn run_passes(
     focus_handling::call_focus_on_init(root_component);
 
     ensure_window::ensure_window(root_component, &doc.local_registry, &style_metrics);
+    lower_tooltips::add_tooltip_impl(root_component, type_loader).await;
+    collect_subcomponents::collect_subcomponents(root_component);
 
     for component in (root_component.used_types.borrow().sub_components.iter())
         .chain(std::iter::once(root_component))
@@ -139,6 +137,7 @@ pub async fn run_passes(
             &global_type_registry.borrow(),
             diag,
         );
+        lower_tooltips::lower_tooltips(component, &global_type_registry.borrow(), diag);
         visible::handle_visible(component, &global_type_registry.borrow(), diag);
         lower_shadows::lower_shadow_properties(component, &doc.local_registry, diag);
         lower_property_to_element::lower_property_to_element(
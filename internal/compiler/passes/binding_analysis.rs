@@ -392,6 +392,19 @@ fn recurse_expression(expr: &Expression, vis: &mut impl FnMut(&PropertyPath)) {
                 crate::layout::Layout::BoxLayout(l) => {
                     visit_layout_items_dependencies(l.elems.iter(), *o, vis)
                 }
+                crate::layout::Layout::FlowLayout(l) => {
+                    visit_layout_items_dependencies(l.elems.iter(), l.orientation, vis);
+                    if *o != l.orientation {
+                        // The rows depend on the size in the direction of the flow
+                        if let Some(nr) = l.geometry.rect.size_reference(l.orientation) {
+                            vis(&nr.clone().into());
+                        }
+                        visit_layout_items_dependencies(l.elems.iter(), *o, vis);
+                    }
+                    for nr in l.wrap.iter().chain(l.cross_alignment.iter()) {
+                        vis(&nr.clone().into());
+                    }
+                }
             }
 
             let mut g = l.geometry().clone();
@@ -472,12 +485,14 @@ fn visit_implicit_layout_info_dependencies(
                 vis(&NamedReference::new(item, "width").into());
             }
         }
-        "Text" | "TextInput" => {
+        "Text" | "TextInput" | "StyledText" => {
             vis(&NamedReference::new(item, "text").into());
             vis(&NamedReference::new(item, "font-family").into());
             vis(&NamedReference::new(item, "font-size").into());
             vis(&NamedReference::new(item, "font-weight").into());
-            vis(&NamedReference::new(item, "letter-spacing").into());
+            if base_type.as_str() != "StyledText" {
+                vis(&NamedReference::new(item, "letter-spacing").into());
+            }
             vis(&NamedReference::new(item, "wrap").into());
             let wrap_set = item.borrow().is_binding_set("wrap", false)
                 || item
@@ -491,7 +506,7 @@ fn visit_implicit_layout_info_dependencies(
             }
             if base_type.as_str() == "TextInput" {
                 vis(&NamedReference::new(item, "single-line").into());
-            } else {
+            } else if base_type.as_str() == "Text" {
                 vis(&NamedReference::new(item, "overflow").into());
             }
         }
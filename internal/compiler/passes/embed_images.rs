@@ -142,13 +142,26 @@ fn embed_image(
                 let mut kind = EmbeddedResourcesKind::RawData;
                 #[cfg(feature = "software-renderer")]
                 if _embed_files == EmbedResourcesKind::EmbedTextures {
-                    match load_image(_file, _scale_factor) {
-                        Ok((img, source_format, original_size)) => {
-                            kind = EmbeddedResourcesKind::TextureData(generate_texture(
-                                img,
-                                source_format,
-                                original_size,
-                            ))
+                    match load_animation_frames(&_file, _scale_factor)
+                        .and_then(|frames| Ok((load_image(_file, _scale_factor)?, frames)))
+                    {
+                        Ok(((img, source_format, original_size), frames)) => {
+                            let frames = frames
+                                .into_iter()
+                                .map(|(img, frame_duration)| Texture {
+                                    total_size: Size { width: img.width(), height: img.height() },
+                                    original_size,
+                                    frame_duration,
+                                    ..generate_texture(img, SourceFormat::Rgba, original_size)
+                                })
+                                .collect::<Vec<_>>();
+                            let texture = match frames.first() {
+                                Some(first_frame) => {
+                                    Texture { frames: frames.clone(), ..first_frame.clone() }
+                                }
+                                None => generate_texture(img, source_format, original_size),
+                            };
+                            kind = EmbeddedResourcesKind::TextureData(texture)
                         }
                         Err(err) => {
                             diag.push_error(
@@ -306,6 +319,8 @@ enum ColorState {
         rect,
         data: convert_image(image, source_format, format, rect),
         format,
+        frames: Vec::new(),
+        frame_duration: 0,
     }
 }
 
@@ -355,6 +370,64 @@ enum SourceFormat {
     Rgba,
 }
 
+/// Decodes the frames of an animated GIF, PNG or WebP, with how long each of them is shown in
+/// milliseconds. Returns no frames if the image is not animated.
+#[cfg(feature = "software-renderer")]
+fn load_animation_frames(
+    file: &crate::fileaccess::VirtualFile,
+    scale_factor: f64,
+) -> image::ImageResult<Vec<(image::RgbaImage, u32)>> {
+    use image::AnimationDecoder;
+    let data = match file.builtin_contents {
+        Some(data) => std::borrow::Cow::Borrowed(data),
+        None => match std::fs::read(&file.canon_path) {
+            Ok(data) => std::borrow::Cow::Owned(data),
+            Err(_) => return Ok(Vec::new()),
+        },
+    };
+    let reader = std::io::Cursor::new(data.as_ref());
+    let frames = match image::guess_format(&data) {
+        Ok(image::ImageFormat::Gif) => image::codecs::gif::GifDecoder::new(reader)?.into_frames(),
+        Ok(image::ImageFormat::Png) => {
+            let decoder = image::codecs::png::PngDecoder::new(reader)?;
+            if !decoder.is_apng() {
+                return Ok(Vec::new());
+            }
+            decoder.apng().into_frames()
+        }
+        Ok(image::ImageFormat::WebP) => {
+            let decoder = image::codecs::webp::WebPDecoder::new(reader)?;
+            if !decoder.has_animation() {
+                return Ok(Vec::new());
+            }
+            decoder.into_frames()
+        }
+        _ => return Ok(Vec::new()),
+    };
+    let frames = frames.collect_frames()?;
+    if frames.len() < 2 {
+        return Ok(Vec::new());
+    }
+    Ok(frames
+        .into_iter()
+        .map(|frame| {
+            let (numer, denom) = frame.delay().numer_denom_ms();
+            let duration = numer.checked_div(denom).unwrap_or(0);
+            let mut image = frame.into_buffer();
+            if scale_factor < 1. {
+                image = image::imageops::resize(
+                    &image,
+                    (image.width() as f64 * scale_factor) as u32,
+                    (image.height() as f64 * scale_factor) as u32,
+                    image::imageops::FilterType::Gaussian,
+                );
+            }
+            // Same as the image decoders of the runtime: very short delays use the default of 10 fps
+            (image, if duration <= 10 { 100 } else { duration })
+        })
+        .collect())
+}
+
 #[cfg(feature = "software-renderer")]
 fn load_image(
     file: crate::fileaccess::VirtualFile,
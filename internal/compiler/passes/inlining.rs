@@ -235,6 +235,7 @@ fn inline_element(
     for p in root_component.popup_windows.borrow_mut().iter_mut() {
         fixup_reference(&mut p.x, &mapping);
         fixup_reference(&mut p.y, &mapping);
+        fixup_reference(&mut p.anchor, &mapping);
     }
 }
 
@@ -354,6 +355,7 @@ fn duplicate_sub_component(
     for p in new_component.popup_windows.borrow_mut().iter_mut() {
         fixup_reference(&mut p.x, mapping);
         fixup_reference(&mut p.y, mapping);
+        fixup_reference(&mut p.anchor, mapping);
     }
     new_component
         .root_constraints
@@ -374,7 +376,9 @@ fn duplicate_popup(
     PopupWindow {
         x: p.x.clone(),
         y: p.y.clone(),
-        close_on_click: p.close_on_click,
+        anchor: p.anchor.clone(),
+        placement: p.placement.clone(),
+        close_policy: p.close_policy.clone(),
         component: duplicate_sub_component(&p.component, &parent, mapping, priority_delta),
         parent_element: mapping
             .get(&element_key(p.parent_element.clone()))
@@ -471,6 +475,11 @@ fn fixup_element_references(
                     fxe(&mut e.element);
                 }
             }
+            crate::layout::Layout::FlowLayout(l) => {
+                for e in &mut l.elems {
+                    fxe(&mut e.element);
+                }
+            }
         },
         Expression::RepeaterModelReference { element }
         | Expression::RepeaterIndexReference { element } => fx(element),
@@ -524,6 +533,7 @@ fn component_requires_inlining(component: &Rc<Component>) -> bool {
             || prop == "opacity"
             || prop == "cache-rendering-hint"
             || prop == "visible"
+            || prop == "tooltip"
         {
             return true;
         }
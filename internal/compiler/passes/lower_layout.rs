@@ -115,6 +115,7 @@ fn lower_element_layout(
         "GridLayout" => lower_grid_layout(component, elem, diag),
         "HorizontalLayout" => lower_box_layout(elem, diag, Orientation::Horizontal),
         "VerticalLayout" => lower_box_layout(elem, diag, Orientation::Vertical),
+        "FlowLayout" => lower_flow_layout(elem, diag),
         "Dialog" => {
             lower_dialog_layout(elem, style_metrics, diag);
             return; // the Dialog stays in the tree as a Dialog
@@ -139,7 +140,7 @@ fn lower_element_layout(
 }
 
 pub fn is_layout_element(element: &ElementRc) -> bool {
-    matches!(&element.borrow().base_type, ElementType::Builtin(n) if n.name == "GridLayout" || n.name == "HorizontalLayout" || n.name == "VerticalLayout")
+    matches!(&element.borrow().base_type, ElementType::Builtin(n) if n.name == "GridLayout" || n.name == "HorizontalLayout" || n.name == "VerticalLayout" || n.name == "FlowLayout")
 }
 
 fn lower_grid_layout(
@@ -441,6 +442,120 @@ fn lower_box_layout(
     }
 }
 
+fn lower_flow_layout(layout_element: &ElementRc, diag: &mut BuildDiagnostics) {
+    let orientation_binding = layout_element.borrow_mut().bindings.remove("orientation");
+    let orientation = match orientation_binding.map(RefCell::into_inner) {
+        None => Orientation::Horizontal,
+        Some(binding) => match &binding.expression {
+            Expression::EnumerationValue(val) => {
+                debug_assert_eq!(val.enumeration.name, "Orientation");
+                if val.enumeration.values[val.value] == "vertical" {
+                    Orientation::Vertical
+                } else {
+                    Orientation::Horizontal
+                }
+            }
+            _ => {
+                diag.push_error(
+                    "The `orientation` property of a FlowLayout must be known at compile-time"
+                        .into(),
+                    &binding,
+                );
+                Orientation::Horizontal
+            }
+        },
+    };
+
+    let mut layout = FlowLayout {
+        orientation,
+        elems: Default::default(),
+        geometry: LayoutGeometry::new(layout_element),
+        wrap: binding_reference(layout_element, "wrap"),
+        cross_alignment: binding_reference(layout_element, "cross-alignment"),
+    };
+
+    let layout_cache_prop_h = create_new_prop(layout_element, "layout-cache-h", Type::LayoutCache);
+    let layout_cache_prop_v = create_new_prop(layout_element, "layout-cache-v", Type::LayoutCache);
+    let layout_info_prop_h = create_new_prop(layout_element, "layoutinfo-h", layout_info_type());
+    let layout_info_prop_v = create_new_prop(layout_element, "layoutinfo-v", layout_info_type());
+
+    let layout_children = std::mem::take(&mut layout_element.borrow_mut().children);
+    for layout_child in &layout_children {
+        if let Some(item) = create_layout_item(layout_child, diag) {
+            let index = layout.elems.len() * 2;
+            let rep_idx = &item.repeater_index;
+            let actual_elem = &item.elem;
+            set_prop_from_cache(actual_elem, "x", &layout_cache_prop_h, index, rep_idx, diag);
+            if !item.item.constraints.fixed_width {
+                set_prop_from_cache(
+                    actual_elem,
+                    "width",
+                    &layout_cache_prop_h,
+                    index + 1,
+                    rep_idx,
+                    diag,
+                );
+            }
+            set_prop_from_cache(actual_elem, "y", &layout_cache_prop_v, index, rep_idx, diag);
+            if !item.item.constraints.fixed_height {
+                set_prop_from_cache(
+                    actual_elem,
+                    "height",
+                    &layout_cache_prop_v,
+                    index + 1,
+                    rep_idx,
+                    diag,
+                );
+            }
+            layout.elems.push(item.item);
+        }
+    }
+    layout_element.borrow_mut().children = layout_children;
+    let span = layout_element.borrow().to_source_location();
+    layout_cache_prop_h.element().borrow_mut().bindings.insert(
+        layout_cache_prop_h.name().into(),
+        BindingExpression::new_with_span(
+            Expression::SolveLayout(Layout::FlowLayout(layout.clone()), Orientation::Horizontal),
+            span.clone(),
+        )
+        .into(),
+    );
+    layout_cache_prop_v.element().borrow_mut().bindings.insert(
+        layout_cache_prop_v.name().into(),
+        BindingExpression::new_with_span(
+            Expression::SolveLayout(Layout::FlowLayout(layout.clone()), Orientation::Vertical),
+            span.clone(),
+        )
+        .into(),
+    );
+    layout_info_prop_h.element().borrow_mut().bindings.insert(
+        layout_info_prop_h.name().into(),
+        BindingExpression::new_with_span(
+            Expression::ComputeLayoutInfo(
+                Layout::FlowLayout(layout.clone()),
+                Orientation::Horizontal,
+            ),
+            span.clone(),
+        )
+        .into(),
+    );
+    layout_info_prop_v.element().borrow_mut().bindings.insert(
+        layout_info_prop_v.name().into(),
+        BindingExpression::new_with_span(
+            Expression::ComputeLayoutInfo(
+                Layout::FlowLayout(layout.clone()),
+                Orientation::Vertical,
+            ),
+            span,
+        )
+        .into(),
+    );
+    layout_element.borrow_mut().layout_info_prop = Some((layout_info_prop_h, layout_info_prop_v));
+    for d in layout_element.borrow_mut().debug.iter_mut() {
+        d.1 = Some(Layout::FlowLayout(layout.clone()));
+    }
+}
+
 fn lower_dialog_layout(
     dialog_element: &ElementRc,
     style_metrics: &Option<Rc<Component>>,
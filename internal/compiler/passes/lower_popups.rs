@@ -4,11 +4,12 @@
 //! Passe that transform the PopupWindow element into a component
 
 use crate::diagnostics::BuildDiagnostics;
-use crate::expression_tree::{Expression, NamedReference};
-use crate::langtype::{ElementType, Type};
+use crate::expression_tree::{Expression, NamedReference, Unit};
+use crate::langtype::{ElementType, Enumeration, EnumerationValue, Type};
 use crate::object_tree::*;
-use crate::typeregister::TypeRegister;
+use crate::typeregister::{TypeRegister, BUILTIN_ENUMS};
 use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
 use std::rc::Rc;
 
 pub fn lower_popups(
@@ -69,23 +70,15 @@ fn lower_popup_window(
 
     popup_window_element.borrow_mut().base_type = window_type.clone();
 
-    let close_on_click =
-        match popup_window_element.borrow_mut().bindings.remove("close-on-click").map_or_else(
-            || Ok(true),
-            |binding| match binding.borrow().expression {
-                Expression::BoolLiteral(value) => Ok(value),
-                _ => Err(binding.borrow().span.clone()),
-            },
-        ) {
-            Ok(coc) => coc,
-            Err(location) => {
-                diag.push_error(
-                    "The close-on-click property only supports constants at the moment".into(),
-                    &location,
-                );
-                return;
-            }
-        };
+    let Some(close_policy) = close_policy(popup_window_element, diag) else { return };
+    let Some(placement) = constant_enum_property(
+        popup_window_element,
+        "placement",
+        BUILTIN_ENUMS.with(|e| e.PopupPlacement.clone()),
+        diag,
+    ) else {
+        return;
+    };
 
     let popup_comp = Rc::new(Component {
         root_element: popup_window_element.clone(),
@@ -102,6 +95,7 @@ fn lower_popup_window(
     // FIXME: this is a hack that doesn't always work, perhaps should we store an item ref or something
     let coord_x = create_coordinate(&popup_comp, parent_element, "x");
     let coord_y = create_coordinate(&popup_comp, parent_element, "y");
+    let anchor = create_anchor(&popup_comp, parent_element);
 
     // Throw error when accessing the popup from outside
     // FIXME:
@@ -123,7 +117,9 @@ fn lower_popup_window(
         component: popup_comp,
         x: coord_x,
         y: coord_y,
-        close_on_click,
+        anchor,
+        placement,
+        close_policy,
         parent_element: parent_element.clone(),
     });
 }
@@ -139,7 +135,7 @@ fn create_coordinate(
         .bindings
         .remove(coord)
         .map(|e| e.into_inner().expression)
-        .unwrap_or(Expression::NumberLiteral(0., crate::expression_tree::Unit::Phx));
+        .unwrap_or(Expression::NumberLiteral(0., Unit::Phx));
     let property_name = format!("{}-popup-{}", popup_comp.root_element.borrow().id, coord);
     parent_element
         .borrow_mut()
@@ -151,3 +147,107 @@ fn create_coordinate(
         .insert(property_name.clone(), RefCell::new(expression.into()));
     NamedReference::new(parent_element, &property_name)
 }
+
+/// Create a property in the parent element with the geometry of the anchor, which is by default
+/// the parent element itself
+fn create_anchor(popup_comp: &Rc<Component>, parent_element: &ElementRc) -> NamedReference {
+    let mut fields = BTreeMap::new();
+    let mut values = HashMap::new();
+    for (field, prop, default_prop) in [
+        ("x", "anchor-x", None),
+        ("y", "anchor-y", None),
+        ("width", "anchor-width", Some("width")),
+        ("height", "anchor-height", Some("height")),
+    ] {
+        let expression = popup_comp
+            .root_element
+            .borrow_mut()
+            .bindings
+            .remove(prop)
+            .map(|e| e.into_inner().expression)
+            .or_else(|| {
+                let default_prop = default_prop?;
+                let lookup = parent_element.borrow().lookup_property(default_prop);
+                (lookup.property_type == Type::LogicalLength).then(|| {
+                    Expression::PropertyReference(NamedReference::new(
+                        parent_element,
+                        lookup.resolved_name.as_ref(),
+                    ))
+                })
+            })
+            .unwrap_or(Expression::NumberLiteral(0., Unit::Phx));
+        fields.insert(field.to_string(), Type::LogicalLength);
+        values.insert(field.to_string(), expression);
+    }
+    let ty = Type::Struct { fields, name: None, node: None, rust_attributes: None };
+    let property_name = format!("{}-popup-anchor", popup_comp.root_element.borrow().id);
+    parent_element
+        .borrow_mut()
+        .property_declarations
+        .insert(property_name.clone(), ty.clone().into());
+    parent_element
+        .borrow_mut()
+        .bindings
+        .insert(property_name.clone(), RefCell::new(Expression::Struct { ty, values }.into()));
+    NamedReference::new(parent_element, &property_name)
+}
+
+/// Return the value of the close-policy, which must be known at compile time.
+/// For compatibility, the close-on-click property is mapped to the corresponding policy.
+fn close_policy(
+    popup_window_element: &ElementRc,
+    diag: &mut BuildDiagnostics,
+) -> Option<EnumerationValue> {
+    let enumeration = BUILTIN_ENUMS.with(|e| e.PopupClosePolicy.clone());
+    let close_on_click = popup_window_element.borrow_mut().bindings.remove("close-on-click");
+    let Some(close_on_click) = close_on_click.map(RefCell::into_inner) else {
+        return constant_enum_property(popup_window_element, "close-policy", enumeration, diag);
+    };
+    if popup_window_element.borrow().bindings.contains_key("close-policy") {
+        diag.push_error(
+            "close-policy and close-on-click cannot be set at the same time".into(),
+            &close_on_click.span,
+        );
+        return None;
+    }
+    match close_on_click.expression {
+        Expression::BoolLiteral(value) => {
+            let value = if value { "close-on-click" } else { "no-auto-close" };
+            Some(EnumerationValue {
+                value: enumeration.values.iter().position(|v| v == value).unwrap(),
+                enumeration,
+            })
+        }
+        _ => {
+            diag.push_error(
+                "The close-on-click property only supports constants at the moment".into(),
+                &close_on_click.span,
+            );
+            None
+        }
+    }
+}
+
+/// Remove the binding of a property of the PopupWindow that must be a constant enumeration value,
+/// and return that value.
+fn constant_enum_property(
+    popup_window_element: &ElementRc,
+    name: &str,
+    enumeration: Rc<Enumeration>,
+    diag: &mut BuildDiagnostics,
+) -> Option<EnumerationValue> {
+    let binding = popup_window_element.borrow_mut().bindings.remove(name);
+    let Some(binding) = binding.map(RefCell::into_inner) else {
+        return Some(EnumerationValue { value: enumeration.default_value, enumeration });
+    };
+    match binding.expression {
+        Expression::EnumerationValue(value) => Some(value),
+        _ => {
+            diag.push_error(
+                format!("The {name} property only supports constants at the moment"),
+                &binding.span,
+            );
+            None
+        }
+    }
+}
@@ -0,0 +1,150 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! Pass that lowers the synthetic `tooltip` property.
+//!
+//! Elements with a `tooltip` get a `TooltipArea` parent that tells the window which tooltip is
+//! under the pointer. The tooltip itself is shown by the `TooltipImpl` component from the style,
+//! which is added as the last child of the window and of the popups.
+
+use crate::diagnostics::BuildDiagnostics;
+use crate::expression_tree::{BindingExpression, Expression, NamedReference, Unit};
+use crate::langtype::{ElementType, EnumerationValue};
+use crate::object_tree::*;
+use crate::typeregister::TypeRegister;
+use std::rc::Rc;
+
+/// Adds an instance of the style's `TooltipImpl` to the root of the window if any element
+/// uses the `tooltip` property, and to each `PopupWindow` that contains such an element, so that
+/// the tooltip is shown above the popup.
+///
+/// Must be run after ensure_window, and must be followed by collect_subcomponents.
+pub async fn add_tooltip_impl(
+    root_component: &Rc<Component>,
+    type_loader: &mut crate::typeloader::TypeLoader,
+) {
+    let mut has_tooltip = false;
+    let mut popups = Vec::new();
+    for component in (root_component.used_types.borrow().sub_components.iter())
+        .chain(std::iter::once(root_component))
+    {
+        recurse_elem_including_sub_components(component, &(), &mut |elem, _| {
+            has_tooltip |= elem.borrow().bindings.contains_key("tooltip");
+            let is_popup = matches!(&elem.borrow().base_type, ElementType::Builtin(base_type) if base_type.name == "PopupWindow");
+            if is_popup && contains_tooltip(elem) {
+                popups.push(elem.clone());
+            }
+        });
+    }
+    if !has_tooltip {
+        return;
+    }
+
+    // Ignore import errors
+    let mut build_diags_to_ignore = BuildDiagnostics::default();
+    let tooltip_impl = type_loader
+        .import_component("std-widgets.slint", "TooltipImpl", &mut build_diags_to_ignore)
+        .await
+        .expect("can't load TooltipImpl from std-widgets.slint");
+
+    add_tooltip_impl_to(&root_component.root_element, &tooltip_impl, false);
+    for popup in popups {
+        add_tooltip_impl_to(&popup, &tooltip_impl, true);
+    }
+}
+
+/// Adds the `TooltipImpl` as the last child of `parent`, covering it.
+fn add_tooltip_impl_to(parent: &ElementRc, tooltip_impl: &Rc<Component>, popup: bool) {
+    let mut tooltip = Element {
+        id: format!("{}-tooltip", parent.borrow().id),
+        base_type: ElementType::Component(tooltip_impl.clone()),
+        enclosing_component: parent.borrow().enclosing_component.clone(),
+        ..Default::default()
+    };
+    for (prop, value) in
+        [("x", None), ("y", None), ("width", Some("width")), ("height", Some("height"))]
+    {
+        let expression = match value {
+            Some(value) => Expression::PropertyReference(NamedReference::new(parent, value)),
+            None => Expression::NumberLiteral(0., Unit::Px),
+        };
+        tooltip.bindings.insert(prop.into(), BindingExpression::from(expression).into());
+    }
+    if popup {
+        tooltip
+            .bindings
+            .insert("popup".into(), BindingExpression::from(Expression::BoolLiteral(true)).into());
+    }
+    parent.borrow_mut().children.push(tooltip.make_rc());
+}
+
+/// Returns true if the element, one of its children, or the components they are based on,
+/// has a tooltip.
+fn contains_tooltip(elem: &ElementRc) -> bool {
+    let elem = elem.borrow();
+    elem.bindings.contains_key("tooltip")
+        || elem.children.iter().any(contains_tooltip)
+        || matches!(&elem.base_type, ElementType::Component(base) if contains_tooltip(&base.root_element))
+}
+
+pub fn lower_tooltips(
+    component: &Rc<Component>,
+    type_register: &TypeRegister,
+    diag: &mut BuildDiagnostics,
+) {
+    // The elements with a tooltip that aren't accessible by themselves
+    let mut not_accessible = Vec::new();
+    recurse_elem_including_sub_components_no_borrow(component, &(), &mut |elem, _| {
+        if !elem.borrow().bindings.contains_key("tooltip") {
+            return;
+        }
+        // The tooltip describes the element to assistive technologies
+        if elem.borrow().is_binding_set("accessible-role", false) {
+            let tooltip = NamedReference::new(elem, "tooltip");
+            elem.borrow_mut().set_binding_if_not_set("accessible-description".into(), || {
+                Expression::PropertyReference(tooltip)
+            });
+        } else {
+            not_accessible.push(elem.clone());
+        }
+    });
+
+    super::lower_property_to_element::lower_property_to_element(
+        component,
+        "tooltip",
+        core::iter::empty(),
+        None,
+        "TooltipArea",
+        type_register,
+        diag,
+    );
+
+    if not_accessible.is_empty() {
+        return;
+    }
+    // Otherwise, the TooltipArea is accessible and described by the tooltip, so that for example
+    // an icon made of an `Image` and a `TouchArea` isn't hidden from assistive technologies.
+    recurse_elem_including_sub_components_no_borrow(component, &(), &mut |elem, _| {
+        if elem.borrow().base_type.to_string() != "TooltipArea" {
+            return;
+        }
+        let Some(child) = elem.borrow().children.first().cloned() else { return };
+        if !not_accessible.iter().any(|e| Rc::ptr_eq(e, &child)) {
+            return;
+        }
+        let role = match child.borrow().builtin_type() {
+            Some(b) if matches!(b.name.as_str(), "Image" | "ClippedImage") => "image",
+            _ => "group",
+        };
+        let enumeration = crate::typeregister::BUILTIN_ENUMS.with(|e| e.AccessibleRole.clone());
+        let role = Expression::EnumerationValue(EnumerationValue {
+            value: enumeration.values.iter().position(|v| v == role).unwrap(),
+            enumeration,
+        });
+        let tooltip = Expression::PropertyReference(NamedReference::new(elem, "tooltip"));
+        let mut elem = elem.borrow_mut();
+        elem.bindings.insert("accessible-role".into(), BindingExpression::from(role).into());
+        elem.bindings
+            .insert("accessible-description".into(), BindingExpression::from(tooltip).into());
+    });
+}
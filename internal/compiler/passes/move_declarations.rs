@@ -81,6 +81,7 @@ fn do_move_declarations(component: &Rc<Component>) {
     component.popup_windows.borrow_mut().iter_mut().for_each(|p| {
         fixup_reference(&mut p.x);
         fixup_reference(&mut p.y);
+        fixup_reference(&mut p.anchor);
         visit_all_named_references(&p.component, &mut fixup_reference)
     });
     component.init_code.borrow_mut().iter_mut().for_each(|expr| {
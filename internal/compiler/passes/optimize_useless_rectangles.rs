@@ -45,6 +45,11 @@ fn can_optimize(elem: &ElementRc) -> bool {
         return false;
     };
 
+    if !e.accessibility_props.0.is_empty() {
+        // The item must stay in the tree to be exposed to assistive technologies
+        return false;
+    }
+
     if e.child_of_layout {
         // The `LayoutItem` still has reference to this component, so we cannot remove it
         return false;
@@ -40,6 +40,7 @@ fn resolve_expression(
             component_scope: &scope.0,
             diag,
             arguments: vec![],
+            local_variables: vec![],
             type_register,
             type_loader: Some(type_loader),
             current_token: None,
@@ -59,6 +60,9 @@ fn resolve_expression(
             SyntaxKind::BindingExpression => {
                 Expression::from_binding_expression_node(node.clone(), &mut lookup_ctx)
             }
+            SyntaxKind::Keyframes => {
+                Expression::from_keyframes_node(node.clone().into(), &mut lookup_ctx)
+            }
             SyntaxKind::TwoWayBinding => {
                 assert!(diag.has_error(), "Two way binding should have been resolved already  (property: {property_name:?})");
                 Expression::Invalid
@@ -168,17 +172,134 @@ pub fn from_binding_expression_node(node: SyntaxNode, ctx: &mut LookupCtx) -> Se
         }
     }
 
+    /// Resolve the `keyframes` of a property animation to an array of AnimationKeyframe
+    fn from_keyframes_node(node: syntax_nodes::Keyframes, ctx: &mut LookupCtx) -> Expression {
+        let keyframe_ty = match &ctx.property_type {
+            Type::Array(ty) => (**ty).clone(),
+            _ => {
+                assert!(ctx.diag.has_error());
+                return Expression::Invalid;
+            }
+        };
+        let value_ty = match &keyframe_ty {
+            Type::Struct { fields, .. } => fields["value"].clone(),
+            _ => unreachable!(),
+        };
+
+        // Keyframes that don't specify an easing use the easing of the animation.
+        // Errors in it are reported when resolving the animation's own binding.
+        let animation_easing = node
+            .parent()
+            .and_then(syntax_nodes::PropertyAnimation::new)
+            .and_then(|a| a.Binding().find(|b| identifier_text(b).as_deref() == Some("easing")))
+            .map(|b| {
+                let mut diag = BuildDiagnostics::default();
+                let mut easing_ctx = LookupCtx {
+                    property_name: Some("easing"),
+                    property_type: Type::Easing,
+                    component_scope: ctx.component_scope,
+                    diag: &mut diag,
+                    arguments: vec![],
+                    local_variables: vec![],
+                    type_register: ctx.type_register,
+                    type_loader: ctx.type_loader,
+                    current_token: None,
+                };
+                Self::from_binding_expression_node(b.BindingExpression().into(), &mut easing_ctx)
+            })
+            .unwrap_or(Expression::EasingCurve(EasingCurve::Linear));
+
+        let mut previous_position = None;
+        let values = node
+            .Keyframe()
+            .filter_map(|keyframe| {
+                let position = match keyframe
+                    .child_text(SyntaxKind::NumberLiteral)
+                    .map(crate::literals::parse_number_literal)
+                {
+                    Some(Ok(Expression::NumberLiteral(p, Unit::Percent)))
+                        if (0. ..=100.).contains(&p) =>
+                    {
+                        p / 100.
+                    }
+                    _ => {
+                        ctx.diag.push_error(
+                            "The position of a keyframe must be a percentage between 0% and 100%"
+                                .into(),
+                            &keyframe,
+                        );
+                        return None;
+                    }
+                };
+                if previous_position.is_some_and(|previous| position <= previous) {
+                    ctx.diag.push_error(
+                        "Keyframes must be declared in increasing order of position".into(),
+                        &keyframe,
+                    );
+                    return None;
+                }
+                previous_position = Some(position);
+
+                let mut value = None;
+                let mut easing = None;
+                for binding in keyframe.Binding() {
+                    let name = identifier_text(&binding).unwrap_or_default();
+                    let (slot, ty) = match name.as_str() {
+                        "value" => (&mut value, value_ty.clone()),
+                        "easing" => (&mut easing, Type::Easing),
+                        _ => {
+                            ctx.diag.push_error(
+                                format!("Unknown property '{name}' in keyframe"),
+                                &binding.child_token(SyntaxKind::Identifier).unwrap(),
+                            );
+                            continue;
+                        }
+                    };
+                    let property_type = std::mem::replace(&mut ctx.property_type, ty);
+                    let e =
+                        Self::from_binding_expression_node(binding.BindingExpression().into(), ctx);
+                    ctx.property_type = property_type;
+                    if slot.replace(e).is_some() {
+                        ctx.diag.push_error(
+                            format!("Duplicated property '{name}' in keyframe"),
+                            &binding.child_token(SyntaxKind::Identifier).unwrap(),
+                        );
+                    }
+                }
+                let value = value.unwrap_or_else(|| {
+                    ctx.diag.push_error("A keyframe needs a value".into(), &keyframe);
+                    Expression::Invalid
+                });
+
+                Some(Expression::Struct {
+                    ty: keyframe_ty.clone(),
+                    values: IntoIterator::into_iter([
+                        ("position".to_owned(), Expression::NumberLiteral(position, Unit::None)),
+                        ("value".to_owned(), value),
+                        ("easing".to_owned(), easing.unwrap_or_else(|| animation_easing.clone())),
+                    ])
+                    .collect(),
+                })
+            })
+            .collect();
+
+        Expression::Array { element_ty: keyframe_ty, values }
+    }
+
     fn from_codeblock_node(node: syntax_nodes::CodeBlock, ctx: &mut LookupCtx) -> Expression {
         debug_assert_eq!(node.kind(), SyntaxKind::CodeBlock);
 
+        ctx.local_variables.push(Vec::new());
         let mut statements_or_exprs = node
             .children()
             .filter_map(|n| match n.kind() {
                 SyntaxKind::Expression => Some(Self::from_expression_node(n.into(), ctx)),
                 SyntaxKind::ReturnStatement => Some(Self::from_return_statement(n.into(), ctx)),
+                SyntaxKind::LetStatement => Some(Self::from_let_statement(n.into(), ctx)),
                 _ => None,
             })
             .collect::<Vec<_>>();
+        ctx.local_variables.pop();
 
         let exit_points_and_return_types = statements_or_exprs
             .iter()
@@ -207,6 +328,44 @@ fn from_codeblock_node(node: syntax_nodes::CodeBlock, ctx: &mut LookupCtx) -> Ex
         Expression::CodeBlock(statements_or_exprs)
     }
 
+    fn from_let_statement(node: syntax_nodes::LetStatement, ctx: &mut LookupCtx) -> Expression {
+        let name = identifier_text(&node.DeclaredIdentifier()).unwrap_or_default();
+        if ctx.local_variables.iter().flatten().any(|(n, _)| *n == name)
+            || ctx.arguments.contains(&name)
+        {
+            ctx.diag.push_error(
+                format!("Redeclaration of local variable '{name}'"),
+                &node.DeclaredIdentifier(),
+            );
+        }
+
+        let expr_node = node.Expression();
+        let value = Self::from_expression_node(expr_node.clone(), ctx);
+        let (ty, value) = match node.Type() {
+            Some(type_node) => {
+                let ty = type_from_node(type_node, ctx.diag, ctx.type_register);
+                let value = value.maybe_convert_to(ty.clone(), &expr_node, ctx.diag);
+                (ty, value)
+            }
+            None => (value.ty(), value),
+        };
+        if ty != Type::Invalid && !ty.is_property_type() {
+            ctx.diag.push_error(
+                format!("Cannot declare a local variable of type '{ty}'"),
+                &node.DeclaredIdentifier(),
+            );
+        }
+
+        ctx.local_variables
+            .last_mut()
+            .expect("let statement must be within a code block")
+            .push((name.clone(), ty));
+        Expression::StoreLocalVariable {
+            name: crate::lookup::local_variable_name(&name),
+            value: Box::new(value),
+        }
+    }
+
     fn from_return_statement(
         node: syntax_nodes::ReturnStatement,
         ctx: &mut LookupCtx,
@@ -1525,6 +1684,7 @@ fn resolve_two_way_bindings(
                             component_scope: &new_scope.0,
                             diag,
                             arguments: vec![],
+                            local_variables: vec![],
                             type_register,
                             type_loader: None,
                             current_token: Some(node.clone().into()),
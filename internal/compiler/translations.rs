@@ -0,0 +1,520 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! Load the translations from `.po` files so they can be bundled in the generated code.
+//!
+//! The translations are looked up in `<path>/<language>/LC_MESSAGES/<domain>.po`, which is the
+//! same layout as the one used by gettext at runtime.
+
+use crate::diagnostics::{BuildDiagnostics, SourceFileInner, SourceLocation, Span};
+use std::collections::HashMap;
+use std::path::Path;
+use std::rc::Rc;
+
+/// The formula of the `Plural-Forms` header of a `.po` file, that computes the index of the
+/// plural form to use for the number `n`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PluralExpression {
+    /// The `n` variable
+    N,
+    NumberLiteral(i32),
+    /// `!` or `-`
+    UnaryOp {
+        op: char,
+        sub: Box<PluralExpression>,
+    },
+    /// One of the C operators `*`, `/`, `%`, `+`, `-`, `<`, `<=`, `>`, `>=`, `==`, `!=`, `&&`, `||`
+    BinaryExpression {
+        lhs: Box<PluralExpression>,
+        rhs: Box<PluralExpression>,
+        op: &'static str,
+    },
+    Condition {
+        condition: Box<PluralExpression>,
+        true_expr: Box<PluralExpression>,
+        false_expr: Box<PluralExpression>,
+    },
+}
+
+impl PluralExpression {
+    /// Parse the C expression of the `plural=` part of the `Plural-Forms` header
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let mut parser = PluralParser { source, pos: 0 };
+        let expr = parser.parse_condition()?;
+        parser.skip_whitespace();
+        match parser.source[parser.pos..].chars().next() {
+            None | Some(';') => Ok(expr),
+            Some(c) => Err(format!("Unexpected '{c}' in plural formula")),
+        }
+    }
+
+    /// The rule of the original strings, which have a singular and a plural form
+    pub fn english() -> Self {
+        Self::BinaryExpression {
+            lhs: Box::new(Self::N),
+            rhs: Box::new(Self::NumberLiteral(1)),
+            op: "!=",
+        }
+    }
+}
+
+struct PluralParser<'a> {
+    source: &'a str,
+    pos: usize,
+}
+
+impl PluralParser<'_> {
+    fn skip_whitespace(&mut self) {
+        let rest = &self.source[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    /// Consume one of the given operators (the longest operators must come first)
+    fn operator(&mut self, operators: &[&'static str]) -> Option<&'static str> {
+        self.skip_whitespace();
+        let op = operators.iter().find(|op| self.source[self.pos..].starts_with(**op))?;
+        self.pos += op.len();
+        Some(op)
+    }
+
+    fn parse_condition(&mut self) -> Result<PluralExpression, String> {
+        let condition = self.parse_binary(0)?;
+        if self.operator(&["?"]).is_none() {
+            return Ok(condition);
+        }
+        let true_expr = self.parse_condition()?;
+        if self.operator(&[":"]).is_none() {
+            return Err("Expected ':' in plural formula".into());
+        }
+        let false_expr = self.parse_condition()?;
+        Ok(PluralExpression::Condition {
+            condition: Box::new(condition),
+            true_expr: Box::new(true_expr),
+            false_expr: Box::new(false_expr),
+        })
+    }
+
+    fn parse_binary(&mut self, level: usize) -> Result<PluralExpression, String> {
+        const LEVELS: &[&[&str]] = &[
+            &["||"],
+            &["&&"],
+            &["==", "!="],
+            &["<=", ">=", "<", ">"],
+            &["+", "-"],
+            &["*", "/", "%"],
+        ];
+        let Some(operators) = LEVELS.get(level) else { return self.parse_unary() };
+        let mut lhs = self.parse_binary(level + 1)?;
+        while let Some(op) = self.operator(operators) {
+            let rhs = self.parse_binary(level + 1)?;
+            lhs = PluralExpression::BinaryExpression { lhs: Box::new(lhs), rhs: Box::new(rhs), op };
+        }
+        Ok(lhs)
+    }
+
+    fn parse_unary(&mut self) -> Result<PluralExpression, String> {
+        self.skip_whitespace();
+        let rest = &self.source[self.pos..];
+        match rest.chars().next() {
+            Some(op @ ('!' | '-')) => {
+                self.pos += 1;
+                Ok(PluralExpression::UnaryOp { op, sub: Box::new(self.parse_unary()?) })
+            }
+            Some('(') => {
+                self.pos += 1;
+                let expr = self.parse_condition()?;
+                if self.operator(&[")"]).is_none() {
+                    return Err("Expected ')' in plural formula".into());
+                }
+                Ok(expr)
+            }
+            Some('n') => {
+                self.pos += 1;
+                Ok(PluralExpression::N)
+            }
+            Some(c) if c.is_ascii_digit() => {
+                let len = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+                self.pos += len;
+                rest[..len]
+                    .parse()
+                    .map(PluralExpression::NumberLiteral)
+                    .map_err(|_| format!("Invalid number '{}' in plural formula", &rest[..len]))
+            }
+            Some(c) => Err(format!("Unexpected '{c}' in plural formula")),
+            None => Err("Unexpected end of plural formula".into()),
+        }
+    }
+}
+
+/// An entry of a `.po` file
+#[derive(Debug, Default, PartialEq)]
+struct PoEntry {
+    context: String,
+    msgid: String,
+    msgid_plural: Option<String>,
+    msgstr: Vec<String>,
+    fuzzy: bool,
+}
+
+/// Parse the content of a `.po` file. On error, returns the offset of the error and a message.
+fn parse_po(source: &str) -> Result<Vec<PoEntry>, (usize, String)> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum Field {
+        None,
+        Context,
+        Id,
+        IdPlural,
+        Str(usize),
+    }
+
+    let mut entries = Vec::new();
+    let mut entry = PoEntry::default();
+    let mut field = Field::None;
+    let mut fuzzy = false;
+
+    let mut offset = 0;
+    for line in source.split_inclusive('\n') {
+        let line_offset = offset;
+        offset += line.len();
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(comment) = line.strip_prefix('#') {
+            // Flags, like `#, fuzzy, c-format`. The other comments, including obsolete entries
+            // (`#~`) are ignored.
+            if let Some(flags) = comment.strip_prefix(',') {
+                fuzzy |= flags.split(',').any(|f| f.trim() == "fuzzy");
+            }
+            continue;
+        }
+
+        let (keyword, string) = match line.find('"') {
+            Some(quote) => (line[..quote].trim(), &line[quote..]),
+            None => return Err((line_offset, format!("Expected a string in '{line}'"))),
+        };
+        let string =
+            unescape_po_string(string).map_err(|e| (line_offset + line.len() - string.len(), e))?;
+
+        let new_field =
+            match keyword {
+                "" => {
+                    if field == Field::None {
+                        return Err((line_offset, "Unexpected string".into()));
+                    }
+                    field
+                }
+                "msgctxt" => Field::Context,
+                "msgid" => Field::Id,
+                "msgid_plural" => Field::IdPlural,
+                "msgstr" => Field::Str(0),
+                _ => match keyword.strip_prefix("msgstr[").and_then(|k| k.strip_suffix(']')) {
+                    Some(index) => Field::Str(index.trim().parse().map_err(|_| {
+                        (line_offset, format!("Invalid plural form index '{index}'"))
+                    })?),
+                    None => return Err((line_offset, format!("Unknown keyword '{keyword}'"))),
+                },
+            };
+
+        // A msgctxt or msgid after a msgstr starts a new entry
+        if matches!(new_field, Field::Context | Field::Id) && matches!(field, Field::Str(_)) {
+            entries.push(std::mem::take(&mut entry));
+        }
+        if keyword.is_empty() {
+            // Continuation of the previous string
+        } else if matches!(new_field, Field::Context | Field::Id)
+            && !matches!(field, Field::Context)
+        {
+            entry.fuzzy = std::mem::take(&mut fuzzy);
+        }
+        let target = match new_field {
+            Field::None => unreachable!(),
+            Field::Context => &mut entry.context,
+            Field::Id => &mut entry.msgid,
+            Field::IdPlural => entry.msgid_plural.get_or_insert_with(String::new),
+            Field::Str(index) => {
+                if entry.msgstr.len() <= index {
+                    entry.msgstr.resize(index + 1, String::new());
+                }
+                &mut entry.msgstr[index]
+            }
+        };
+        target.push_str(&string);
+        field = new_field;
+    }
+    if matches!(field, Field::Str(_)) {
+        entries.push(entry);
+    } else if field != Field::None {
+        return Err((source.len(), "Expected msgstr at the end of the file".into()));
+    }
+    Ok(entries)
+}
+
+/// Unescape a string like `"Hello\n"`, which must start and end with a quote
+fn unescape_po_string(string: &str) -> Result<String, String> {
+    let Some(content) = string.strip_prefix('"').and_then(|s| s.strip_suffix('"')) else {
+        return Err(format!("Invalid string {string}"));
+    };
+    let mut result = String::with_capacity(content.len());
+    let mut chars = content.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => result.push('\n'),
+            Some('t') => result.push('\t'),
+            Some('r') => result.push('\r'),
+            Some(c @ ('"' | '\\')) => result.push(c),
+            Some(c) => return Err(format!("Unknown escape sequence '\\{c}'")),
+            None => return Err("Unterminated escape sequence".into()),
+        }
+    }
+    Ok(result)
+}
+
+/// The translations of one language
+#[derive(Debug, Clone)]
+struct Catalog {
+    /// Maps (context, msgid) to the translated forms
+    messages: HashMap<(String, String), Vec<String>>,
+    plural_rule: Option<PluralExpression>,
+}
+
+impl Catalog {
+    fn from_po(source: &str) -> Result<Self, (usize, String)> {
+        let mut messages = HashMap::new();
+        let mut plural_rule = None;
+        for entry in parse_po(source)? {
+            if entry.msgid.is_empty() && entry.context.is_empty() {
+                // The header
+                let header = entry.msgstr.first().map_or("", |s| s.as_str());
+                if let Some(plural_forms) = header
+                    .lines()
+                    .find_map(|l| l.trim().strip_prefix("Plural-Forms:"))
+                    .and_then(|l| l.split_once("plural=").map(|x| x.1))
+                {
+                    plural_rule = Some(PluralExpression::parse(plural_forms).map_err(|e| (0, e))?);
+                }
+                continue;
+            }
+            // Untranslated and fuzzy entries are not used, like with gettext
+            if entry.fuzzy || entry.msgstr.is_empty() || entry.msgstr.iter().any(|s| s.is_empty()) {
+                continue;
+            }
+            messages.insert((entry.context, entry.msgid), entry.msgstr);
+        }
+        Ok(Self { messages, plural_rule })
+    }
+}
+
+/// Collects the strings that are translated with `@tr` while lowering the code, and looks up
+/// their translation in each of the languages.
+#[derive(Debug, Clone)]
+pub struct TranslationsBuilder {
+    catalogs: Vec<Catalog>,
+    /// Maps (context, string) to the index in `Translations::strings`
+    strings: HashMap<(String, String), usize>,
+    /// Maps (context, string, plural) to the index in `Translations::plurals`
+    plurals: HashMap<(String, String, String), usize>,
+    result: Translations,
+}
+
+/// The translations to bundle in the generated code
+#[derive(Debug, Clone, Default)]
+pub struct Translations {
+    /// The bundled languages. The first one is the language of the original strings, and is empty
+    pub languages: Vec<String>,
+    /// For each string without plural form, the original string followed by its translation in
+    /// each language
+    pub strings: Vec<Vec<Option<String>>>,
+    /// For each string with plural forms, the original forms followed by the translated forms in
+    /// each language
+    pub plurals: Vec<Vec<Option<Vec<String>>>>,
+    /// The rule to select the plural form for each language
+    pub plural_rules: Vec<Option<PluralExpression>>,
+}
+
+impl TranslationsBuilder {
+    /// Load the `.po` files of the given domain in each of the language directories of `path`
+    pub fn load_translations(path: &Path, domain: &str, diag: &mut BuildDiagnostics) -> Self {
+        let mut languages = Vec::new();
+        match std::fs::read_dir(path) {
+            Ok(dir) => {
+                for entry in dir.flatten() {
+                    let po_file = entry.path().join("LC_MESSAGES").join(format!("{domain}.po"));
+                    if po_file.is_file() {
+                        languages.push((entry.file_name().to_string_lossy().into_owned(), po_file));
+                    }
+                }
+            }
+            Err(err) => diag.push_error_with_span(
+                format!("Cannot read the translations directory: {err}"),
+                SourceLocation {
+                    source_file: Some(SourceFileInner::from_path_only(path.into())),
+                    span: Span::default(),
+                },
+            ),
+        }
+        languages.sort();
+
+        let mut catalogs = Vec::with_capacity(languages.len());
+        for (_, po_file) in &languages {
+            let source = match std::fs::read_to_string(po_file) {
+                Ok(source) => source,
+                Err(err) => {
+                    diag.push_error_with_span(
+                        format!("Cannot read the translation file: {err}"),
+                        SourceLocation {
+                            source_file: Some(SourceFileInner::from_path_only(po_file.clone())),
+                            span: Span::default(),
+                        },
+                    );
+                    String::new()
+                }
+            };
+            catalogs.push(Catalog::from_po(&source).unwrap_or_else(|(offset, message)| {
+                diag.push_error_with_span(
+                    message,
+                    SourceLocation {
+                        source_file: Some(Rc::new(SourceFileInner::new(
+                            po_file.clone(),
+                            source.clone(),
+                            None,
+                        ))),
+                        span: Span::new(offset),
+                    },
+                );
+                Catalog { messages: Default::default(), plural_rule: None }
+            }));
+        }
+
+        let result = Translations {
+            languages: std::iter::once(String::new())
+                .chain(languages.into_iter().map(|(language, _)| language))
+                .collect(),
+            plural_rules: std::iter::once(Some(PluralExpression::english()))
+                .chain(catalogs.iter().map(|c| c.plural_rule.clone()))
+                .collect(),
+            ..Default::default()
+        };
+        Self { catalogs, strings: Default::default(), plurals: Default::default(), result }
+    }
+
+    /// Register a string without plural form and return its index in [`Translations::strings`]
+    pub fn collect_string(&mut self, string: &str, context: &str) -> usize {
+        let key = (context.to_owned(), string.to_owned());
+        if let Some(index) = self.strings.get(&key) {
+            return *index;
+        }
+        let translations =
+            std::iter::once(Some(string.to_owned()))
+                .chain(self.catalogs.iter().map(|catalog| {
+                    catalog.messages.get(&key).and_then(|forms| forms.first().cloned())
+                }))
+                .collect();
+        let index = self.result.strings.len();
+        self.result.strings.push(translations);
+        self.strings.insert(key, index);
+        index
+    }
+
+    /// Register a string with plural forms and return its index in [`Translations::plurals`]
+    pub fn collect_plural(&mut self, string: &str, plural: &str, context: &str) -> usize {
+        let key = (context.to_owned(), string.to_owned(), plural.to_owned());
+        if let Some(index) = self.plurals.get(&key) {
+            return *index;
+        }
+        let lookup_key = (context.to_owned(), string.to_owned());
+        let translations = std::iter::once(Some(vec![string.to_owned(), plural.to_owned()]))
+            .chain(self.catalogs.iter().map(|catalog| catalog.messages.get(&lookup_key).cloned()))
+            .collect();
+        let index = self.result.plurals.len();
+        self.result.plurals.push(translations);
+        self.plurals.insert(key, index);
+        index
+    }
+
+    pub fn result(self) -> Translations {
+        self.result
+    }
+}
+
+#[test]
+fn test_parse_plural_expression() {
+    use PluralExpression::*;
+    assert_eq!(PluralExpression::parse("(n != 1);"), Ok(PluralExpression::english()));
+    assert_eq!(PluralExpression::parse(" 0;"), Ok(NumberLiteral(0)));
+    let polish = PluralExpression::parse(
+        "(n==1 ? 0 : n%10>=2 && n%10<=4 && (n%100<10 || n%100>=20) ? 1 : 2);",
+    )
+    .unwrap();
+    let Condition { condition, false_expr, .. } = polish else { panic!("{polish:?}") };
+    assert_eq!(
+        *condition,
+        BinaryExpression { lhs: Box::new(N), rhs: Box::new(NumberLiteral(1)), op: "==" }
+    );
+    assert!(matches!(*false_expr, Condition { .. }));
+    assert_eq!(PluralExpression::parse("!n"), Ok(UnaryOp { op: '!', sub: Box::new(N) }));
+    assert!(PluralExpression::parse("n +").is_err());
+    assert!(PluralExpression::parse("n ? 1").is_err());
+    assert!(PluralExpression::parse("x").is_err());
+}
+
+#[test]
+fn test_parse_po() {
+    let source = r#"
+# A comment
+msgid ""
+msgstr ""
+"Content-Type: text/plain; charset=UTF-8\n"
+"Plural-Forms: nplurals=2; plural=(n > 1);\n"
+
+#: main.slint:3
+msgctxt "App"
+msgid "Hello"
+msgstr "Bonjour"
+
+#, fuzzy
+msgctxt "App"
+msgid "Fuzzy"
+msgstr "Flou"
+
+msgctxt "App"
+msgid "Untranslated"
+msgstr ""
+
+msgctxt "App"
+msgid "One file"
+msgid_plural "{n} files"
+msgstr[0] "Un fichier"
+msgstr[1] "{n} "
+"fichiers"
+
+#~ msgid "Obsolete"
+#~ msgstr "Obsolète"
+msgid "Quote \"\\\""
+msgstr "Guillemet «\\»"
+"#;
+    let catalog = Catalog::from_po(source).unwrap();
+    assert_eq!(
+        catalog.plural_rule,
+        Some(PluralExpression::BinaryExpression {
+            lhs: Box::new(PluralExpression::N),
+            rhs: Box::new(PluralExpression::NumberLiteral(1)),
+            op: ">",
+        })
+    );
+    let get = |ctx: &str, id: &str| catalog.messages.get(&(ctx.into(), id.into())).cloned();
+    assert_eq!(get("App", "Hello"), Some(vec!["Bonjour".into()]));
+    assert_eq!(get("App", "Fuzzy"), None);
+    assert_eq!(get("App", "Untranslated"), None);
+    assert_eq!(get("App", "One file"), Some(vec!["Un fichier".into(), "{n} fichiers".into()]));
+    assert_eq!(get("", "Quote \"\\\""), Some(vec!["Guillemet «\\»".into()]));
+    assert_eq!(catalog.messages.len(), 3);
+
+    assert_eq!(parse_po("msgid \"a\"\nfoo \"b\"").unwrap_err().0, 10);
+    assert!(parse_po("msgid \"a\"").is_err());
+    assert!(parse_po("msgid \"a\\q\"\nmsgstr \"\"").is_err());
+}
@@ -83,6 +83,7 @@ fn fill_register(&self, register: &mut TypeRegister) {
     ("opacity", Type::Float32),
     ("cache-rendering-hint", Type::Bool),
     ("visible", Type::Bool), // ("enabled", Type::Bool),
+    ("tooltip", Type::String),
 ];
 
 pub const RESERVED_DROP_SHADOW_PROPERTIES: &[(&str, Type)] = &[
@@ -98,7 +99,7 @@ fn fill_register(&self, register: &mut TypeRegister) {
     ("rotation-origin-y", Type::LogicalLength),
 ];
 
-pub const RESERVED_ACCESSIBILITY_PROPERTIES: &[(&str, Type)] = &[
+const RESERVED_ACCESSIBILITY_PROPERTIES: &[(&str, Type)] = &[
     //("accessible-role", ...)
     ("accessible-checkable", Type::Bool),
     ("accessible-checked", Type::Bool),
@@ -111,6 +112,20 @@ fn fill_register(&self, register: &mut TypeRegister) {
     ("accessible-value-step", Type::Float32),
 ];
 
+/// The accessibility properties and the `accessible-action-*` callbacks (except `accessible-role`)
+pub fn reserved_accessibility_properties() -> impl Iterator<Item = (&'static str, Type)> {
+    let noarg_callback_type = || Type::Callback { return_type: None, args: vec![] };
+    RESERVED_ACCESSIBILITY_PROPERTIES.iter().cloned().chain(IntoIterator::into_iter([
+        ("accessible-action-default", noarg_callback_type()),
+        ("accessible-action-increment", noarg_callback_type()),
+        ("accessible-action-decrement", noarg_callback_type()),
+        (
+            "accessible-action-set-value",
+            Type::Callback { return_type: None, args: vec![Type::String] },
+        ),
+    ]))
+}
+
 /// list of reserved property injected in every item
 pub fn reserved_properties() -> impl Iterator<Item = (&'static str, Type, PropertyVisibility)> {
     RESERVED_GEOMETRY_PROPERTIES
@@ -119,8 +134,9 @@ pub fn reserved_properties() -> impl Iterator<Item = (&'static str, Type, Proper
         .chain(RESERVED_OTHER_PROPERTIES.iter())
         .chain(RESERVED_DROP_SHADOW_PROPERTIES.iter())
         .chain(RESERVED_ROTATION_PROPERTIES.iter())
-        .chain(RESERVED_ACCESSIBILITY_PROPERTIES.iter())
-        .map(|(k, v)| (*k, v.clone(), PropertyVisibility::InOut))
+        .map(|(k, v)| (*k, v.clone()))
+        .chain(reserved_accessibility_properties())
+        .map(|(k, v)| (k, v, PropertyVisibility::InOut))
         .chain(
             RESERVED_GRIDLAYOUT_PROPERTIES
                 .iter()
@@ -261,48 +277,9 @@ fn builtin_internal() -> Self {
         register.supported_property_animation_types.insert(Type::Brush.to_string());
         register.supported_property_animation_types.insert(Type::Angle.to_string());
 
-        #[rustfmt::skip]
-        macro_rules! map_type {
-            ($pub_type:ident, bool) => { Type::Bool };
-            ($pub_type:ident, i32) => { Type::Int32 };
-            ($pub_type:ident, f32) => { Type::Float32 };
-            ($pub_type:ident, SharedString) => { Type::String };
-            ($pub_type:ident, Coord) => { Type::LogicalLength };
-            ($pub_type:ident, KeyboardModifiers) => { $pub_type.clone() };
-            ($pub_type:ident, $_:ident) => {
-                BUILTIN_ENUMS.with(|e| Type::Enumeration(e.$pub_type.clone()))
-            };
-        }
-        #[rustfmt::skip]
-        macro_rules! maybe_clone {
-            ($pub_type:ident, KeyboardModifiers) => { $pub_type.clone() };
-            ($pub_type:ident, $_:ident) => { $pub_type };
-        }
-        macro_rules! register_builtin_structs {
-            ($(
-                $(#[$attr:meta])*
-                struct $Name:ident {
-                    @name = $inner_name:literal
-                    export {
-                        $( $(#[$pub_attr:meta])* $pub_field:ident : $pub_type:ident, )*
-                    }
-                    private {
-                        $( $(#[$pri_attr:meta])* $pri_field:ident : $pri_type:ty, )*
-                    }
-                }
-            )*) => { $(
-                let $Name = Type::Struct {
-                    fields: BTreeMap::from([
-                        $((stringify!($pub_field).replace('_', "-"), map_type!($pub_type, $pub_type))),*
-                    ]),
-                    name: Some(format!("{}", $inner_name)),
-                    node: None,
-                    rust_attributes: None,
-                };
-                register.insert_type_with_name(maybe_clone!($Name, $Name), stringify!($Name).to_string());
-            )* };
+        for (name, ty) in builtin_structs() {
+            register.insert_type_with_name(ty, name);
         }
-        i_slint_common::for_each_builtin_structs!(register_builtin_structs);
 
         crate::load_builtins::load_builtins(&mut register);
 
@@ -328,8 +305,10 @@ struct $Name:ident {
                 );
                 popup.member_functions.insert("close".into(), BuiltinFunction::ClosePopupWindow);
 
-                popup.properties.get_mut("close-on-click").unwrap().property_visibility =
-                    PropertyVisibility::Constexpr;
+                for prop in ["close-on-click", "close-policy", "placement"] {
+                    popup.properties.get_mut(prop).unwrap().property_visibility =
+                        PropertyVisibility::Constexpr;
+                }
             }
 
             _ => unreachable!(),
@@ -491,6 +470,59 @@ pub fn empty_type(&self) -> ElementType {
     }
 }
 
+/// The types of the builtin structs (such as `KeyEvent`), indexed by their name in the language
+fn builtin_structs() -> BTreeMap<String, Type> {
+    let mut structs = BTreeMap::new();
+    #[rustfmt::skip]
+    macro_rules! map_type {
+        ($pub_type:ident, bool) => { Type::Bool };
+        ($pub_type:ident, i32) => { Type::Int32 };
+        ($pub_type:ident, f32) => { Type::Float32 };
+        ($pub_type:ident, SharedString) => { Type::String };
+        ($pub_type:ident, Coord) => { Type::LogicalLength };
+        ($pub_type:ident, KeyboardModifiers) => { $pub_type.clone() };
+        ($pub_type:ident, $_:ident) => {
+            BUILTIN_ENUMS.with(|e| Type::Enumeration(e.$pub_type.clone()))
+        };
+    }
+    #[rustfmt::skip]
+    macro_rules! maybe_clone {
+        ($pub_type:ident, KeyboardModifiers) => { $pub_type.clone() };
+        ($pub_type:ident, $_:ident) => { $pub_type };
+    }
+    macro_rules! register_builtin_structs {
+        ($(
+            $(#[$attr:meta])*
+            struct $Name:ident {
+                @name = $inner_name:literal
+                export {
+                    $( $(#[$pub_attr:meta])* $pub_field:ident : $pub_type:ident, )*
+                }
+                private {
+                    $( $(#[$pri_attr:meta])* $pri_field:ident : $pri_type:ty, )*
+                }
+            }
+        )*) => { $(
+            let $Name = Type::Struct {
+                fields: BTreeMap::from([
+                    $((stringify!($pub_field).replace('_', "-"), map_type!($pub_type, $pub_type))),*
+                ]),
+                name: Some(format!("{}", $inner_name)),
+                node: None,
+                rust_attributes: None,
+            };
+            structs.insert(stringify!($Name).to_string(), maybe_clone!($Name, $Name));
+        )* };
+    }
+    i_slint_common::for_each_builtin_structs!(register_builtin_structs);
+    structs
+}
+
+/// The type of the `KeyEvent` struct passed to the key callbacks of `FocusScope`
+pub fn key_event_type() -> Type {
+    builtin_structs().remove("KeyEvent").unwrap()
+}
+
 pub fn logical_point_type() -> Type {
     Type::Struct {
         fields: IntoIterator::into_iter([
@@ -503,3 +535,18 @@ pub fn logical_point_type() -> Type {
         rust_attributes: None,
     }
 }
+
+/// The type of a keyframe in the `keyframes` of a property animation, for a property of the given type
+pub fn animation_keyframe_type(value_type: Type) -> Type {
+    Type::Struct {
+        fields: IntoIterator::into_iter([
+            ("position".to_owned(), Type::Float32),
+            ("value".to_owned(), value_type),
+            ("easing".to_owned(), Type::Easing),
+        ])
+        .collect(),
+        name: Some("slint::private_api::AnimationKeyframe".into()),
+        node: None,
+        rust_attributes: None,
+    }
+}
@@ -7,6 +7,7 @@
 use alloc::vec::Vec;
 
 use crate::items::ItemRc;
+use crate::SharedString;
 
 // The property names of the accessible-properties
 #[repr(u32)]
@@ -24,6 +25,29 @@ pub enum AccessibleStringProperty {
     ValueStep,
 }
 
+/// The accessibility action that an assistive technology requests, and its argument.
+#[repr(C)]
+#[derive(PartialEq, Clone, Debug)]
+pub enum AccessibilityAction {
+    Default,
+    Decrement,
+    Increment,
+    SetValue(SharedString),
+}
+
+bitflags::bitflags! {
+    /// Define the accessibility actions that an item supports, because it has the
+    /// corresponding `accessible-action-*` callback set.
+    #[repr(transparent)]
+    #[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+    pub struct SupportedAccessibilityAction: u32 {
+        const Default = 1;
+        const Decrement = 1 << 1;
+        const Increment = 1 << 2;
+        const SetValue = 1 << 3;
+    }
+}
+
 /// Find accessible descendents of `root_item`.
 ///
 /// This will recurse through all children of `root_item`, but will not recurse
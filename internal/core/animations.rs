@@ -160,7 +160,11 @@ pub enum EasingCurve {
     EaseOutBounce,
     /// Easing curve as defined at: <https://easings.net/#easeInOutBounce>
     EaseInOutBounce,
-    // Custom(Box<dyn Fn(f32) -> f32>),
+    /// A staircase curve with the given number of steps, like `steps(n, jump-end)` in CSS
+    Steps(u32),
+    /// A physics based spring with its stiffness, damping and mass.
+    /// The duration of the animation is given by the time the spring takes to settle.
+    Spring([f32; 3]),
 }
 
 /// Represent an instant, in milliseconds since the AnimationDriver's initial_instant
@@ -378,7 +382,107 @@ pub fn easing_curve(curve: &EasingCurve, value: f32) -> f32 {
                 (1.0 + ease_out_bounce_curve(2.0 * value - 1.0)) / 2.0
             }
         }
+        EasingCurve::Steps(steps) => {
+            let steps = (*steps).max(1) as f32;
+            ((value * steps).floor() / steps).min(1.0)
+        }
+        EasingCurve::Spring(params) => {
+            // Without the time, use the time a spring starting at rest takes to settle
+            let duration = spring_settling_time(params, 0.0);
+            spring_curve(params, 0.0, value * duration).0
+        }
+    }
+}
+
+/// Tolerance, in progress units, under which a spring is considered at rest
+const SPRING_REST_THRESHOLD: f32 = 0.001;
+
+/// Returns the progress and the velocity (in progress per second) of a spring going
+/// from 0 to 1, `time` seconds after it started with the given `initial_velocity`.
+///
+/// `params` are the stiffness, damping and mass of the spring.
+pub fn spring_curve(params: &[f32; 3], initial_velocity: f32, time: f32) -> (f32, f32) {
+    let [stiffness, damping, mass] = *params;
+    let stiffness = stiffness.max(f32::EPSILON);
+    let mass = mass.max(f32::EPSILON);
+    let damping = damping.max(0.0);
+    let omega = (stiffness / mass).sqrt();
+    let zeta = damping / (2.0 * (stiffness * mass).sqrt());
+    // Displacement from the target
+    let x0 = -1.0;
+    let v0 = initial_velocity;
+
+    let (x, v) = if zeta < 1.0 - 1e-4 {
+        let omega_d = omega * (1.0 - zeta * zeta).sqrt();
+        let a = x0;
+        let b = (v0 + zeta * omega * x0) / omega_d;
+        let envelope = (-zeta * omega * time).exp();
+        let (sin, cos) = (omega_d * time).sin_cos();
+        let x = envelope * (a * cos + b * sin);
+        let v = envelope
+            * ((b * omega_d - zeta * omega * a) * cos - (a * omega_d + zeta * omega * b) * sin);
+        (x, v)
+    } else if (zeta - 1.0).abs() < 1e-4 {
+        let b = v0 + omega * x0;
+        let envelope = (-omega * time).exp();
+        (envelope * (x0 + b * time), envelope * (b - omega * (x0 + b * time)))
+    } else {
+        let root = (zeta * zeta - 1.0).sqrt();
+        let r1 = -omega * (zeta - root);
+        let r2 = -omega * (zeta + root);
+        let c2 = (v0 - r1 * x0) / (r2 - r1);
+        let c1 = x0 - c2;
+        let (e1, e2) = ((r1 * time).exp(), (r2 * time).exp());
+        (c1 * e1 + c2 * e2, c1 * r1 * e1 + c2 * r2 * e2)
+    };
+    (1.0 + x, v)
+}
+
+/// Returns whether a spring with the given progress and velocity is at rest on its target
+pub fn spring_is_at_rest(progress: f32, velocity: f32) -> bool {
+    (1.0 - progress).abs() < SPRING_REST_THRESHOLD && velocity.abs() < SPRING_REST_THRESHOLD
+}
+
+/// Returns the time in seconds a spring takes until it is at rest
+fn spring_settling_time(params: &[f32; 3], initial_velocity: f32) -> f32 {
+    const STEP: f32 = 0.016;
+    const MAX_TIME: f32 = 10.0;
+    let mut time = 0.0;
+    while time < MAX_TIME {
+        let (progress, velocity) = spring_curve(params, initial_velocity, time);
+        if spring_is_at_rest(progress, velocity) {
+            break;
+        }
+        time += STEP;
+    }
+    time.max(STEP)
+}
+
+#[test]
+fn steps_curve() {
+    let curve = EasingCurve::Steps(4);
+    assert_eq!(easing_curve(&curve, 0.0), 0.0);
+    assert_eq!(easing_curve(&curve, 0.2), 0.0);
+    assert_eq!(easing_curve(&curve, 0.3), 0.25);
+    assert_eq!(easing_curve(&curve, 0.99), 0.75);
+    assert_eq!(easing_curve(&curve, 1.0), 1.0);
+}
+
+#[test]
+fn spring_curve_settles() {
+    for params in [[170., 26., 1.], [100., 5., 1.], [100., 40., 1.], [100., 20., 1.]] {
+        assert_eq!(spring_curve(&params, 0., 0.), (0., 0.));
+        let (progress, velocity) = spring_curve(&params, 0., 5.);
+        assert!(spring_is_at_rest(progress, velocity), "{params:?}: {progress} {velocity}");
+        assert!((easing_curve(&EasingCurve::Spring(params), 1.0) - 1.0).abs() < 0.01);
     }
+    // An under-damped spring overshoots the target
+    let overshoot = (0..100).map(|i| spring_curve(&[100., 5., 1.], 0., i as f32 * 0.01).0);
+    assert!(overshoot.fold(0., f32::max) > 1.);
+    // A positive initial velocity moves faster at the start
+    assert!(
+        spring_curve(&[170., 26., 1.], 10., 0.05).0 > spring_curve(&[170., 26., 1.], 0., 0.05).0
+    );
 }
 
 /*
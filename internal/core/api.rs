@@ -557,6 +557,31 @@ pub fn dispatch_event(&self, event: crate::platform::WindowEvent) {
                 }
             }
             crate::platform::WindowEvent::WindowActiveChanged(bool) => self.0.set_active(bool),
+            crate::platform::WindowEvent::TouchPressed { touch_id, position } => {
+                self.0.process_touch_pressed(touch_id, position.to_euclid().cast())
+            }
+            crate::platform::WindowEvent::TouchMoved { touch_id, position } => {
+                self.0.process_touch_moved(touch_id, position.to_euclid().cast())
+            }
+            crate::platform::WindowEvent::TouchReleased { touch_id, position } => {
+                self.0.process_touch_released(touch_id, position.to_euclid().cast())
+            }
+            crate::platform::WindowEvent::DragMoved { position, mime_type, data } => {
+                self.0.start_drag(crate::items::DropEvent { mime_type, data }, Default::default());
+                self.0.process_mouse_input(MouseEvent::DragMove {
+                    position: position.to_euclid().cast(),
+                })
+            }
+            crate::platform::WindowEvent::DragDropped { position, mime_type, data } => {
+                self.0.start_drag(crate::items::DropEvent { mime_type, data }, Default::default());
+                self.0.process_mouse_input(MouseEvent::DragDrop {
+                    position: position.to_euclid().cast(),
+                })
+            }
+            crate::platform::WindowEvent::DragExited => {
+                self.0.process_mouse_input(MouseEvent::Exit);
+                self.0.end_drag();
+            }
         }
     }
 
@@ -876,6 +901,54 @@ fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
     }
 }
 
+/// Selects the language of the translations that were bundled in the binary at compile time.
+///
+/// The language is a locale name, like `de` or `fr_CH`. If no translation was bundled for the
+/// full locale name, the translation for the language only (`fr`) is selected. An empty string
+/// selects the original strings from the `.slint` files.
+///
+/// All the bindings using `@tr(...)` are re-evaluated with the newly selected language.
+///
+/// The translations are bundled when compiling with
+/// [`slint_build::CompilerConfiguration::with_bundled_translations()`](https://docs.rs/slint-build/latest/slint_build/struct.CompilerConfiguration.html#method.with_bundled_translations).
+/// The available languages are only known once a component was created.
+pub fn select_bundled_translation(language: &str) -> Result<(), SelectBundledTranslationError> {
+    crate::translations::select_bundled_translation(language)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+/// Error returned from [`select_bundled_translation()`]
+pub enum SelectBundledTranslationError {
+    /// There is no translation for the requested language.
+    LanguageNotFound {
+        /// The languages for which a translation is bundled
+        available_languages: crate::SharedVector<SharedString>,
+    },
+    /// No translation was bundled, or no component was created yet.
+    NoTranslationsBundled,
+}
+
+impl core::fmt::Display for SelectBundledTranslationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SelectBundledTranslationError::LanguageNotFound { available_languages } => {
+                write!(f, "The specified language was not found. Available languages are:")?;
+                for language in available_languages.iter().filter(|l| !l.is_empty()) {
+                    write!(f, " {language}")?;
+                }
+                Ok(())
+            }
+            SelectBundledTranslationError::NoTranslationsBundled => {
+                f.write_str("There are no translations bundled")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SelectBundledTranslationError {}
+
 /// The platform encountered a fatal error.
 ///
 /// This error typically indicates an issue with initialization or connecting to the windowing system.
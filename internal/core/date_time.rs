@@ -0,0 +1,66 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! Locale information used by the date and time widgets
+
+/// The regions where the week starts on Sunday, according to the Unicode CLDR.
+const SUNDAY_FIRST_REGIONS: &[&str] = &[
+    "AG", "AS", "BD", "BR", "BS", "BT", "BW", "BZ", "CA", "CO", "DM", "DO", "ET", "GT", "GU", "HK",
+    "HN", "ID", "IL", "IN", "JM", "JP", "KE", "KH", "KR", "LA", "MH", "MM", "MO", "MT", "MX", "MZ",
+    "NI", "NP", "PA", "PE", "PH", "PK", "PR", "PT", "PY", "SA", "SG", "SV", "TH", "TT", "TW", "UM",
+    "US", "VE", "VI", "WS", "YE", "ZA", "ZW",
+];
+
+/// The regions where the week starts on Saturday, according to the Unicode CLDR.
+const SATURDAY_FIRST_REGIONS: &[&str] =
+    &["AE", "AF", "BH", "DJ", "DZ", "EG", "IQ", "IR", "JO", "KW", "LY", "OM", "QA", "SD", "SY"];
+
+/// Returns the first day of the week for the given locale name, such as `en_US.UTF-8` or `pt-BR`,
+/// from 0 for Sunday to 6 for Saturday. Monday is returned when the locale has no known region.
+pub fn first_day_of_week_for_locale(locale: &str) -> i32 {
+    let language = locale.split(['.', '@']).next().unwrap_or_default();
+    let region = language.split(['_', '-']).nth(1).unwrap_or_default();
+    if SUNDAY_FIRST_REGIONS.iter().any(|r| r.eq_ignore_ascii_case(region)) {
+        0
+    } else if SATURDAY_FIRST_REGIONS.iter().any(|r| r.eq_ignore_ascii_case(region)) {
+        6
+    } else {
+        1
+    }
+}
+
+/// Returns the first day of the week in the locale of the user, from 0 for Sunday to 6 for Saturday.
+///
+/// The locale is read from the `LC_ALL`, `LC_TIME` or `LANG` environment variables.
+/// Monday is returned when none of them is set.
+pub fn first_day_of_week() -> i32 {
+    #[cfg(feature = "std")]
+    if let Some(locale) = ["LC_ALL", "LC_TIME", "LANG"]
+        .iter()
+        .find_map(|var| std::env::var(var).ok().filter(|locale| !locale.is_empty()))
+    {
+        return first_day_of_week_for_locale(&locale);
+    }
+    1
+}
+
+#[cfg(feature = "ffi")]
+mod ffi {
+    #![allow(unsafe_code)]
+
+    /// Returns the first day of the week in the locale of the user, for the `DatePicker`
+    #[no_mangle]
+    pub extern "C" fn slint_date_time_first_day_of_week() -> i32 {
+        super::first_day_of_week()
+    }
+}
+
+#[test]
+fn test_first_day_of_week_for_locale() {
+    assert_eq!(first_day_of_week_for_locale("en_US.UTF-8"), 0);
+    assert_eq!(first_day_of_week_for_locale("pt-BR"), 0);
+    assert_eq!(first_day_of_week_for_locale("de_DE@euro"), 1);
+    assert_eq!(first_day_of_week_for_locale("ar_EG.UTF-8"), 6);
+    assert_eq!(first_day_of_week_for_locale("C"), 1);
+    assert_eq!(first_day_of_week_for_locale(""), 1);
+}
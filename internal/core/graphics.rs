@@ -34,9 +34,7 @@
 pub(crate) mod color;
 pub use color::*;
 
-#[cfg(feature = "std")]
 mod path;
-#[cfg(feature = "std")]
 pub use path::*;
 
 mod brush;
@@ -152,6 +150,16 @@ pub struct FontRequest {
     pub italic: bool,
 }
 
+/// The vertical metrics of a font, in logical pixels, as returned by the renderer.
+/// The sum of the ascent and the descent is the height of one line of text.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct FontMetrics {
+    /// The distance from the top of a line of text to its baseline
+    pub ascent: Coord,
+    /// The distance from the baseline to the bottom of a line of text
+    pub descent: Coord,
+}
+
 #[cfg(feature = "shared-fontdb")]
 impl FontRequest {
     /// Returns the relevant properties of this FontRequest propagated into a fontdb Query.
@@ -198,7 +206,6 @@ struct Point {
         y: f32,
     }
 
-    #[cfg(feature = "std")]
     pub use super::path::ffi::*;
 
     /// Conversion function used by C++ platform API layer to
@@ -5,7 +5,8 @@
 This module contains a cache helper for caching box shadow textures.
 */
 
-use std::{cell::RefCell, collections::BTreeMap};
+use alloc::collections::BTreeMap;
+use core::cell::RefCell;
 
 use crate::items::ItemRc;
 use crate::lengths::RectLengths;
@@ -31,23 +32,23 @@ pub struct BoxShadowOptions {
 
 impl Eq for BoxShadowOptions {}
 impl Ord for BoxShadowOptions {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
         if (other.width, other.height, other.color, other.blur, other.radius)
             < (self.width, self.height, self.color, self.blur, self.radius)
         {
-            std::cmp::Ordering::Less
+            core::cmp::Ordering::Less
         } else if (self.width, self.height, self.color, self.blur, self.radius)
             < (other.width, other.height, other.color, other.blur, other.radius)
         {
-            std::cmp::Ordering::Greater
+            core::cmp::Ordering::Greater
         } else {
-            std::cmp::Ordering::Equal
+            core::cmp::Ordering::Equal
         }
     }
 }
 
 impl PartialOrd for BoxShadowOptions {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         Some(self.cmp(other))
     }
 }
@@ -58,7 +59,7 @@ impl BoxShadowOptions {
     /// alpha on the box shadow would imply that no shadow is to be rendered.
     pub fn new(
         item_rc: &ItemRc,
-        box_shadow: std::pin::Pin<&crate::items::BoxShadow>,
+        box_shadow: core::pin::Pin<&crate::items::BoxShadow>,
         scale_factor: ScaleFactor,
     ) -> Option<Self> {
         let color = box_shadow.color();
@@ -82,32 +83,105 @@ pub fn new(
 }
 
 /// Cache to hold box textures for given box shadow options.
-pub struct BoxShadowCache<ImageType>(RefCell<BTreeMap<BoxShadowOptions, ImageType>>);
+///
+/// Renderers that keep the cache across frames should call [`Self::end_frame`] after each frame,
+/// so that the textures of shadows that are no longer drawn (for example because their blur or
+/// size is animated) are released.
+pub struct BoxShadowCache<ImageType> {
+    current_frame: RefCell<BTreeMap<BoxShadowOptions, ImageType>>,
+    previous_frame: RefCell<BTreeMap<BoxShadowOptions, ImageType>>,
+}
 
 impl<ImageType> Default for BoxShadowCache<ImageType> {
     fn default() -> Self {
-        Self(Default::default())
+        Self { current_frame: Default::default(), previous_frame: Default::default() }
     }
 }
 
 impl<ImageType: Clone> BoxShadowCache<ImageType> {
+    /// Look up a box shadow texture for the given options, or create a new one if needed.
+    pub fn get_or_create(
+        &self,
+        shadow_options: BoxShadowOptions,
+        shadow_render_fn: impl FnOnce(&BoxShadowOptions) -> ImageType,
+    ) -> ImageType {
+        let mut current_frame = self.current_frame.borrow_mut();
+        if let Some(image) = current_frame.get(&shadow_options) {
+            return image.clone();
+        }
+        let image = self
+            .previous_frame
+            .borrow_mut()
+            .remove(&shadow_options)
+            .unwrap_or_else(|| shadow_render_fn(&shadow_options));
+        current_frame.insert(shadow_options, image.clone());
+        image
+    }
+
+    /// Releases the textures that were not used since the previous call to this function.
+    ///
+    /// This keeps the cache bounded to the shadows drawn in the last two frames.
+    pub fn end_frame(&self) {
+        let current_frame = core::mem::take(&mut *self.current_frame.borrow_mut());
+        *self.previous_frame.borrow_mut() = current_frame;
+    }
+
+    /// Removes all the textures from the cache.
+    pub fn clear(&self) {
+        self.current_frame.borrow_mut().clear();
+        self.previous_frame.borrow_mut().clear();
+    }
+
     /// Look up a box shadow texture for a given box shadow item, or create a new one if needed.
+    #[cfg(feature = "std")]
     pub fn get_box_shadow(
         &self,
         item_rc: &ItemRc,
         item_cache: &crate::item_rendering::ItemCache<Option<ImageType>>,
-        box_shadow: std::pin::Pin<&crate::items::BoxShadow>,
+        box_shadow: core::pin::Pin<&crate::items::BoxShadow>,
         scale_factor: ScaleFactor,
         shadow_render_fn: impl FnOnce(&BoxShadowOptions) -> ImageType,
     ) -> Option<ImageType> {
         item_cache.get_or_update_cache_entry(item_rc, || {
             let shadow_options = BoxShadowOptions::new(item_rc, box_shadow, scale_factor)?;
-            self.0
-                .borrow_mut()
-                .entry(shadow_options.clone())
-                .or_insert_with(|| shadow_render_fn(&shadow_options))
-                .clone()
-                .into()
+            Some(self.get_or_create(shadow_options, shadow_render_fn))
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+
+    #[test]
+    fn test_end_frame_releases_unused_textures() {
+        let cache = BoxShadowCache::<u32>::default();
+        let created = Cell::new(0);
+        let get = |blur: f32| {
+            let options =
+                BoxShadowOptions { blur: euclid::Length::new(blur), ..Default::default() };
+            cache.get_or_create(options, |_| {
+                created.set(created.get() + 1);
+                created.get()
+            })
+        };
+
+        // An animated blur creates a new texture every frame
+        for frame in 0..10 {
+            get(frame as f32);
+            get(100.);
+            cache.end_frame();
+        }
+        assert_eq!(created.get(), 11);
+        assert!(cache.current_frame.borrow().is_empty());
+        assert_eq!(cache.previous_frame.borrow().len(), 2);
+
+        // Textures used in the previous frame are kept, the other ones are released
+        assert_eq!(get(100.), 2);
+        cache.end_frame();
+        cache.end_frame();
+        assert!(cache.previous_frame.borrow().is_empty());
+        assert_eq!(get(100.), 12);
+    }
+}
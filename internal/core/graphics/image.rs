@@ -48,6 +48,11 @@ pub struct OpaqueImageVTable {
     pub static NINE_SLICE_VT for NineSliceImage
 }
 
+OpaqueImageVTable_static! {
+    /// VTable for RC wrapped animated image helper struct.
+    pub static ANIMATED_IMAGE_VT for AnimatedImage
+}
+
 /// SharedPixelBuffer is a container for storing image data as pixels. It is
 /// internally reference counted and cheap to clone.
 ///
@@ -275,6 +280,11 @@ pub struct StaticTextures {
     pub data: Slice<'static, u8>,
     /// The list of textures
     pub textures: Slice<'static, StaticTexture>,
+    /// The frames of an animated image, empty if the image is not animated.
+    /// The textures of the image are the ones of the first frame.
+    pub frames: Slice<'static, StaticTextures>,
+    /// How long the image is shown when it is a frame of an animated image, in milliseconds
+    pub frame_duration: u32,
 }
 
 /// ImageCacheKey encapsulates the different ways of indexing images in the
@@ -292,6 +302,16 @@ pub enum ImageCacheKey {
     URL(SharedString) = 2,
     /// The image is identified by the static address of its encoded data.
     EmbeddedData(usize) = 3,
+    /// The image is a frame of an animated image, which is identified by its path on the file
+    /// system or by the static address of its encoded data.
+    AnimationFrame {
+        /// The path of the animated image, empty for embedded data.
+        path: SharedString,
+        /// The address of the encoded data of the animated image, 0 for a path.
+        embedded_data: usize,
+        /// The index of the frame.
+        index: u32,
+    } = 4,
 }
 
 impl ImageCacheKey {
@@ -312,6 +332,7 @@ pub fn new(resource: &ImageInner) -> Option<Self> {
             #[cfg(not(target_arch = "wasm32"))]
             ImageInner::BorrowedOpenGLTexture(..) => return None,
             ImageInner::NineSlice(nine) => vtable::VRc::borrow(nine).cache_key(),
+            ImageInner::Animated(animated) => animated.cache_key.clone(),
         };
         if matches!(key, ImageCacheKey::Invalid) {
             None
@@ -324,6 +345,21 @@ pub fn new(resource: &ImageInner) -> Option<Self> {
     pub fn from_embedded_image_data(data: &'static [u8]) -> Self {
         Self::EmbeddedData(data.as_ptr() as usize)
     }
+
+    /// Returns the cache key for the frame at the given index of the animated image
+    /// identified by this key.
+    fn for_animation_frame(&self, index: usize) -> Self {
+        let index = index as u32;
+        match self {
+            Self::Path(path) => {
+                Self::AnimationFrame { path: path.clone(), embedded_data: 0, index }
+            }
+            Self::EmbeddedData(address) => {
+                Self::AnimationFrame { path: Default::default(), embedded_data: *address, index }
+            }
+            _ => Self::Invalid,
+        }
+    }
 }
 
 /// Represent a nine-slice image with the base image and the 4 borders
@@ -345,6 +381,42 @@ fn cache_key(&self) -> ImageCacheKey {
     }
 }
 
+/// A frame of an [`AnimatedImage`]
+#[derive(Clone, Debug)]
+pub struct AnimatedImageFrame {
+    /// The pixels of the whole frame
+    pub buffer: SharedImageBuffer,
+    /// How long the frame is shown, in milliseconds
+    pub duration: u32,
+}
+
+/// An image made of several frames, such as an animated GIF or PNG
+pub struct AnimatedImage {
+    /// The cache key of the image the frames were decoded from
+    pub cache_key: ImageCacheKey,
+    /// The frames of the animation, there is always at least one.
+    pub frames: alloc::vec::Vec<AnimatedImageFrame>,
+}
+
+impl AnimatedImage {
+    /// Returns the image for the frame at the given index
+    pub fn frame(&self, index: usize) -> Image {
+        Image(ImageInner::EmbeddedImage {
+            cache_key: self.cache_key.for_animation_frame(index),
+            buffer: self.frames[index].buffer.clone(),
+        })
+    }
+}
+
+impl OpaqueImage for AnimatedImage {
+    fn size(&self) -> IntSize {
+        self.frames[0].buffer.size()
+    }
+    fn cache_key(&self) -> ImageCacheKey {
+        self.cache_key.clone()
+    }
+}
+
 /// A resource is a reference to binary data, for example images. They can be accessible on the file
 /// system or embedded in the resulting binary. Or they might be URLs to a web server and a downloaded
 /// is necessary before they can be used.
@@ -369,6 +441,7 @@ pub enum ImageInner {
     #[cfg(not(target_arch = "wasm32"))]
     BorrowedOpenGLTexture(BorrowedOpenGLTexture) = 6,
     NineSlice(vtable::VRc<OpaqueImageVTable, NineSliceImage>) = 7,
+    Animated(vtable::VRc<OpaqueImageVTable, AnimatedImage>) = 8,
 }
 
 impl ImageInner {
@@ -454,6 +527,7 @@ pub fn render_to_buffer(
                 Some(SharedImageBuffer::RGBA8Premultiplied(buffer))
             }
             ImageInner::NineSlice(nine) => nine.0.render_to_buffer(None),
+            ImageInner::Animated(animated) => Some(animated.frames[0].buffer.clone()),
             _ => None,
         }
     }
@@ -483,6 +557,7 @@ pub fn size(&self) -> IntSize {
             #[cfg(not(target_arch = "wasm32"))]
             ImageInner::BorrowedOpenGLTexture(BorrowedOpenGLTexture { size, .. }) => *size,
             ImageInner::NineSlice(nine) => nine.0.size(),
+            ImageInner::Animated(animated) => animated.size(),
         }
     }
 }
@@ -503,6 +578,7 @@ fn eq(&self, other: &Self) -> bool {
             #[cfg(not(target_arch = "wasm32"))]
             (Self::BorrowedOpenGLTexture(l0), Self::BorrowedOpenGLTexture(r0)) => l0 == r0,
             (Self::NineSlice(l), Self::NineSlice(r)) => l.0 == r.0 && l.1 == r.1,
+            (Self::Animated(l), Self::Animated(r)) => vtable::VRc::ptr_eq(l, r),
             _ => false,
         }
     }
@@ -731,6 +807,56 @@ pub fn size(&self) -> IntSize {
         self.0.size()
     }
 
+    /// Returns the number of frames of an animated image, such as an animated GIF or PNG.
+    /// Images that are not animated have a single frame.
+    pub fn frame_count(&self) -> usize {
+        match &self.0 {
+            ImageInner::Animated(animated) => animated.frames.len(),
+            ImageInner::StaticTextures(textures) if !textures.frames.is_empty() => {
+                textures.frames.len()
+            }
+            _ => 1,
+        }
+    }
+
+    /// Returns a still image of the frame at the given index of an animated image.
+    ///
+    /// For images that are not animated, this returns the image itself.
+    /// Panics if the index is out of bounds.
+    pub fn frame(&self, index: usize) -> Image {
+        match &self.0 {
+            ImageInner::Animated(animated) => animated.frame(index),
+            ImageInner::StaticTextures(textures) if !textures.frames.is_empty() => {
+                ImageInner::StaticTextures(&textures.frames.as_slice()[index]).into()
+            }
+            _ => {
+                assert_eq!(index, 0, "frame index out of bounds");
+                self.clone()
+            }
+        }
+    }
+
+    /// Returns how long the frame at the given index of an animated image is shown.
+    ///
+    /// For images that are not animated, this returns zero.
+    /// Panics if the index is out of bounds.
+    pub fn frame_duration(&self, index: usize) -> core::time::Duration {
+        match &self.0 {
+            ImageInner::Animated(animated) => {
+                core::time::Duration::from_millis(animated.frames[index].duration as u64)
+            }
+            ImageInner::StaticTextures(textures) if !textures.frames.is_empty() => {
+                core::time::Duration::from_millis(
+                    textures.frames.as_slice()[index].frame_duration as u64,
+                )
+            }
+            _ => {
+                assert_eq!(index, 0, "frame index out of bounds");
+                core::time::Duration::ZERO
+            }
+        }
+    }
+
     #[cfg(feature = "std")]
     /// Returns the path of the image on disk, if it was constructed via [`Self::load_from_path`].
     ///
@@ -748,6 +874,10 @@ pub fn path(&self) -> Option<&std::path::Path> {
             ImageInner::EmbeddedImage { cache_key: ImageCacheKey::Path(path), .. } => {
                 Some(std::path::Path::new(path.as_str()))
             }
+            ImageInner::Animated(animated) => match &animated.cache_key {
+                ImageCacheKey::Path(path) => Some(std::path::Path::new(path.as_str())),
+                _ => None,
+            },
             ImageInner::NineSlice(nine) => match &nine.0 {
                 ImageInner::EmbeddedImage { cache_key: ImageCacheKey::Path(path), .. } => {
                     Some(std::path::Path::new(path.as_str()))
@@ -866,6 +996,126 @@ fn test_image_invalid_svg() {
     assert!(result.is_err());
 }
 
+#[cfg(feature = "image-decoders")]
+#[test]
+fn test_animated_gif() {
+    use image::codecs::gif::GifEncoder;
+    let mut data = Vec::new();
+    {
+        let mut encoder = GifEncoder::new(&mut data);
+        for (red, delay) in [(255, 50), (0, 0)] {
+            let buffer = image::RgbaImage::from_pixel(4, 2, image::Rgba([red, 0, 0, 255]));
+            let delay = image::Delay::from_numer_denom_ms(delay, 1);
+            encoder.encode_frame(image::Frame::from_parts(buffer, 0, 0, delay)).unwrap();
+        }
+    }
+    let data: &'static [u8] = Box::leak(data.into_boxed_slice());
+    let image = load_image_from_embedded_data(data.into(), Slice::from_slice(b"gif"));
+    assert_eq!(image.size(), [4, 2].into());
+    assert_eq!(image.frame_count(), 2);
+    assert_eq!(image.frame_duration(0), core::time::Duration::from_millis(50));
+    // Frames without a delay are shown with the default frame rate
+    assert_eq!(image.frame_duration(1), core::time::Duration::from_millis(100));
+    let red_of = |image: Image| match image.0.render_to_buffer(None) {
+        Some(SharedImageBuffer::RGBA8(pixels)) => pixels.as_slice()[0].r,
+        _ => panic!("expected a pixel buffer"),
+    };
+    assert_eq!(red_of(image.frame(0)), 255);
+    assert_eq!(red_of(image.frame(1)), 0);
+    // Each frame has its own cache key, which is the same every time
+    let key_of = |image: Image| ImageCacheKey::new(&image.0);
+    assert!(key_of(image.frame(0)).is_some());
+    assert_eq!(key_of(image.frame(0)), key_of(image.frame(0)));
+    assert_ne!(key_of(image.frame(0)), key_of(image.frame(1)));
+
+    // A single frame GIF is not animated
+    let mut data = Vec::new();
+    GifEncoder::new(&mut data)
+        .encode_frame(image::Frame::new(image::RgbaImage::new(3, 3)))
+        .unwrap();
+    let data: &'static [u8] = Box::leak(data.into_boxed_slice());
+    let image = load_image_from_embedded_data(data.into(), Slice::from_slice(b"gif"));
+    assert_eq!(image.size(), [3, 3].into());
+    assert_eq!(image.frame_count(), 1);
+    assert!(matches!(image.0, ImageInner::EmbeddedImage { .. }));
+}
+
+#[cfg(feature = "image-decoders")]
+#[test]
+fn test_animated_webp() {
+    // The image crate can't encode WebP, so write a lossless animation where each frame has a
+    // single color by hand
+    fn chunk(name: &[u8; 4], payload: &[u8]) -> Vec<u8> {
+        let mut chunk = name.to_vec();
+        chunk.extend((payload.len() as u32).to_le_bytes());
+        chunk.extend(payload);
+        if payload.len() % 2 == 1 {
+            chunk.push(0);
+        }
+        chunk
+    }
+    fn u24(value: u32) -> [u8; 3] {
+        let [a, b, c, _] = value.to_le_bytes();
+        [a, b, c]
+    }
+    fn single_color_vp8l(width: u32, height: u32, [r, g, b, a]: [u8; 4]) -> Vec<u8> {
+        // The header, without transforms, color cache or meta prefix codes
+        let mut bits = vec![(width - 1, 14), (height - 1, 14), (1, 1), (0, 3), (0, 3)];
+        // A prefix code with a single 8 bit symbol for green, red, blue, alpha and the distance
+        for symbol in [g, r, b, a, 0] {
+            bits.extend([(1, 1), (0, 1), (1, 1), (symbol as u32, 8)]);
+        }
+        let mut data = vec![0x2f];
+        let (mut pending, mut pending_bits) = (0u64, 0);
+        for (value, count) in bits {
+            pending |= (value as u64) << pending_bits;
+            pending_bits += count;
+            while pending_bits >= 8 {
+                data.push(pending as u8);
+                pending >>= 8;
+                pending_bits -= 8;
+            }
+        }
+        if pending_bits > 0 {
+            data.push(pending as u8);
+        }
+        data
+    }
+
+    let mut webp = b"WEBP".to_vec();
+    // The animation and alpha flags, and the size of the canvas
+    webp.extend(chunk(b"VP8X", &[&[0x12, 0, 0, 0][..], &u24(3), &u24(1)].concat()));
+    webp.extend(chunk(b"ANIM", &[0, 0, 0, 0, 0, 0]));
+    for (red, duration) in [(255, 50), (0, 0)] {
+        // The position, the size and the duration of the frame, which isn't blended
+        let mut frame = [u24(0), u24(0), u24(3), u24(1), u24(duration)].concat();
+        frame.push(0x02);
+        frame.extend(chunk(b"VP8L", &single_color_vp8l(4, 2, [red, 0, 0, 255])));
+        webp.extend(chunk(b"ANMF", &frame));
+    }
+    let data: &'static [u8] = Box::leak(chunk(b"RIFF", &webp).into_boxed_slice());
+    let image = load_image_from_embedded_data(data.into(), Slice::from_slice(b"webp"));
+    assert_eq!(image.size(), [4, 2].into());
+    assert_eq!(image.frame_count(), 2);
+    assert_eq!(image.frame_duration(0), core::time::Duration::from_millis(50));
+    assert_eq!(image.frame_duration(1), core::time::Duration::from_millis(100));
+    let red_of = |image: Image| match image.0.render_to_buffer(None) {
+        Some(SharedImageBuffer::RGBA8(pixels)) => pixels.as_slice()[0].r,
+        _ => panic!("expected a pixel buffer"),
+    };
+    assert_eq!(red_of(image.frame(0)), 255);
+    assert_eq!(red_of(image.frame(1)), 0);
+
+    // A WebP without animation is a still image
+    let still =
+        [&b"WEBP"[..], &chunk(b"VP8L", &single_color_vp8l(3, 3, [0, 0, 255, 255]))].concat();
+    let data: &'static [u8] = Box::leak(chunk(b"RIFF", &still).into_boxed_slice());
+    let image = load_image_from_embedded_data(data.into(), Slice::from_slice(b"webp"));
+    assert_eq!(image.size(), [3, 3].into());
+    assert_eq!(image.frame_count(), 1);
+    assert!(matches!(image.0, ImageInner::EmbeddedImage { .. }));
+}
+
 /// The result of the fit function
 #[derive(Debug)]
 pub struct FitResult {
@@ -1177,6 +1427,10 @@ pub extern "C" fn slint_image_path(image: &Image) -> Option<&SharedString> {
                 ImageCacheKey::Path(path) => Some(path),
                 _ => None,
             },
+            ImageInner::Animated(animated) => match &animated.cache_key {
+                ImageCacheKey::Path(path) => Some(path),
+                _ => None,
+            },
             ImageInner::NineSlice(nine) => match &nine.0 {
                 ImageInner::EmbeddedImage { cache_key, .. } => match cache_key {
                     ImageCacheKey::Path(path) => Some(path),
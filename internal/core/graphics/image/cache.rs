@@ -5,7 +5,10 @@
 This module contains image and caching related types for the run-time library.
 */
 
-use super::{Image, ImageCacheKey, ImageInner, SharedImageBuffer, SharedPixelBuffer};
+use super::{
+    AnimatedImage, AnimatedImageFrame, Image, ImageCacheKey, ImageInner, SharedImageBuffer,
+    SharedPixelBuffer,
+};
 use crate::{slice::Slice, SharedString};
 
 struct ImageWeightInBytes;
@@ -28,6 +31,9 @@ fn weight(&self, key: &ImageCacheKey, value: &ImageInner) -> usize {
             #[cfg(not(target_arch = "wasm32"))]
             ImageInner::BorrowedOpenGLTexture(..) => 0, // Assume storage in GPU memory
             ImageInner::NineSlice(nine) => self.weight(key, &nine.0),
+            ImageInner::Animated(animated) => {
+                animated.frames.iter().map(|frame| frame.buffer.size().area() as usize * 4).sum()
+            }
         }
     }
 }
@@ -95,6 +101,13 @@ pub(crate) fn load_image_from_path(&mut self, path: &SharedString) -> Option<Ima
                 )));
             }
 
+            if let Some(animated) = image::ImageFormat::from_path(path.as_str())
+                .ok()
+                .and_then(|format| load_animated_image_from_path(path, format, &cache_key))
+            {
+                return Some(animated);
+            }
+
             image::open(std::path::Path::new(&path.as_str())).map_or_else(
                 |decode_err| {
                     eprintln!("Error loading image from {}: {}", &path, decode_err);
@@ -133,6 +146,12 @@ pub(crate) fn load_image_from_embedded_data(
             let format = std::str::from_utf8(format.as_slice())
                 .ok()
                 .and_then(image::ImageFormat::from_extension);
+            if let Some(animated) = format
+                .or_else(|| image::guess_format(data.as_slice()).ok())
+                .and_then(|format| decode_animated_image(data.as_slice(), format, &cache_key))
+            {
+                return Some(animated);
+            }
             let maybe_image = if let Some(format) = format {
                 image::load_from_memory_with_format(data.as_slice(), format)
             } else {
@@ -153,6 +172,77 @@ pub(crate) fn load_image_from_embedded_data(
     }
 }
 
+fn load_animated_image_from_path(
+    path: &SharedString,
+    format: image::ImageFormat,
+    cache_key: &ImageCacheKey,
+) -> Option<ImageInner> {
+    if !matches!(
+        format,
+        image::ImageFormat::Gif | image::ImageFormat::Png | image::ImageFormat::WebP
+    ) {
+        return None;
+    }
+    let file = std::fs::File::open(path.as_str()).ok()?;
+    decode_animated_image(std::io::BufReader::new(file), format, cache_key)
+}
+
+/// Decodes all the frames of an animated GIF, PNG or WebP.
+/// Returns None if the image is not animated, so that it is decoded as a still image instead.
+fn decode_animated_image(
+    reader: impl std::io::Read,
+    format: image::ImageFormat,
+    cache_key: &ImageCacheKey,
+) -> Option<ImageInner> {
+    use image::AnimationDecoder;
+    let frames = match format {
+        image::ImageFormat::Gif => image::codecs::gif::GifDecoder::new(reader).ok()?.into_frames(),
+        image::ImageFormat::Png => {
+            let decoder = image::codecs::png::PngDecoder::new(reader).ok()?;
+            if !decoder.is_apng() {
+                return None;
+            }
+            decoder.apng().into_frames()
+        }
+        image::ImageFormat::WebP => {
+            let decoder = image::codecs::webp::WebPDecoder::new(reader).ok()?;
+            if !decoder.has_animation() {
+                return None;
+            }
+            decoder.into_frames()
+        }
+        _ => return None,
+    };
+    let frames = frames
+        .collect_frames()
+        .map_err(|decode_err| eprintln!("Error decoding animated image: {}", decode_err))
+        .ok()?;
+    if frames.len() < 2 {
+        return None;
+    }
+    let frames = frames
+        .into_iter()
+        .map(|frame| {
+            let (numer, denom) = frame.delay().numer_denom_ms();
+            let duration = numer.checked_div(denom).unwrap_or(0);
+            let buffer = frame.into_buffer();
+            AnimatedImageFrame {
+                buffer: SharedImageBuffer::RGBA8(SharedPixelBuffer::clone_from_slice(
+                    buffer.as_raw(),
+                    buffer.width(),
+                    buffer.height(),
+                )),
+                // Like web browsers, treat very short delays as the default frame rate of 10 fps
+                duration: if duration <= 10 { 100 } else { duration },
+            }
+        })
+        .collect();
+    Some(ImageInner::Animated(vtable::VRc::new(AnimatedImage {
+        cache_key: cache_key.clone(),
+        frames,
+    })))
+}
+
 fn dynamic_image_to_shared_image_buffer(dynamic_image: image::DynamicImage) -> SharedImageBuffer {
     if dynamic_image.color().has_alpha() {
         let rgba8image = dynamic_image.to_rgba8();
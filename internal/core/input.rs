@@ -9,7 +9,7 @@
 use crate::item_tree::{ItemRc, ItemWeak, VisitChildrenResult};
 pub use crate::items::PointerEventButton;
 use crate::items::{ItemRef, TextCursorDirection};
-pub use crate::items::{KeyEvent, KeyboardModifiers};
+pub use crate::items::{KeyEvent, KeyboardModifiers, StandardShortcut};
 use crate::lengths::{LogicalPoint, LogicalVector};
 use crate::timers::Timer;
 use crate::window::{WindowAdapter, WindowInner};
@@ -49,6 +49,16 @@ pub enum MouseEvent {
     Wheel { position: LogicalPoint, delta_x: Coord, delta_y: Coord },
     /// The mouse exited the item or component
     Exit,
+    /// A gesture made of two touch points is in progress.
+    /// `position` is the center between the two touch points.
+    /// `scale` is the distance between the touch points relative to the distance when the gesture started.
+    /// `rotation` is the angle, in degrees, by which the touch points rotated since the gesture started.
+    TouchGesture { position: LogicalPoint, scale: f32, rotation: f32, phase: GesturePhase },
+    /// Something is being dragged over the item.
+    /// The dragged data can be queried with [`WindowInner::drag_data`](crate::window::WindowInner::drag_data).
+    DragMove { position: LogicalPoint },
+    /// The dragged data was dropped over the item.
+    DragDrop { position: LogicalPoint },
 }
 
 impl MouseEvent {
@@ -60,6 +70,9 @@ pub fn position(&self) -> Option<LogicalPoint> {
             MouseEvent::Moved { position } => Some(*position),
             MouseEvent::Wheel { position, .. } => Some(*position),
             MouseEvent::Exit => None,
+            MouseEvent::TouchGesture { position, .. } => Some(*position),
+            MouseEvent::DragMove { position } => Some(*position),
+            MouseEvent::DragDrop { position } => Some(*position),
         }
     }
 
@@ -71,6 +84,9 @@ pub fn translate(&mut self, vec: LogicalVector) {
             MouseEvent::Moved { position } => Some(position),
             MouseEvent::Wheel { position, .. } => Some(position),
             MouseEvent::Exit => None,
+            MouseEvent::TouchGesture { position, .. } => Some(position),
+            MouseEvent::DragMove { position } => Some(position),
+            MouseEvent::DragDrop { position } => Some(position),
         };
         if let Some(pos) = pos {
             *pos += vec;
@@ -88,6 +104,18 @@ fn set_click_count(&mut self, count: u8) {
     }
 }
 
+/// The phase of a [`MouseEvent::TouchGesture`]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum GesturePhase {
+    /// A second touch point was pressed, and the gesture starts.
+    Started,
+    /// One of the touch points moved.
+    Updated,
+    /// One of the touch points was released.
+    Ended,
+}
+
 /// This value is returned by the `input_event` function of an Item
 /// to notify the run-time about how the event was handled and
 /// what the next steps are.
@@ -394,30 +422,6 @@ pub fn text_shortcut(&self) -> Option<TextShortcut> {
     }
 }
 
-/// Represents a non context specific shortcut.
-pub enum StandardShortcut {
-    /// Copy Something
-    Copy,
-    /// Cut Something
-    Cut,
-    /// Paste Something
-    Paste,
-    /// Select All
-    SelectAll,
-    /// Find/Search Something
-    Find,
-    /// Save Something
-    Save,
-    /// Print Something
-    Print,
-    /// Undo the last action
-    Undo,
-    /// Redo the last undone action
-    Redo,
-    /// Refresh
-    Refresh,
-}
-
 /// Shortcuts that are used when editing text
 pub enum TextShortcut {
     /// Move the cursor
@@ -554,6 +558,155 @@ pub fn top_item(&self) -> Option<ItemRc> {
     }
 }
 
+/// Angle and distance between the two touch points of a gesture
+#[derive(Debug, Clone, Copy)]
+struct GestureState {
+    /// The ids of the two touch points. The gesture events are sent to the items of the first one.
+    touch_ids: [u64; 2],
+    start_distance: f32,
+    last_angle: f32,
+    rotation: f32,
+}
+
+/// Where the events of a touch point are sent
+pub(crate) enum TouchTarget {
+    /// The touch point emulates the mouse, with the [`MouseInputState`] of the window
+    Mouse,
+    /// The touch point has its own item stack and grab, so that it can press an item while
+    /// another touch point presses another one
+    Items(MouseInputState),
+    /// The touch point only moves the gesture it started with another touch point
+    Gesture,
+}
+
+/// A touch point that is currently pressed
+pub(crate) struct TouchPoint {
+    pub(crate) id: u64,
+    pub(crate) position: LogicalPoint,
+    pub(crate) target: TouchTarget,
+}
+
+/// The state which a window holds for the touch points.
+///
+/// The first touch point emulates the mouse. A touch point pressed in the same `GestureArea` as
+/// another one turns both into a [`MouseEvent::TouchGesture`], and the other touch points are
+/// dispatched to the items on their own, each with its own grab.
+#[derive(Default)]
+pub(crate) struct TouchState {
+    /// The touch points currently pressed, in the order in which they were pressed
+    pub(crate) points: Vec<TouchPoint>,
+    gesture: Option<GestureState>,
+}
+
+impl TouchState {
+    pub(crate) fn point_mut(&mut self, touch_id: u64) -> Option<&mut TouchPoint> {
+        self.points.iter_mut().find(|p| p.id == touch_id)
+    }
+
+    /// Returns true if one of the touch points emulates the mouse
+    pub(crate) fn has_mouse(&self) -> bool {
+        self.points.iter().any(|p| matches!(p.target, TouchTarget::Mouse))
+    }
+
+    pub(crate) fn has_gesture(&self) -> bool {
+        self.gesture.is_some()
+    }
+
+    /// Start a gesture with two touch points and return the event for the items of the first one
+    pub(crate) fn start_gesture(&mut self, first: u64, second: u64) -> MouseEvent {
+        let touch_ids = [first, second];
+        let (distance, angle) = self.distance_and_angle(touch_ids);
+        self.gesture = Some(GestureState {
+            touch_ids,
+            start_distance: distance,
+            last_angle: angle,
+            rotation: 0.,
+        });
+        self.gesture_event(GesturePhase::Started)
+    }
+
+    /// Update the gesture after the touch point moved. Returns the event and the touch point
+    /// whose items receive it, or None if the touch point isn't part of the gesture.
+    pub(crate) fn update_gesture(&mut self, touch_id: u64) -> Option<(u64, MouseEvent)> {
+        let gesture = self.gesture.filter(|g| g.touch_ids.contains(&touch_id))?;
+        let (_, angle) = self.distance_and_angle(gesture.touch_ids);
+        let mut delta = angle - gesture.last_angle;
+        // Take the shortest way around
+        if delta > 180. {
+            delta -= 360.;
+        } else if delta < -180. {
+            delta += 360.;
+        }
+        self.gesture =
+            Some(GestureState { last_angle: angle, rotation: gesture.rotation + delta, ..gesture });
+        Some((gesture.touch_ids[0], self.gesture_event(GesturePhase::Updated)))
+    }
+
+    /// End the gesture when one of its touch points is released. Returns the event and the touch
+    /// point whose items receive it, or None if the touch point isn't part of the gesture.
+    pub(crate) fn end_gesture(&mut self, touch_id: u64) -> Option<(u64, MouseEvent)> {
+        let gesture = self.gesture.filter(|g| g.touch_ids.contains(&touch_id))?;
+        let event = self.gesture_event(GesturePhase::Ended);
+        self.gesture = None;
+        Some((gesture.touch_ids[0], event))
+    }
+
+    fn position(&self, touch_id: u64) -> LogicalPoint {
+        self.points.iter().find(|p| p.id == touch_id).map(|p| p.position).unwrap_or_default()
+    }
+
+    /// Distance and angle in degrees between the two touch points
+    fn distance_and_angle(&self, touch_ids: [u64; 2]) -> (f32, f32) {
+        let v = (self.position(touch_ids[1]) - self.position(touch_ids[0])).cast::<f32>();
+        (v.length(), v.angle_from_x_axis().to_degrees())
+    }
+
+    fn gesture_event(&self, phase: GesturePhase) -> MouseEvent {
+        let gesture = self.gesture.unwrap();
+        let (distance, _) = self.distance_and_angle(gesture.touch_ids);
+        let first = self.position(gesture.touch_ids[0]).cast::<f32>();
+        let center = first.lerp(self.position(gesture.touch_ids[1]).cast::<f32>(), 0.5);
+        MouseEvent::TouchGesture {
+            position: center.cast(),
+            scale: if gesture.start_distance > 0. { distance / gesture.start_distance } else { 1. },
+            rotation: gesture.rotation,
+            phase,
+        }
+    }
+}
+
+/// Returns true if an enabled `GestureArea` of the `component` contains both positions
+pub(crate) fn in_same_gesture_area(
+    component: &ItemTreeRc,
+    first: LogicalPoint,
+    second: LogicalPoint,
+) -> bool {
+    fn visit(item: ItemRc, first: LogicalPoint, second: LogicalPoint) -> bool {
+        let geometry = item.geometry();
+        let contains = geometry.contains(first) && geometry.contains(second);
+        if !contains && crate::item_rendering::is_clipping_item(item.borrow()) {
+            return false;
+        }
+        if contains
+            && item
+                .downcast::<crate::items::GestureArea>()
+                .map_or(false, |area| area.as_pin_ref().enabled())
+        {
+            return true;
+        }
+        let offset = geometry.origin.to_vector();
+        let mut child = item.first_child();
+        while let Some(c) = child {
+            if visit(c.clone(), first - offset, second - offset) {
+                return true;
+            }
+            child = c.next_sibling();
+        }
+        false
+    }
+    visit(ItemRc::new(component.clone(), 0), first, second)
+}
+
 /// Try to handle the mouse grabber. Return None if the event has been handled, otherwise
 /// return the event that must be handled
 pub(crate) fn handle_mouse_grab(
@@ -565,6 +718,12 @@ pub(crate) fn handle_mouse_grab(
         return Some(mouse_event);
     };
 
+    if matches!(mouse_event, MouseEvent::DragMove { .. } | MouseEvent::DragDrop { .. }) {
+        // Drag and drop events go to the item under the pointer, not to the item that started the drag
+        mouse_input_state.grabbed = false;
+        return Some(mouse_event);
+    }
+
     let mut event = mouse_event;
     let mut intercept = false;
     let mut invalid = false;
@@ -910,3 +1069,19 @@ pub fn stop(&self) {
         self.cursor_blink_timer.stop()
     }
 }
+
+#[cfg(feature = "ffi")]
+pub(crate) mod ffi {
+    #![allow(unsafe_code)]
+
+    use super::*;
+
+    /// Returns true if the key event corresponds to the given standard shortcut on this platform
+    #[no_mangle]
+    pub extern "C" fn slint_key_event_matches_shortcut(
+        event: &KeyEvent,
+        shortcut: StandardShortcut,
+    ) -> bool {
+        event.shortcut() == Some(shortcut)
+    }
+}
@@ -321,13 +321,25 @@ fn draw_image(
         _cache: &CachedRenderingData,
     );
     fn draw_text(&mut self, text: Pin<&Text>, _self_rc: &ItemRc, _size: LogicalSize);
+    /// Draw a [`StyledText`]. The default implementation draws each run of the laid out
+    /// text with [`Self::draw_text`].
+    fn draw_styled_text(&mut self, text: Pin<&StyledText>, self_rc: &ItemRc, size: LogicalSize) {
+        let window_adapter = self.window().window_adapter();
+        let (spans, layout) = text.layout(&window_adapter, size);
+        for run in &layout.runs {
+            let run_text = core::pin::pin!(text.text_item_for_run(run, &spans[run.span].style));
+            self.save_state();
+            self.translate(run.rect.origin.to_vector());
+            self.draw_text(run_text.as_ref(), self_rc, run.rect.size);
+            self.restore_state();
+        }
+    }
     fn draw_text_input(
         &mut self,
         text_input: Pin<&TextInput>,
         _self_rc: &ItemRc,
         _size: LogicalSize,
     );
-    #[cfg(feature = "std")]
     fn draw_path(&mut self, path: Pin<&Path>, _self_rc: &ItemRc, _size: LogicalSize);
     fn draw_box_shadow(
         &mut self,
@@ -443,8 +455,38 @@ fn metrics(&self) -> crate::graphics::rendering_metrics_collector::RenderingMetr
     }
 }
 
+/// Return the area, relative to the item's parent, in which the item draws.
+///
+/// This is usually the same as the geometry, except for items such as the BoxShadow
+/// that draw outside of their geometry.
+pub(crate) fn item_bounding_rect(item: Pin<ItemRef>, geometry: LogicalRect) -> LogicalRect {
+    //(FIXME: there should be some flag in the vtable instead of down-casting)
+    if let Some(box_shadow) = ItemRef::downcast_pin::<BoxShadow>(item) {
+        let blur = box_shadow.blur().get().max(0 as Coord);
+        let offset = LogicalVector::from_lengths(box_shadow.offset_x(), box_shadow.offset_y());
+        return geometry.union(&geometry.translate(offset).inflate(blur, blur));
+    }
+    geometry
+}
+
+/// The geometry and bounding rectangle of an item, as stored in the [`PartialRenderingCache`]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CachedItemBoundingBox {
+    /// The geometry of the item, relative to its parent
+    pub geometry: LogicalRect,
+    /// The area, relative to the item's parent, that is covered when drawing the item.
+    pub bounding_rect: LogicalRect,
+}
+
+impl CachedItemBoundingBox {
+    fn new(item_rc: &ItemRc) -> Self {
+        let geometry = item_rc.geometry();
+        Self { geometry, bounding_rect: item_bounding_rect(item_rc.borrow(), geometry) }
+    }
+}
+
 /// The cache that needs to be held by the Window for the partial rendering
-pub type PartialRenderingCache = RenderingCache<LogicalRect>;
+pub type PartialRenderingCache = RenderingCache<CachedItemBoundingBox>;
 
 /// FIXME: Should actually be a region and not just a rectangle
 pub type DirtyRegion = euclid::Box2D<Coord, LogicalPx>;
@@ -487,21 +529,23 @@ struct ComputeDirtyRegionState {
                 let item_rc = ItemRc::new(component.clone(), index);
 
                 match item.cached_rendering_data_offset().get_entry(&mut borrowed) {
-                    Some(CachedGraphicsData {
-                        data: cached_geom,
-                        dependency_tracker: Some(tr),
-                    }) => {
+                    Some(CachedGraphicsData { data: cached, dependency_tracker: Some(tr) }) => {
                         if tr.is_dirty() {
-                            let old_geom = *cached_geom;
+                            let old = *cached;
                             drop(borrowed);
-                            let geom =
-                                crate::properties::evaluate_no_tracking(|| item_rc.geometry());
-
-                            self.mark_dirty_rect(old_geom, state.old_offset, &state.clipped);
-                            self.mark_dirty_rect(geom, state.offset, &state.clipped);
-
-                            new_state.offset += geom.origin.to_vector();
-                            new_state.old_offset += old_geom.origin.to_vector();
+                            let new = crate::properties::evaluate_no_tracking(|| {
+                                CachedItemBoundingBox::new(&item_rc)
+                            });
+
+                            self.mark_dirty_rect(
+                                old.bounding_rect,
+                                state.old_offset,
+                                &state.clipped,
+                            );
+                            self.mark_dirty_rect(new.bounding_rect, state.offset, &state.clipped);
+
+                            new_state.offset += new.geometry.origin.to_vector();
+                            new_state.old_offset += old.geometry.origin.to_vector();
                             if ItemRef::downcast_pin::<Clip>(item).is_some()
                                 || ItemRef::downcast_pin::<Opacity>(item).is_some()
                             {
@@ -518,13 +562,18 @@ struct ComputeDirtyRegionState {
                                 || new_state.offset != new_state.old_offset
                             {
                                 self.mark_dirty_rect(
-                                    *cached_geom,
+                                    cached.bounding_rect,
                                     state.old_offset,
                                     &state.clipped,
                                 );
-                                self.mark_dirty_rect(*cached_geom, state.offset, &state.clipped);
+                                self.mark_dirty_rect(
+                                    cached.bounding_rect,
+                                    state.offset,
+                                    &state.clipped,
+                                );
                             }
 
+                            let cached_geom = cached.geometry;
                             new_state.offset += cached_geom.origin.to_vector();
                             new_state.old_offset += cached_geom.origin.to_vector();
                             if crate::properties::evaluate_no_tracking(|| is_clipping_item(item)) {
@@ -542,7 +591,7 @@ struct ComputeDirtyRegionState {
                     }
                     _ => {
                         drop(borrowed);
-                        let geom = crate::properties::evaluate_no_tracking(|| {
+                        let bounding_rect = crate::properties::evaluate_no_tracking(|| {
                             let geom = item_rc.geometry();
                             new_state.offset += geom.origin.to_vector();
                             new_state.old_offset += geom.origin.to_vector();
@@ -552,9 +601,9 @@ struct ComputeDirtyRegionState {
                                     .intersection(&geom.translate(state.offset))
                                     .unwrap_or_default();
                             }
-                            geom
+                            item_bounding_rect(item, geom)
                         });
-                        self.mark_dirty_rect(geom, state.offset, &state.clipped);
+                        self.mark_dirty_rect(bounding_rect, state.offset, &state.clipped);
                         ItemVisitorResult::Continue(new_state)
                     }
                 }
@@ -584,7 +633,7 @@ fn mark_dirty_rect(
     fn do_rendering(
         cache: &RefCell<PartialRenderingCache>,
         rendering_data: &CachedRenderingData,
-        render_fn: impl FnOnce() -> LogicalRect,
+        render_fn: impl FnOnce() -> CachedItemBoundingBox,
     ) {
         let mut cache = cache.borrow_mut();
         if let Some(entry) = rendering_data.get_entry(&mut cache) {
@@ -612,7 +661,7 @@ fn $fn(&mut self, obj: Pin<&$Ty>, item_rc: &ItemRc, size: LogicalSize) $(-> $Ret
             let mut ret = None;
             Self::do_rendering(&self.cache, &obj.cached_rendering_data, || {
                 ret = Some(self.actual_renderer.$fn(obj, item_rc, size));
-                item_rc.geometry()
+                CachedItemBoundingBox::new(item_rc)
             });
             ret.unwrap_or_default()
         }
@@ -625,7 +674,7 @@ fn $fn(&mut self, obj: Pin<&$Ty>, item_rc: &ItemRc, size: LogicalSize, cache: &C
             let mut ret = None;
             Self::do_rendering(&self.cache, &cache, || {
                 ret = Some(self.actual_renderer.$fn(obj, item_rc, size, &cache));
-                item_rc.geometry()
+                CachedItemBoundingBox::new(item_rc)
             });
             ret.unwrap_or_default()
         }
@@ -640,12 +689,12 @@ fn filter_item(&mut self, item_rc: &ItemRc) -> (bool, LogicalRect) {
                 // Make sure we register a dependency on the clip
                 clip.clip();
             }
-            item_rc.geometry()
+            CachedItemBoundingBox::new(item_rc)
         };
 
         let rendering_data = item.cached_rendering_data_offset();
         let mut cache = self.cache.borrow_mut();
-        let item_geometry = match rendering_data.get_entry(&mut cache) {
+        let item_bounding_box = match rendering_data.get_entry(&mut cache) {
             Some(CachedGraphicsData { data, dependency_tracker }) => {
                 dependency_tracker
                     .get_or_insert_with(|| Box::pin(PropertyTracker::default()))
@@ -665,16 +714,16 @@ fn filter_item(&mut self, item_rc: &ItemRc) -> (bool, LogicalRect) {
         //let clip = self.get_current_clip().intersection(&self.dirty_region.to_rect());
         //let draw = clip.map_or(false, |r| r.intersects(&item_geometry));
         //FIXME: the dirty_region is in global coordinate but item_geometry and current_clip is not
-        let draw = self.get_current_clip().intersects(&item_geometry);
-        (draw, item_geometry)
+        let draw = self.get_current_clip().intersects(&item_bounding_box.bounding_rect);
+        (draw, item_bounding_box.geometry)
     }
 
     forward_rendering_call!(fn draw_rectangle(Rectangle));
     forward_rendering_call2!(fn draw_border_rectangle(dyn RenderBorderRectangle));
     forward_rendering_call2!(fn draw_image(dyn RenderImage));
     forward_rendering_call!(fn draw_text(Text));
+    forward_rendering_call!(fn draw_styled_text(StyledText));
     forward_rendering_call!(fn draw_text_input(TextInput));
-    #[cfg(feature = "std")]
     forward_rendering_call!(fn draw_path(Path));
     forward_rendering_call!(fn draw_box_shadow(BoxShadow));
 
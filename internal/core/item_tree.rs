@@ -5,7 +5,9 @@
 
 //! This module contains the ItemTree and code that helps navigating it
 
-use crate::accessibility::AccessibleStringProperty;
+use crate::accessibility::{
+    AccessibilityAction, AccessibleStringProperty, SupportedAccessibilityAction,
+};
 use crate::items::{AccessibleRole, ItemRef, ItemVTable};
 use crate::layout::{LayoutInfo, Orientation};
 use crate::lengths::{LogicalPoint, LogicalRect};
@@ -113,6 +115,30 @@ pub struct ItemTreeVTable {
         result: &mut SharedString,
     ),
 
+    /// Executes an accessibility action.
+    pub accessibility_action: extern "C" fn(
+        core::pin::Pin<VRef<ItemTreeVTable>>,
+        item_index: u32,
+        action: &AccessibilityAction,
+    ),
+
+    /// Returns the accessibility actions supported by the given item.
+    pub supported_accessibility_actions: extern "C" fn(
+        core::pin::Pin<VRef<ItemTreeVTable>>,
+        item_index: u32,
+    ) -> SupportedAccessibilityAction,
+
+    /// Returns the debug information about the elements that were lowered to the given item,
+    /// or false if the information wasn't generated.
+    ///
+    /// Each element is described on its own line, as its qualified id (`Component::id`, or empty
+    /// if the element doesn't have an id), followed by a comma and its type name.
+    pub item_element_infos: extern "C" fn(
+        core::pin::Pin<VRef<ItemTreeVTable>>,
+        item_index: u32,
+        result: &mut SharedString,
+    ) -> bool,
+
     /// Returns a Window, creating a fresh one if `do_create` is true.
     pub window_adapter: extern "C" fn(
         core::pin::Pin<VRef<ItemTreeVTable>>,
@@ -334,6 +360,24 @@ pub fn accessible_string_property(
         result
     }
 
+    pub fn accessible_action(&self, action: &crate::accessibility::AccessibilityAction) {
+        let comp_ref_pin = vtable::VRc::borrow_pin(&self.item_tree);
+        comp_ref_pin.as_ref().accessibility_action(self.index, action);
+    }
+
+    pub fn supported_accessibility_actions(&self) -> SupportedAccessibilityAction {
+        let comp_ref_pin = vtable::VRc::borrow_pin(&self.item_tree);
+        comp_ref_pin.as_ref().supported_accessibility_actions(self.index)
+    }
+
+    /// Returns the debug information of the elements lowered to this item, as documented in
+    /// [`ItemTreeVTable::item_element_infos`]
+    pub fn element_infos(&self) -> Option<SharedString> {
+        let comp_ref_pin = vtable::VRc::borrow_pin(&self.item_tree);
+        let mut result = SharedString::default();
+        comp_ref_pin.as_ref().item_element_infos(self.index, &mut result).then_some(result)
+    }
+
     pub fn geometry(&self) -> LogicalRect {
         let comp_ref_pin = vtable::VRc::borrow_pin(&self.item_tree);
         comp_ref_pin.as_ref().item_geometry(self.index)
@@ -1125,6 +1169,19 @@ fn accessible_string_property(
         ) {
         }
 
+        fn accessibility_action(self: Pin<&Self>, _: u32, _: &AccessibilityAction) {}
+
+        fn supported_accessibility_actions(
+            self: Pin<&Self>,
+            _: u32,
+        ) -> SupportedAccessibilityAction {
+            Default::default()
+        }
+
+        fn item_element_infos(self: Pin<&Self>, _: u32, _: &mut SharedString) -> bool {
+            false
+        }
+
         fn window_adapter(
             self: Pin<&Self>,
             _do_create: bool,
@@ -22,8 +22,8 @@
 
 use crate::graphics::{Brush, Color, Point};
 use crate::input::{
-    FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEventResult,
-    KeyEventType, MouseEvent,
+    FocusEvent, FocusEventResult, GesturePhase, InputEventFilterResult, InputEventResult,
+    KeyEventResult, KeyEventType, MouseEvent,
 };
 use crate::item_rendering::{CachedRenderingData, RenderBorderRectangle};
 pub use crate::item_tree::ItemRc;
@@ -51,10 +51,12 @@
 pub use text::*;
 mod image;
 pub use self::image::*;
-#[cfg(feature = "std")]
 mod path;
-#[cfg(feature = "std")]
 pub use path::*;
+mod drag_n_drop;
+pub use drag_n_drop::*;
+mod tooltip;
+pub use tooltip::*;
 
 /// Alias for `&mut dyn ItemRenderer`. Required so cbindgen generates the ItemVTable
 /// despite the presence of trait object
@@ -65,7 +67,9 @@
 pub type KeyEventArg = (KeyEvent,);
 type PointerEventArg = (PointerEvent,);
 type PointerScrollEventArg = (PointerScrollEvent,);
+type DropEventArg = (DropEvent,);
 type PointArg = (Point,);
+type StringArg = (SharedString,);
 
 #[cfg(all(feature = "ffi", windows))]
 #[macro_export]
@@ -547,12 +551,41 @@ pub struct TouchArea {
     pub clicked: Callback<VoidArg>,
     pub double_clicked: Callback<VoidArg>,
     pub moved: Callback<VoidArg>,
+    pub long_pressed: Callback<VoidArg>,
     pub pointer_event: Callback<PointerEventArg>,
     pub scroll_event: Callback<PointerScrollEventArg, EventResult>,
     /// FIXME: remove this
     pub cached_rendering_data: CachedRenderingData,
     /// true when we are currently grabbing the mouse
     grabbed: Cell<bool>,
+    /// Incremented on every press, release, and move that cancels a long-press, so that the
+    /// long-press timer of an earlier press doesn't fire.
+    long_press_generation: Cell<u32>,
+}
+
+/// How long the left button or the finger needs to stay pressed for `long-pressed` to be invoked
+const LONG_PRESS_DURATION: core::time::Duration = core::time::Duration::from_millis(500);
+/// How far the pointer can move before the press isn't considered a long-press anymore
+const LONG_PRESS_DISTANCE_THRESHOLD: LogicalLength = LogicalLength::new(8 as _);
+
+impl TouchArea {
+    fn start_long_press_timer(self: Pin<&Self>, self_rc: &ItemRc) {
+        let generation = self.long_press_generation.get().wrapping_add(1);
+        self.long_press_generation.set(generation);
+        let self_weak = self_rc.downgrade();
+        crate::timers::Timer::single_shot(LONG_PRESS_DURATION, move || {
+            let Some(self_rc) = self_weak.upgrade() else { return };
+            let Some(touch_area) = self_rc.downcast::<TouchArea>() else { return };
+            let touch_area = touch_area.as_pin_ref();
+            if touch_area.pressed() && touch_area.long_press_generation.get() == generation {
+                Self::FIELD_OFFSETS.long_pressed.apply_pin(touch_area).call(&());
+            }
+        });
+    }
+
+    fn cancel_long_press(&self) {
+        self.long_press_generation.set(self.long_press_generation.get().wrapping_add(1));
+    }
 }
 
 impl Item for TouchArea {
@@ -575,6 +608,10 @@ fn input_event_filter_before_children(
         if !self.enabled() {
             return InputEventFilterResult::ForwardAndIgnore;
         }
+        if matches!(event, MouseEvent::DragMove { .. } | MouseEvent::DragDrop { .. }) {
+            // The TouchArea doesn't react to drag and drop, and must not be hovered while dragging
+            return InputEventFilterResult::ForwardAndIgnore;
+        }
         if let Some(pos) = event.position() {
             Self::FIELD_OFFSETS.mouse_x.apply_pin(self).set(pos.x_length());
             Self::FIELD_OFFSETS.mouse_y.apply_pin(self).set(pos.y_length());
@@ -612,6 +649,7 @@ fn input_event(
                     Self::FIELD_OFFSETS.pressed_x.apply_pin(self).set(position.x_length());
                     Self::FIELD_OFFSETS.pressed_y.apply_pin(self).set(position.y_length());
                     Self::FIELD_OFFSETS.pressed.apply_pin(self).set(true);
+                    self.start_long_press_timer(self_rc);
                 }
                 Self::FIELD_OFFSETS.pointer_event.apply_pin(self).call(&(PointerEvent {
                     button,
@@ -623,6 +661,7 @@ fn input_event(
             }
             MouseEvent::Exit => {
                 Self::FIELD_OFFSETS.pressed.apply_pin(self).set(false);
+                self.cancel_long_press();
                 if self.grabbed.replace(false) {
                     Self::FIELD_OFFSETS.pointer_event.apply_pin(self).call(&(PointerEvent {
                         button: PointerEventButton::Other,
@@ -649,6 +688,7 @@ fn input_event(
                 self.grabbed.set(false);
                 if button == PointerEventButton::Left {
                     Self::FIELD_OFFSETS.pressed.apply_pin(self).set(false);
+                    self.cancel_long_press();
                 }
                 Self::FIELD_OFFSETS.pointer_event.apply_pin(self).call(&(PointerEvent {
                     button,
@@ -658,7 +698,12 @@ fn input_event(
 
                 InputEventResult::EventAccepted
             }
-            MouseEvent::Moved { .. } => {
+            MouseEvent::Moved { position } => {
+                let pressed_position =
+                    LogicalPoint::from_lengths(self.pressed_x(), self.pressed_y());
+                if (position - pressed_position).length() > LONG_PRESS_DISTANCE_THRESHOLD.get() {
+                    self.cancel_long_press();
+                }
                 Self::FIELD_OFFSETS.pointer_event.apply_pin(self).call(&(PointerEvent {
                     button: PointerEventButton::Other,
                     kind: PointerEventKind::Move,
@@ -692,6 +737,16 @@ fn input_event(
                     }
                 }
             }
+            MouseEvent::TouchGesture { .. } => {
+                if self.grabbed.get() {
+                    InputEventResult::GrabMouse
+                } else {
+                    InputEventResult::EventIgnored
+                }
+            }
+            MouseEvent::DragMove { .. } | MouseEvent::DragDrop { .. } => {
+                InputEventResult::EventIgnored
+            }
         }
     }
 
@@ -734,6 +789,150 @@ impl ItemConsts for TouchArea {
     fn slint_get_TouchAreaVTable() -> TouchAreaVTable for TouchArea
 }
 
+/// The implementation of the `GestureArea` element
+#[repr(C)]
+#[derive(FieldOffsets, SlintElement, Default)]
+#[pin]
+pub struct GestureArea {
+    pub enabled: Property<bool>,
+    pub active: Property<bool>,
+    pub scale: Property<f32>,
+    pub rotation: Property<f32>,
+    pub pan_x: Property<LogicalLength>,
+    pub pan_y: Property<LogicalLength>,
+    pub started: Callback<VoidArg>,
+    pub updated: Callback<VoidArg>,
+    pub finished: Callback<VoidArg>,
+    pub cancelled: Callback<VoidArg>,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+    /// The center of the touch points when the gesture started
+    start_position: Cell<LogicalPoint>,
+}
+
+impl GestureArea {
+    fn update(self: Pin<&Self>, position: LogicalPoint, scale: f32, rotation: f32) {
+        let pan = position - self.start_position.get();
+        Self::FIELD_OFFSETS.scale.apply_pin(self).set(scale);
+        Self::FIELD_OFFSETS.rotation.apply_pin(self).set(rotation);
+        Self::FIELD_OFFSETS.pan_x.apply_pin(self).set(pan.x_length());
+        Self::FIELD_OFFSETS.pan_y.apply_pin(self).set(pan.y_length());
+    }
+}
+
+impl Item for GestureArea {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        if !self.enabled() {
+            return InputEventFilterResult::ForwardAndIgnore;
+        }
+        match event {
+            // Take the gesture away from the children, which may have grabbed the first touch point
+            MouseEvent::TouchGesture { phase: GesturePhase::Started, .. } => {
+                InputEventFilterResult::Intercept
+            }
+            MouseEvent::TouchGesture { .. } if self.active() => InputEventFilterResult::Intercept,
+            _ => InputEventFilterResult::ForwardAndInterceptGrab,
+        }
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        let active = self.active();
+        if !self.enabled() {
+            if active {
+                Self::FIELD_OFFSETS.active.apply_pin(self).set(false);
+                Self::FIELD_OFFSETS.cancelled.apply_pin(self).call(&());
+            }
+            return InputEventResult::EventIgnored;
+        }
+
+        match event {
+            MouseEvent::TouchGesture { position, phase: GesturePhase::Started, .. } => {
+                self.start_position.set(position);
+                self.update(position, 1., 0.);
+                Self::FIELD_OFFSETS.active.apply_pin(self).set(true);
+                Self::FIELD_OFFSETS.started.apply_pin(self).call(&());
+                InputEventResult::GrabMouse
+            }
+            MouseEvent::TouchGesture { position, scale, rotation, phase } if active => {
+                self.update(position, scale, rotation);
+                if phase == GesturePhase::Ended {
+                    Self::FIELD_OFFSETS.active.apply_pin(self).set(false);
+                    Self::FIELD_OFFSETS.finished.apply_pin(self).call(&());
+                    InputEventResult::EventAccepted
+                } else {
+                    Self::FIELD_OFFSETS.updated.apply_pin(self).call(&());
+                    InputEventResult::GrabMouse
+                }
+            }
+            MouseEvent::Exit if active => {
+                Self::FIELD_OFFSETS.active.apply_pin(self).set(false);
+                Self::FIELD_OFFSETS.cancelled.apply_pin(self).call(&());
+                InputEventResult::EventAccepted
+            }
+            _ if active => InputEventResult::GrabMouse,
+            _ => InputEventResult::EventIgnored,
+        }
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for GestureArea {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<
+        GestureArea,
+        CachedRenderingData,
+    > = GestureArea::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+declare_item_vtable! {
+    fn slint_get_GestureAreaVTable() -> GestureAreaVTable for GestureArea
+}
+
 /// A runtime item that exposes key
 #[repr(C)]
 #[derive(FieldOffsets, Default, SlintElement)]
@@ -1234,13 +1433,21 @@ pub struct PropertyAnimation {
     pub iteration_count: f32,
     #[rtti_field]
     pub easing: crate::animations::EasingCurve,
+    #[rtti_field]
+    pub direction: AnimationDirection,
 }
 
 impl Default for PropertyAnimation {
     fn default() -> Self {
         // Defaults for PropertyAnimation are defined here (for internal Rust code doing programmatic animations)
         // as well as in `builtins.slint` (for generated C++ and Rust code)
-        Self { delay: 0, duration: 0, iteration_count: 1., easing: Default::default() }
+        Self {
+            delay: 0,
+            duration: 0,
+            iteration_count: 1.,
+            easing: Default::default(),
+            direction: Default::default(),
+        }
     }
 }
 
@@ -1259,6 +1466,7 @@ pub struct WindowItem {
     pub default_font_family: Property<SharedString>,
     pub default_font_size: Property<LogicalLength>,
     pub default_font_weight: Property<i32>,
+    pub layout_direction: Property<LayoutDirection>,
     pub cached_rendering_data: CachedRenderingData,
 }
 
@@ -1446,6 +1654,10 @@ fn slint_get_ComponentContainerVTable() -> ComponentContainerVTable for Componen
     fn slint_get_TextVTable() -> TextVTable for Text
 }
 
+declare_item_vtable! {
+    fn slint_get_StyledTextVTable() -> StyledTextVTable for StyledText
+}
+
 declare_item_vtable! {
     fn slint_get_TextInputVTable() -> TextInputVTable for TextInput
 }
@@ -1458,11 +1670,22 @@ fn slint_get_ImageItemVTable() -> ImageItemVTable for ImageItem
     fn slint_get_ClippedImageVTable() -> ClippedImageVTable for ClippedImage
 }
 
-#[cfg(feature = "std")]
 declare_item_vtable! {
     fn slint_get_PathVTable() -> PathVTable for Path
 }
 
+declare_item_vtable! {
+    fn slint_get_DragAreaVTable() -> DragAreaVTable for DragArea
+}
+
+declare_item_vtable! {
+    fn slint_get_DropAreaVTable() -> DropAreaVTable for DropArea
+}
+
+declare_item_vtable! {
+    fn slint_get_TooltipAreaVTable() -> TooltipAreaVTable for TooltipArea
+}
+
 macro_rules! declare_enums {
     ($( $(#[$enum_doc:meta])* enum $Name:ident { $( $(#[$value_doc:meta])* $Value:ident,)* })*) => {
         $(
@@ -0,0 +1,285 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+/*!
+This module contains the builtin `DragArea` and `DropArea` items
+
+When adding an item or a property, it needs to be kept in sync with different place.
+Lookup the [`crate::items`] module documentation.
+*/
+use super::{
+    DropEvent, DropEventArg, Item, ItemConsts, ItemRc, ItemRendererRef, PointerEventButton,
+    RenderingResult,
+};
+use crate::input::{
+    FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEvent,
+    KeyEventResult, MouseEvent,
+};
+use crate::item_rendering::CachedRenderingData;
+use crate::layout::{LayoutInfo, Orientation};
+use crate::lengths::{LogicalLength, LogicalPoint, LogicalSize};
+#[cfg(feature = "rtti")]
+use crate::rtti::*;
+use crate::window::{WindowAdapter, WindowInner};
+use crate::{Callback, Property, SharedString};
+use alloc::rc::Rc;
+use const_field_offset::FieldOffsets;
+use core::cell::Cell;
+use core::pin::Pin;
+use i_slint_core_macros::*;
+
+/// The distance the pointer must move while pressed before a drag starts
+const DRAG_THRESHOLD: LogicalLength = LogicalLength::new(8 as _);
+
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+/// The implementation of the `DragArea` element
+pub struct DragArea {
+    pub enabled: Property<bool>,
+    pub mime_type: Property<SharedString>,
+    pub data: Property<SharedString>,
+    pub dragging: Property<bool>,
+    pub cached_rendering_data: CachedRenderingData,
+    /// The position of the left button press that may start a drag
+    pressed_position: Cell<Option<LogicalPoint>>,
+}
+
+impl DragArea {
+    fn exceeds_threshold(self: Pin<&Self>, position: LogicalPoint) -> bool {
+        self.pressed_position.get().map_or(false, |pressed| {
+            (position - pressed).square_length() > DRAG_THRESHOLD.get() * DRAG_THRESHOLD.get()
+        })
+    }
+
+    fn start_drag(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>, self_rc: &ItemRc) {
+        self.pressed_position.set(None);
+        Self::FIELD_OFFSETS.dragging.apply_pin(self).set(true);
+        WindowInner::from_pub(window_adapter.window()).start_drag(
+            DropEvent { mime_type: self.mime_type(), data: self.data() },
+            self_rc.downgrade(),
+        );
+    }
+}
+
+impl Item for DragArea {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        if !self.enabled() {
+            self.pressed_position.set(None);
+            return InputEventFilterResult::ForwardAndIgnore;
+        }
+        match event {
+            MouseEvent::Pressed { position, button: PointerEventButton::Left, .. } => {
+                self.pressed_position.set(Some(position));
+                InputEventFilterResult::ForwardAndInterceptGrab
+            }
+            // Take the pointer away from the children, which may have grabbed the press
+            MouseEvent::Moved { position } if self.exceeds_threshold(position) => {
+                InputEventFilterResult::Intercept
+            }
+            MouseEvent::Released { .. } | MouseEvent::Exit => {
+                self.pressed_position.set(None);
+                InputEventFilterResult::ForwardAndInterceptGrab
+            }
+            MouseEvent::DragMove { .. } | MouseEvent::DragDrop { .. } => {
+                InputEventFilterResult::ForwardAndIgnore
+            }
+            _ => InputEventFilterResult::ForwardAndInterceptGrab,
+        }
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+    ) -> InputEventResult {
+        if !self.enabled() {
+            return InputEventResult::EventIgnored;
+        }
+        match event {
+            MouseEvent::Pressed { position, button: PointerEventButton::Left, .. } => {
+                self.pressed_position.set(Some(position));
+                InputEventResult::GrabMouse
+            }
+            MouseEvent::Moved { position } if self.pressed_position.get().is_some() => {
+                if self.exceeds_threshold(position) {
+                    self.start_drag(window_adapter, self_rc);
+                }
+                InputEventResult::GrabMouse
+            }
+            MouseEvent::Released { .. } | MouseEvent::Exit => {
+                self.pressed_position.set(None);
+                InputEventResult::EventIgnored
+            }
+            _ => InputEventResult::EventIgnored,
+        }
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for DragArea {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<
+        DragArea,
+        CachedRenderingData,
+    > = DragArea::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+/// The implementation of the `DropArea` element
+pub struct DropArea {
+    pub enabled: Property<bool>,
+    pub contains_drag: Property<bool>,
+    pub can_drop: Callback<DropEventArg, bool>,
+    pub dropped: Callback<DropEventArg>,
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl DropArea {
+    /// Returns the data being dragged over the window if this area accepts it
+    fn accepted_data(
+        self: Pin<&Self>,
+        window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> Option<DropEvent> {
+        let data = WindowInner::from_pub(window_adapter.window()).drag_data()?;
+        let can_drop = Self::FIELD_OFFSETS.can_drop.apply_pin(self);
+        // Without a `can-drop` handler, anything can be dropped
+        (!can_drop.has_handler() || can_drop.call(&(data.clone(),))).then_some(data)
+    }
+}
+
+impl Item for DropArea {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        match event {
+            MouseEvent::DragMove { .. } | MouseEvent::DragDrop { .. } if self.enabled() => {
+                InputEventFilterResult::ForwardEvent
+            }
+            _ => InputEventFilterResult::ForwardAndIgnore,
+        }
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        let contains_drag = Self::FIELD_OFFSETS.contains_drag.apply_pin(self);
+        match event {
+            MouseEvent::DragMove { .. } if self.enabled() => {
+                let accepted = self.accepted_data(window_adapter).is_some();
+                contains_drag.set(accepted);
+                if accepted {
+                    InputEventResult::EventAccepted
+                } else {
+                    InputEventResult::EventIgnored
+                }
+            }
+            MouseEvent::DragDrop { .. } if self.enabled() => {
+                contains_drag.set(false);
+                if let Some(data) = self.accepted_data(window_adapter) {
+                    Self::FIELD_OFFSETS.dropped.apply_pin(self).call(&(data,));
+                    InputEventResult::EventAccepted
+                } else {
+                    InputEventResult::EventIgnored
+                }
+            }
+            _ => {
+                contains_drag.set(false);
+                InputEventResult::EventIgnored
+            }
+        }
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for DropArea {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<
+        DropArea,
+        CachedRenderingData,
+    > = DropArea::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
@@ -258,7 +258,10 @@ fn handle_mouse_filter(
                     InputEventFilterResult::ForwardEvent
                 }
             }
-            MouseEvent::Wheel { .. } => InputEventFilterResult::ForwardEvent,
+            MouseEvent::Wheel { .. }
+            | MouseEvent::TouchGesture { .. }
+            | MouseEvent::DragMove { .. }
+            | MouseEvent::DragDrop { .. } => InputEventFilterResult::ForwardEvent,
             // Not the left button
             MouseEvent::Pressed { .. } | MouseEvent::Released { .. } => {
                 InputEventFilterResult::ForwardAndIgnore
@@ -351,6 +354,17 @@ fn handle_mouse(
                 }
                 InputEventResult::EventAccepted
             }
+            MouseEvent::TouchGesture { .. } => {
+                // Keep flicking with the first finger
+                if inner.capture_events {
+                    InputEventResult::GrabMouse
+                } else {
+                    InputEventResult::EventIgnored
+                }
+            }
+            MouseEvent::DragMove { .. } | MouseEvent::DragDrop { .. } => {
+                InputEventResult::EventIgnored
+            }
         }
     }
 
@@ -25,9 +25,115 @@
 use crate::{Brush, Coord, Property};
 use alloc::rc::Rc;
 use const_field_offset::FieldOffsets;
+use core::cell::Cell;
 use core::pin::Pin;
 use i_slint_core_macros::*;
 
+/// Plays the frames of an animated image.
+///
+/// It is owned by the binding of the `current-frame` property of the image item, so that it
+/// goes away with the item, and is only created once the item shows an animated image. Its timer
+/// increments the `advanced` property when the next frame needs to be shown, which makes that
+/// binding, and thus the rendering of the item, dirty. The timer for the following frame is only
+/// started once the binding is evaluated again, so the images that are not rendered are not played.
+///
+/// A timer is used rather than the animation driver because the driver would request a new
+/// frame of the whole window on every tick while an image plays, and not only when the next
+/// frame of the image is due.
+#[derive(FieldOffsets, Default)]
+#[repr(C)]
+#[pin]
+struct AnimatedImagePlayer {
+    /// The number of times the timer fired
+    advanced: Property<u32>,
+    timer: crate::timers::Timer,
+    /// The value of the `frame` property, and of `advanced`, when the playback started
+    start: Cell<Option<(i32, u32)>>,
+}
+
+impl AnimatedImagePlayer {
+    /// Sets a binding on the `current_frame` property of the item `self_rc` that evaluates to
+    /// the index of the frame to show. `inputs` returns the `source`, `playing` and `frame`
+    /// properties of the item.
+    fn set_binding<T: vtable::HasStaticVTable<super::ItemVTable> + 'static>(
+        current_frame: Pin<&Property<i32>>,
+        self_rc: &ItemRc,
+        inputs: fn(Pin<&T>) -> (crate::graphics::Image, bool, i32),
+    ) {
+        let player = once_cell::unsync::OnceCell::<Pin<Rc<Self>>>::new();
+        let item = self_rc.downgrade();
+        current_frame.set_binding(move || {
+            let Some(item) = item.upgrade() else { return 0 };
+            let Some(item) = item.downcast::<T>() else { return 0 };
+            let (source, playing, frame) = inputs(item.as_pin_ref());
+            if source.frame_count() <= 1 {
+                if let Some(player) = player.get() {
+                    player.stop();
+                }
+                return 0;
+            }
+            player.get_or_init(|| Rc::pin(Self::default())).current_frame(&source, playing, frame)
+        });
+    }
+
+    /// Stops the playback when the image is no longer animated.
+    fn stop(&self) {
+        self.timer.stop();
+        self.start.set(None);
+    }
+
+    /// Returns the index of the frame of the animated `source` image to show.
+    ///
+    /// The playback starts from `frame`, and starts again from there when it changes.
+    /// When not `playing`, the frame that was shown last remains.
+    fn current_frame(
+        self: &Pin<Rc<Self>>,
+        source: &crate::graphics::Image,
+        playing: bool,
+        frame: i32,
+    ) -> i32 {
+        let frame_count = source.frame_count() as i64;
+        let advanced = Self::FIELD_OFFSETS.advanced.apply_pin(self.as_ref()).get();
+        let start_advanced = match self.start.get() {
+            Some((start_frame, start_advanced)) if start_frame == frame => start_advanced,
+            _ => {
+                self.timer.stop();
+                self.start.set(Some((frame, advanced)));
+                advanced
+            }
+        };
+        let index =
+            (frame as i64 + advanced.wrapping_sub(start_advanced) as i64).rem_euclid(frame_count);
+
+        if !playing {
+            self.timer.stop();
+        } else if !self.timer.running() {
+            let weak_player = pin_weak::rc::PinWeak::downgrade(self.clone());
+            self.timer.start(
+                crate::timers::TimerMode::SingleShot,
+                source.frame_duration(index as usize),
+                move || {
+                    if let Some(player) = weak_player.upgrade() {
+                        let advanced = Self::FIELD_OFFSETS.advanced.apply_pin(player.as_ref());
+                        advanced.set(advanced.get_untracked().wrapping_add(1));
+                    }
+                },
+            );
+        }
+        index as i32
+    }
+}
+
+/// Returns the frame at `index` of the `source` image, if it is animated.
+fn animation_frame(source: crate::graphics::Image, index: i32) -> crate::graphics::Image {
+    let frame_count = source.frame_count();
+    if frame_count <= 1 {
+        source
+    } else {
+        source.frame(index.rem_euclid(frame_count as i32) as usize)
+    }
+}
+
 #[repr(C)]
 #[derive(FieldOffsets, Default, SlintElement)]
 #[pin]
@@ -39,11 +145,20 @@ pub struct ImageItem {
     pub image_fit: Property<ImageFit>,
     pub image_rendering: Property<ImageRendering>,
     pub colorize: Property<Brush>,
+    pub playing: Property<bool>,
+    pub frame: Property<i32>,
+    pub current_frame: Property<i32>,
     pub cached_rendering_data: CachedRenderingData,
 }
 
 impl Item for ImageItem {
-    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+    fn init(self: Pin<&Self>, self_rc: &ItemRc) {
+        AnimatedImagePlayer::set_binding(
+            Self::FIELD_OFFSETS.current_frame.apply_pin(self),
+            self_rc,
+            |image: Pin<&Self>| (image.source(), image.playing(), image.frame()),
+        );
+    }
 
     fn layout_info(
         self: Pin<&Self>,
@@ -116,7 +231,7 @@ fn target_size(self: Pin<&Self>) -> LogicalSize {
     }
 
     fn source(self: Pin<&Self>) -> crate::graphics::Image {
-        self.source()
+        animation_frame(self.source(), self.current_frame())
     }
 
     fn source_clip(self: Pin<&Self>) -> Option<crate::graphics::IntRect> {
@@ -172,11 +287,21 @@ pub struct ClippedImage {
     pub horizontal_tiling: Property<ImageTiling>,
     pub vertical_tiling: Property<ImageTiling>,
 
+    pub playing: Property<bool>,
+    pub frame: Property<i32>,
+    pub current_frame: Property<i32>,
+
     pub cached_rendering_data: CachedRenderingData,
 }
 
 impl Item for ClippedImage {
-    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+    fn init(self: Pin<&Self>, self_rc: &ItemRc) {
+        AnimatedImagePlayer::set_binding(
+            Self::FIELD_OFFSETS.current_frame.apply_pin(self),
+            self_rc,
+            |image: Pin<&Self>| (image.source(), image.playing(), image.frame()),
+        );
+    }
 
     fn layout_info(
         self: Pin<&Self>,
@@ -253,7 +378,7 @@ fn target_size(self: Pin<&Self>) -> LogicalSize {
     }
 
     fn source(self: Pin<&Self>) -> crate::graphics::Image {
-        self.source()
+        animation_frame(self.source(), self.current_frame())
     }
 
     fn source_clip(self: Pin<&Self>) -> Option<crate::graphics::IntRect> {
@@ -292,3 +417,81 @@ impl ItemConsts for ClippedImage {
         CachedRenderingData,
     > = ClippedImage::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
 }
+
+#[test]
+fn test_animated_image_playback() {
+    use crate::graphics::{
+        AnimatedImage, AnimatedImageFrame, Image, ImageCacheKey, ImageInner, SharedImageBuffer,
+        SharedPixelBuffer,
+    };
+    use crate::tests::{slint_get_mocked_time, slint_mock_elapsed_time};
+
+    // The timers are started relative to the time of the platform, which follows the mocked time
+    struct MockedTimePlatform;
+    impl crate::platform::Platform for MockedTimePlatform {
+        fn create_window_adapter(
+            &self,
+        ) -> Result<Rc<dyn WindowAdapter>, crate::platform::PlatformError> {
+            Err(crate::platform::PlatformError::NoPlatform)
+        }
+        fn duration_since_start(&self) -> core::time::Duration {
+            let mocked_time = crate::properties::evaluate_no_tracking(|| slint_get_mocked_time());
+            core::time::Duration::from_millis(mocked_time)
+        }
+    }
+    crate::platform::set_platform(alloc::boxed::Box::new(MockedTimePlatform)).unwrap();
+
+    let frame = |red, duration| AnimatedImageFrame {
+        buffer: SharedImageBuffer::RGBA8(SharedPixelBuffer::clone_from_slice(
+            &[red, 0, 0, 255],
+            1,
+            1,
+        )),
+        duration,
+    };
+    let image = Image::from(ImageInner::Animated(vtable::VRc::new(AnimatedImage {
+        cache_key: ImageCacheKey::Invalid,
+        frames: alloc::vec![frame(0, 100), frame(1, 200), frame(2, 100)],
+    })));
+    assert_eq!(image.frame_count(), 3);
+    assert_eq!(image.frame_duration(1), core::time::Duration::from_millis(200));
+
+    let player = Rc::pin(AnimatedImagePlayer::default());
+    assert_eq!(player.current_frame(&image, true, 0), 0);
+    slint_mock_elapsed_time(50);
+    assert_eq!(player.current_frame(&image, true, 0), 0);
+    slint_mock_elapsed_time(100);
+    assert_eq!(player.current_frame(&image, true, 0), 1);
+    // The second frame is shown for 200ms
+    slint_mock_elapsed_time(150);
+    assert_eq!(player.current_frame(&image, true, 0), 1);
+    slint_mock_elapsed_time(50);
+    assert_eq!(player.current_frame(&image, true, 0), 2);
+    slint_mock_elapsed_time(100);
+    assert_eq!(player.current_frame(&image, true, 0), 0);
+
+    // Changing the frame continues the playback from there
+    assert_eq!(player.current_frame(&image, true, 2), 2);
+    slint_mock_elapsed_time(100);
+    assert_eq!(player.current_frame(&image, true, 2), 0);
+
+    // When paused, the frame that was shown remains, until the frame is changed
+    assert_eq!(player.current_frame(&image, false, 2), 0);
+    slint_mock_elapsed_time(1000);
+    assert_eq!(player.current_frame(&image, false, 2), 0);
+    assert_eq!(player.current_frame(&image, false, 1), 1);
+    slint_mock_elapsed_time(1000);
+    assert_eq!(player.current_frame(&image, false, 1), 1);
+
+    // Images that are not animated only have one frame
+    assert_eq!(player.current_frame(&Image::default(), true, 1), 0);
+
+    // The frame doesn't depend on the animation tick
+    let tracker = Box::pin(crate::properties::PropertyTracker::default());
+    let player = Rc::pin(AnimatedImagePlayer::default());
+    tracker.as_ref().evaluate(|| player.current_frame(&image, true, 0));
+    slint_mock_elapsed_time(50);
+    assert!(!tracker.is_dirty());
+    slint_mock_elapsed_time(50);
+    assert!(tracker.is_dirty());
+}
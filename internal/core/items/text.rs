@@ -8,8 +8,8 @@
 Lookup the [`crate::items`] module documentation.
 */
 use super::{
-    InputType, Item, ItemConsts, ItemRc, KeyEventResult, KeyEventType, PointArg,
-    PointerEventButton, RenderingResult, TextHorizontalAlignment, TextOverflow,
+    InputType, Item, ItemConsts, ItemRc, KeyEventResult, KeyEventType, LayoutDirection, PointArg,
+    PointerEventButton, RenderingResult, StringArg, TextHorizontalAlignment, TextOverflow,
     TextVerticalAlignment, TextWrap, VoidArg,
 };
 use crate::graphics::{Brush, Color, FontRequest};
@@ -19,17 +19,26 @@
 };
 use crate::item_rendering::{CachedRenderingData, ItemRenderer};
 use crate::layout::{LayoutInfo, Orientation};
-use crate::lengths::{LogicalLength, LogicalPoint, LogicalRect, LogicalSize, ScaleFactor};
+use crate::lengths::{
+    LogicalLength, LogicalPoint, LogicalRect, LogicalSize, ScaleFactor, SizeLengths,
+};
 use crate::platform::Clipboard;
 #[cfg(feature = "rtti")]
 use crate::rtti::*;
+use crate::textlayout::{
+    layout_styled_text, parse_styled_text, SpanStyle, StyledSpan, StyledTextLayout, StyledTextRun,
+};
 use crate::window::{InputMethodProperties, InputMethodRequest, WindowAdapter, WindowInner};
 use crate::{Callback, Coord, Property, SharedString, SharedVector};
+#[cfg(not(feature = "std"))]
+use alloc::boxed::Box;
 use alloc::rc::Rc;
 #[cfg(not(feature = "std"))]
 use alloc::string::String;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
 use const_field_offset::FieldOffsets;
-use core::cell::Cell;
+use core::cell::{Cell, RefCell};
 use core::pin::Pin;
 #[allow(unused)]
 use euclid::num::Ceil;
@@ -51,6 +60,10 @@ pub struct Text {
     pub wrap: Property<TextWrap>,
     pub overflow: Property<TextOverflow>,
     pub letter_spacing: Property<LogicalLength>,
+    pub font_underline: Property<bool>,
+    pub font_strikethrough: Property<bool>,
+    pub stroke: Property<Brush>,
+    pub stroke_width: Property<LogicalLength>,
     pub width: Property<LogicalLength>,
     pub height: Property<LogicalLength>,
     pub cached_rendering_data: CachedRenderingData,
@@ -166,7 +179,36 @@ impl ItemConsts for Text {
         Text::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
 }
 
+impl TextHorizontalAlignment {
+    /// Resolves `start` and `end` to `left` or `right` for the given layout direction
+    pub fn for_direction(self, direction: LayoutDirection) -> Self {
+        match (self, direction) {
+            (Self::Start, LayoutDirection::LeftToRight)
+            | (Self::End, LayoutDirection::RightToLeft) => Self::Left,
+            (Self::Start, LayoutDirection::RightToLeft)
+            | (Self::End, LayoutDirection::LeftToRight) => Self::Right,
+            (alignment, _) => alignment,
+        }
+    }
+}
+
+/// Returns the `layout-direction` of the window
+fn window_layout_direction(window: &WindowInner) -> LayoutDirection {
+    window
+        .window_item()
+        .map_or(LayoutDirection::default(), |item| item.as_pin_ref().layout_direction())
+}
+
 impl Text {
+    /// Returns the horizontal alignment, with `start` and `end` resolved against the
+    /// `layout-direction` of the window
+    pub fn resolved_horizontal_alignment(
+        self: Pin<&Self>,
+        window: &WindowInner,
+    ) -> TextHorizontalAlignment {
+        self.horizontal_alignment().for_direction(window_layout_direction(window))
+    }
+
     pub fn font_request(self: Pin<&Self>, window: &WindowInner) -> FontRequest {
         let window_item = window.window_item();
 
@@ -201,6 +243,347 @@ pub fn font_request(self: Pin<&Self>, window: &WindowInner) -> FontRequest {
     }
 }
 
+/// The implementation of the `StyledText` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct StyledText {
+    pub text: Property<SharedString>,
+    pub font_family: Property<SharedString>,
+    pub font_size: Property<LogicalLength>,
+    pub font_weight: Property<i32>,
+    pub color: Property<Brush>,
+    pub horizontal_alignment: Property<TextHorizontalAlignment>,
+    pub vertical_alignment: Property<TextVerticalAlignment>,
+    pub wrap: Property<TextWrap>,
+    pub width: Property<LogicalLength>,
+    pub height: Property<LogicalLength>,
+    pub link_clicked: Callback<StringArg>,
+    /// The link under the pointer when the left button was pressed
+    pressed_link: Property<SharedString>,
+    layout_cache: StyledTextLayoutCacheBox,
+    pub cached_rendering_data: CachedRenderingData,
+    /// Whether the mouse cursor was changed because the pointer is over a link
+    hovering_link: Cell<bool>,
+}
+
+impl Item for StyledText {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        orientation: Orientation,
+        window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        let implicit_size =
+            |max_width| self.layout_with_max_width(window_adapter, max_width).1.size;
+
+        match orientation {
+            Orientation::Horizontal => {
+                let implicit_size = implicit_size(None);
+                let min = match self.wrap() {
+                    TextWrap::NoWrap => implicit_size.width,
+                    TextWrap::WordWrap => 0 as Coord,
+                };
+                LayoutInfo {
+                    min: min.ceil(),
+                    preferred: implicit_size.width.ceil(),
+                    ..LayoutInfo::default()
+                }
+            }
+            Orientation::Vertical => {
+                let h = match self.wrap() {
+                    TextWrap::NoWrap => implicit_size(None).height,
+                    TextWrap::WordWrap => implicit_size(Some(self.width())).height,
+                }
+                .ceil();
+                LayoutInfo { min: h, preferred: h, ..LayoutInfo::default() }
+            }
+        }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardEvent
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        let set_hovering_link = |hovering: bool| {
+            if self.hovering_link.replace(hovering) != hovering {
+                if let Some(x) = window_adapter.internal(crate::InternalToken) {
+                    x.set_mouse_cursor(if hovering {
+                        super::MouseCursor::Pointer
+                    } else {
+                        super::MouseCursor::Default
+                    });
+                }
+            }
+        };
+        match event {
+            MouseEvent::Pressed { position, button: PointerEventButton::Left, .. } => {
+                match self.link_at(position, window_adapter) {
+                    Some(link) => {
+                        self.pressed_link.set(link);
+                        InputEventResult::GrabMouse
+                    }
+                    None => InputEventResult::EventIgnored,
+                }
+            }
+            MouseEvent::Released { position, button: PointerEventButton::Left, .. } => {
+                let pressed_link = self.pressed_link();
+                if pressed_link.is_empty() {
+                    return InputEventResult::EventIgnored;
+                }
+                self.pressed_link.set(Default::default());
+                if self.link_at(position, window_adapter).as_ref() == Some(&pressed_link) {
+                    Self::FIELD_OFFSETS.link_clicked.apply_pin(self).call(&(pressed_link,));
+                }
+                InputEventResult::EventAccepted
+            }
+            MouseEvent::Moved { position } => {
+                let link = self.link_at(position, window_adapter);
+                set_hovering_link(link.is_some());
+                if !self.pressed_link().is_empty() {
+                    InputEventResult::GrabMouse
+                } else if link.is_some() {
+                    InputEventResult::EventAccepted
+                } else {
+                    InputEventResult::EventIgnored
+                }
+            }
+            MouseEvent::Exit => {
+                set_hovering_link(false);
+                self.pressed_link.set(Default::default());
+                InputEventResult::EventIgnored
+            }
+            _ => InputEventResult::EventIgnored,
+        }
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        backend: &mut &mut dyn ItemRenderer,
+        self_rc: &ItemRc,
+        size: LogicalSize,
+    ) -> RenderingResult {
+        (*backend).draw_styled_text(self, self_rc, size);
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for StyledText {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<
+        StyledText,
+        CachedRenderingData,
+    > = StyledText::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+impl StyledText {
+    /// Returns the font request for a span with the given style
+    pub fn font_request(self: Pin<&Self>, style: &SpanStyle, window: &WindowInner) -> FontRequest {
+        let window_item = window.window_item();
+
+        FontRequest {
+            family: {
+                let maybe_family = style.font_family.clone().unwrap_or_else(|| self.font_family());
+                if !maybe_family.is_empty() {
+                    Some(maybe_family)
+                } else {
+                    window_item.as_ref().and_then(|item| item.as_pin_ref().font_family())
+                }
+            },
+            weight: {
+                let weight = style.font_weight.unwrap_or_else(|| self.font_weight());
+                if weight == 0 {
+                    window_item.as_ref().and_then(|item| item.as_pin_ref().font_weight())
+                } else {
+                    Some(weight)
+                }
+            },
+            pixel_size: {
+                let font_size = style.font_size.unwrap_or_else(|| self.font_size());
+                if font_size.get() == 0 as Coord {
+                    window_item.as_ref().and_then(|item| item.as_pin_ref().font_size())
+                } else {
+                    Some(font_size)
+                }
+            },
+            letter_spacing: None,
+            italic: style.italic,
+        }
+    }
+
+    /// Parse the markup and lay out the spans, aligned within a rectangle of the given size.
+    /// The runs refer to the returned spans.
+    pub fn layout(
+        self: Pin<&Self>,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        size: LogicalSize,
+    ) -> (Rc<Vec<StyledSpan>>, StyledTextLayout) {
+        let max_width = match self.wrap() {
+            TextWrap::NoWrap => None,
+            TextWrap::WordWrap => Some(size.width_length()),
+        };
+        let (spans, mut layout) = self.layout_with_max_width(window_adapter, max_width);
+        let horizontal_alignment = self
+            .horizontal_alignment()
+            .for_direction(window_layout_direction(WindowInner::from_pub(window_adapter.window())));
+        layout.align(size, horizontal_alignment, self.vertical_alignment());
+        (spans, layout)
+    }
+
+    /// Returns the parsed spans and their layout, from the cache unless a property they
+    /// depend on changed.
+    fn layout_with_max_width(
+        self: Pin<&Self>,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        max_width: Option<LogicalLength>,
+    ) -> (Rc<Vec<StyledSpan>>, StyledTextLayout) {
+        let slot = max_width.is_some() as usize;
+        // Take the entry out of the cache, as evaluating the properties may lay out the text again
+        let cached = self.layout_cache.layouts.borrow_mut()[slot].take();
+        let cached = match cached {
+            Some(cached) if cached.max_width == max_width && !cached.tracker.is_dirty() => {
+                cached.tracker.as_ref().register_as_dependency_to_current_binding();
+                cached
+            }
+            _ => {
+                let tracker = Box::pin(crate::properties::PropertyTracker::default());
+                let (spans, layout) = tracker.as_ref().evaluate(|| {
+                    let window_inner = WindowInner::from_pub(window_adapter.window());
+                    let scale_factor = ScaleFactor::new(window_inner.scale_factor());
+                    let spans = parse_styled_text(&self.text());
+                    let layout =
+                        layout_styled_text(&spans, max_width.map(|w| w.get()), |style, text| {
+                            let renderer = window_adapter.renderer();
+                            let font_request = self.font_request(style, window_inner);
+                            let width = renderer
+                                .text_size(font_request.clone(), text, None, scale_factor)
+                                .width;
+                            (width, renderer.font_metrics(font_request, scale_factor))
+                        });
+                    (spans, layout)
+                });
+                CachedStyledTextLayout { max_width, spans: Rc::new(spans), layout, tracker }
+            }
+        };
+        let result = (cached.spans.clone(), cached.layout.clone());
+        self.layout_cache.layouts.borrow_mut()[slot] = Some(cached);
+        result
+    }
+
+    /// Returns a `Text` item that draws the given run, with the given style, at (0, 0)
+    pub fn text_item_for_run(self: Pin<&Self>, run: &StyledTextRun, style: &SpanStyle) -> Text {
+        let text = Text::default();
+        text.text.set(run.text.clone());
+        text.font_family.set(style.font_family.clone().unwrap_or_else(|| self.font_family()));
+        text.font_size.set(style.font_size.unwrap_or_else(|| self.font_size()));
+        text.font_weight.set(style.font_weight.unwrap_or_else(|| self.font_weight()));
+        text.font_italic.set(style.italic);
+        text.color.set(style.color.map_or_else(|| self.color(), Brush::SolidColor));
+        text.font_underline.set(style.underline);
+        text.font_strikethrough.set(style.strikethrough);
+        text.width.set(run.rect.size.width_length());
+        text.height.set(run.rect.size.height_length());
+        text
+    }
+
+    fn link_at(
+        self: Pin<&Self>,
+        position: LogicalPoint,
+        window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> Option<SharedString> {
+        let (spans, layout) =
+            self.layout(window_adapter, LogicalSize::from_lengths(self.width(), self.height()));
+        layout.run_at(position).and_then(|run| spans[run.span].style.link.clone())
+    }
+}
+
+/// The spans parsed from the markup of a `StyledText` and their layout for a maximum width
+struct CachedStyledTextLayout {
+    max_width: Option<LogicalLength>,
+    spans: Rc<Vec<StyledSpan>>,
+    layout: StyledTextLayout,
+    /// Tracks the properties that the spans and the layout were computed from
+    tracker: Pin<Box<crate::properties::PropertyTracker>>,
+}
+
+/// The layouts of a `StyledText`, so that the text isn't parsed and shaped again for every
+/// frame or mouse move.
+#[derive(Default)]
+pub struct StyledTextLayoutCache {
+    /// The layout without maximum width, and the one for the last maximum width
+    layouts: RefCell<[Option<CachedStyledTextLayout>; 2]>,
+}
+
+#[repr(C)]
+/// Wraps the internal layout cache of the StyledText
+pub struct StyledTextLayoutCacheBox(core::ptr::NonNull<StyledTextLayoutCache>);
+
+impl Default for StyledTextLayoutCacheBox {
+    fn default() -> Self {
+        StyledTextLayoutCacheBox(Box::leak(Box::<StyledTextLayoutCache>::default()).into())
+    }
+}
+impl Drop for StyledTextLayoutCacheBox {
+    fn drop(&mut self) {
+        // Safety: the self.0 was constructed from a Box::leak in StyledTextLayoutCacheBox::default
+        drop(unsafe { Box::from_raw(self.0.as_ptr()) });
+    }
+}
+
+impl core::ops::Deref for StyledTextLayoutCacheBox {
+    type Target = StyledTextLayoutCache;
+    fn deref(&self) -> &Self::Target {
+        // Safety: initialized in StyledTextLayoutCacheBox::default
+        unsafe { self.0.as_ref() }
+    }
+}
+
+/// # Safety
+/// This must be called using a non-null pointer pointing to a chunk of memory big enough to
+/// hold a StyledTextLayoutCacheBox
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn slint_styled_text_layout_cache_init(data: *mut StyledTextLayoutCacheBox) {
+    core::ptr::write(data, StyledTextLayoutCacheBox::default());
+}
+
+/// # Safety
+/// This must be called using a non-null pointer pointing to an initialized StyledTextLayoutCacheBox
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn slint_styled_text_layout_cache_free(data: *mut StyledTextLayoutCacheBox) {
+    core::ptr::drop_in_place(data);
+}
+
 #[repr(C)]
 #[derive(Default, Clone, Copy, PartialEq)]
 /// Similar as `Option<core::ops::Range<i32>>` but `repr(C)`
@@ -259,6 +642,10 @@ pub struct TextInput {
     pub wrap: Property<TextWrap>,
     pub input_type: Property<InputType>,
     pub letter_spacing: Property<LogicalLength>,
+    pub font_underline: Property<bool>,
+    pub font_strikethrough: Property<bool>,
+    pub stroke: Property<Brush>,
+    pub stroke_width: Property<LogicalLength>,
     pub width: Property<LogicalLength>,
     pub height: Property<LogicalLength>,
     pub cursor_position_byte_offset: Property<i32>,
@@ -457,6 +844,25 @@ fn key_event(
                 match event.text_shortcut() {
                     Some(text_shortcut) if !self.read_only() => match text_shortcut {
                         TextShortcut::Move(direction) => {
+                            // The left and right arrows move the cursor in the direction of the
+                            // arrow, so by word and to the ends of the line they are swapped in
+                            // right-to-left paragraphs.
+                            let direction = match (event.text.chars().next(), direction) {
+                                (
+                                    Some(key_codes::LeftArrow),
+                                    TextCursorDirection::Backward | TextCursorDirection::Forward,
+                                ) => TextCursorDirection::Left,
+                                (
+                                    Some(key_codes::RightArrow),
+                                    TextCursorDirection::Backward | TextCursorDirection::Forward,
+                                ) => TextCursorDirection::Right,
+                                (Some(key_codes::LeftArrow | key_codes::RightArrow), direction)
+                                    if self.is_right_to_left_at_cursor() =>
+                                {
+                                    direction.mirrored()
+                                }
+                                (_, direction) => direction,
+                            };
                             TextInput::move_cursor(
                                 self,
                                 direction,
@@ -741,6 +1147,8 @@ pub enum TextCursorDirection {
     EndOfParagraph,
     StartOfText,
     EndOfText,
+    Left, // the visual order of bidirectional text, used by the arrow keys
+    Right,
 }
 
 impl core::convert::TryFrom<char> for TextCursorDirection {
@@ -762,6 +1170,21 @@ fn try_from(value: char) -> Result<Self, Self::Error> {
     }
 }
 
+impl TextCursorDirection {
+    /// Returns the direction that moves the cursor the opposite way along the line
+    fn mirrored(self) -> Self {
+        match self {
+            Self::Forward => Self::Backward,
+            Self::Backward => Self::Forward,
+            Self::ForwardByWord => Self::BackwardByWord,
+            Self::BackwardByWord => Self::ForwardByWord,
+            Self::StartOfLine => Self::EndOfLine,
+            Self::EndOfLine => Self::StartOfLine,
+            direction => direction,
+        }
+    }
+}
+
 enum AnchorMode {
     KeepAnchor,
     MoveAnchor,
@@ -978,6 +1401,12 @@ fn move_cursor(
             }
             TextCursorDirection::StartOfText => 0,
             TextCursorDirection::EndOfText => text.len(),
+            TextCursorDirection::Left => {
+                self.visual_cursor_move(&text, last_cursor_pos, false, window_adapter)
+            }
+            TextCursorDirection::Right => {
+                self.visual_cursor_move(&text, last_cursor_pos, true, window_adapter)
+            }
         };
 
         match anchor_mode {
@@ -1107,6 +1536,100 @@ pub fn anchor_position(self: Pin<&Self>, text: &str) -> usize {
         safe_byte_offset(self.anchor_position_byte_offset(), text)
     }
 
+    /// Returns the byte offset of the cursor moved by one grapheme to the left or to the right on
+    /// its line, following the visual order of bidirectional text. From the left or right end of
+    /// the line, the cursor moves to the previous or next line.
+    fn visual_cursor_move(
+        self: Pin<&Self>,
+        text: &str,
+        cursor: usize,
+        to_right: bool,
+        window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> usize {
+        use crate::textlayout::bidi;
+        let levels = bidi::BidiLevels::new(text);
+        let paragraph_is_rtl = bidi::is_rtl(levels.paragraph_level_at(cursor));
+        let next_grapheme = |from: usize, forward: bool| {
+            let mut grapheme_cursor =
+                unicode_segmentation::GraphemeCursor::new(from, text.len(), true);
+            if forward {
+                grapheme_cursor.next_boundary(text, 0).ok().flatten().unwrap_or(text.len())
+            } else {
+                grapheme_cursor.prev_boundary(text, 0).ok().flatten().unwrap_or(0)
+            }
+        };
+        if !levels.has_rtl() {
+            return next_grapheme(cursor, to_right);
+        }
+
+        let mut cursor_xy_pos = self.cursor_rect_for_byte_offset(cursor, window_adapter).center();
+        cursor_xy_pos.x = 0 as Coord;
+        let left_end = self.byte_offset_for_position(cursor_xy_pos, window_adapter);
+        cursor_xy_pos.x = Coord::MAX;
+        let right_end = self.byte_offset_for_position(cursor_xy_pos, window_adapter);
+        let line = left_end.min(right_end)..left_end.max(right_end);
+        let beyond_line = || {
+            if to_right != paragraph_is_rtl {
+                next_grapheme(line.end, true)
+            } else {
+                next_grapheme(line.start, false)
+            }
+        };
+
+        // The graphemes of the line with whether they run right-to-left, in visual order
+        let graphemes = text[line.clone()]
+            .grapheme_indices(true)
+            .map(|(offset, grapheme)| {
+                let start = line.start + offset;
+                (start..start + grapheme.len(), bidi::is_rtl(levels.level_at(start)))
+            })
+            .collect::<Vec<_>>();
+        let visual_order = bidi::visual_order(
+            &graphemes.iter().map(|(range, _)| levels.level_at(range.start)).collect::<Vec<_>>(),
+        );
+        let graphemes =
+            visual_order.into_iter().map(|index| graphemes[index].clone()).collect::<Vec<_>>();
+
+        // The cursor is drawn on the leading edge of the grapheme at its byte offset, which is the
+        // right edge for right-to-left text. Returns the number of graphemes left of the cursor.
+        let position_of = |offset: usize| match graphemes
+            .iter()
+            .position(|(range, _)| range.start == offset)
+        {
+            Some(slot) => Some(if graphemes[slot].1 { slot + 1 } else { slot }),
+            None if offset == line.end => Some(if paragraph_is_rtl { 0 } else { graphemes.len() }),
+            None => None,
+        };
+        let Some(mut position) = position_of(cursor) else {
+            return next_grapheme(cursor, to_right != paragraph_is_rtl);
+        };
+        loop {
+            if (to_right && position == graphemes.len()) || (!to_right && position == 0) {
+                return beyond_line();
+            }
+            position = if to_right { position + 1 } else { position - 1 };
+            // Not every position between two graphemes can show the cursor, in that case it moves
+            // further
+            let adjacent = graphemes[position.saturating_sub(1)..graphemes.len().min(position + 1)]
+                .iter()
+                .flat_map(|(range, _)| [range.start, range.end]);
+            if let Some(offset) = adjacent
+                .filter(|offset| *offset != cursor)
+                .find(|offset| position_of(*offset) == Some(position))
+            {
+                return offset;
+            }
+        }
+    }
+
+    /// Returns true if the paragraph containing the cursor runs right-to-left
+    fn is_right_to_left_at_cursor(self: Pin<&Self>) -> bool {
+        use crate::textlayout::bidi;
+        let text = self.text();
+        let cursor_position = self.cursor_position(&text);
+        bidi::is_rtl(bidi::BidiLevels::new(&text).paragraph_level_at(cursor_position))
+    }
+
     pub fn cursor_position(self: Pin<&Self>, text: &str) -> usize {
         safe_byte_offset(self.cursor_position_byte_offset(), text)
     }
@@ -1332,6 +1855,16 @@ fn paste_clipboard(
         }
     }
 
+    /// Returns the horizontal alignment, with `start` and `end` resolved against the
+    /// `layout-direction` of the window
+    pub fn resolved_horizontal_alignment(
+        self: Pin<&Self>,
+        window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> TextHorizontalAlignment {
+        self.horizontal_alignment()
+            .for_direction(window_layout_direction(WindowInner::from_pub(window_adapter.window())))
+    }
+
     pub fn font_request(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>) -> FontRequest {
         let window_item = WindowInner::from_pub(window_adapter.window()).window_item();
 
@@ -1420,6 +1953,7 @@ fn cursor_rect_for_byte_offset(
             self,
             byte_offset,
             self.font_request(window_adapter),
+            self.resolved_horizontal_alignment(window_adapter),
             ScaleFactor::new(window_adapter.window().scale_factor()),
         )
     }
@@ -1433,6 +1967,7 @@ fn byte_offset_for_position(
             self,
             pos,
             self.font_request(window_adapter),
+            self.resolved_horizontal_alignment(window_adapter),
             ScaleFactor::new(window_adapter.window().scale_factor()),
         )
     }
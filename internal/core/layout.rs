@@ -5,7 +5,7 @@
 
 // cspell:ignore coord
 
-use crate::items::{DialogButtonRole, LayoutAlignment};
+use crate::items::{DialogButtonRole, LayoutAlignment, LayoutCrossAlignment, LayoutDirection};
 use crate::{slice::Slice, Coord, SharedVector};
 use alloc::vec::Vec;
 
@@ -419,12 +419,25 @@ pub struct Padding {
     pub end: Coord,
 }
 
+impl Padding {
+    /// The padding is given for left-to-right, but right-to-left layouts are solved from the right
+    /// before being mirrored, so the begin and end are swapped.
+    fn for_direction(self, direction: LayoutDirection) -> Self {
+        match direction {
+            LayoutDirection::LeftToRight => self,
+            LayoutDirection::RightToLeft => Self { begin: self.end, end: self.begin },
+        }
+    }
+}
+
 #[repr(C)]
 #[derive(Debug)]
 pub struct GridLayoutData<'a> {
     pub size: Coord,
     pub spacing: Coord,
     pub padding: Padding,
+    /// When right-to-left, the columns are placed from the right. Always left-to-right for rows.
+    pub direction: LayoutDirection,
     pub cells: Slice<'a, GridLayoutCellData>,
 }
 
@@ -447,22 +460,24 @@ pub fn solve_grid_layout(data: &GridLayoutData) -> SharedVector<Coord> {
         return Default::default();
     }
 
+    let padding = data.padding.for_direction(data.direction);
     grid_internal::layout_items(
         &mut layout_data,
-        data.padding.begin,
-        data.size - (data.padding.begin + data.padding.end),
+        padding.begin,
+        data.size - (padding.begin + padding.end),
         data.spacing,
     );
 
     let mut result = SharedVector::with_capacity(4 * data.cells.len());
     for cell in data.cells.iter() {
-        let cdata = &layout_data[cell.col_or_row as usize];
-        result.push(cdata.pos);
-        result.push({
-            let first_cell = &layout_data[cell.col_or_row as usize];
-            let last_cell = &layout_data[cell.col_or_row as usize + cell.span as usize - 1];
-            last_cell.pos + last_cell.size - first_cell.pos
+        let first_cell = &layout_data[cell.col_or_row as usize];
+        let last_cell = &layout_data[cell.col_or_row as usize + cell.span as usize - 1];
+        let size = last_cell.pos + last_cell.size - first_cell.pos;
+        result.push(match data.direction {
+            LayoutDirection::LeftToRight => first_cell.pos,
+            LayoutDirection::RightToLeft => data.size - first_cell.pos - size,
         });
+        result.push(size);
     }
     result
 }
@@ -494,6 +509,8 @@ pub struct BoxLayoutData<'a> {
     pub spacing: Coord,
     pub padding: Padding,
     pub alignment: LayoutAlignment,
+    /// When right-to-left, the items are placed from the right. Always left-to-right for vertical layouts.
+    pub direction: LayoutDirection,
     pub cells: Slice<'a, BoxLayoutCellData>,
 }
 
@@ -505,77 +522,90 @@ pub struct BoxLayoutCellData {
 
 /// Solve a BoxLayout
 pub fn solve_box_layout(data: &BoxLayoutData, repeater_indexes: Slice<u32>) -> SharedVector<Coord> {
-    let mut result = SharedVector::<Coord>::default();
-    result.resize(data.cells.len() * 2 + repeater_indexes.len(), 0 as _);
-
     if data.cells.is_empty() {
-        return result;
-    }
-
-    let mut layout_data: Vec<_> = data
-        .cells
-        .iter()
-        .map(|c| {
-            let min = c.constraint.min.max(c.constraint.min_percent * data.size / 100 as Coord);
-            let max = c.constraint.max.min(c.constraint.max_percent * data.size / 100 as Coord);
-            grid_internal::LayoutData {
-                min,
-                max,
-                pref: c.constraint.preferred.min(max).max(min),
-                stretch: c.constraint.stretch,
-                ..Default::default()
-            }
-        })
-        .collect();
+        return layout_result(&[], repeater_indexes);
+    }
+
+    let mut layout_data: Vec<_> =
+        data.cells.iter().map(|c| to_box_layout_data(&c.constraint, data.size)).collect();
+    let padding = data.padding.for_direction(data.direction);
+    box_layout_items(&mut layout_data, data.size, data.spacing, &padding, data.alignment);
+    if data.direction == LayoutDirection::RightToLeft {
+        for it in &mut layout_data {
+            it.pos = data.size - it.pos - it.size;
+        }
+    }
+    layout_result(&layout_data, repeater_indexes)
+}
+
+/// Create the LayoutData for a cell of a layout of the given size, taking the percentage constraints into account
+fn to_box_layout_data(constraint: &LayoutInfo, size: Coord) -> grid_internal::LayoutData {
+    let min = constraint.min.max(constraint.min_percent * size / 100 as Coord);
+    let max = constraint.max.min(constraint.max_percent * size / 100 as Coord);
+    grid_internal::LayoutData {
+        min,
+        max,
+        pref: constraint.preferred.min(max).max(min),
+        stretch: constraint.stretch,
+        ..Default::default()
+    }
+}
 
-    let size_without_padding = data.size - data.padding.begin - data.padding.end;
+/// Compute the position and size of the items placed in a row of the given size, according to the alignment
+fn box_layout_items(
+    layout_data: &mut [grid_internal::LayoutData],
+    size: Coord,
+    spacing: Coord,
+    padding: &Padding,
+    alignment: LayoutAlignment,
+) {
+    let size_without_padding = size - padding.begin - padding.end;
     let pref_size: Coord = layout_data.iter().map(|it| it.pref).sum();
     let num_spacings = (layout_data.len() - 1) as Coord;
-    let spacings = data.spacing * num_spacings;
+    let spacings = spacing * num_spacings;
 
-    let align = match data.alignment {
+    let align = match alignment {
         LayoutAlignment::Stretch => {
-            grid_internal::layout_items(
-                &mut layout_data,
-                data.padding.begin,
-                size_without_padding,
-                data.spacing,
-            );
+            grid_internal::layout_items(layout_data, padding.begin, size_without_padding, spacing);
             None
         }
         _ if size_without_padding <= pref_size + spacings => {
-            grid_internal::layout_items(
-                &mut layout_data,
-                data.padding.begin,
-                size_without_padding,
-                data.spacing,
-            );
+            grid_internal::layout_items(layout_data, padding.begin, size_without_padding, spacing);
             None
         }
         LayoutAlignment::Center => Some((
-            data.padding.begin + (size_without_padding - pref_size - spacings) / 2 as Coord,
-            data.spacing,
+            padding.begin + (size_without_padding - pref_size - spacings) / 2 as Coord,
+            spacing,
         )),
-        LayoutAlignment::Start => Some((data.padding.begin, data.spacing)),
+        LayoutAlignment::Start => Some((padding.begin, spacing)),
         LayoutAlignment::End => {
-            Some((data.padding.begin + (size_without_padding - pref_size - spacings), data.spacing))
+            Some((padding.begin + (size_without_padding - pref_size - spacings), spacing))
         }
         LayoutAlignment::SpaceBetween => {
-            Some((data.padding.begin, (size_without_padding - pref_size) / num_spacings))
+            Some((padding.begin, (size_without_padding - pref_size) / num_spacings))
         }
         LayoutAlignment::SpaceAround => {
             let spacing = (size_without_padding - pref_size) / (num_spacings + 1 as Coord);
-            Some((data.padding.begin + spacing / 2 as Coord, spacing))
+            Some((padding.begin + spacing / 2 as Coord, spacing))
         }
     };
     if let Some((mut pos, spacing)) = align {
-        for it in &mut layout_data {
+        for it in layout_data {
             it.pos = pos;
             it.size = it.pref;
             pos += spacing + it.size;
         }
     }
+}
 
+/// Return the layout cache with the position and size of each item, as expected by the generated code:
+/// The repeated items are placed after the non-repeated ones, and the `repeater_indexes` are used to find them.
+fn layout_result(
+    layout_data: &[grid_internal::LayoutData],
+    repeater_indexes: Slice<u32>,
+) -> SharedVector<Coord> {
+    let mut result = SharedVector::<Coord>::default();
+    result.resize(layout_data.len() * 2 + repeater_indexes.len(), 0 as _);
     let res = result.make_mut_slice();
 
     // The index/2 in result in which we should add the next repeated item
@@ -656,6 +686,187 @@ pub fn box_layout_info_ortho(cells: Slice<BoxLayoutCellData>, padding: &Padding)
     fold
 }
 
+#[repr(C)]
+#[derive(Debug)]
+/// The FlowLayoutData represents a FlowLayout in the direction of the flow.
+/// For a horizontal flow, the size, spacing and padding are the horizontal ones and the
+/// cells are wrapped into rows. For a vertical flow, they are the vertical ones and the
+/// cells are wrapped into columns.
+pub struct FlowLayoutData<'a> {
+    pub size: Coord,
+    pub spacing: Coord,
+    pub padding: Padding,
+    pub alignment: LayoutAlignment,
+    pub wrap: bool,
+    /// When right-to-left, the cells of each row are placed from the right. Always left-to-right
+    /// for vertical flows.
+    pub direction: LayoutDirection,
+    pub cells: Slice<'a, BoxLayoutCellData>,
+}
+
+#[repr(C)]
+#[derive(Debug)]
+/// The FlowCrossLayoutData represents a FlowLayout in the direction orthogonal to the flow.
+/// The spacing is the space between the rows (or columns), and the cells are in the same
+/// order as the cells of the corresponding [`FlowLayoutData`].
+pub struct FlowCrossLayoutData<'a> {
+    pub size: Coord,
+    pub spacing: Coord,
+    pub padding: Padding,
+    pub alignment: LayoutCrossAlignment,
+    pub cells: Slice<'a, BoxLayoutCellData>,
+}
+
+/// Place the cells of a flow layout in the direction of the flow, and return the ranges
+/// of cells that form the rows (or columns)
+fn flow_layout_rows(
+    data: &FlowLayoutData,
+) -> (Vec<grid_internal::LayoutData>, Vec<core::ops::Range<usize>>) {
+    let mut layout_data: Vec<_> =
+        data.cells.iter().map(|c| to_box_layout_data(&c.constraint, data.size)).collect();
+    let available = data.size - data.padding.begin - data.padding.end;
+    let mut rows = Vec::new();
+    let mut row_start = 0;
+    let mut row_size = 0 as Coord;
+    for (idx, it) in layout_data.iter().enumerate() {
+        if idx > row_start {
+            if data.wrap && row_size + data.spacing + it.pref > available {
+                rows.push(row_start..idx);
+                row_start = idx;
+                row_size = it.pref;
+            } else {
+                row_size += data.spacing + it.pref;
+            }
+        } else {
+            row_size = it.pref;
+        }
+    }
+    if row_start < layout_data.len() {
+        rows.push(row_start..layout_data.len());
+    }
+    let padding = data.padding.for_direction(data.direction);
+    for row in &rows {
+        box_layout_items(
+            &mut layout_data[row.clone()],
+            data.size,
+            data.spacing,
+            &padding,
+            data.alignment,
+        );
+    }
+    (layout_data, rows)
+}
+
+/// Solve a FlowLayout in the direction of the flow
+pub fn solve_flow_layout(
+    data: &FlowLayoutData,
+    repeater_indexes: Slice<u32>,
+) -> SharedVector<Coord> {
+    let (mut layout_data, _) = flow_layout_rows(data);
+    if data.direction == LayoutDirection::RightToLeft {
+        for it in &mut layout_data {
+            it.pos = data.size - it.pos - it.size;
+        }
+    }
+    layout_result(&layout_data, repeater_indexes)
+}
+
+/// Solve a FlowLayout in the direction orthogonal to the flow: the cells of `cross` are placed
+/// in the rows (or columns) that result from the `data`.
+pub fn solve_flow_layout_cross(
+    data: &FlowLayoutData,
+    cross: &FlowCrossLayoutData,
+    repeater_indexes: Slice<u32>,
+) -> SharedVector<Coord> {
+    let (_, rows) = flow_layout_rows(data);
+    let mut layout_data: Vec<_> =
+        cross.cells.iter().map(|c| to_box_layout_data(&c.constraint, cross.size)).collect();
+    let mut pos = cross.padding.begin;
+    for row in rows {
+        let row_data = &mut layout_data[row];
+        let row_size = row_data.iter().map(|it| it.pref).fold(0 as Coord, |a, b| a.max(b));
+        for it in row_data.iter_mut() {
+            let (offset, size) = match cross.alignment {
+                LayoutCrossAlignment::Stretch => (0 as Coord, row_size.min(it.max).max(it.min)),
+                LayoutCrossAlignment::Start => (0 as Coord, it.pref),
+                LayoutCrossAlignment::Center => ((row_size - it.pref) / 2 as Coord, it.pref),
+                LayoutCrossAlignment::End => (row_size - it.pref, it.pref),
+            };
+            it.pos = pos + offset;
+            it.size = size;
+        }
+        pos += row_size + cross.spacing;
+    }
+    layout_result(&layout_data, repeater_indexes)
+}
+
+/// Return the LayoutInfo for a FlowLayout in the direction of the flow.
+pub fn flow_layout_info(
+    cells: Slice<BoxLayoutCellData>,
+    spacing: Coord,
+    padding: &Padding,
+    alignment: LayoutAlignment,
+    wrap: bool,
+) -> LayoutInfo {
+    if !wrap {
+        return box_layout_info(cells, spacing, padding, alignment);
+    }
+    let count = cells.len();
+    if count < 1 {
+        return LayoutInfo { max: 0 as _, ..LayoutInfo::default() };
+    };
+    let extra_w = padding.begin + padding.end;
+    // Each element may be on its own row, but all of them are in the same row when there is enough space
+    let min = cells.iter().map(|c| c.constraint.min).fold(0 as Coord, |a, b| a.max(b)) + extra_w;
+    let preferred = cells.iter().map(|c| c.constraint.preferred_bounded()).sum::<Coord>()
+        + spacing * (count - 1) as Coord
+        + extra_w;
+    let stretch = cells.iter().map(|c| c.constraint.stretch).sum::<f32>();
+    LayoutInfo {
+        min,
+        max: Coord::MAX,
+        min_percent: 0 as _,
+        max_percent: 100 as _,
+        preferred: preferred.max(min),
+        stretch,
+    }
+}
+
+/// Return the LayoutInfo for a FlowLayout in the direction orthogonal to the flow.
+/// It depends on the rows (or columns) that result from the `data`.
+pub fn flow_layout_info_cross(
+    data: &FlowLayoutData,
+    cells: Slice<BoxLayoutCellData>,
+    spacing: Coord,
+    padding: &Padding,
+) -> LayoutInfo {
+    let (_, rows) = flow_layout_rows(data);
+    if rows.is_empty() {
+        return LayoutInfo { max: 0 as _, ..LayoutInfo::default() };
+    }
+    let extra_w = padding.begin + padding.end + spacing * (rows.len() - 1) as Coord;
+    let mut min = extra_w;
+    let mut preferred = extra_w;
+    for row in rows {
+        let row_cells = &cells.as_slice()[row];
+        let row_min = row_cells.iter().map(|c| c.constraint.min).fold(0 as Coord, |a, b| a.max(b));
+        min += row_min;
+        preferred += row_cells
+            .iter()
+            .map(|c| c.constraint.preferred_bounded())
+            .fold(row_min, |a, b| a.max(b));
+    }
+    let stretch = cells.iter().map(|c| c.constraint.stretch).fold(f32::MAX, f32::min);
+    LayoutInfo {
+        min,
+        max: Coord::MAX,
+        min_percent: 0 as _,
+        max_percent: 100 as _,
+        preferred,
+        stretch,
+    }
+}
+
 /// Given the cells of a layout of a Dialog, re-order the button according to the platform
 ///
 /// This function assume that the `roles` contains the roles of the button which are the first `cells`
@@ -777,6 +988,48 @@ pub extern "C" fn slint_box_layout_info_ortho(
         super::box_layout_info_ortho(cells, padding)
     }
 
+    #[no_mangle]
+    pub extern "C" fn slint_solve_flow_layout(
+        data: &FlowLayoutData,
+        repeater_indexes: Slice<u32>,
+        result: &mut SharedVector<Coord>,
+    ) {
+        *result = super::solve_flow_layout(data, repeater_indexes)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_solve_flow_layout_cross(
+        data: &FlowLayoutData,
+        cross: &FlowCrossLayoutData,
+        repeater_indexes: Slice<u32>,
+        result: &mut SharedVector<Coord>,
+    ) {
+        *result = super::solve_flow_layout_cross(data, cross, repeater_indexes)
+    }
+
+    #[no_mangle]
+    /// Return the LayoutInfo for a FlowLayout in the direction of the flow.
+    pub extern "C" fn slint_flow_layout_info(
+        cells: Slice<BoxLayoutCellData>,
+        spacing: Coord,
+        padding: &Padding,
+        alignment: LayoutAlignment,
+        wrap: bool,
+    ) -> LayoutInfo {
+        super::flow_layout_info(cells, spacing, padding, alignment, wrap)
+    }
+
+    #[no_mangle]
+    /// Return the LayoutInfo for a FlowLayout in the direction orthogonal to the flow.
+    pub extern "C" fn slint_flow_layout_info_cross(
+        data: &FlowLayoutData,
+        cells: Slice<BoxLayoutCellData>,
+        spacing: Coord,
+        padding: &Padding,
+    ) -> LayoutInfo {
+        super::flow_layout_info_cross(data, cells, spacing, padding)
+    }
+
     /// Calls [`reorder_dialog_button_layout`].
     ///
     /// Safety: `cells` must be a pointer to a mutable array of cell data, the array must have at
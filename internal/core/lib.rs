@@ -24,6 +24,7 @@
 pub mod callbacks;
 pub mod component_factory;
 pub mod context;
+pub mod date_time;
 pub mod future;
 pub mod graphics;
 pub mod input;
@@ -74,7 +75,6 @@
 #[doc(inline)]
 pub use graphics::RgbaColor;
 
-#[cfg(feature = "std")]
 #[doc(inline)]
 pub use graphics::PathData;
 
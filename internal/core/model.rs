@@ -7,7 +7,7 @@
 
 use crate::item_tree::ItemTreeVTable;
 use crate::item_tree::TraversalOrder;
-pub use crate::items::{StandardListViewItem, TableColumn};
+pub use crate::items::{StandardListViewItem, StandardTreeViewItem, TableColumn};
 use crate::layout::Orientation;
 use crate::lengths::{LogicalLength, RectLengths};
 use crate::{Coord, Property, SharedString, SharedVector};
@@ -26,6 +26,9 @@
 
 mod adapters;
 mod model_peer;
+mod tree_model;
+
+pub use tree_model::{FlattenedTreeModel, TreeModelNotify, TreeModelPeer};
 
 type ItemTreeRc<C> = vtable::VRc<crate::item_tree::ItemTreeVTable, C>;
 
@@ -249,6 +252,115 @@ fn reverse(self) -> ReverseModel<Self>
 
 impl<T: Model> ModelExt for T {}
 
+/// This trait defines the interface that users of a [`TreeModel`] can use to track changes
+/// to the tree. It is supplied via [`TreeModel::model_tracker`] and implementation usually
+/// return a reference to its field of [`TreeModelNotify`].
+pub trait TreeModelTracker {
+    /// Attach one peer. The peer will be notified when the tree changes
+    fn attach_peer(&self, peer: TreeModelPeer);
+}
+
+impl TreeModelTracker for () {
+    fn attach_peer(&self, _peer: TreeModelPeer) {}
+}
+
+/// A TreeModel is providing hierarchical data, such as the content of a file system, for the
+/// `StandardTreeView` widget of the `.slint` language.
+///
+/// A node of the tree is identified by its path: the index of the node within its parent,
+/// preceded by the path of the parent. The top level nodes are the children of the empty path.
+///
+/// Views only query the children of the nodes that the user expanded, so the children can
+/// be loaded lazily. [`TreeModel::has_children`] can be re-implemented to tell whether a
+/// node has children without loading them.
+///
+/// Use a [`FlattenedTreeModel`] to set a tree model to a property of type
+/// `[StandardTreeViewItem]`.
+///
+/// If the tree can be changed, the type implementing the TreeModel trait should hold
+/// a [`TreeModelNotify`], and call functions on it to let the views know that something
+/// has changed.
+///
+/// ## Example
+///
+/// ```
+/// # use i_slint_core::model::{TreeModel, TreeModelTracker, StandardListViewItem};
+/// struct Chapters(Vec<(&'static str, Vec<&'static str>)>);
+///
+/// impl TreeModel for Chapters {
+///     type Data = StandardListViewItem;
+///
+///     fn child_count(&self, path: &[usize]) -> usize {
+///         match path {
+///             [] => self.0.len(),
+///             [chapter] => self.0[*chapter].1.len(),
+///             _ => 0,
+///         }
+///     }
+///
+///     fn data(&self, path: &[usize]) -> Option<Self::Data> {
+///         match path {
+///             [chapter] => self.0.get(*chapter).map(|c| c.0.into()),
+///             [chapter, section] => self.0.get(*chapter)?.1.get(*section).map(|s| (*s).into()),
+///             _ => None,
+///         }
+///     }
+///
+///     fn model_tracker(&self) -> &dyn TreeModelTracker {
+///         &()
+///     }
+/// }
+/// ```
+pub trait TreeModel {
+    /// The data of each node of the tree
+    type Data;
+    /// The amount of children of the node at `path`. The empty path gives the amount of top level nodes.
+    fn child_count(&self, path: &[usize]) -> usize;
+    /// Returns the data of the node at `path`, or None if there is no such node.
+    fn data(&self, path: &[usize]) -> Option<Self::Data>;
+    /// Returns true if the node at `path` has children.
+    ///
+    /// The default implementation calls [`TreeModel::child_count`]. Models that load their
+    /// children lazily can re-implement it to avoid loading them.
+    fn has_children(&self, path: &[usize]) -> bool {
+        self.child_count(path) > 0
+    }
+
+    /// The implementation should return a reference to its [`TreeModelNotify`] field.
+    ///
+    /// You can return `&()` if the tree is constant and does not have a TreeModelNotify field.
+    fn model_tracker(&self) -> &dyn TreeModelTracker;
+
+    /// Return something that can be downcast'ed (typically self)
+    fn as_any(&self) -> &dyn core::any::Any {
+        &()
+    }
+}
+
+impl<M: TreeModel> TreeModel for Rc<M> {
+    type Data = M::Data;
+
+    fn child_count(&self, path: &[usize]) -> usize {
+        (**self).child_count(path)
+    }
+
+    fn data(&self, path: &[usize]) -> Option<Self::Data> {
+        (**self).data(path)
+    }
+
+    fn has_children(&self, path: &[usize]) -> bool {
+        (**self).has_children(path)
+    }
+
+    fn model_tracker(&self) -> &dyn TreeModelTracker {
+        (**self).model_tracker()
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        (**self).as_any()
+    }
+}
+
 /// An iterator over the elements of a model.
 /// This struct is created by the [`Model::iter()`] trait function.
 pub struct ModelIterator<'a, T> {
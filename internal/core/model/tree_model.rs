@@ -0,0 +1,398 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! This module contains the types to notify changes of a [`TreeModel`], and the adapter
+//! that lists its nodes in a flat model.
+
+use super::*;
+use alloc::rc::Weak;
+
+trait TreeModelChangeListener {
+    fn data_changed(&self, path: &[usize]);
+    fn children_changed(&self, path: &[usize]);
+    fn reset(&self);
+}
+
+/// Represents a handle to the view that listens to changes to a [`TreeModel`].
+#[derive(Clone)]
+pub struct TreeModelPeer {
+    inner: Weak<dyn TreeModelChangeListener>,
+}
+
+/// Dispatch notifications from a [`TreeModel`] to one or several [`TreeModelPeer`].
+/// Typically, you would want to put this in the implementation of the TreeModel
+#[derive(Default)]
+pub struct TreeModelNotify {
+    peers: RefCell<Vec<TreeModelPeer>>,
+}
+
+impl TreeModelNotify {
+    /// Notify the peers that the data of the node at `path` was changed
+    pub fn data_changed(&self, path: &[usize]) {
+        self.for_each_peer(|peer| peer.data_changed(path));
+    }
+
+    /// Notify the peers that children were added to or removed from the node at `path`,
+    /// or that its children were changed in any other way. The empty path stands for the
+    /// top level nodes.
+    pub fn children_changed(&self, path: &[usize]) {
+        self.for_each_peer(|peer| peer.children_changed(path));
+    }
+
+    /// Notify the peers that the tree has been changed and that everything needs to be reloaded
+    pub fn reset(&self) {
+        self.for_each_peer(|peer| peer.reset());
+    }
+
+    fn for_each_peer(&self, f: impl Fn(&dyn TreeModelChangeListener)) {
+        self.peers.borrow_mut().retain(|peer| peer.inner.strong_count() > 0);
+        let peers = self.peers.borrow().clone();
+        for peer in peers {
+            if let Some(peer) = peer.inner.upgrade() {
+                f(&*peer);
+            }
+        }
+    }
+}
+
+impl TreeModelTracker for TreeModelNotify {
+    fn attach_peer(&self, peer: TreeModelPeer) {
+        self.peers.borrow_mut().push(peer);
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct FlattenedRow {
+    path: Vec<usize>,
+    expanded: bool,
+}
+
+struct FlattenedTreeModelInner<M> {
+    tree: M,
+    // The nodes that are listed, in depth-first order
+    rows: RefCell<Vec<FlattenedRow>>,
+    notify: ModelNotify,
+}
+
+impl<M: TreeModel> FlattenedTreeModelInner<M> {
+    fn child_rows(&self, path: &[usize]) -> Vec<FlattenedRow> {
+        (0..self.tree.child_count(path))
+            .map(|index| {
+                let mut path = path.to_vec();
+                path.push(index);
+                FlattenedRow { path, expanded: false }
+            })
+            .collect()
+    }
+
+    /// Returns the row after the last descendant of `row`
+    fn end_of_descendants(rows: &[FlattenedRow], row: usize) -> usize {
+        let level = rows[row].path.len();
+        rows[row + 1..]
+            .iter()
+            .position(|r| r.path.len() <= level)
+            .map_or(rows.len(), |n| row + 1 + n)
+    }
+
+    fn set_expanded(&self, row: usize, expanded: bool) {
+        let Some(path) = self.rows.borrow().get(row).map(|r| r.path.clone()) else { return };
+        if self.rows.borrow()[row].expanded == expanded {
+            return;
+        }
+        if expanded {
+            let children = self.child_rows(&path);
+            let count = children.len();
+            let mut rows = self.rows.borrow_mut();
+            rows[row].expanded = true;
+            rows.splice(row + 1..row + 1, children);
+            drop(rows);
+            self.notify.row_changed(row);
+            if count > 0 {
+                self.notify.row_added(row + 1, count);
+            }
+        } else {
+            let mut rows = self.rows.borrow_mut();
+            let end = Self::end_of_descendants(&rows, row);
+            rows[row].expanded = false;
+            rows.drain(row + 1..end);
+            drop(rows);
+            self.notify.row_changed(row);
+            if end > row + 1 {
+                self.notify.row_removed(row + 1, end - row - 1);
+            }
+        }
+    }
+
+    fn row_of(&self, path: &[usize]) -> Option<usize> {
+        self.rows.borrow().iter().position(|r| r.path == path)
+    }
+}
+
+impl<M: TreeModel> TreeModelChangeListener for FlattenedTreeModelInner<M> {
+    fn data_changed(&self, path: &[usize]) {
+        if let Some(row) = self.row_of(path) {
+            self.notify.row_changed(row);
+        }
+    }
+
+    fn children_changed(&self, path: &[usize]) {
+        if path.is_empty() {
+            return self.reset();
+        }
+        let Some(row) = self.row_of(path) else { return };
+        if self.rows.borrow()[row].expanded {
+            // The descendants are listed again, collapsed, as their paths may have changed.
+            let end = Self::end_of_descendants(&self.rows.borrow(), row);
+            self.rows.borrow_mut().drain(row + 1..end);
+            if end > row + 1 {
+                self.notify.row_removed(row + 1, end - row - 1);
+            }
+            let children = self.child_rows(path);
+            let count = children.len();
+            let mut rows = self.rows.borrow_mut();
+            rows[row].expanded = count > 0;
+            rows.splice(row + 1..row + 1, children);
+            drop(rows);
+            if count > 0 {
+                self.notify.row_added(row + 1, count);
+            }
+        }
+        self.notify.row_changed(row);
+    }
+
+    fn reset(&self) {
+        *self.rows.borrow_mut() = self.child_rows(&[]);
+        self.notify.reset();
+    }
+}
+
+/// Lists the nodes of a [`TreeModel`] in a flat model of [`StandardTreeViewItem`], so that
+/// the tree can be shown in a `StandardTreeView`.
+///
+/// The top level nodes are listed first, each followed by its descendants when it is expanded.
+/// The children of a node are only queried from the tree when the node is expanded, which
+/// happens when the view sets the row with the `expanded` field set to true, or when
+/// [`FlattenedTreeModel::set_expanded`] is called.
+///
+/// ## Example
+///
+/// ```
+/// # use i_slint_core::model::*;
+/// # struct Chapters(Vec<(&'static str, Vec<&'static str>)>);
+/// # impl TreeModel for Chapters {
+/// #     type Data = StandardListViewItem;
+/// #     fn child_count(&self, path: &[usize]) -> usize {
+/// #         match path { [] => self.0.len(), [c] => self.0[*c].1.len(), _ => 0 }
+/// #     }
+/// #     fn data(&self, path: &[usize]) -> Option<Self::Data> {
+/// #         match path {
+/// #             [c] => self.0.get(*c).map(|c| c.0.into()),
+/// #             [c, s] => self.0.get(*c)?.1.get(*s).map(|s| (*s).into()),
+/// #             _ => None,
+/// #         }
+/// #     }
+/// #     fn model_tracker(&self) -> &dyn TreeModelTracker { &() }
+/// # }
+/// let model = FlattenedTreeModel::new(Chapters(vec![
+///     ("Introduction", vec![]),
+///     ("Usage", vec!["Installation", "Configuration"]),
+/// ]));
+/// assert_eq!(model.row_count(), 2);
+/// model.set_expanded(1, true);
+/// assert_eq!(model.row_count(), 4);
+/// assert_eq!(model.row_data(3).unwrap().text, "Configuration");
+/// assert_eq!(model.row_data(3).unwrap().level, 1);
+/// assert_eq!(model.path(3).unwrap(), [1, 1]);
+/// ```
+pub struct FlattenedTreeModel<M>(Rc<FlattenedTreeModelInner<M>>);
+
+impl<M> FlattenedTreeModel<M>
+where
+    M: TreeModel<Data = StandardListViewItem> + 'static,
+{
+    /// Creates a new FlattenedTreeModel listing the top level nodes of `tree`, collapsed.
+    pub fn new(tree: M) -> Self {
+        let inner = Rc::new(FlattenedTreeModelInner {
+            tree,
+            rows: Default::default(),
+            notify: Default::default(),
+        });
+        *inner.rows.borrow_mut() = inner.child_rows(&[]);
+        let listener: Rc<dyn TreeModelChangeListener> = inner.clone();
+        inner.tree.model_tracker().attach_peer(TreeModelPeer { inner: Rc::downgrade(&listener) });
+        Self(inner)
+    }
+
+    /// Expands or collapses the node listed at `row`.
+    pub fn set_expanded(&self, row: usize, expanded: bool) {
+        self.0.set_expanded(row, expanded)
+    }
+
+    /// Returns the path in the tree of the node listed at `row`.
+    pub fn path(&self, row: usize) -> Option<Vec<usize>> {
+        self.0.rows.borrow().get(row).map(|r| r.path.clone())
+    }
+
+    /// Returns the row at which the node at `path` is listed, or None if it's not listed
+    /// because one of its ancestors is collapsed.
+    pub fn row_of(&self, path: &[usize]) -> Option<usize> {
+        self.0.row_of(path)
+    }
+
+    /// Returns a reference to the tree model.
+    pub fn source_model(&self) -> &M {
+        &self.0.tree
+    }
+}
+
+impl<M> Model for FlattenedTreeModel<M>
+where
+    M: TreeModel<Data = StandardListViewItem> + 'static,
+{
+    type Data = StandardTreeViewItem;
+
+    fn row_count(&self) -> usize {
+        self.0.rows.borrow().len()
+    }
+
+    fn row_data(&self, row: usize) -> Option<Self::Data> {
+        let FlattenedRow { path, expanded } = self.0.rows.borrow().get(row)?.clone();
+        Some(StandardTreeViewItem {
+            text: self.0.tree.data(&path).map(|data| data.text).unwrap_or_default(),
+            level: path.len() as i32 - 1,
+            has_children: self.0.tree.has_children(&path),
+            expanded,
+        })
+    }
+
+    /// Only the `expanded` field can be changed, which expands or collapses the node.
+    fn set_row_data(&self, row: usize, data: Self::Data) {
+        self.0.set_expanded(row, data.expanded)
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        &self.0.notify
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct TestTree {
+        // The children of the top level nodes
+        nodes: RefCell<Vec<(SharedString, Vec<SharedString>)>>,
+        notify: TreeModelNotify,
+        loaded: RefCell<Vec<Vec<usize>>>,
+    }
+
+    impl TreeModel for TestTree {
+        type Data = StandardListViewItem;
+
+        fn child_count(&self, path: &[usize]) -> usize {
+            self.loaded.borrow_mut().push(path.to_vec());
+            match path {
+                [] => self.nodes.borrow().len(),
+                [node] => self.nodes.borrow()[*node].1.len(),
+                _ => 0,
+            }
+        }
+
+        fn data(&self, path: &[usize]) -> Option<Self::Data> {
+            let nodes = self.nodes.borrow();
+            match path {
+                [node] => nodes.get(*node).map(|n| n.0.clone().into()),
+                [node, child] => nodes.get(*node)?.1.get(*child).map(|c| c.clone().into()),
+                _ => None,
+            }
+        }
+
+        fn has_children(&self, path: &[usize]) -> bool {
+            path.len() == 1
+        }
+
+        fn model_tracker(&self) -> &dyn TreeModelTracker {
+            &self.notify
+        }
+    }
+
+    fn texts(model: &impl Model<Data = StandardTreeViewItem>) -> Vec<(String, i32, bool)> {
+        model.iter().map(|item| (item.text.to_string(), item.level, item.expanded)).collect()
+    }
+
+    #[test]
+    fn test_flattened_tree_model() {
+        let tree = Rc::new(TestTree::default());
+        *tree.nodes.borrow_mut() = vec![
+            ("a".into(), vec!["a1".into(), "a2".into()]),
+            ("b".into(), vec![]),
+            ("c".into(), vec!["c1".into()]),
+        ];
+        let model = Rc::new(FlattenedTreeModel::new(tree.clone()));
+        let view = super::super::adapters::ModelChecker::new(model.clone());
+
+        assert_eq!(
+            texts(&*model),
+            [("a".into(), 0, false), ("b".into(), 0, false), ("c".into(), 0, false)]
+        );
+        // children are only loaded when their parent is expanded
+        assert_eq!(*tree.loaded.borrow(), [Vec::<usize>::new()]);
+
+        let mut item = model.row_data(2).unwrap();
+        item.expanded = true;
+        model.set_row_data(2, item);
+        model.set_expanded(0, true);
+        assert_eq!(
+            texts(&*model),
+            [
+                ("a".into(), 0, true),
+                ("a1".into(), 1, false),
+                ("a2".into(), 1, false),
+                ("b".into(), 0, false),
+                ("c".into(), 0, true),
+                ("c1".into(), 1, false),
+            ]
+        );
+        assert_eq!(model.path(5).unwrap(), [2, 0]);
+        assert_eq!(model.row_of(&[2, 0]), Some(5));
+        view.check();
+
+        tree.nodes.borrow_mut()[0].1.remove(0);
+        tree.notify.children_changed(&[0]);
+        tree.nodes.borrow_mut()[2].1[0] = "c1'".into();
+        tree.notify.data_changed(&[2, 0]);
+        assert_eq!(
+            texts(&*model),
+            [
+                ("a".into(), 0, true),
+                ("a2".into(), 1, false),
+                ("b".into(), 0, false),
+                ("c".into(), 0, true),
+                ("c1'".into(), 1, false),
+            ]
+        );
+        view.check();
+
+        model.set_expanded(0, false);
+        assert_eq!(
+            texts(&*model),
+            [
+                ("a".into(), 0, false),
+                ("b".into(), 0, false),
+                ("c".into(), 0, true),
+                ("c1'".into(), 1, false)
+            ]
+        );
+        view.check();
+
+        tree.nodes.borrow_mut().remove(1);
+        tree.notify.children_changed(&[]);
+        assert_eq!(texts(&*model), [("a".into(), 0, false), ("c".into(), 0, false)]);
+        view.check();
+    }
+}
@@ -110,6 +110,12 @@ fn click_interval(&self) -> core::time::Duration {
         core::time::Duration::from_millis(500)
     }
 
+    /// Returns the delay after which the tooltip of an element is shown, when the pointer
+    /// stays over that element.
+    fn tooltip_delay(&self) -> core::time::Duration {
+        core::time::Duration::from_millis(700)
+    }
+
     /// Sends the given text into the system clipboard.
     ///
     /// If the platform doesn't support the specified clipboard, this function should do nothing
@@ -348,6 +354,48 @@ pub enum WindowEvent {
     /// The backend should dispatch this event with true when the window gains focus
     /// and false when the window loses focus.
     WindowActiveChanged(bool),
+    /// A finger touched the screen.
+    ///
+    /// The first touch point is treated like a mouse pointer with the left button pressed.
+    /// Pressing a second touch point in the same `GestureArea` starts a pinch and rotation gesture,
+    /// otherwise each touch point presses the items under it with its own grab.
+    TouchPressed {
+        /// Identifies the touch point until it is released.
+        touch_id: u64,
+        position: LogicalPosition,
+    },
+    /// A touch point moved.
+    TouchMoved {
+        /// The id of a touch point previously sent with [`WindowEvent::TouchPressed`].
+        touch_id: u64,
+        position: LogicalPosition,
+    },
+    /// A touch point was released or cancelled.
+    TouchReleased {
+        /// The id of a touch point previously sent with [`WindowEvent::TouchPressed`].
+        touch_id: u64,
+        position: LogicalPosition,
+    },
+    /// Data from outside of the window, such as files from the file manager, is dragged over the window.
+    ///
+    /// Send this event each time the pointer moves during the drag.
+    DragMoved {
+        position: LogicalPosition,
+        /// The MIME type of the data, for example `text/uri-list` for a list of files.
+        mime_type: SharedString,
+        /// The data being dragged.
+        data: SharedString,
+    },
+    /// Data from outside of the window was dropped on the window.
+    DragDropped {
+        position: LogicalPosition,
+        /// The MIME type of the data, for example `text/uri-list` for a list of files.
+        mime_type: SharedString,
+        /// The data being dropped.
+        data: SharedString,
+    },
+    /// The data dragged from outside of the window left the window, or the drag was cancelled.
+    DragExited,
 }
 
 impl WindowEvent {
@@ -358,6 +406,11 @@ pub fn position(&self) -> Option<LogicalPosition> {
             WindowEvent::PointerReleased { position, .. } => Some(*position),
             WindowEvent::PointerMoved { position } => Some(*position),
             WindowEvent::PointerScrolled { position, .. } => Some(*position),
+            WindowEvent::TouchPressed { position, .. } => Some(*position),
+            WindowEvent::TouchMoved { position, .. } => Some(*position),
+            WindowEvent::TouchReleased { position, .. } => Some(*position),
+            WindowEvent::DragMoved { position, .. } => Some(*position),
+            WindowEvent::DragDropped { position, .. } => Some(*position),
             _ => None,
         }
     }
@@ -2,8 +2,10 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
 
 use super::*;
+use crate::animations::EasingCurve;
 use crate::graphics::{Brush, Color};
 use crate::items::PropertyAnimation;
+use crate::SharedVector;
 
 #[allow(non_camel_case_types)]
 type c_void = ();
@@ -228,6 +230,13 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
     transition_data: Option<
         extern "C" fn(user_data: *mut c_void, start_instant: &mut u64) -> PropertyAnimation,
     >,
+    keyframes: Option<
+        extern "C" fn(
+            user_data: *mut c_void,
+            keyframes: *mut c_void,
+            add_keyframe: extern "C" fn(*mut c_void, f32, &T, &EasingCurve),
+        ),
+    >,
 ) {
     let binding = core::mem::transmute::<
         extern "C" fn(*mut c_void, *mut T),
@@ -250,6 +259,23 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
         T::default(),
         animation_data.cloned().unwrap_or_default(),
     ));
+    let compute_keyframes = keyframes.map(|keyframes| -> properties_animations::KeyframesFn<T> {
+        extern "C" fn add_keyframe<T: Clone>(
+            keyframes: *mut c_void,
+            position: f32,
+            value: &T,
+            easing: &EasingCurve,
+        ) {
+            // Safety: keyframes is the vector passed to the keyframes function below
+            let keyframes = unsafe { &mut *(keyframes as *mut SharedVector<AnimationKeyframe<T>>) };
+            keyframes.push(AnimationKeyframe { position, value: value.clone(), easing: *easing });
+        }
+        Box::new(move || {
+            let mut result = SharedVector::default();
+            keyframes(user_data, &mut result as *mut _ as *mut c_void, add_keyframe::<T>);
+            result
+        })
+    });
     if let Some(transition_data) = transition_data {
         handle.0.set_binding(properties_animations::AnimatedBindingCallable::<T, _> {
             original_binding,
@@ -260,6 +286,7 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
                 let anim = transition_data(user_data, &mut start_instant);
                 Some((anim, crate::animations::Instant(start_instant)))
             },
+            compute_keyframes,
         });
     } else {
         handle.0.set_binding(properties_animations::AnimatedBindingCallable::<T, _> {
@@ -267,6 +294,7 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
             state: Cell::new(properties_animations::AnimatedBindingState::NotAnimating),
             animation_data,
             compute_animation_details: || -> properties_animations::AnimationDetail { None },
+            compute_keyframes,
         });
     }
     handle.0.mark_dirty();
@@ -283,6 +311,13 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
     transition_data: Option<
         extern "C" fn(user_data: *mut c_void, start_instant: &mut u64) -> PropertyAnimation,
     >,
+    keyframes: Option<
+        extern "C" fn(
+            user_data: *mut c_void,
+            keyframes: *mut c_void,
+            add_keyframe: extern "C" fn(*mut c_void, f32, &core::ffi::c_int, &EasingCurve),
+        ),
+    >,
 ) {
     c_set_animated_binding(
         handle,
@@ -291,6 +326,7 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
         drop_user_data,
         animation_data,
         transition_data,
+        keyframes,
     );
 }
 
@@ -305,6 +341,13 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
     transition_data: Option<
         extern "C" fn(user_data: *mut c_void, start_instant: &mut u64) -> PropertyAnimation,
     >,
+    keyframes: Option<
+        extern "C" fn(
+            user_data: *mut c_void,
+            keyframes: *mut c_void,
+            add_keyframe: extern "C" fn(*mut c_void, f32, &f32, &EasingCurve),
+        ),
+    >,
 ) {
     c_set_animated_binding(
         handle,
@@ -313,6 +356,7 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
         drop_user_data,
         animation_data,
         transition_data,
+        keyframes,
     );
 }
 
@@ -327,6 +371,13 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
     transition_data: Option<
         extern "C" fn(user_data: *mut c_void, start_instant: &mut u64) -> PropertyAnimation,
     >,
+    keyframes: Option<
+        extern "C" fn(
+            user_data: *mut c_void,
+            keyframes: *mut c_void,
+            add_keyframe: extern "C" fn(*mut c_void, f32, &Color, &EasingCurve),
+        ),
+    >,
 ) {
     c_set_animated_binding(
         handle,
@@ -335,6 +386,7 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
         drop_user_data,
         animation_data,
         transition_data,
+        keyframes,
     );
 }
 
@@ -349,6 +401,13 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
     transition_data: Option<
         extern "C" fn(user_data: *mut c_void, start_instant: &mut u64) -> PropertyAnimation,
     >,
+    keyframes: Option<
+        extern "C" fn(
+            user_data: *mut c_void,
+            keyframes: *mut c_void,
+            add_keyframe: extern "C" fn(*mut c_void, f32, &Brush, &EasingCurve),
+        ),
+    >,
 ) {
     c_set_animated_binding(
         handle,
@@ -357,6 +416,7 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
         drop_user_data,
         animation_data,
         transition_data,
+        keyframes,
     );
 }
 
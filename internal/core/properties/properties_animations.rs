@@ -2,10 +2,25 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
 
 use super::*;
-use crate::{items::PropertyAnimation, lengths::LogicalLength};
+use crate::animations::EasingCurve;
+use crate::items::{AnimationDirection, PropertyAnimation};
+use crate::{lengths::LogicalLength, SharedVector};
 #[cfg(not(feature = "std"))]
 use num_traits::Float;
 
+/// A keyframe of a property animation: the value that the property has at a given position
+/// of every iteration of the animation.
+#[repr(C)]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AnimationKeyframe<T> {
+    /// The position of the keyframe within an iteration, between 0 and 1
+    pub position: f32,
+    /// The value of the property at this keyframe
+    pub value: T,
+    /// The easing curve used to interpolate from the previous keyframe to this one
+    pub easing: EasingCurve,
+}
+
 enum AnimationState {
     Delaying,
     Animating { current_iteration: u64 },
@@ -16,15 +31,69 @@ pub(super) struct PropertyValueAnimationData<T> {
     from_value: T,
     to_value: T,
     details: PropertyAnimation,
+    /// The intermediate values the animation goes through, sorted by position
+    keyframes: SharedVector<AnimationKeyframe<T>>,
     start_time: crate::animations::Instant,
     state: AnimationState,
+    /// The velocity of a spring animation when it starts, in progress per second
+    initial_velocity: f32,
+    /// The velocity, in units of [`InterpolatedPropertyValue::distance_to`] per second, of a
+    /// spring animation that was interrupted. It is carried over to the next animation.
+    interrupted_velocity: f32,
 }
 
 impl<T: InterpolatedPropertyValue + Clone> PropertyValueAnimationData<T> {
     pub fn new(from_value: T, to_value: T, details: PropertyAnimation) -> Self {
         let start_time = crate::animations::current_tick();
 
-        Self { from_value, to_value, details, start_time, state: AnimationState::Delaying }
+        Self {
+            from_value,
+            to_value,
+            details,
+            keyframes: Default::default(),
+            start_time,
+            state: AnimationState::Delaying,
+            initial_velocity: 0.,
+            interrupted_velocity: 0.,
+        }
+    }
+
+    /// Returns the progress and velocity of a running spring animation
+    fn spring_state(&self, params: &[f32; 3], elapsed_ms: u64) -> (f32, f32) {
+        crate::animations::spring_curve(params, self.initial_velocity, elapsed_ms as f32 / 1000.)
+    }
+
+    /// Returns true if the iteration with the given index plays backward
+    fn is_reversed(&self, iteration: u64) -> bool {
+        match self.details.direction {
+            AnimationDirection::Normal => false,
+            AnimationDirection::Reverse => true,
+            AnimationDirection::Alternate => iteration % 2 == 1,
+            AnimationDirection::AlternateReverse => iteration % 2 == 0,
+        }
+    }
+
+    /// Returns the value at the given progress (between 0 and 1) of an iteration.
+    /// The iteration goes from the start value through the keyframes to the target value.
+    fn value_at(&self, progress: f32) -> T {
+        let mut previous_position = 0.;
+        let mut previous_value = &self.from_value;
+        let (position, value, easing) = self
+            .keyframes
+            .iter()
+            .find_map(|k| {
+                if progress <= k.position {
+                    Some((k.position, &k.value, &k.easing))
+                } else {
+                    previous_position = k.position;
+                    previous_value = &k.value;
+                    None
+                }
+            })
+            .unwrap_or((1., &self.to_value, &self.details.easing));
+        let length = position - previous_position;
+        let t = if length > 0. { (progress - previous_position) / length } else { 1. };
+        previous_value.interpolate(value, crate::animations::easing_curve(easing, t))
     }
 
     pub fn compute_interpolated_value(&mut self) -> (T, bool) {
@@ -52,6 +121,18 @@ pub fn compute_interpolated_value(&mut self) -> (T, bool) {
                 }
             }
             AnimationState::Animating { mut current_iteration } => {
+                // Springs run until they are at rest, regardless of the duration
+                if let (EasingCurve::Spring(params), true, AnimationDirection::Normal) =
+                    (self.details.easing, self.keyframes.is_empty(), self.details.direction)
+                {
+                    let (progress, velocity) = self.spring_state(&params, time_progress);
+                    if crate::animations::spring_is_at_rest(progress, velocity) {
+                        self.state = AnimationState::Done;
+                        return self.compute_interpolated_value();
+                    }
+                    return (self.from_value.interpolate(&self.to_value, progress), false);
+                }
+
                 if self.details.duration <= 0 || self.details.iteration_count == 0. {
                     self.state = AnimationState::Done;
                     return self.compute_interpolated_value();
@@ -71,12 +152,13 @@ pub fn compute_interpolated_value(&mut self) -> (T, bool) {
                 {
                     self.state = AnimationState::Animating { current_iteration };
 
-                    let progress =
+                    let mut progress =
                         (time_progress as f32 / self.details.duration as f32).clamp(0., 1.);
-                    let t = crate::animations::easing_curve(&self.details.easing, progress);
-                    let val = self.from_value.interpolate(&self.to_value, t);
+                    if self.is_reversed(current_iteration) {
+                        progress = 1. - progress;
+                    }
 
-                    (val, false)
+                    (self.value_at(progress), false)
                 } else {
                     self.state = AnimationState::Done;
                     self.compute_interpolated_value()
@@ -87,9 +169,29 @@ pub fn compute_interpolated_value(&mut self) -> (T, bool) {
     }
 
     fn reset(&mut self) {
+        self.interrupted_velocity = match (&self.state, &self.details.easing) {
+            (AnimationState::Animating { .. }, EasingCurve::Spring(params)) => {
+                let elapsed = crate::animations::current_tick()
+                    .duration_since(self.start_time)
+                    .as_millis() as u64;
+                let (_, velocity) = self.spring_state(params, elapsed);
+                self.from_value.distance_to(&self.to_value).map_or(0., |d| velocity * d)
+            }
+            _ => 0.,
+        };
         self.state = AnimationState::Delaying;
         self.start_time = crate::animations::current_tick();
     }
+
+    /// Start the spring animation with the velocity the interrupted animation had, so that
+    /// retargeting an animation in flight doesn't cause a jump in velocity.
+    fn carry_interrupted_velocity(&mut self) {
+        self.initial_velocity = match self.from_value.distance_to(&self.to_value) {
+            Some(distance) if distance.abs() > f32::EPSILON => self.interrupted_velocity / distance,
+            _ => 0.,
+        };
+        self.interrupted_velocity = 0.;
+    }
 }
 
 #[derive(Clone, Copy, Eq, PartialEq, Debug)]
@@ -104,8 +206,11 @@ pub(super) struct AnimatedBindingCallable<T, A> {
     pub(super) state: Cell<AnimatedBindingState>,
     pub(super) animation_data: RefCell<PropertyValueAnimationData<T>>,
     pub(super) compute_animation_details: A,
+    pub(super) compute_keyframes: Option<KeyframesFn<T>>,
 }
 
+pub(super) type KeyframesFn<T> = Box<dyn Fn() -> SharedVector<AnimationKeyframe<T>>>;
+
 pub(super) type AnimationDetail = Option<(PropertyAnimation, crate::animations::Instant)>;
 
 unsafe impl<T: InterpolatedPropertyValue + Clone, A: Fn() -> AnimationDetail> BindingCallable
@@ -142,6 +247,10 @@ unsafe fn evaluate(self: Pin<&Self>, value: *mut ()) -> BindingResult {
                     animation_data.start_time = start_time;
                     animation_data.details = details;
                 }
+                if let Some(compute_keyframes) = &self.compute_keyframes {
+                    animation_data.keyframes = compute_keyframes();
+                }
+                animation_data.carry_interrupted_velocity();
                 let (val, finished) = animation_data.compute_interpolated_value();
                 *value = val;
                 if finished {
@@ -175,24 +284,39 @@ pub trait InterpolatedPropertyValue: PartialEq + Default + 'static {
     /// easing curves it may over- or undershoot though.
     #[must_use]
     fn interpolate(&self, target_value: &Self, t: f32) -> Self;
+
+    /// Returns the signed distance from self to the target value, for types that have one.
+    /// This is used to keep the velocity of spring animations when their target changes.
+    fn distance_to(&self, _target_value: &Self) -> Option<f32> {
+        None
+    }
 }
 
 impl InterpolatedPropertyValue for f32 {
     fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         self + t * (target_value - self)
     }
+    fn distance_to(&self, target_value: &Self) -> Option<f32> {
+        Some(target_value - self)
+    }
 }
 
 impl InterpolatedPropertyValue for i32 {
     fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         self + (t * (target_value - self) as f32).round() as i32
     }
+    fn distance_to(&self, target_value: &Self) -> Option<f32> {
+        Some((target_value - self) as f32)
+    }
 }
 
 impl InterpolatedPropertyValue for i64 {
     fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         self + (t * (target_value - self) as f32).round() as Self
     }
+    fn distance_to(&self, target_value: &Self) -> Option<f32> {
+        Some((target_value - self) as f32)
+    }
 }
 
 impl InterpolatedPropertyValue for u8 {
@@ -200,12 +324,18 @@ fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         ((*self as f32) + (t * ((*target_value as f32) - (*self as f32)))).round().min(255.).max(0.)
             as u8
     }
+    fn distance_to(&self, target_value: &Self) -> Option<f32> {
+        Some(*target_value as f32 - *self as f32)
+    }
 }
 
 impl InterpolatedPropertyValue for LogicalLength {
     fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         LogicalLength::new(self.get().interpolate(&target_value.get(), t))
     }
+    fn distance_to(&self, target_value: &Self) -> Option<f32> {
+        self.get().distance_to(&target_value.get())
+    }
 }
 
 impl<T: Clone + InterpolatedPropertyValue + 'static> Property<T> {
@@ -253,38 +383,30 @@ pub fn set_animated_binding(
         binding: impl Binding<T> + 'static,
         animation_data: PropertyAnimation,
     ) {
-        let binding_callable = properties_animations::AnimatedBindingCallable::<T, _> {
-            original_binding: PropertyHandle {
-                handle: Cell::new(
-                    (alloc_binding_holder(move |val: *mut ()| unsafe {
-                        let val = &mut *(val as *mut T);
-                        *(val as *mut T) = binding.evaluate(val);
-                        BindingResult::KeepBinding
-                    }) as usize)
-                        | 0b10,
-                ),
-            },
-            state: Cell::new(properties_animations::AnimatedBindingState::NotAnimating),
-            animation_data: RefCell::new(properties_animations::PropertyValueAnimationData::new(
-                T::default(),
-                T::default(),
-                animation_data,
-            )),
-            compute_animation_details: || -> properties_animations::AnimationDetail { None },
-        };
+        self.set_animated_binding_impl(
+            binding,
+            animation_data,
+            || -> properties_animations::AnimationDetail { None },
+            None,
+        )
+    }
 
-        // Safety: the `AnimatedBindingCallable`'s type match the property type
-        unsafe {
-            self.handle.set_binding(
-                binding_callable,
-                #[cfg(slint_debug_property)]
-                self.debug_name.borrow().as_str(),
-            )
-        };
-        self.handle.mark_dirty(
-            #[cfg(slint_debug_property)]
-            self.debug_name.borrow().as_str(),
-        );
+    /// Set a binding to this property, with an animation that goes through the keyframes
+    /// returned by `keyframes` before reaching the value of the binding.
+    ///
+    /// The keyframes are computed each time the animation starts.
+    pub fn set_animated_binding_with_keyframes(
+        &self,
+        binding: impl Binding<T> + 'static,
+        animation_data: PropertyAnimation,
+        keyframes: impl Fn() -> SharedVector<AnimationKeyframe<T>> + 'static,
+    ) {
+        self.set_animated_binding_impl(
+            binding,
+            animation_data,
+            || -> properties_animations::AnimationDetail { None },
+            Some(Box::new(keyframes)),
+        )
     }
 
     /// Set a binding to this property, providing a callback for the transition animation
@@ -294,6 +416,39 @@ pub fn set_animated_binding_for_transition(
         binding: impl Binding<T> + 'static,
         compute_animation_details: impl Fn() -> (PropertyAnimation, crate::animations::Instant)
             + 'static,
+    ) {
+        self.set_animated_binding_impl(
+            binding,
+            PropertyAnimation::default(),
+            move || Some(compute_animation_details()),
+            None,
+        )
+    }
+
+    /// Set a binding to this property, providing a callback for the transition animation
+    /// and one for the keyframes of that animation.
+    ///
+    pub fn set_animated_binding_for_transition_with_keyframes(
+        &self,
+        binding: impl Binding<T> + 'static,
+        compute_animation_details: impl Fn() -> (PropertyAnimation, crate::animations::Instant)
+            + 'static,
+        keyframes: impl Fn() -> SharedVector<AnimationKeyframe<T>> + 'static,
+    ) {
+        self.set_animated_binding_impl(
+            binding,
+            PropertyAnimation::default(),
+            move || Some(compute_animation_details()),
+            Some(Box::new(keyframes)),
+        )
+    }
+
+    fn set_animated_binding_impl(
+        &self,
+        binding: impl Binding<T> + 'static,
+        animation_data: PropertyAnimation,
+        compute_animation_details: impl Fn() -> properties_animations::AnimationDetail + 'static,
+        compute_keyframes: Option<properties_animations::KeyframesFn<T>>,
     ) {
         let binding_callable = properties_animations::AnimatedBindingCallable::<T, _> {
             original_binding: PropertyHandle {
@@ -310,9 +465,10 @@ pub fn set_animated_binding_for_transition(
             animation_data: RefCell::new(properties_animations::PropertyValueAnimationData::new(
                 T::default(),
                 T::default(),
-                PropertyAnimation::default(),
+                animation_data,
             )),
-            compute_animation_details: move || Some(compute_animation_details()),
+            compute_animation_details,
+            compute_keyframes,
         };
 
         // Safety: the `AnimatedBindingCallable`'s type match the property type
@@ -782,6 +938,138 @@ fn properties_test_animation_triggered_by_binding() {
         assert_eq!(get_prop_value(&compo.width_times_two), 400);
     }
 
+    #[test]
+    fn properties_test_animation_with_keyframes() {
+        let compo = Component::new_test_component();
+
+        let start_time = crate::animations::current_tick();
+
+        let animation_details = PropertyAnimation {
+            duration: DURATION.as_millis() as _,
+            iteration_count: 1.,
+            ..PropertyAnimation::default()
+        };
+
+        let w = Rc::downgrade(&compo);
+        compo.width.set_animated_binding_with_keyframes(
+            move || {
+                let compo = w.upgrade().unwrap();
+                get_prop_value(&compo.feed_property)
+            },
+            animation_details,
+            || {
+                SharedVector::from_slice(&[AnimationKeyframe {
+                    position: 0.5,
+                    value: 500,
+                    easing: EasingCurve::Linear,
+                }])
+            },
+        );
+
+        compo.feed_property.set(100);
+        assert_eq!(get_prop_value(&compo.width), 100);
+
+        compo.feed_property.set(200);
+        assert_eq!(get_prop_value(&compo.width), 100);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION / 4));
+        assert_eq!(get_prop_value(&compo.width), 300);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION / 2));
+        assert_eq!(get_prop_value(&compo.width), 500);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION * 3 / 4));
+        assert_eq!(get_prop_value(&compo.width), 350);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION));
+        assert_eq!(get_prop_value(&compo.width), 200);
+    }
+
+    #[test]
+    fn properties_test_animation_alternate_direction() {
+        let compo = Component::new_test_component();
+
+        let animation_details = PropertyAnimation {
+            duration: DURATION.as_millis() as _,
+            iteration_count: 2.,
+            direction: AnimationDirection::Alternate,
+            ..PropertyAnimation::default()
+        };
+
+        compo.width.set(100);
+        let start_time = crate::animations::current_tick();
+        compo.width.set_animated_value(200, animation_details);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION / 4));
+        assert_eq!(get_prop_value(&compo.width), 125);
+
+        // The second iteration goes backward
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION + DURATION / 4));
+        assert_eq!(get_prop_value(&compo.width), 175);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION * 2));
+        assert_eq!(get_prop_value(&compo.width), 200);
+    }
+
+    #[test]
+    fn properties_test_spring_animation_keeps_velocity() {
+        let compo = Component::new_test_component();
+
+        let start_time = crate::animations::current_tick();
+
+        let animation_details = PropertyAnimation {
+            // The duration is ignored by springs
+            duration: 1,
+            iteration_count: 1.,
+            easing: EasingCurve::Spring([170., 26., 1.]),
+            ..PropertyAnimation::default()
+        };
+
+        let w = Rc::downgrade(&compo);
+        compo.width.set_animated_binding(
+            move || {
+                let compo = w.upgrade().unwrap();
+                get_prop_value(&compo.feed_property)
+            },
+            animation_details,
+        );
+
+        compo.feed_property.set(0);
+        assert_eq!(get_prop_value(&compo.width), 0);
+
+        compo.feed_property.set(1000);
+        assert_eq!(get_prop_value(&compo.width), 0);
+
+        let tick = |ms| {
+            crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| {
+                driver.update_animations(start_time + std::time::Duration::from_millis(ms))
+            })
+        };
+        tick(50);
+        let first = get_prop_value(&compo.width);
+        tick(100);
+        let second = get_prop_value(&compo.width);
+        assert!(0 < first && first < second && second < 1000);
+
+        // Going back while the spring is moving: the value continues in the same direction
+        // for a while instead of immediately reversing.
+        compo.feed_property.set(0);
+        assert_eq!(get_prop_value(&compo.width), second);
+        tick(110);
+        assert!(get_prop_value(&compo.width) > second);
+
+        // Eventually the spring settles on the target
+        tick(5000);
+        assert_eq!(get_prop_value(&compo.width), 0);
+    }
+
     #[test]
     fn properties_test_delayed_animation_triggered_by_binding() {
         let compo = Component::new_test_component();
@@ -34,15 +34,24 @@ fn text_size(
         scale_factor: ScaleFactor,
     ) -> LogicalSize;
 
+    /// Returns the ascent and the descent of the font, such that text of different fonts can be
+    /// aligned on the same baseline.
+    fn font_metrics(
+        &self,
+        font_request: crate::graphics::FontRequest,
+        scale_factor: ScaleFactor,
+    ) -> crate::graphics::FontMetrics;
+
     /// Returns the (UTF-8) byte offset in the text property that refers to the character that contributed to
     /// the glyph cluster that's visually nearest to the given coordinate. This is used for hit-testing,
     /// for example when receiving a mouse click into a text field. Then this function returns the "cursor"
-    /// position.
+    /// position. `start` and `end` must already be resolved in the `horizontal_alignment`.
     fn text_input_byte_offset_for_position(
         &self,
         text_input: Pin<&crate::items::TextInput>,
         pos: LogicalPoint,
         font_request: crate::graphics::FontRequest,
+        horizontal_alignment: crate::items::TextHorizontalAlignment,
         scale_factor: ScaleFactor,
     ) -> usize;
 
@@ -54,6 +63,7 @@ fn text_input_cursor_rect_for_byte_offset(
         text_input: Pin<&crate::items::TextInput>,
         byte_offset: usize,
         font_request: crate::graphics::FontRequest,
+        horizontal_alignment: crate::items::TextHorizontalAlignment,
         scale_factor: ScaleFactor,
     ) -> LogicalRect;
 
@@ -10,7 +10,9 @@
 
 pub type FieldOffset<T, U> = const_field_offset::FieldOffset<T, U, const_field_offset::AllowPin>;
 use crate::items::PropertyAnimation;
+use crate::properties::AnimationKeyframe;
 use alloc::rc::Rc;
+use alloc::vec::Vec;
 #[cfg(not(feature = "std"))]
 use core::convert::{TryFrom, TryInto};
 use core::pin::Pin;
@@ -38,12 +40,16 @@ macro_rules! declare_ValueType_2 {
             crate::PathData,
             crate::animations::EasingCurve,
             crate::model::StandardListViewItem,
+            crate::model::StandardTreeViewItem,
             crate::model::TableColumn,
+            crate::items::Date,
+            crate::items::Time,
             crate::input::KeyEvent,
             crate::Brush,
             crate::graphics::Point,
             crate::items::PointerEvent,
             crate::items::PointerScrollEvent,
+            crate::items::DropEvent,
             crate::lengths::LogicalLength,
             crate::component_factory::ComponentFactory,
             $(crate::items::$Name,)*
@@ -89,6 +95,18 @@ fn set_binding(
         animation: AnimatedBindingKind,
     ) -> Result<(), ()>;
 
+    /// Like [`Self::set_binding`], but the animation goes through the keyframes returned by
+    /// the `keyframes` function. Returns an error if the property cannot be animated.
+    fn set_binding_with_keyframes(
+        &self,
+        _item: Pin<&Item>,
+        _binding: Box<dyn Fn() -> Value>,
+        _animation: AnimatedBindingKind,
+        _keyframes: Box<dyn Fn() -> Vec<AnimationKeyframe<Value>>>,
+    ) -> Result<(), ()> {
+        Err(())
+    }
+
     /// The offset of the property in the item.
     /// The use of this is unsafe
     fn offset(&self) -> usize;
@@ -194,50 +212,16 @@ fn set_binding(
         binding: Box<dyn Fn() -> Value>,
         animation: AnimatedBindingKind,
     ) -> Result<(), ()> {
-        // Put in a function that does not depends on Item to avoid code bloat
-        fn set_binding_impl<T, Value>(
-            p: Pin<&crate::Property<T>>,
-            binding: Box<dyn Fn() -> Value>,
-            animation: AnimatedBindingKind,
-        ) -> Result<(), ()>
-        where
-            T: Clone + TryInto<Value> + crate::properties::InterpolatedPropertyValue + 'static,
-            Value: TryInto<T> + 'static,
-        {
-            match animation {
-                AnimatedBindingKind::NotAnimated => {
-                    p.set_binding(move || {
-                        binding().try_into().map_err(|_| ()).expect("binding was of the wrong type")
-                    });
-                    Ok(())
-                }
-                AnimatedBindingKind::Animation(animation) => {
-                    p.set_animated_binding(
-                        move || {
-                            binding()
-                                .try_into()
-                                .map_err(|_| ())
-                                .expect("binding was of the wrong type")
-                        },
-                        animation,
-                    );
-                    Ok(())
-                }
-                AnimatedBindingKind::Transition(tr) => {
-                    p.set_animated_binding_for_transition(
-                        move || {
-                            binding()
-                                .try_into()
-                                .map_err(|_| ())
-                                .expect("binding was of the wrong type")
-                        },
-                        tr,
-                    );
-                    Ok(())
-                }
-            }
-        }
-        set_binding_impl(self.apply_pin(item), binding, animation)
+        set_animated_binding_impl(self.apply_pin(item), binding, animation, None)
+    }
+    fn set_binding_with_keyframes(
+        &self,
+        item: Pin<&Item>,
+        binding: Box<dyn Fn() -> Value>,
+        animation: AnimatedBindingKind,
+        keyframes: Box<dyn Fn() -> Vec<AnimationKeyframe<Value>>>,
+    ) -> Result<(), ()> {
+        set_animated_binding_impl(self.apply_pin(item), binding, animation, Some(keyframes))
     }
     fn offset(&self) -> usize {
         self.get_byte_offset()
@@ -252,6 +236,59 @@ unsafe fn link_two_ways(&self, item: Pin<&Item>, property2: *const ()) {
     }
 }
 
+// Put in a function that does not depends on Item to avoid code bloat
+fn set_animated_binding_impl<T, Value>(
+    p: Pin<&crate::Property<T>>,
+    binding: Box<dyn Fn() -> Value>,
+    animation: AnimatedBindingKind,
+    keyframes: Option<Box<dyn Fn() -> Vec<AnimationKeyframe<Value>>>>,
+) -> Result<(), ()>
+where
+    T: Clone + TryInto<Value> + crate::properties::InterpolatedPropertyValue + 'static,
+    Value: TryInto<T> + 'static,
+{
+    let binding =
+        move || binding().try_into().map_err(|_| ()).expect("binding was of the wrong type");
+    let keyframes = keyframes.map(|keyframes| {
+        move || {
+            keyframes()
+                .into_iter()
+                .map(|k| AnimationKeyframe {
+                    position: k.position,
+                    value: k
+                        .value
+                        .try_into()
+                        .map_err(|_| ())
+                        .expect("keyframe was of the wrong type"),
+                    easing: k.easing,
+                })
+                .collect()
+        }
+    });
+    match (animation, keyframes) {
+        (AnimatedBindingKind::NotAnimated, _) => {
+            p.set_binding(binding);
+            Ok(())
+        }
+        (AnimatedBindingKind::Animation(animation), None) => {
+            p.set_animated_binding(binding, animation);
+            Ok(())
+        }
+        (AnimatedBindingKind::Animation(animation), Some(keyframes)) => {
+            p.set_animated_binding_with_keyframes(binding, animation, keyframes);
+            Ok(())
+        }
+        (AnimatedBindingKind::Transition(tr), None) => {
+            p.set_animated_binding_for_transition(binding, tr);
+            Ok(())
+        }
+        (AnimatedBindingKind::Transition(tr), Some(keyframes)) => {
+            p.set_animated_binding_for_transition_with_keyframes(binding, tr, keyframes);
+            Ok(())
+        }
+    }
+}
+
 pub trait CallbackInfo<Item, Value> {
     fn call(&self, item: Pin<&Item>, args: &[Value]) -> Result<Value, ()>;
     fn set_handler(
@@ -7,12 +7,15 @@
 
 #![warn(missing_docs)]
 
+mod box_shadow;
 mod draw_functions;
 mod fixed;
 mod fonts;
+mod path;
 
 use self::fonts::GlyphRenderer;
 use crate::api::Window;
+use crate::graphics::boxshadowcache::{BoxShadowCache, BoxShadowOptions};
 use crate::graphics::rendering_metrics_collector::{RefreshMode, RenderingMetricsCollector};
 use crate::graphics::{BorderRadius, PixelFormat, SharedImageBuffer, SharedPixelBuffer};
 use crate::item_rendering::{
@@ -240,6 +243,7 @@ pub fn bounding_box_origin(&self) -> crate::api::PhysicalPosition {
 ///     in one single buffer
 pub struct SoftwareRenderer {
     partial_cache: RefCell<crate::item_rendering::PartialRenderingCache>,
+    box_shadow_cache: BoxShadowCache<SharedBufferData>,
     repaint_buffer_type: Cell<RepaintBufferType>,
     /// This is the area which we are going to redraw in the next frame, no matter if the items are dirty or not
     force_dirty: Cell<crate::item_rendering::DirtyRegion>,
@@ -257,6 +261,7 @@ impl Default for SoftwareRenderer {
     fn default() -> Self {
         Self {
             partial_cache: Default::default(),
+            box_shadow_cache: Default::default(),
             repaint_buffer_type: Default::default(),
             force_dirty: Default::default(),
             force_screen_refresh: Default::default(),
@@ -389,6 +394,7 @@ pub fn render(&self, buffer: &mut [impl TargetPixel], pixel_stride: usize) -> Ph
             window_inner,
             RenderToBuffer { buffer, stride: pixel_stride },
             rotation,
+            &self.box_shadow_cache,
         );
         let mut renderer = crate::item_rendering::PartialRenderer::new(
             &self.partial_cache,
@@ -417,8 +423,9 @@ pub fn render(&self, buffer: &mut [impl TargetPixel], pixel_stride: usize) -> Ph
                 );
 
                 let mut bg = TargetPixel::background();
-                // TODO: gradient background
-                TargetPixel::blend(&mut bg, background.color().into());
+                if let Brush::SolidColor(color) = background {
+                    TargetPixel::blend(&mut bg, color.into());
+                }
                 let to_draw_tr = to_draw.transformed(renderer.actual_renderer.rotation);
                 for line in to_draw_tr.min_y()..to_draw_tr.max_y() {
                     let begin = line as usize * pixel_stride + to_draw_tr.origin.x as usize;
@@ -426,6 +433,7 @@ pub fn render(&self, buffer: &mut [impl TargetPixel], pixel_stride: usize) -> Ph
                         [..to_draw_tr.width() as usize]
                         .fill(bg);
                 }
+                renderer.actual_renderer.draw_gradient_background(&background);
 
                 for (component, origin) in components {
                     crate::item_rendering::render_component_items(
@@ -434,6 +442,7 @@ pub fn render(&self, buffer: &mut [impl TargetPixel], pixel_stride: usize) -> Ph
                         *origin,
                     );
                 }
+                self.box_shadow_cache.end_frame();
 
                 if let Some(metrics) = &self.rendering_metrics_collector {
                     metrics.measure_frame_rendered(&mut renderer);
@@ -520,11 +529,20 @@ fn text_size(
         fonts::text_size(font_request, text, max_width, scale_factor)
     }
 
+    fn font_metrics(
+        &self,
+        font_request: crate::graphics::FontRequest,
+        scale_factor: ScaleFactor,
+    ) -> crate::graphics::FontMetrics {
+        fonts::font_metrics(font_request, scale_factor)
+    }
+
     fn text_input_byte_offset_for_position(
         &self,
         text_input: Pin<&crate::items::TextInput>,
         pos: LogicalPoint,
         font_request: crate::graphics::FontRequest,
+        horizontal_alignment: crate::items::TextHorizontalAlignment,
         scale_factor: ScaleFactor,
     ) -> usize {
         let visual_representation = text_input.visual_representation(None);
@@ -547,7 +565,7 @@ fn text_input_byte_offset_for_position(
                     layout,
                     max_width: width,
                     max_height: height,
-                    horizontal_alignment: text_input.horizontal_alignment(),
+                    horizontal_alignment,
                     vertical_alignment: text_input.vertical_alignment(),
                     wrap: text_input.wrap(),
                     overflow: TextOverflow::Clip,
@@ -567,7 +585,7 @@ fn text_input_byte_offset_for_position(
                     layout,
                     max_width: width,
                     max_height: height,
-                    horizontal_alignment: text_input.horizontal_alignment(),
+                    horizontal_alignment,
                     vertical_alignment: text_input.vertical_alignment(),
                     wrap: text_input.wrap(),
                     overflow: TextOverflow::Clip,
@@ -586,6 +604,7 @@ fn text_input_cursor_rect_for_byte_offset(
         text_input: Pin<&crate::items::TextInput>,
         byte_offset: usize,
         font_request: crate::graphics::FontRequest,
+        horizontal_alignment: crate::items::TextHorizontalAlignment,
         scale_factor: ScaleFactor,
     ) -> LogicalRect {
         let visual_representation = text_input.visual_representation(None);
@@ -604,7 +623,7 @@ fn text_input_cursor_rect_for_byte_offset(
                     layout,
                     max_width: width,
                     max_height: height,
-                    horizontal_alignment: text_input.horizontal_alignment(),
+                    horizontal_alignment,
                     vertical_alignment: text_input.vertical_alignment(),
                     wrap: text_input.wrap(),
                     overflow: TextOverflow::Clip,
@@ -622,7 +641,7 @@ fn text_input_cursor_rect_for_byte_offset(
                     layout,
                     max_width: width,
                     max_height: height,
-                    horizontal_alignment: text_input.horizontal_alignment(),
+                    horizontal_alignment,
                     vertical_alignment: text_input.vertical_alignment(),
                     wrap: text_input.wrap(),
                     overflow: TextOverflow::Clip,
@@ -653,6 +672,7 @@ fn free_graphics_resources(
         for item in items {
             item.cached_rendering_data_offset().release(&mut self.partial_cache.borrow_mut());
         }
+        self.box_shadow_cache.clear();
         // We don't have a way to determine the screen region of the delete items, what's in the cache is relative. So
         // as a last resort, refresh everything.
         self.force_screen_refresh.set(true);
@@ -690,6 +710,7 @@ fn default_font_size(&self) -> LogicalLength {
     fn set_window_adapter(&self, window_adapter: &Rc<dyn WindowAdapter>) {
         *self.maybe_window_adapter.borrow_mut() = Some(Rc::downgrade(window_adapter));
         self.partial_cache.borrow_mut().clear();
+        self.box_shadow_cache.clear();
     }
 }
 
@@ -701,7 +722,7 @@ fn render_window_frame_by_line(
     mut line_buffer: impl LineBufferProvider,
 ) -> PhysicalRegion {
     let rotation = RotationInfo { orientation: renderer.rotation.get(), screen_size: size };
-    let mut scene = prepare_scene(window, size, renderer);
+    let mut scene = prepare_scene(window, size, &background, renderer);
 
     let dirty_region = scene.dirty_region;
     let to_draw_tr = dirty_region.transformed(rotation);
@@ -709,8 +730,9 @@ fn render_window_frame_by_line(
     scene.current_line = to_draw_tr.origin.y_length();
 
     let mut background_color = TargetPixel::background();
-    // FIXME gradient
-    TargetPixel::blend(&mut background_color, background.color().into());
+    if let Brush::SolidColor(color) = background {
+        TargetPixel::blend(&mut background_color, color.into());
+    }
 
     while scene.current_line < to_draw_tr.origin.y_length() + to_draw_tr.size.height_length() {
         line_buffer.process_line(
@@ -1041,6 +1063,7 @@ struct SceneTextureExtra {
     rotation: RenderingRotation,
 }
 
+#[derive(Clone)]
 enum SharedBufferData {
     SharedImage(SharedImageBuffer),
     AlphaMap { data: Rc<[u8]>, width: u16 },
@@ -1144,11 +1167,15 @@ struct GradientCommand {
     right_clip: PhysicalLength,
     top_clip: PhysicalLength,
     bottom_clip: PhysicalLength,
+    /// If set, an alpha map of the size of the geometry (in screen orientation) by which the
+    /// gradient is multiplied. This is used for the glyphs and the paths.
+    mask: Option<Rc<[u8]>>,
 }
 
 fn prepare_scene(
     window: &WindowInner,
     size: PhysicalSize,
+    background: &Brush,
     software_renderer: &SoftwareRenderer,
 ) -> Scene {
     let factor = ScaleFactor::new(window.scale_factor());
@@ -1158,6 +1185,7 @@ fn prepare_scene(
         window,
         PrepareScene::default(),
         software_renderer.rotation.get(),
+        &software_renderer.box_shadow_cache,
     );
     let mut renderer = crate::item_rendering::PartialRenderer::new(
         &software_renderer.partial_cache,
@@ -1179,10 +1207,12 @@ fn prepare_scene(
             LogicalBorderRadius::zero(),
             LogicalLength::zero(),
         );
+        renderer.actual_renderer.draw_gradient_background(background);
         for (component, origin) in components {
             crate::item_rendering::render_component_items(component, &mut renderer, *origin);
         }
     });
+    software_renderer.box_shadow_cache.end_frame();
 
     if let Some(metrics) = &software_renderer.rendering_metrics_collector {
         metrics.measure_frame_rendered(&mut renderer);
@@ -1342,6 +1372,7 @@ struct SceneBuilder<'a, T> {
     scale_factor: ScaleFactor,
     window: &'a WindowInner,
     rotation: RotationInfo,
+    box_shadow_cache: &'a BoxShadowCache<SharedBufferData>,
 }
 
 impl<'a, T: ProcessScene> SceneBuilder<'a, T> {
@@ -1351,6 +1382,7 @@ fn new(
         window: &'a WindowInner,
         processor: T,
         orientation: RenderingRotation,
+        box_shadow_cache: &'a BoxShadowCache<SharedBufferData>,
     ) -> Self {
         Self {
             processor,
@@ -1366,6 +1398,7 @@ fn new(
             scale_factor,
             window,
             rotation: RotationInfo { orientation, screen_size },
+            box_shadow_cache,
         }
     }
 
@@ -1538,27 +1571,39 @@ fn draw_text_paragraph<Font>(
         paragraph: &TextParagraphLayout<'_, Font>,
         physical_clip: euclid::Rect<f32, PhysicalPx>,
         offset: euclid::Vector2D<f32, PhysicalPx>,
-        color: Color,
+        brush: &Brush,
         selection: Option<SelectionInfo>,
     ) where
         Font: AbstractFont + crate::textlayout::TextShaper<Length = PhysicalLength> + GlyphRenderer,
     {
+        let color = self.alpha_color(brush.color());
+        let is_gradient = !matches!(brush, Brush::SolidColor(_));
+        // The gradient is mapped on the geometry of the text item
+        let gradient_rect = euclid::Rect::new(
+            offset.to_point(),
+            euclid::size2(paragraph.max_width.get() as f32, paragraph.max_height.get() as f32),
+        );
         paragraph
             .layout_lines::<()>(
                 |glyphs, line_x, line_y, _, sel| {
                     let baseline_y = line_y + paragraph.layout.font.ascent();
-                    if let (Some(sel), Some(selection)) = (sel, &selection) {
-                        let geometry = euclid::rect(
-                            line_x.get() + sel.start.get(),
-                            line_y.get(),
-                            (sel.end - sel.start).get(),
-                            paragraph.layout.font.height().get(),
-                        );
-                        if let Some(clipped_src) = geometry.intersection(&physical_clip.cast()) {
-                            let geometry =
-                                clipped_src.translate(offset.cast()).transformed(self.rotation);
-                            self.processor
-                                .process_rectangle(geometry, selection.selection_background.into());
+                    if let Some(selection) = &selection {
+                        for sel in sel {
+                            let geometry = euclid::rect(
+                                line_x.get() + sel.start.get(),
+                                line_y.get(),
+                                (sel.end - sel.start).get(),
+                                paragraph.layout.font.height().get(),
+                            );
+                            if let Some(clipped_src) = geometry.intersection(&physical_clip.cast())
+                            {
+                                let geometry =
+                                    clipped_src.translate(offset.cast()).transformed(self.rotation);
+                                self.processor.process_rectangle(
+                                    geometry,
+                                    selection.selection_background.into(),
+                                );
+                            }
                         }
                     }
                     for positioned_glyph in glyphs {
@@ -1573,11 +1618,11 @@ fn draw_text_paragraph<Font>(
                         )
                         .cast();
 
-                        let color = match &selection {
+                        let (color, is_gradient) = match &selection {
                             Some(s) if s.selection.contains(&positioned_glyph.text_byte_offset) => {
-                                s.selection_color
+                                (s.selection_color, false)
                             }
-                            _ => color,
+                            _ => (color, is_gradient),
                         };
 
                         if let Some(clipped_src) = src_rect.intersection(&physical_clip) {
@@ -1597,6 +1642,20 @@ fn draw_text_paragraph<Font>(
                             if source_size.is_empty() {
                                 continue;
                             }
+                            if is_gradient {
+                                let data: &[u8] = match &glyph.alpha_map {
+                                    fonts::GlyphAlphaMap::Static(data) => data,
+                                    fonts::GlyphAlphaMap::Shared(data) => data,
+                                };
+                                let coverage = (0..source_size.height as usize).flat_map(|y| {
+                                    data[actual_x + (actual_y + y) * pixel_stride as usize..]
+                                        [..source_size.width as usize]
+                                        .iter()
+                                        .copied()
+                                });
+                                self.draw_masked_brush(geometry, coverage, brush, gradient_rect);
+                                continue;
+                            }
                             match &glyph.alpha_map {
                                 fonts::GlyphAlphaMap::Static(data) => {
                                     self.processor.process_texture(
@@ -1654,6 +1713,602 @@ fn draw_text_paragraph<Font>(
             .ok();
     }
 
+    /// Like `draw_text_paragraph`, but also draws the outline, the underline and the strikethrough
+    fn draw_decorated_text_paragraph<Font>(
+        &mut self,
+        paragraph: &TextParagraphLayout<'_, Font>,
+        physical_clip: euclid::Rect<f32, PhysicalPx>,
+        offset: euclid::Vector2D<f32, PhysicalPx>,
+        brush: &Brush,
+        selection: Option<SelectionInfo>,
+        decorations: &TextDecorations,
+    ) where
+        Font: AbstractFont + crate::textlayout::TextShaper<Length = PhysicalLength> + GlyphRenderer,
+    {
+        if let Some((stroke, width)) = &decorations.stroke {
+            self.draw_text_stroke(paragraph, physical_clip, offset, stroke, *width);
+        }
+
+        self.draw_text_paragraph(paragraph, physical_clip, offset, brush, selection);
+
+        if !decorations.underline && !decorations.strikethrough {
+            return;
+        }
+        let font = &paragraph.layout.font;
+        let color = self.alpha_color(brush.color());
+        let thickness = euclid::approxord::max(font.height() / 16, PhysicalLength::new(1));
+        // The offsets of the lines relative to the baseline
+        let lines = [
+            (decorations.underline, euclid::approxord::max(-font.descent() / 3, thickness)),
+            (decorations.strikethrough, -(font.ascent() * 3 / 10)),
+        ];
+        paragraph
+            .layout_lines::<()>(
+                |glyphs, line_x, line_y, line, _| {
+                    let end = glyphs.fold(PhysicalLength::zero(), |end, glyph| {
+                        euclid::approxord::max(end, glyph.x + glyph.advance)
+                    });
+                    let width = euclid::approxord::min(end, line.text_width);
+                    let baseline_y = line_y + font.ascent();
+                    for (enabled, y) in lines {
+                        if !enabled {
+                            continue;
+                        }
+                        let geometry = euclid::rect(
+                            line_x.get(),
+                            (baseline_y + y).get(),
+                            width.get(),
+                            thickness.get(),
+                        );
+                        if let Some(clipped_src) = geometry.intersection(&physical_clip.cast()) {
+                            let geometry =
+                                clipped_src.translate(offset.cast()).transformed(self.rotation);
+                            self.processor.process_rectangle(geometry, color.into());
+                        }
+                    }
+                    core::ops::ControlFlow::Continue(())
+                },
+                None,
+            )
+            .ok();
+    }
+
+    /// Draw the outline of the glyphs of the paragraph with the stroke brush, below the text.
+    fn draw_text_stroke<Font>(
+        &mut self,
+        paragraph: &TextParagraphLayout<'_, Font>,
+        physical_clip: euclid::Rect<f32, PhysicalPx>,
+        offset: euclid::Vector2D<f32, PhysicalPx>,
+        stroke: &Brush,
+        width: f32,
+    ) where
+        Font: AbstractFont + crate::textlayout::TextShaper<Length = PhysicalLength> + GlyphRenderer,
+    {
+        let mut rasterizer = path::Rasterizer::default();
+        let outlined = paragraph
+            .layout_lines::<()>(
+                |glyphs, line_x, line_y, _, _| {
+                    let baseline_y = line_y + paragraph.layout.font.ascent();
+                    for positioned_glyph in glyphs {
+                        let Some(outline) =
+                            paragraph.layout.font.glyph_outline(positioned_glyph.glyph_id)
+                        else {
+                            return core::ops::ControlFlow::Break(());
+                        };
+                        let origin = offset
+                            + euclid::vec2(
+                                (line_x + positioned_glyph.x).get() as f32,
+                                baseline_y.get() as f32,
+                            );
+                        // Twice the width, as the inner half is covered by the text
+                        rasterizer.stroke_path(
+                            outline.iter(),
+                            &lyon_path::math::Transform::translation(origin.x, origin.y),
+                            width * 2.,
+                        );
+                    }
+                    core::ops::ControlFlow::Continue(())
+                },
+                None,
+            )
+            .is_ok();
+
+        // The gradient is mapped on the geometry of the text item
+        let size = euclid::size2::<f32, PhysicalPx>(
+            paragraph.max_width.get() as f32,
+            paragraph.max_height.get() as f32,
+        ) / self.scale_factor;
+        let geom = LogicalRect::from_size(size.cast());
+
+        if outlined {
+            let physical_clip = physical_clip.translate(offset).round().cast::<i16>();
+            self.draw_rasterized_path(
+                rasterizer,
+                physical_clip,
+                crate::items::FillRule::Nonzero,
+                stroke,
+                geom,
+            );
+            return;
+        }
+
+        // The glyphs of pixel fonts are pre-rendered without their outline, so the stroke is
+        // their alpha map dilated with a disc of the stroke width.
+        let mut glyphs = Vec::new();
+        paragraph
+            .layout_lines::<()>(
+                |line_glyphs, line_x, line_y, _, _| {
+                    let baseline_y = line_y + paragraph.layout.font.ascent();
+                    for positioned_glyph in line_glyphs {
+                        let glyph = paragraph.layout.font.render_glyph(positioned_glyph.glyph_id);
+                        let origin = euclid::point2::<i32, PhysicalPx>(
+                            (line_x + positioned_glyph.x + glyph.x).get() as i32,
+                            (baseline_y - glyph.y - glyph.height).get() as i32,
+                        );
+                        glyphs.push((origin, glyph));
+                    }
+                    core::ops::ControlFlow::Continue(())
+                },
+                None,
+            )
+            .ok();
+
+        let reach = width.ceil() as i32;
+        let Some(bounding_box) = glyphs
+            .iter()
+            .map(|(origin, glyph)| euclid::Rect::new(*origin, glyph.size().cast::<i32>()))
+            .reduce(|a, b| a.union(&b))
+        else {
+            return;
+        };
+        let Some(rect) = bounding_box
+            .inflate(reach, reach)
+            .intersection(&physical_clip.round_out().cast::<i32>())
+        else {
+            return;
+        };
+        if rect.is_empty() {
+            return;
+        }
+
+        // The weight of each pixel of the disc, anti-aliased on its border
+        let disc = (-reach..=reach)
+            .flat_map(|dy| (-reach..=reach).map(move |dx| (dx, dy)))
+            .filter_map(|(dx, dy)| {
+                let distance = ((dx * dx + dy * dy) as f32).sqrt();
+                let weight = ((width + 0.5 - distance).clamp(0., 1.) * 255.) as u16;
+                (weight > 0).then_some((dx, dy, weight))
+            })
+            .collect::<Vec<_>>();
+
+        let mut coverage = vec![0u8; rect.area() as usize];
+        for (origin, glyph) in &glyphs {
+            let data: &[u8] = match &glyph.alpha_map {
+                fonts::GlyphAlphaMap::Static(data) => data,
+                fonts::GlyphAlphaMap::Shared(data) => data,
+            };
+            let glyph_width = glyph.width.get() as i32;
+            for y in 0..glyph.height.get() as i32 {
+                for x in 0..glyph_width {
+                    let alpha = data[(x + y * glyph_width) as usize] as u16;
+                    if alpha == 0 {
+                        continue;
+                    }
+                    for (dx, dy, weight) in &disc {
+                        let px = origin.x + x + dx - rect.origin.x;
+                        let py = origin.y + y + dy - rect.origin.y;
+                        if px < 0 || py < 0 || px >= rect.width() || py >= rect.height() {
+                            continue;
+                        }
+                        let pixel = &mut coverage[(px + py * rect.width()) as usize];
+                        *pixel = (*pixel).max((alpha * weight / 255) as u8);
+                    }
+                }
+            }
+        }
+
+        let rect = rect.translate(offset.round().cast()).cast::<i16>();
+        self.draw_alpha_map(rect, coverage, stroke, geom);
+    }
+
+    fn text_decorations(
+        &self,
+        underline: bool,
+        strikethrough: bool,
+        stroke: Brush,
+        stroke_width: LogicalLength,
+    ) -> TextDecorations {
+        let stroke_width = (stroke_width.cast() * self.scale_factor).get();
+        TextDecorations {
+            underline,
+            strikethrough,
+            stroke: (!stroke.is_transparent() && stroke_width > 0.)
+                .then_some((stroke, stroke_width)),
+        }
+    }
+
+    /// Rasterize the path and draw it with the brush. `physical_clip` is the clip in absolute
+    /// physical coordinates, and `geom` is the logical geometry of the item used to map gradients.
+    fn draw_rasterized_path(
+        &mut self,
+        rasterizer: path::Rasterizer,
+        physical_clip: PhysicalRect,
+        fill_rule: crate::items::FillRule,
+        brush: &Brush,
+        geom: LogicalRect,
+    ) {
+        let Some(bounding_box) = rasterizer.bounding_box() else { return };
+        let Some(rect) =
+            bounding_box.to_rect().round_out().cast::<i16>().intersection(&physical_clip)
+        else {
+            return;
+        };
+        if rect.is_empty() {
+            return;
+        }
+        let alpha_map = rasterizer.rasterize(rect, fill_rule);
+        self.draw_alpha_map(rect, alpha_map, brush, geom);
+    }
+
+    /// Draw the brush over `rect` (in absolute physical coordinates), multiplied by `alpha_map`.
+    /// The gradient is mapped on `geom`, in logical coordinates relative to the current item.
+    fn draw_alpha_map(
+        &mut self,
+        rect: PhysicalRect,
+        alpha_map: Vec<u8>,
+        brush: &Brush,
+        geom: LogicalRect,
+    ) {
+        if let Brush::SolidColor(color) = brush {
+            let color = self.alpha_color(*color);
+            self.processor.process_shared_image_buffer(
+                rect.transformed(self.rotation),
+                SharedBufferCommand {
+                    buffer: SharedBufferData::AlphaMap {
+                        data: alpha_map.into(),
+                        width: rect.width() as u16,
+                    },
+                    source_rect: PhysicalRect::from_size(rect.size),
+                    extra: SceneTextureExtra {
+                        colorize: color,
+                        alpha: color.alpha(),
+                        rotation: self.rotation.orientation,
+                        dx: Fixed::from_integer(1),
+                        dy: Fixed::from_integer(1),
+                        off_x: Fixed::from_integer(0),
+                        off_y: Fixed::from_integer(0),
+                    },
+                },
+            );
+        } else {
+            let gradient_rect =
+                geom.translate(self.current_state.offset.to_vector()).cast() * self.scale_factor;
+            self.draw_masked_brush(rect, alpha_map.into_iter(), brush, gradient_rect);
+        }
+    }
+
+    /// Draw the brush over `rect` (in absolute physical coordinates), multiplied by the
+    /// `coverage` alpha map. The gradient is mapped on `gradient_rect`, also in absolute
+    /// physical coordinates.
+    fn draw_masked_brush(
+        &mut self,
+        rect: PhysicalRect,
+        coverage: impl Iterator<Item = u8>,
+        brush: &Brush,
+        gradient_rect: euclid::Rect<f32, PhysicalPx>,
+    ) {
+        match brush {
+            Brush::SolidColor(_) => {}
+            Brush::LinearGradient(g) => {
+                let mask = transform_alpha_map(coverage, rect.size, self.rotation.orientation);
+                self.process_linear_gradient(gradient_rect, rect.cast(), rect, g, Some(mask));
+            }
+            Brush::RadialGradient(g) => self.draw_radial_gradient(rect, coverage, g, gradient_rect),
+        }
+    }
+
+    /// Draw a texture covering `rect` (in absolute physical coordinates) with the color of the
+    /// radial gradient at each pixel, multiplied by the `coverage` alpha map.
+    /// The gradient is mapped on `gradient_rect`, also in absolute physical coordinates.
+    ///
+    /// Unlike linear gradients, which are drawn with [`GradientCommand`]s, the radial
+    /// gradients are computed for each pixel into a buffer.
+    fn draw_radial_gradient(
+        &mut self,
+        rect: PhysicalRect,
+        coverage: impl Iterator<Item = u8>,
+        g: &crate::graphics::RadialGradientBrush,
+        gradient_rect: euclid::Rect<f32, PhysicalPx>,
+    ) {
+        let mut pixels = SharedPixelBuffer::<crate::graphics::Rgba8Pixel>::new(
+            rect.width() as _,
+            rect.height() as _,
+        );
+        let size = gradient_rect.size.to_untyped();
+        for ((i, pixel), coverage) in pixels.make_mut_slice().iter_mut().enumerate().zip(coverage) {
+            if coverage == 0 {
+                continue;
+            }
+            let x = rect.origin.x as f32 + (i % rect.width() as usize) as f32 + 0.5;
+            let y = rect.origin.y as f32 + (i / rect.width() as usize) as f32 + 0.5;
+            let pos = euclid::point2(x - gradient_rect.origin.x, y - gradient_rect.origin.y);
+            let color = path::radial_gradient_color_at(g, pos, size);
+            let color = PremultipliedRgbaColor::from(Color::from_argb_u8(
+                (color.alpha() as u16 * coverage as u16 / 255) as u8,
+                color.red(),
+                color.green(),
+                color.blue(),
+            ));
+            *pixel =
+                crate::graphics::Rgba8Pixel::new(color.red, color.green, color.blue, color.alpha);
+        }
+
+        self.processor.process_shared_image_buffer(
+            rect.transformed(self.rotation),
+            SharedBufferCommand {
+                buffer: SharedBufferData::SharedImage(SharedImageBuffer::RGBA8Premultiplied(
+                    pixels,
+                )),
+                source_rect: PhysicalRect::from_size(rect.size),
+                extra: SceneTextureExtra {
+                    colorize: Color::default(),
+                    alpha: (self.current_state.alpha * 255.) as u8,
+                    rotation: self.rotation.orientation,
+                    dx: Fixed::from_integer(1),
+                    dy: Fixed::from_integer(1),
+                    off_x: Fixed::from_integer(0),
+                    off_y: Fixed::from_integer(0),
+                },
+            },
+        );
+    }
+
+    /// Draw the window background if it is a gradient.
+    /// Solid color backgrounds are filled directly in the buffer instead.
+    fn draw_gradient_background(&mut self, background: &Brush) {
+        if matches!(background, Brush::SolidColor(_)) {
+            return;
+        }
+        let geom =
+            LogicalRect::from_size((self.rotation.screen_size.cast() / self.scale_factor).cast());
+        if let Some(clipped) = geom.intersection(&self.current_state.clip) {
+            self.draw_brush_rect(geom, clipped, background);
+        }
+    }
+
+    /// Fill `clipped` with the brush, with gradients mapped on `geom`.
+    /// Both rectangles are relative to the current offset.
+    fn draw_brush_rect(&mut self, geom: LogicalRect, clipped: LogicalRect, brush: &Brush) {
+        match brush {
+            Brush::SolidColor(color) => {
+                let color = self.alpha_color(*color);
+                if color.alpha() == 0 {
+                    return;
+                }
+                let geometry = (clipped.translate(self.current_state.offset.to_vector()).cast()
+                    * self.scale_factor)
+                    .round()
+                    .cast()
+                    .transformed(self.rotation);
+                self.processor.process_rectangle(geometry, color.into());
+            }
+            Brush::LinearGradient(g) => self.draw_linear_gradient(geom, clipped, g),
+            Brush::RadialGradient(g) => {
+                let rect = (clipped.translate(self.current_state.offset.to_vector()).cast()
+                    * self.scale_factor)
+                    .round()
+                    .cast();
+                if rect.is_empty() {
+                    return;
+                }
+                let gradient_rect = geom.translate(self.current_state.offset.to_vector()).cast()
+                    * self.scale_factor;
+                self.draw_radial_gradient(rect, core::iter::repeat(255), g, gradient_rect);
+            }
+        }
+    }
+
+    /// Draw a rectangle with a border, when the background or the border is a gradient.
+    /// The background covers the whole rectangle and the border is drawn on top of it.
+    #[allow(clippy::unnecessary_cast)] // Coord
+    fn draw_gradient_border_rectangle(
+        &mut self,
+        geom: LogicalRect,
+        border: LogicalLength,
+        radius: LogicalBorderRadius,
+        background: &Brush,
+        border_brush: &Brush,
+    ) {
+        let Some(clipped) = geom.intersection(&self.current_state.clip) else { return };
+        let border = if border_brush.is_transparent() {
+            0 as Coord
+        } else {
+            border.get().max(0 as Coord).min(geom.width().min(geom.height()) / 2 as Coord)
+        };
+
+        if radius.is_zero() {
+            if !background.is_transparent() {
+                self.draw_brush_rect(geom, clipped, background);
+            }
+            if border as f32 > 0.01 {
+                let (w, h) = (geom.width(), geom.height());
+                for r in [
+                    euclid::rect(0 as _, 0 as _, w, border),
+                    euclid::rect(0 as _, h - border, w, border),
+                    euclid::rect(0 as _, border, border, h - border - border),
+                    euclid::rect(w - border, border, border, h - border - border),
+                ] {
+                    if let Some(r) = r.intersection(&clipped) {
+                        self.draw_brush_rect(geom, r, border_brush);
+                    }
+                }
+            }
+            return;
+        }
+
+        let radius = radius
+            .min(LogicalBorderRadius::from_length(geom.width_length() / 2 as Coord))
+            .min(LogicalBorderRadius::from_length(geom.height_length() / 2 as Coord));
+        let physical_clip = (clipped.translate(self.current_state.offset.to_vector()).cast()
+            * self.scale_factor)
+            .round()
+            .cast::<i16>();
+        let origin = (self.current_state.offset.cast() * self.scale_factor).to_vector();
+        let transform =
+            lyon_path::math::Transform::scale(self.scale_factor.get(), self.scale_factor.get())
+                .then_translate(origin.to_untyped());
+        let outer = path::rounded_rectangle_path(geom, radius);
+
+        if !background.is_transparent() {
+            let mut rasterizer = path::Rasterizer::default();
+            rasterizer.fill_path(outer.iter(), &transform);
+            self.draw_rasterized_path(
+                rasterizer,
+                physical_clip,
+                crate::items::FillRule::Nonzero,
+                background,
+                geom,
+            );
+        }
+        if border as f32 > 0.01 {
+            let inner = path::rounded_rectangle_path(
+                geom.inflate(-border, -border),
+                radius.inner(LogicalLength::new(border)),
+            );
+            let mut rasterizer = path::Rasterizer::default();
+            rasterizer.fill_path(outer.iter(), &transform);
+            rasterizer.fill_path(inner.iter(), &transform);
+            self.draw_rasterized_path(
+                rasterizer,
+                physical_clip,
+                crate::items::FillRule::Evenodd,
+                border_brush,
+                geom,
+            );
+        }
+    }
+
+    /// Fill `clipped` with a linear gradient mapped on `geom`, using [`GradientCommand`]s.
+    /// Both rectangles are relative to the current offset.
+    fn draw_linear_gradient(
+        &mut self,
+        geom: LogicalRect,
+        clipped: LogicalRect,
+        g: &crate::graphics::LinearGradientBrush,
+    ) {
+        let act_rect = (clipped.translate(self.current_state.offset.to_vector()).cast()
+            * self.scale_factor)
+            .round()
+            .cast();
+        self.process_linear_gradient(
+            geom.cast() * self.scale_factor,
+            clipped.cast() * self.scale_factor,
+            act_rect,
+            g,
+            None,
+        );
+    }
+
+    /// Emit the [`GradientCommand`]s filling `act_rect` (in absolute physical coordinates)
+    /// with a linear gradient mapped on `geom`. `clipped` is the part of `geom` covered by
+    /// `act_rect`, in the same coordinates as `geom`.
+    /// If set, `mask` is an alpha map of the size of `act_rect`, in screen orientation.
+    #[allow(clippy::unnecessary_cast)] // Coord!
+    fn process_linear_gradient(
+        &mut self,
+        geom: euclid::Rect<f32, PhysicalPx>,
+        clipped: euclid::Rect<f32, PhysicalPx>,
+        act_rect: PhysicalRect,
+        g: &crate::graphics::LinearGradientBrush,
+        mask: Option<Rc<[u8]>>,
+    ) {
+        let geom2 = geom.transformed(self.rotation);
+        let clipped2 = clipped.transformed(self.rotation);
+        let act_rect = act_rect.transformed(self.rotation);
+        let axis_angle = (360. - self.rotation.orientation.angle()) % 360.;
+        let angle = g.angle() - axis_angle;
+        let tan = angle.to_radians().tan().abs();
+        let start = if !tan.is_finite() {
+            255.
+        } else {
+            let h = tan * geom2.width() as f32;
+            255. * h / (h + geom2.height() as f32)
+        } as u8;
+        let mut angle = angle as i32 % 360;
+        if angle < 0 {
+            angle += 360;
+        }
+        let mut stops = g.stops().copied().peekable();
+        let mut idx = 0;
+        let stop_count = g.stops().count();
+        while let (Some(mut s1), Some(mut s2)) = (stops.next(), stops.peek().copied()) {
+            let mut flags = 0;
+            if (angle % 180) > 90 {
+                flags |= 0b1;
+            }
+            if angle <= 90 || angle > 270 {
+                core::mem::swap(&mut s1, &mut s2);
+                s1.position = 1. - s1.position;
+                s2.position = 1. - s2.position;
+                if idx == 0 {
+                    flags |= 0b100;
+                }
+                if idx == stop_count - 2 {
+                    flags |= 0b010;
+                }
+            } else {
+                if idx == 0 {
+                    flags |= 0b010;
+                }
+                if idx == stop_count - 2 {
+                    flags |= 0b100;
+                }
+            }
+
+            idx += 1;
+
+            let (adjust_left, adjust_right) = if (angle % 180) > 90 {
+                (
+                    (geom2.width() * s1.position).floor() as i16,
+                    (geom2.width() * (1. - s2.position)).ceil() as i16,
+                )
+            } else {
+                (
+                    (geom2.width() * (1. - s2.position)).ceil() as i16,
+                    (geom2.width() * s1.position).floor() as i16,
+                )
+            };
+
+            let gr = GradientCommand {
+                color1: self.alpha_color(s1.color).into(),
+                color2: self.alpha_color(s2.color).into(),
+                start,
+                flags,
+                top_clip: Length::new(
+                    (clipped2.min_y() - geom2.min_y()) as i16
+                        - (geom2.height() * s1.position).floor() as i16,
+                ),
+                bottom_clip: Length::new(
+                    (geom2.max_y() - clipped2.max_y()) as i16
+                        - (geom2.height() * (1. - s2.position)).ceil() as i16,
+                ),
+                left_clip: Length::new((clipped2.min_x() - geom2.min_x()) as i16 - adjust_left),
+                right_clip: Length::new((geom2.max_x() - clipped2.max_x()) as i16 - adjust_right),
+                mask: mask.clone(),
+            };
+
+            let size_y = act_rect.height_length() + gr.top_clip + gr.bottom_clip;
+            let size_x = act_rect.width_length() + gr.left_clip + gr.right_clip;
+            if size_x.get() == 0 || size_y.get() == 0 {
+                // the position are too close to each other
+                // FIXME: For the first or the last, we should draw a plain color to the end
+                continue;
+            }
+
+            self.processor.process_gradient(act_rect, gr);
+        }
+    }
+
     /// Returns the color, mixed with the current_state's alpha
     fn alpha_color(&self, color: Color) -> Color {
         if self.current_state.alpha < 1.0 {
@@ -1669,12 +2324,47 @@ fn alpha_color(&self, color: Color) -> Color {
     }
 }
 
+/// Collect the alpha map of the given size, and transform it to the screen orientation
+fn transform_alpha_map(
+    coverage: impl Iterator<Item = u8>,
+    size: PhysicalSize,
+    rotation: RenderingRotation,
+) -> Rc<[u8]> {
+    let (width, height) = (size.width as usize, size.height as usize);
+    if rotation == RenderingRotation::NoRotation {
+        return coverage.take(width * height).collect();
+    }
+    let stride = if rotation.is_transpose() { height } else { width };
+    let mut result = vec![0; width * height];
+    for (i, alpha) in coverage.take(width * height).enumerate() {
+        let (mut x, mut y) = (i % width, i / width);
+        if rotation.mirror_width() {
+            x = width - 1 - x;
+        }
+        if rotation.mirror_height() {
+            y = height - 1 - y;
+        }
+        if rotation.is_transpose() {
+            core::mem::swap(&mut x, &mut y);
+        }
+        result[y * stride + x] = alpha;
+    }
+    result.into()
+}
+
 struct SelectionInfo {
     selection_color: Color,
     selection_background: Color,
     selection: core::ops::Range<usize>,
 }
 
+struct TextDecorations {
+    underline: bool,
+    strikethrough: bool,
+    /// The brush and the width in physical pixels of the outline
+    stroke: Option<(Brush, f32)>,
+}
+
 #[derive(Clone, Copy)]
 struct RenderState {
     alpha: f32,
@@ -1697,115 +2387,7 @@ fn draw_rectangle(
                 None => return,
             };
 
-            let background = rect.background();
-            if let Brush::LinearGradient(g) = background {
-                let geom2 = (geom.cast() * self.scale_factor).transformed(self.rotation);
-                let clipped2 = (clipped.cast() * self.scale_factor).transformed(self.rotation);
-                let act_rect = (clipped.translate(self.current_state.offset.to_vector()).cast()
-                    * self.scale_factor)
-                    .round()
-                    .cast()
-                    .transformed(self.rotation);
-                let axis_angle = (360. - self.rotation.orientation.angle()) % 360.;
-                let angle = g.angle() - axis_angle;
-                let tan = angle.to_radians().tan().abs();
-                let start = if !tan.is_finite() {
-                    255.
-                } else {
-                    let h = tan * geom2.width() as f32;
-                    255. * h / (h + geom2.height() as f32)
-                } as u8;
-                let mut angle = angle as i32 % 360;
-                if angle < 0 {
-                    angle += 360;
-                }
-                let mut stops = g.stops().copied().peekable();
-                let mut idx = 0;
-                let stop_count = g.stops().count();
-                while let (Some(mut s1), Some(mut s2)) = (stops.next(), stops.peek().copied()) {
-                    let mut flags = 0;
-                    if (angle % 180) > 90 {
-                        flags |= 0b1;
-                    }
-                    if angle <= 90 || angle > 270 {
-                        core::mem::swap(&mut s1, &mut s2);
-                        s1.position = 1. - s1.position;
-                        s2.position = 1. - s2.position;
-                        if idx == 0 {
-                            flags |= 0b100;
-                        }
-                        if idx == stop_count - 2 {
-                            flags |= 0b010;
-                        }
-                    } else {
-                        if idx == 0 {
-                            flags |= 0b010;
-                        }
-                        if idx == stop_count - 2 {
-                            flags |= 0b100;
-                        }
-                    }
-
-                    idx += 1;
-
-                    let (adjust_left, adjust_right) = if (angle % 180) > 90 {
-                        (
-                            (geom2.width() * s1.position).floor() as i16,
-                            (geom2.width() * (1. - s2.position)).ceil() as i16,
-                        )
-                    } else {
-                        (
-                            (geom2.width() * (1. - s2.position)).ceil() as i16,
-                            (geom2.width() * s1.position).floor() as i16,
-                        )
-                    };
-
-                    let gr = GradientCommand {
-                        color1: self.alpha_color(s1.color).into(),
-                        color2: self.alpha_color(s2.color).into(),
-                        start,
-                        flags,
-                        top_clip: Length::new(
-                            (clipped2.min_y() - geom2.min_y()) as i16
-                                - (geom2.height() * s1.position).floor() as i16,
-                        ),
-                        bottom_clip: Length::new(
-                            (geom2.max_y() - clipped2.max_y()) as i16
-                                - (geom2.height() * (1. - s2.position)).ceil() as i16,
-                        ),
-                        left_clip: Length::new(
-                            (clipped2.min_x() - geom2.min_x()) as i16 - adjust_left,
-                        ),
-                        right_clip: Length::new(
-                            (geom2.max_x() - clipped2.max_x()) as i16 - adjust_right,
-                        ),
-                    };
-
-                    let size_y = act_rect.height_length() + gr.top_clip + gr.bottom_clip;
-                    let size_x = act_rect.width_length() + gr.left_clip + gr.right_clip;
-                    if size_x.get() == 0 || size_y.get() == 0 {
-                        // the position are too close to each other
-                        // FIXME: For the first or the last, we should draw a plain color to the end
-                        continue;
-                    }
-
-                    self.processor.process_gradient(act_rect, gr);
-                }
-                return;
-            }
-
-            let color = self.alpha_color(background.color());
-
-            if color.alpha() == 0 {
-                return;
-            }
-            let geometry = (clipped.translate(self.current_state.offset.to_vector()).cast()
-                * self.scale_factor)
-                .round()
-                .cast()
-                .transformed(self.rotation);
-
-            self.processor.process_rectangle(geometry, color.into());
+            self.draw_brush_rect(geom, clipped, &rect.background());
         }
     }
 
@@ -1821,10 +2403,23 @@ fn draw_border_rectangle(
         if self.should_draw(&geom) {
             let mut border = rect.border_width();
             let radius = rect.border_radius();
-            // FIXME: gradients
-            let color = self.alpha_color(rect.background().color());
+            let background = rect.background();
+            let border_brush = rect.border_color();
+            if !matches!(background, Brush::SolidColor(_))
+                || (border.get() as f32 > 0.01 && !matches!(border_brush, Brush::SolidColor(_)))
+            {
+                self.draw_gradient_border_rectangle(
+                    geom,
+                    border,
+                    radius,
+                    &background,
+                    &border_brush,
+                );
+                return;
+            }
+            let color = self.alpha_color(background.color());
             let border_color = if border.get() as f32 > 0.01 {
-                self.alpha_color(rect.border_color().color())
+                self.alpha_color(border_brush.color())
             } else {
                 Color::default()
             };
@@ -1910,7 +2505,6 @@ fn draw_border_rectangle(
                 }
             }
 
-            // FIXME: gradients
             if border_color.alpha > 0 {
                 let mut add_border = |r: LogicalRect| {
                     if let Some(r) = r.intersection(&self.current_state.clip) {
@@ -1991,8 +2585,15 @@ fn draw_text(&mut self, text: Pin<&crate::items::Text>, _: &ItemRc, size: Logica
         }
 
         let font_request = text.font_request(self.window);
-
-        let color = self.alpha_color(text.color().color());
+        let horizontal_alignment = text.resolved_horizontal_alignment(self.window);
+
+        let brush = text.color();
+        let decorations = self.text_decorations(
+            text.font_underline(),
+            text.font_strikethrough(),
+            text.stroke(),
+            text.stroke_width(),
+        );
         let max_size = (geom.size.cast() * self.scale_factor).cast();
 
         // Clip glyphs not only against the global clip but also against the Text's geometry to avoid drawing outside
@@ -2017,14 +2618,21 @@ fn draw_text(&mut self, text: Pin<&crate::items::Text>, _: &ItemRc, size: Logica
                     layout,
                     max_width: max_size.width_length(),
                     max_height: max_size.height_length(),
-                    horizontal_alignment: text.horizontal_alignment(),
+                    horizontal_alignment,
                     vertical_alignment: text.vertical_alignment(),
                     wrap: text.wrap(),
                     overflow: text.overflow(),
                     single_line: false,
                 };
 
-                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, None);
+                self.draw_decorated_text_paragraph(
+                    &paragraph,
+                    physical_clip,
+                    offset,
+                    &brush,
+                    None,
+                    &decorations,
+                );
             }
             #[cfg(all(feature = "software-renderer-systemfonts", not(target_arch = "wasm32")))]
             fonts::Font::VectorFont(vf) => {
@@ -2035,14 +2643,21 @@ fn draw_text(&mut self, text: Pin<&crate::items::Text>, _: &ItemRc, size: Logica
                     layout,
                     max_width: max_size.width_length(),
                     max_height: max_size.height_length(),
-                    horizontal_alignment: text.horizontal_alignment(),
+                    horizontal_alignment,
                     vertical_alignment: text.vertical_alignment(),
                     wrap: text.wrap(),
                     overflow: text.overflow(),
                     single_line: false,
                 };
 
-                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, None);
+                self.draw_decorated_text_paragraph(
+                    &paragraph,
+                    physical_clip,
+                    offset,
+                    &brush,
+                    None,
+                    &decorations,
+                );
             }
         }
     }
@@ -2059,8 +2674,17 @@ fn draw_text_input(
         }
 
         let font_request = text_input.font_request(&self.window.window_adapter());
-
-        let color = self.alpha_color(text_input.color().color());
+        let horizontal_alignment =
+            text_input.resolved_horizontal_alignment(&self.window.window_adapter());
+
+        let brush = text_input.color();
+        let color = self.alpha_color(brush.color());
+        let decorations = self.text_decorations(
+            text_input.font_underline(),
+            text_input.font_strikethrough(),
+            text_input.stroke(),
+            text_input.stroke_width(),
+        );
         let max_size = (geom.size.cast() * self.scale_factor).cast();
 
         // Clip glyphs not only against the global clip but also against the Text's geometry to avoid drawing outside
@@ -2092,14 +2716,21 @@ fn draw_text_input(
                     layout: fonts::text_layout_for_font(&pf, &font_request, self.scale_factor),
                     max_width: max_size.width_length(),
                     max_height: max_size.height_length(),
-                    horizontal_alignment: text_input.horizontal_alignment(),
+                    horizontal_alignment,
                     vertical_alignment: text_input.vertical_alignment(),
                     wrap: text_input.wrap(),
                     overflow: TextOverflow::Clip,
                     single_line: text_input.single_line(),
                 };
 
-                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, selection);
+                self.draw_decorated_text_paragraph(
+                    &paragraph,
+                    physical_clip,
+                    offset,
+                    &brush,
+                    selection,
+                    &decorations,
+                );
 
                 text_visual_representation.cursor_position.map(|cursor_offset| {
                     (paragraph.cursor_pos_for_byte_offset(cursor_offset), pf.height())
@@ -2112,14 +2743,21 @@ fn draw_text_input(
                     layout: fonts::text_layout_for_font(&vf, &font_request, self.scale_factor),
                     max_width: max_size.width_length(),
                     max_height: max_size.height_length(),
-                    horizontal_alignment: text_input.horizontal_alignment(),
+                    horizontal_alignment,
                     vertical_alignment: text_input.vertical_alignment(),
                     wrap: text_input.wrap(),
                     overflow: TextOverflow::Clip,
                     single_line: text_input.single_line(),
                 };
 
-                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, selection);
+                self.draw_decorated_text_paragraph(
+                    &paragraph,
+                    physical_clip,
+                    offset,
+                    &brush,
+                    selection,
+                    &decorations,
+                );
 
                 text_visual_representation.cursor_position.map(|cursor_offset| {
                     (paragraph.cursor_pos_for_byte_offset(cursor_offset), vf.height())
@@ -2143,18 +2781,114 @@ fn draw_text_input(
         }
     }
 
-    #[cfg(feature = "std")]
-    fn draw_path(&mut self, _path: Pin<&crate::items::Path>, _: &ItemRc, _size: LogicalSize) {
-        // TODO
+    fn draw_path(&mut self, path: Pin<&crate::items::Path>, item_rc: &ItemRc, size: LogicalSize) {
+        let geom = LogicalRect::from(size);
+        if !self.should_draw(&geom) {
+            return;
+        }
+        let Some((offset, path_events)) = path.fitted_path_events(item_rc) else { return };
+
+        // Like for text, clip against the item's geometry so that partial rendering stays correct.
+        let Some(clipped) = self.current_state.clip.intersection(&geom) else { return };
+        let physical_clip = (clipped.translate(self.current_state.offset.to_vector()).cast()
+            * self.scale_factor)
+            .round()
+            .cast::<i16>();
+
+        let origin = (self.current_state.offset.cast() * self.scale_factor).to_vector()
+            + offset.cast() * self.scale_factor;
+        let transform =
+            lyon_path::math::Transform::scale(self.scale_factor.get(), self.scale_factor.get())
+                .then_translate(origin.to_untyped());
+
+        let fill = path.fill();
+        if !fill.is_transparent() {
+            let mut rasterizer = path::Rasterizer::default();
+            rasterizer.fill_path(path_events.iter(), &transform);
+            self.draw_rasterized_path(rasterizer, physical_clip, path.fill_rule(), &fill, geom);
+        }
+
+        let stroke = path.stroke();
+        let stroke_width = (path.stroke_width().cast() * self.scale_factor).get();
+        if !stroke.is_transparent() && stroke_width > 0. {
+            let mut rasterizer = path::Rasterizer::default();
+            rasterizer.stroke_path(path_events.iter(), &transform, stroke_width);
+            self.draw_rasterized_path(
+                rasterizer,
+                physical_clip,
+                crate::items::FillRule::Nonzero,
+                &stroke,
+                geom,
+            );
+        }
     }
 
     fn draw_box_shadow(
         &mut self,
-        _box_shadow: Pin<&crate::items::BoxShadow>,
-        _: &ItemRc,
-        _size: LogicalSize,
+        box_shadow: Pin<&crate::items::BoxShadow>,
+        self_rc: &ItemRc,
+        size: LogicalSize,
     ) {
-        // TODO
+        let offset = LogicalVector::from_lengths(box_shadow.offset_x(), box_shadow.offset_y());
+        let blur = box_shadow.blur().max(LogicalLength::zero());
+        if offset.x == 0 as Coord && offset.y == 0 as Coord && blur.get() == 0 as Coord {
+            // The shadow is hidden behind the element
+            return;
+        }
+        let color = self.alpha_color(box_shadow.color());
+        if color.alpha() == 0 {
+            return;
+        }
+        let shadow_rect =
+            LogicalRect::from_size(size).translate(offset).inflate(blur.get(), blur.get());
+        if !self.should_draw(&shadow_rect) {
+            return;
+        }
+        let Some(options) = BoxShadowOptions::new(self_rc, box_shadow, self.scale_factor) else {
+            return;
+        };
+        // The texture only depends on the shape, it is colorized when drawn
+        let options = BoxShadowOptions { color: Color::default(), ..options };
+        let physical_blur = options.blur.get().max(0.);
+
+        let buffer = self.box_shadow_cache.get_or_create(options, |options| {
+            let alpha_map = box_shadow::render_box_shadow(options);
+            SharedBufferData::AlphaMap { data: alpha_map.data.into(), width: alpha_map.width }
+        });
+        let SharedBufferData::AlphaMap { data, width } = &buffer else { return };
+        if *width == 0 {
+            return;
+        }
+        let height = data.len() / *width as usize;
+
+        let origin = (self.current_state.offset + offset).cast() * self.scale_factor
+            - euclid::vec2(physical_blur, physical_blur);
+        let target_rect =
+            PhysicalRect::new(origin.round().cast(), euclid::size2(*width as _, height as _));
+        let physical_clip =
+            (self.current_state.clip.translate(self.current_state.offset.to_vector()).cast()
+                * self.scale_factor)
+                .round()
+                .cast();
+        let Some(clipped_target) = target_rect.intersection(&physical_clip) else { return };
+        let source_rect = clipped_target.translate(PhysicalPoint::zero() - target_rect.origin);
+
+        self.processor.process_shared_image_buffer(
+            clipped_target.transformed(self.rotation),
+            SharedBufferCommand {
+                buffer,
+                source_rect,
+                extra: SceneTextureExtra {
+                    colorize: color,
+                    alpha: color.alpha(),
+                    rotation: self.rotation.orientation,
+                    dx: Fixed::from_integer(1),
+                    dy: Fixed::from_integer(1),
+                    off_x: Fixed::from_integer(0),
+                    off_y: Fixed::from_integer(0),
+                },
+            },
+        );
     }
 
     fn combine_clip(
@@ -2267,7 +3001,13 @@ fn draw_string(&mut self, string: &str, color: Color) {
                     single_line: false,
                 };
 
-                self.draw_text_paragraph(&paragraph, clip, Default::default(), color, None);
+                self.draw_text_paragraph(
+                    &paragraph,
+                    clip,
+                    Default::default(),
+                    &Brush::SolidColor(color),
+                    None,
+                );
             }
             #[cfg(all(feature = "software-renderer-systemfonts", not(target_arch = "wasm32")))]
             fonts::Font::VectorFont(vf) => {
@@ -2285,7 +3025,13 @@ fn draw_string(&mut self, string: &str, color: Color) {
                     single_line: false,
                 };
 
-                self.draw_text_paragraph(&paragraph, clip, Default::default(), color, None);
+                self.draw_text_paragraph(
+                    &paragraph,
+                    clip,
+                    Default::default(),
+                    &Brush::SolidColor(color),
+                    None,
+                );
             }
         }
     }
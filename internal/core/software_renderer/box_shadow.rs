@@ -0,0 +1,136 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! This module renders the alpha map of a `BoxShadow` for the software renderer.
+//!
+//! The rounded rectangle is rendered with anti-aliasing, and then blurred with three
+//! successive box blurs, which approximate a gaussian blur.
+
+use crate::graphics::boxshadowcache::BoxShadowOptions;
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+#[allow(unused)]
+use num_traits::Float;
+
+/// An alpha map containing a blurred rounded rectangle.
+pub(super) struct BoxShadowAlphaMap {
+    pub data: Vec<u8>,
+    pub width: u16,
+}
+
+/// Renders the shadow described by `options`.
+///
+/// The rectangle is placed at (blur, blur) within the resulting alpha map, which is
+/// therefore `2 * blur` bigger than the rectangle in each dimension.
+pub(super) fn render_box_shadow(options: &BoxShadowOptions) -> BoxShadowAlphaMap {
+    let blur = options.blur.get().max(0.);
+    let (w, h) = (options.width.get(), options.height.get());
+    let width = (w + 2. * blur).ceil().min(u16::MAX as f32) as u16;
+    let height = (h + 2. * blur).ceil().min(u16::MAX as f32) as u16;
+    let radius = options.radius.get().max(0.).min(w.min(h) / 2.);
+
+    // Half the size of the rectangle, and its center
+    let (hw, hh) = (w / 2., h / 2.);
+    let (cx, cy) = (blur + hw, blur + hh);
+
+    let mut data = vec![0u8; width as usize * height as usize];
+    for (y, line) in data.chunks_exact_mut(width.max(1) as usize).enumerate() {
+        let qy = (y as f32 + 0.5 - cy).abs() - (hh - radius);
+        for (x, pixel) in line.iter_mut().enumerate() {
+            let qx = (x as f32 + 0.5 - cx).abs() - (hw - radius);
+            // Signed distance from the border of the rounded rectangle
+            let distance = if qx > 0. && qy > 0. {
+                (qx * qx + qy * qy).sqrt() - radius
+            } else {
+                qx.max(qy) - radius
+            };
+            *pixel = ((0.5 - distance).clamp(0., 1.) * 255.).round() as u8;
+        }
+    }
+
+    // The GPU renderers use a gaussian blur with a standard deviation of half the blur.
+    for box_size in box_sizes_for_gauss(blur / 2.) {
+        box_blur(&mut data, width as usize, height as usize, box_size / 2);
+    }
+
+    BoxShadowAlphaMap { data, width }
+}
+
+/// Compute the size of the three boxes that, applied one after the other, approximate a
+/// gaussian blur with the given standard deviation.
+fn box_sizes_for_gauss(sigma: f32) -> [usize; 3] {
+    const PASSES: f32 = 3.;
+    if sigma < 0.5 {
+        return [1; 3];
+    }
+    let ideal_width = (12. * sigma * sigma / PASSES + 1.).sqrt();
+    let mut lower = ideal_width.floor() as usize;
+    if lower % 2 == 0 {
+        lower -= 1;
+    }
+    let upper = lower + 2;
+    let wl = lower as f32;
+    let lower_count = ((12. * sigma * sigma - PASSES * wl * wl - 4. * PASSES * wl - 3. * PASSES)
+        / (-4. * wl - 4.))
+        .round() as usize;
+    core::array::from_fn(|i| if i < lower_count { lower } else { upper })
+}
+
+/// Blur horizontally then vertically with a box of `2 * radius + 1` pixels.
+/// Pixels outside of the buffer are considered transparent.
+fn box_blur(data: &mut [u8], width: usize, height: usize, radius: usize) {
+    if radius == 0 {
+        return;
+    }
+    let mut line = Vec::with_capacity(width.max(height));
+    for y in 0..height {
+        line.clear();
+        line.extend_from_slice(&data[y * width..][..width]);
+        blur_line(&line, radius, |x, v| data[y * width + x] = v);
+    }
+    for x in 0..width {
+        line.clear();
+        line.extend((0..height).map(|y| data[y * width + x]));
+        blur_line(&line, radius, |y, v| data[y * width + x] = v);
+    }
+}
+
+fn blur_line(line: &[u8], radius: usize, mut set: impl FnMut(usize, u8)) {
+    let window = 2 * radius as u32 + 1;
+    // The sum of the pixels in [i - radius, i + radius]
+    let mut sum: u32 = line.iter().take(radius).map(|v| *v as u32).sum();
+    for i in 0..line.len() {
+        if let Some(v) = line.get(i + radius) {
+            sum += *v as u32;
+        }
+        set(i, ((sum + window / 2) / window) as u8);
+        if i >= radius {
+            sum -= line[i - radius] as u32;
+        }
+    }
+}
+
+#[test]
+fn box_shadow_alpha_map() {
+    let options = BoxShadowOptions {
+        width: euclid::Length::new(10.),
+        height: euclid::Length::new(6.),
+        blur: euclid::Length::new(4.),
+        ..Default::default()
+    };
+    let map = render_box_shadow(&options);
+    assert_eq!((map.width, map.data.len()), (18, 18 * 14));
+    let at = |x: usize, y: usize| map.data[y * map.width as usize + x];
+    // The center is opaque, and fades out towards the edges
+    assert!(at(9, 7) > 180);
+    assert!(at(9, 7) > at(4, 7));
+    assert!(at(4, 7) > at(1, 7));
+    assert!(at(0, 0) < 10);
+    // The result is symmetric
+    assert_eq!(at(2, 3), at(15, 10));
+
+    // Without blur, this is just the rectangle
+    let map = render_box_shadow(&BoxShadowOptions { blur: Default::default(), ..options });
+    assert_eq!((map.width, map.data.len()), (10, 10 * 6));
+    assert!(map.data.iter().all(|v| *v == 255));
+}
@@ -509,6 +509,9 @@ pub(super) fn draw_gradient_line(
 ) {
     let mut buffer = &mut line_buffer
         [rect.origin.x as usize..(rect.origin.x_length() + rect.width_length()).get() as usize];
+    let width = rect.width() as usize;
+    let mut mask =
+        g.mask.as_ref().map(|mask| &mask[(line.get() - rect.min_y()) as usize * width..][..width]);
 
     let fill_col1 = g.flags & 0b010 != 0;
     let fill_col2 = g.flags & 0b100 != 0;
@@ -528,7 +531,7 @@ pub(super) fn draw_gradient_line(
         };
         if (fill_col1 || p >= 0) && (fill_col2 || p < 255) {
             let col = interpolate_color(p.clamp(0, 255) as u32, color1, color2);
-            TargetPixel::blend_slice(buffer, col);
+            blend_masked_slice(buffer, mask, col);
         }
         return;
     }
@@ -547,12 +550,13 @@ pub(super) fn draw_gradient_line(
         let l = (-x as usize).min(buffer.len());
         if invert_slope {
             if fill_col1 {
-                TargetPixel::blend_slice(&mut buffer[..l], g.color1);
+                blend_masked_slice(&mut buffer[..l], mask.map(|m| &m[..l]), g.color1);
             }
         } else if fill_col2 {
-            TargetPixel::blend_slice(&mut buffer[..l], g.color2);
+            blend_masked_slice(&mut buffer[..l], mask.map(|m| &m[..l]), g.color2);
         }
         buffer = &mut buffer[l..];
+        mask = mask.map(|m| &m[l..]);
         x = 0;
     }
 
@@ -560,12 +564,13 @@ pub(super) fn draw_gradient_line(
         let l = len.saturating_sub(x as usize);
         if invert_slope {
             if fill_col2 {
-                TargetPixel::blend_slice(&mut buffer[l..], g.color2);
+                blend_masked_slice(&mut buffer[l..], mask.map(|m| &m[l..]), g.color2);
             }
         } else if fill_col1 {
-            TargetPixel::blend_slice(&mut buffer[l..], g.color1);
+            blend_masked_slice(&mut buffer[l..], mask.map(|m| &m[l..]), g.color1);
         }
         buffer = &mut buffer[..l];
+        mask = mask.map(|m| &m[..l]);
     }
 
     if buffer.is_empty() {
@@ -586,7 +591,7 @@ pub(super) fn draw_gradient_line(
     let mut b = ((color1.blue as u32) << 15).wrapping_add((x * db) as _);
     let mut a = ((color1.alpha as u32) << 15).wrapping_add((x * da) as _);
 
-    if color1.alpha == 255 && color2.alpha == 255 {
+    if color1.alpha == 255 && color2.alpha == 255 && mask.is_none() {
         buffer.fill_with(|| {
             let pix = TargetPixel::from_rgb((r >> 15) as u8, (g >> 15) as u8, (b >> 15) as u8);
             r = r.wrapping_add(dr as _);
@@ -595,13 +600,17 @@ pub(super) fn draw_gradient_line(
             pix
         })
     } else {
-        for pix in buffer {
-            pix.blend(PremultipliedRgbaColor {
+        for (i, pix) in buffer.iter_mut().enumerate() {
+            let color = PremultipliedRgbaColor {
                 red: (r >> 15) as u8,
                 green: (g >> 15) as u8,
                 blue: (b >> 15) as u8,
                 alpha: (a >> 15) as u8,
-            });
+            };
+            match mask {
+                Some(mask) => pix.blend(mask_color(color, mask[i])),
+                None => pix.blend(color),
+            }
             r = r.wrapping_add(dr as _);
             g = g.wrapping_add(dg as _);
             b = b.wrapping_add(db as _);
@@ -610,6 +619,35 @@ pub(super) fn draw_gradient_line(
     }
 }
 
+/// Blend the color on the buffer, multiplied by the alpha of the corresponding pixel of the mask
+fn blend_masked_slice(
+    buffer: &mut [impl TargetPixel],
+    mask: Option<&[u8]>,
+    color: PremultipliedRgbaColor,
+) {
+    match mask {
+        None => TargetPixel::blend_slice(buffer, color),
+        Some(mask) => {
+            for (pix, alpha) in buffer.iter_mut().zip(mask) {
+                if *alpha != 0 {
+                    pix.blend(mask_color(color, *alpha));
+                }
+            }
+        }
+    }
+}
+
+/// Multiply all the components of the pre-multiplied color by `alpha / 255`
+fn mask_color(color: PremultipliedRgbaColor, alpha: u8) -> PremultipliedRgbaColor {
+    let mul = |c: u8| ((c as u16 * alpha as u16) / 255) as u8;
+    PremultipliedRgbaColor {
+        red: mul(color.red),
+        green: mul(color.green),
+        blue: mul(color.blue),
+        alpha: mul(color.alpha),
+    }
+}
+
 /// A color whose component have been pre-multiplied by alpha
 ///
 /// The renderer operates faster on pre-multiplied color since it
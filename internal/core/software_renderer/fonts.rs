@@ -12,7 +12,7 @@
 use super::{PhysicalLength, PhysicalSize};
 use crate::graphics::{BitmapFont, FontRequest};
 use crate::lengths::{LogicalLength, LogicalSize, ScaleFactor};
-use crate::textlayout::TextLayout;
+use crate::textlayout::{FontMetrics as _, TextLayout};
 use crate::Coord;
 
 thread_local! {
@@ -42,6 +42,10 @@ pub fn size(&self) -> PhysicalSize {
 
 pub trait GlyphRenderer {
     fn render_glyph(&self, glyph_id: core::num::NonZeroU16) -> RenderableGlyph;
+    /// The outline of the glyph in physical pixels, relative to its origin on the baseline with
+    /// the y axis pointing down. The path is empty for glyphs without outline, such as the space.
+    /// None if the font has no outlines at all because it only has pre-rendered glyphs.
+    fn glyph_outline(&self, glyph_id: core::num::NonZeroU16) -> Option<lyon_path::Path>;
 }
 
 pub(super) const DEFAULT_FONT_SIZE: LogicalLength = LogicalLength::new(12 as Coord);
@@ -169,3 +173,19 @@ pub fn text_size(
 
     (PhysicalSize::from_lengths(longest_line_width, height).cast() / scale_factor).cast()
 }
+
+pub fn font_metrics(
+    font_request: FontRequest,
+    scale_factor: ScaleFactor,
+) -> crate::graphics::FontMetrics {
+    let (ascent, height) = match match_font(&font_request, scale_factor) {
+        Font::PixelFont(pf) => (pf.ascent(), pf.height()),
+        #[cfg(all(feature = "software-renderer-systemfonts", not(target_arch = "wasm32")))]
+        Font::VectorFont(vf) => (vf.ascent(), vf.height()),
+    };
+    let to_logical = |length: PhysicalLength| (length.cast::<f32>() / scale_factor).get() as Coord;
+    crate::graphics::FontMetrics {
+        ascent: to_logical(ascent),
+        descent: to_logical(height - ascent),
+    }
+}
@@ -58,6 +58,10 @@ fn render_glyph(&self, glyph_id: core::num::NonZeroU16) -> RenderableGlyph {
             alpha_map: bitmap_glyph.data.as_slice().into(),
         }
     }
+
+    fn glyph_outline(&self, _glyph_id: core::num::NonZeroU16) -> Option<lyon_path::Path> {
+        None
+    }
 }
 
 impl TextShaper for PixelFont {
@@ -203,4 +203,80 @@ fn render_glyph(&self, glyph_id: core::num::NonZeroU16) -> super::RenderableGlyp
             }
         })
     }
+
+    fn glyph_outline(&self, glyph_id: core::num::NonZeroU16) -> Option<lyon_path::Path> {
+        sharedfontdb::FONT_DB.with(|db| {
+            db.borrow()
+                .with_face_data(self.id, |face_data, font_index| {
+                    let face = rustybuzz::ttf_parser::Face::parse(face_data, font_index).unwrap();
+                    let mut builder = GlyphOutlineBuilder {
+                        scale: self.scale.get(),
+                        path: lyon_path::Path::builder(),
+                        open: false,
+                    };
+                    // Glyphs such as the space have no outline, the path stays empty
+                    face.outline_glyph(
+                        rustybuzz::ttf_parser::GlyphId(glyph_id.get()),
+                        &mut builder,
+                    );
+                    Some(builder.build())
+                })
+                .unwrap()
+        })
+    }
+}
+
+/// Converts the outline of a glyph from font units to a path in physical pixels, with the y
+/// axis pointing down.
+struct GlyphOutlineBuilder {
+    scale: f32,
+    path: lyon_path::path::Builder,
+    /// true while a contour is started and not closed yet
+    open: bool,
+}
+
+impl GlyphOutlineBuilder {
+    fn point(&self, x: f32, y: f32) -> lyon_path::math::Point {
+        lyon_path::math::point(x * self.scale, -y * self.scale)
+    }
+
+    fn build(mut self) -> lyon_path::Path {
+        if self.open {
+            self.path.end(false);
+        }
+        self.path.build()
+    }
+}
+
+impl rustybuzz::ttf_parser::OutlineBuilder for GlyphOutlineBuilder {
+    fn move_to(&mut self, x: f32, y: f32) {
+        if self.open {
+            self.path.end(false);
+        }
+        let at = self.point(x, y);
+        self.path.begin(at);
+        self.open = true;
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let to = self.point(x, y);
+        self.path.line_to(to);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let (ctrl, to) = (self.point(x1, y1), self.point(x, y));
+        self.path.quadratic_bezier_to(ctrl, to);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let (ctrl1, ctrl2, to) = (self.point(x1, y1), self.point(x2, y2), self.point(x, y));
+        self.path.cubic_bezier_to(ctrl1, ctrl2, to);
+    }
+
+    fn close(&mut self) {
+        if self.open {
+            self.path.end(true);
+            self.open = false;
+        }
+    }
 }
@@ -0,0 +1,430 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! This module contains a small scanline rasterizer used to render the `Path` element
+//! with the software renderer.
+//!
+//! The path is flattened into line segments, and the coverage of each pixel is computed
+//! by sampling a few sub-scanlines per pixel row, with exact horizontal coverage.
+//! The result is an alpha map that can be blended like a glyph.
+
+use super::PhysicalRect;
+use crate::graphics::{GradientStop, RadialGradientBrush};
+use crate::items::FillRule;
+use crate::Color;
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+use lyon_path::iterator::PathIterator;
+use lyon_path::math::{Point, Transform};
+#[allow(unused)]
+use num_traits::Float;
+
+/// Number of sub-scanlines sampled per pixel row.
+const SUBSAMPLES: usize = 5;
+
+/// Tolerance, in physical pixels, used when flattening the curves.
+const FLATTENING_TOLERANCE: f32 = 0.1;
+
+#[derive(Clone, Copy, Debug)]
+struct Edge {
+    /// The top point (lowest y)
+    x0: f32,
+    y0: f32,
+    /// The bottom point (highest y)
+    x1: f32,
+    y1: f32,
+    /// +1 if the edge goes downwards, -1 if it goes upwards.
+    winding: i32,
+}
+
+/// Accumulates line segments and computes their coverage into an alpha map.
+#[derive(Default)]
+pub(super) struct Rasterizer {
+    edges: Vec<Edge>,
+}
+
+impl Rasterizer {
+    fn add_line(&mut self, from: Point, to: Point) {
+        if from.y == to.y || !from.y.is_finite() || !to.y.is_finite() {
+            return;
+        }
+        let edge = if from.y < to.y {
+            Edge { x0: from.x, y0: from.y, x1: to.x, y1: to.y, winding: 1 }
+        } else {
+            Edge { x0: to.x, y0: to.y, x1: from.x, y1: from.y, winding: -1 }
+        };
+        self.edges.push(edge);
+    }
+
+    /// Add a closed polygon. The orientation of the polygon is normalized so that
+    /// overlapping polygons are unified by the non-zero fill rule.
+    fn add_polygon(&mut self, points: &[Point]) {
+        let area: f32 = points
+            .iter()
+            .zip(points.iter().cycle().skip(1))
+            .map(|(a, b)| a.x * b.y - b.x * a.y)
+            .sum();
+        let mut add = |a: Point, b: Point| {
+            if area >= 0. {
+                self.add_line(a, b)
+            } else {
+                self.add_line(b, a)
+            }
+        };
+        for (a, b) in points.iter().zip(points.iter().cycle().skip(1)) {
+            add(*a, *b);
+        }
+    }
+
+    /// Add the edges for the interior of the path, mapped with `transform`.
+    /// Each sub-path is implicitly closed.
+    pub fn fill_path(
+        &mut self,
+        events: impl Iterator<Item = lyon_path::PathEvent>,
+        transform: &Transform,
+    ) {
+        for_each_segment(events, transform, |from, to| self.add_line(from, to));
+    }
+
+    /// Add the edges for the outline of the path, mapped with `transform`, with the given
+    /// stroke width (in the transformed coordinates).
+    /// Joins and caps are round. The result must be rendered with [`FillRule::Nonzero`].
+    pub fn stroke_path(
+        &mut self,
+        events: impl Iterator<Item = lyon_path::PathEvent>,
+        transform: &Transform,
+        stroke_width: f32,
+    ) {
+        let half_width = stroke_width / 2.;
+        if !half_width.is_finite() || half_width <= 0. {
+            return;
+        }
+        // The round joins are only added at the vertices of the original path. Within a
+        // flattened curve, consecutive segments are almost parallel, so the gap between them
+        // is filled with a bevel.
+        let mut previous_normal = None;
+        for event in events.transformed(transform) {
+            match event {
+                lyon_path::Event::Begin { at } => self.add_round_join(at, half_width),
+                lyon_path::Event::Line { from, to } => {
+                    self.add_stroke_segment(from, to, half_width, &mut None);
+                    self.add_round_join(to, half_width);
+                }
+                lyon_path::Event::Quadratic { from, ctrl, to } => {
+                    lyon_path::geom::QuadraticBezierSegment { from, ctrl, to }.for_each_flattened(
+                        FLATTENING_TOLERANCE,
+                        &mut |s| {
+                            self.add_stroke_segment(s.from, s.to, half_width, &mut previous_normal)
+                        },
+                    );
+                    previous_normal = None;
+                    self.add_round_join(to, half_width);
+                }
+                lyon_path::Event::Cubic { from, ctrl1, ctrl2, to } => {
+                    lyon_path::geom::CubicBezierSegment { from, ctrl1, ctrl2, to }
+                        .for_each_flattened(FLATTENING_TOLERANCE, &mut |s| {
+                            self.add_stroke_segment(s.from, s.to, half_width, &mut previous_normal)
+                        });
+                    previous_normal = None;
+                    self.add_round_join(to, half_width);
+                }
+                lyon_path::Event::End { last, first, close } => {
+                    if close && last != first {
+                        self.add_stroke_segment(last, first, half_width, &mut None);
+                        self.add_round_join(first, half_width);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Add the quad covering the stroke of the segment from `from` to `to`.
+    /// If `previous_normal` is set, the gap with the previous segment ending at `from` is
+    /// filled with a bevel, and it is updated with the normal of this segment.
+    fn add_stroke_segment(
+        &mut self,
+        from: Point,
+        to: Point,
+        half_width: f32,
+        previous_normal: &mut Option<lyon_path::math::Vector>,
+    ) {
+        let d = to - from;
+        let len = d.length();
+        if len <= 0. {
+            return;
+        }
+        let n = lyon_path::math::vector(-d.y, d.x) * (half_width / len);
+        self.add_polygon(&[from + n, to + n, to - n, from - n]);
+        if let Some(p) = previous_normal.replace(n) {
+            self.add_polygon(&[from, from + p, from + n]);
+            self.add_polygon(&[from, from - p, from - n]);
+        }
+    }
+
+    fn add_round_join(&mut self, center: Point, radius: f32) {
+        let segments = ((radius * 4.) as usize).clamp(8, 64);
+        let points = (0..segments)
+            .map(|i| {
+                let (s, c) = (i as f32 * core::f32::consts::TAU / segments as f32).sin_cos();
+                center + lyon_path::math::vector(c, s) * radius
+            })
+            .collect::<Vec<_>>();
+        self.add_polygon(&points);
+    }
+
+    /// Returns the bounding box of all the edges, or None if there are no edges.
+    pub fn bounding_box(&self) -> Option<euclid::Box2D<f32, crate::lengths::PhysicalPx>> {
+        let mut iter = self.edges.iter();
+        let first = iter.next()?;
+        let init = euclid::Box2D::new(
+            euclid::point2(first.x0.min(first.x1), first.y0),
+            euclid::point2(first.x0.max(first.x1), first.y1),
+        );
+        Some(iter.fold(init, |b, e| {
+            euclid::Box2D::new(
+                euclid::point2(b.min.x.min(e.x0).min(e.x1), b.min.y.min(e.y0)),
+                euclid::point2(b.max.x.max(e.x0).max(e.x1), b.max.y.max(e.y1)),
+            )
+        }))
+    }
+
+    /// Compute the coverage of the edges within `rect`.
+    ///
+    /// Returns an alpha map of `rect.width() * rect.height()` bytes.
+    pub fn rasterize(mut self, rect: PhysicalRect, fill_rule: FillRule) -> Vec<u8> {
+        let width = rect.width() as usize;
+        let height = rect.height() as usize;
+        let mut result = vec![0u8; width * height];
+        if width == 0 || height == 0 {
+            return result;
+        }
+
+        let (ox, oy) = (rect.origin.x as f32, rect.origin.y as f32);
+        self.edges.sort_unstable_by(|a, b| a.y0.total_cmp(&b.y0));
+
+        let mut next_edge = 0;
+        let mut active: Vec<Edge> = Vec::new();
+        let mut crossings: Vec<(f32, i32)> = Vec::new();
+        let mut accumulator = vec![0f32; width];
+        let weight = 1. / SUBSAMPLES as f32;
+
+        for (row, line) in result.chunks_exact_mut(width).enumerate() {
+            accumulator.fill(0.);
+            for sample in 0..SUBSAMPLES {
+                let y = oy + row as f32 + (sample as f32 + 0.5) * weight;
+                while next_edge < self.edges.len() && self.edges[next_edge].y0 <= y {
+                    active.push(self.edges[next_edge]);
+                    next_edge += 1;
+                }
+                active.retain(|e| e.y1 > y);
+
+                crossings.clear();
+                crossings.extend(active.iter().map(|e| {
+                    let t = (y - e.y0) / (e.y1 - e.y0);
+                    (e.x0 + (e.x1 - e.x0) * t - ox, e.winding)
+                }));
+                crossings.sort_unstable_by(|a, b| a.0.total_cmp(&b.0));
+
+                let mut winding = 0;
+                let mut span_start = 0.;
+                for &(x, w) in crossings.iter() {
+                    let was_inside = is_inside(winding, fill_rule);
+                    winding += w;
+                    let inside = is_inside(winding, fill_rule);
+                    if !was_inside && inside {
+                        span_start = x;
+                    } else if was_inside && !inside {
+                        add_span(&mut accumulator, span_start, x, weight);
+                    }
+                }
+            }
+            for (pixel, coverage) in line.iter_mut().zip(accumulator.iter()) {
+                *pixel = (coverage.clamp(0., 1.) * 255. + 0.5) as u8;
+            }
+        }
+        result
+    }
+}
+
+/// Flatten the path and call `f` for each line segment.
+/// All the sub-paths are closed, even if they are not explicitly closed.
+fn for_each_segment(
+    events: impl Iterator<Item = lyon_path::PathEvent>,
+    transform: &Transform,
+    mut f: impl FnMut(Point, Point),
+) {
+    // Track the start of the sub-path ourselves rather than relying on the `first` and `last`
+    // of the End event.
+    let mut first = Point::zero();
+    let mut last = Point::zero();
+    for event in events.transformed(transform).flattened(FLATTENING_TOLERANCE) {
+        match event {
+            lyon_path::Event::Begin { at } => {
+                first = at;
+                last = at;
+            }
+            lyon_path::Event::Line { from, to } => {
+                f(from, to);
+                last = to;
+            }
+            lyon_path::Event::End { .. } => {
+                if last != first {
+                    f(last, first);
+                }
+                last = first;
+            }
+            lyon_path::Event::Quadratic { .. } | lyon_path::Event::Cubic { .. } => {
+                unreachable!("flattened path only contains lines")
+            }
+        }
+    }
+}
+
+fn is_inside(winding: i32, fill_rule: FillRule) -> bool {
+    match fill_rule {
+        FillRule::Nonzero => winding != 0,
+        FillRule::Evenodd => winding % 2 != 0,
+    }
+}
+
+/// Add the coverage of the horizontal span from `x0` to `x1` to the pixels of `line`.
+fn add_span(line: &mut [f32], x0: f32, x1: f32, weight: f32) {
+    let width = line.len() as f32;
+    let (x0, x1) = (x0.clamp(0., width), x1.clamp(0., width));
+    if x1 <= x0 {
+        return;
+    }
+    let (first, last) = (x0 as usize, x1 as usize);
+    if first == last {
+        line[first] += (x1 - x0) * weight;
+        return;
+    }
+    line[first] += (first as f32 + 1. - x0) * weight;
+    for pixel in &mut line[first + 1..last] {
+        *pixel += weight;
+    }
+    if let Some(pixel) = line.get_mut(last) {
+        *pixel += (x1 - last as f32) * weight;
+    }
+}
+
+/// Returns the path of a rectangle with rounded corners.
+pub(super) fn rounded_rectangle_path(
+    rect: crate::lengths::LogicalRect,
+    radius: crate::lengths::LogicalBorderRadius,
+) -> lyon_path::Path {
+    let radius = radius.cast::<f32>();
+    let mut builder = lyon_path::Path::builder();
+    builder.add_rounded_rectangle(
+        &rect.cast::<f32>().to_box2d().to_untyped(),
+        &lyon_path::builder::BorderRadii {
+            top_left: radius.top_left,
+            top_right: radius.top_right,
+            bottom_left: radius.bottom_left,
+            bottom_right: radius.bottom_right,
+        },
+        lyon_path::Winding::Positive,
+    );
+    builder.build()
+}
+
+/// Returns the color of the radial gradient at the given position within a shape of the given size.
+pub(super) fn radial_gradient_color_at(
+    g: &RadialGradientBrush,
+    pos: euclid::default::Point2D<f32>,
+    size: euclid::default::Size2D<f32>,
+) -> Color {
+    let radius = size.width.max(size.height) / 2.;
+    let center = euclid::point2(size.width / 2., size.height / 2.);
+    let t = if radius > 0. { (pos - center).length() / radius } else { 0. };
+    interpolate_stops(g.stops(), t)
+}
+
+fn interpolate_stops<'a>(stops: impl Iterator<Item = &'a GradientStop>, t: f32) -> Color {
+    let mut previous: Option<&GradientStop> = None;
+    for stop in stops {
+        if t <= stop.position {
+            return match previous {
+                Some(p) if stop.position > p.position => {
+                    let f = (t - p.position) / (stop.position - p.position);
+                    crate::properties::InterpolatedPropertyValue::interpolate(
+                        &p.color,
+                        &stop.color,
+                        f,
+                    )
+                }
+                _ => stop.color,
+            };
+        }
+        previous = Some(stop);
+    }
+    previous.map(|s| s.color).unwrap_or_default()
+}
+
+#[test]
+fn rasterize_square() {
+    let mut rasterizer = Rasterizer::default();
+    rasterizer.add_polygon(&[
+        Point::new(1., 1.),
+        Point::new(3., 1.),
+        Point::new(3., 3.),
+        Point::new(1., 3.),
+    ]);
+    let alpha = rasterizer.rasterize(euclid::rect(0, 0, 4, 4), FillRule::Nonzero);
+    #[rustfmt::skip]
+    assert_eq!(alpha, [
+        0, 0, 0, 0,
+        0, 255, 255, 0,
+        0, 255, 255, 0,
+        0, 0, 0, 0,
+    ]);
+}
+
+#[test]
+fn rasterize_fill_rule() {
+    // Two nested squares with the same orientation
+    let square = |min: f32, max: f32| {
+        [Point::new(min, min), Point::new(max, min), Point::new(max, max), Point::new(min, max)]
+    };
+    let rasterize = |fill_rule| {
+        let mut rasterizer = Rasterizer::default();
+        for (a, b) in [square(0., 3.), square(1., 2.)]
+            .iter()
+            .flat_map(|s| s.iter().zip(s.iter().cycle().skip(1)))
+        {
+            rasterizer.add_line(*a, *b);
+        }
+        rasterizer.rasterize(euclid::rect(0, 0, 3, 3), fill_rule)
+    };
+    assert_eq!(rasterize(FillRule::Nonzero)[4], 255);
+    assert_eq!(rasterize(FillRule::Evenodd)[4], 0);
+    assert_eq!(rasterize(FillRule::Evenodd)[0], 255);
+}
+
+#[test]
+fn stroke_line() {
+    let mut builder = lyon_path::Path::builder();
+    builder.begin(Point::new(1., 2.));
+    builder.line_to(Point::new(7., 2.));
+    builder.end(false);
+    let path = builder.build();
+    let mut rasterizer = Rasterizer::default();
+    rasterizer.stroke_path(path.iter(), &Transform::identity(), 2.);
+    let alpha = rasterizer.rasterize(euclid::rect(0, 0, 8, 4), FillRule::Nonzero);
+    assert_eq!(&alpha[8 + 1..8 + 7], [255; 6]);
+    assert_eq!(&alpha[16 + 1..16 + 7], [255; 6]);
+    assert_eq!(alpha[3], 0);
+    assert_eq!(alpha[24 + 3], 0);
+}
+
+#[test]
+fn gradient_color() {
+    let brush = RadialGradientBrush::new_circle([
+        GradientStop { color: Color::from_rgb_u8(0, 0, 0), position: 0. },
+        GradientStop { color: Color::from_rgb_u8(200, 0, 0), position: 1. },
+    ]);
+    let size = euclid::size2(100., 10.);
+    assert_eq!(radial_gradient_color_at(&brush, euclid::point2(50., 5.), size).red(), 0);
+    assert_eq!(radial_gradient_color_at(&brush, euclid::point2(75., 5.), size).red(), 100);
+    assert_eq!(radial_gradient_color_at(&brush, euclid::point2(100., 5.), size).red(), 200);
+}
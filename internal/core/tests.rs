@@ -24,6 +24,25 @@ pub extern "C" fn slint_mock_elapsed_time(time_in_ms: u64) {
     crate::timers::TimerList::maybe_activate_timers(tick);
 }
 
+/// Advance the mocked time by some milliseconds, like [`slint_mock_elapsed_time`], but stop at
+/// the timeout of each timer in between. The timers fire in order and at their exact time,
+/// repeated timers fire once per interval, and the animations are updated at each step.
+#[no_mangle]
+pub extern "C" fn slint_mock_advance_time(time_in_ms: u64) {
+    let end = crate::animations::current_tick() + core::time::Duration::from_millis(time_in_ms);
+    loop {
+        let now = crate::animations::current_tick();
+        let tick = crate::timers::TimerList::next_timeout()
+            .filter(|timeout| *timeout > now && *timeout < end)
+            .unwrap_or(end);
+        crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| driver.update_animations(tick));
+        crate::timers::TimerList::maybe_activate_timers(tick);
+        if tick == end {
+            break;
+        }
+    }
+}
+
 /// Return the current mocked time.
 #[no_mangle]
 pub extern "C" fn slint_get_mocked_time() -> u64 {
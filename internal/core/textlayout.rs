@@ -38,6 +38,8 @@
 #[cfg(not(feature = "unicode-linebreak"))]
 use linebreak_simple::{BreakOpportunity, LineBreakIterator};
 
+pub(crate) mod bidi;
+use bidi::BidiLevels;
 mod fragments;
 mod glyphclusters;
 mod shaping;
@@ -49,6 +51,11 @@
 
 pub use linebreaker::TextLineBreaker;
 
+mod styled;
+pub use styled::{
+    layout_styled_text, parse_styled_text, SpanStyle, StyledSpan, StyledTextLayout, StyledTextRun,
+};
+
 pub struct TextLayout<'a, Font: AbstractFont> {
     pub font: &'a Font,
     pub letter_spacing: Option<<Font as TextShaper>::Length>,
@@ -102,6 +109,8 @@ pub struct TextParagraphLayout<'a, Font: AbstractFont> {
 impl<'a, Font: AbstractFont> TextParagraphLayout<'a, Font> {
     /// Layout the given string in lines, and call the `layout_line` callback with the line to draw at position y.
     /// The signature of the `layout_line` function is: `(glyph_iterator, line_x, line_y, text_line, selection)`.
+    /// The selection has a horizontal range for each run of adjacent selected glyphs on the line. There are several
+    /// when the selected text goes over runs of different directions.
     /// Returns the baseline y coordinate as Ok, or the break value if `line_callback` returns `core::ops::ControlFlow::Break`.
     pub fn layout_lines<R>(
         &self,
@@ -110,7 +119,29 @@ pub fn layout_lines<R>(
             Font::Length,
             Font::Length,
             &TextLine<Font::Length>,
-            Option<core::ops::Range<Font::Length>>,
+            &[core::ops::Range<Font::Length>],
+        ) -> core::ops::ControlFlow<R>,
+        selection: Option<core::ops::Range<usize>>,
+    ) -> Result<Font::Length, R> {
+        self.layout_lines_with_bidi_levels(
+            |glyphs, line_x, line_y, line, selection, _| {
+                line_callback(glyphs, line_x, line_y, line, selection)
+            },
+            selection,
+        )
+    }
+
+    /// Same as [`Self::layout_lines`], but the callback also gets the bidi levels of the text,
+    /// which are resolved once for the whole layout.
+    fn layout_lines_with_bidi_levels<R>(
+        &self,
+        mut line_callback: impl FnMut(
+            &mut dyn Iterator<Item = PositionedGlyph<Font::Length>>,
+            Font::Length,
+            Font::Length,
+            &TextLine<Font::Length>,
+            &[core::ops::Range<Font::Length>],
+            &BidiLevels,
         ) -> core::ops::ControlFlow<R>,
         selection: Option<core::ops::Range<usize>>,
     ) -> Result<Font::Length, R> {
@@ -177,35 +208,62 @@ pub fn layout_lines<R>(
             };
 
             let x = match self.horizontal_alignment {
-                TextHorizontalAlignment::Left => Font::Length::zero(),
+                TextHorizontalAlignment::Left | TextHorizontalAlignment::Start => {
+                    Font::Length::zero()
+                }
                 TextHorizontalAlignment::Center => self.max_width / two - text_width() / two,
-                TextHorizontalAlignment::Right => self.max_width - text_width(),
+                TextHorizontalAlignment::Right | TextHorizontalAlignment::End => {
+                    self.max_width - text_width()
+                }
             };
 
             let mut elide_glyph = elide_glyph.as_ref();
 
-            let selection = selection
-                .as_ref()
-                .filter(|selection| {
-                    line.byte_range.start < selection.end && selection.start < line.byte_range.end
-                })
-                .map(|selection| {
-                    let mut begin = Font::Length::zero();
-                    let mut end = Font::Length::zero();
-                    for glyph in glyphs[line.glyph_range.clone()].iter() {
-                        if glyph.text_byte_offset < selection.start {
-                            begin += glyph.advance;
-                        }
-                        if glyph.text_byte_offset >= selection.end {
-                            break;
-                        }
-                        end += glyph.advance;
+            let line_glyphs = &glyphs[line.glyph_range.clone()];
+            let bidi_levels = &shape_buffer.bidi_levels;
+            // The glyphs are in logical order, they are visited in the visual order of the line
+            let visual_order = bidi_levels.has_rtl().then(|| {
+                bidi::visual_order(
+                    &line_glyphs
+                        .iter()
+                        .map(|glyph| line_glyph_level(bidi_levels, line, glyph.text_byte_offset))
+                        .collect::<Vec<_>>(),
+                )
+            });
+            let visual_order = visual_order.as_deref();
+            let visual_glyph =
+                move |index: usize| &line_glyphs[visual_order.map_or(index, |order| order[index])];
+            // The trailing whitespace of a right-to-left paragraph is on the left of the line, but
+            // it isn't considered for the alignment, just like on the right for left-to-right text.
+            let start_x = if bidi::is_rtl(bidi_levels.paragraph_level_at(line.byte_range.start)) {
+                Font::Length::zero() - line.trailing_whitespace
+            } else {
+                Font::Length::zero()
+            };
+
+            // With bidirectional text, the selected glyphs aren't necessarily next to each other
+            let mut selection_ranges = Vec::<core::ops::Range<Font::Length>>::new();
+            if let Some(selection) = selection.as_ref().filter(|selection| {
+                line.byte_range.start < selection.end && selection.start < line.byte_range.end
+            }) {
+                let mut glyph_x = start_x;
+                let mut previous_selected = false;
+                for index in 0..line_glyphs.len() {
+                    let glyph = visual_glyph(index);
+                    let glyph_end = glyph_x + glyph.advance;
+                    let selected = selection.contains(&glyph.text_byte_offset);
+                    match selection_ranges.last_mut() {
+                        Some(range) if selected && previous_selected => range.end = glyph_end,
+                        _ if selected => selection_ranges.push(glyph_x..glyph_end),
+                        _ => (),
                     }
-                    begin..end
-                });
+                    previous_selected = selected;
+                    glyph_x = glyph_end;
+                }
+            }
 
-            let glyph_it = glyphs[line.glyph_range.clone()].iter();
-            let mut glyph_x = Font::Length::zero();
+            let glyph_it = (0..line_glyphs.len()).map(visual_glyph);
+            let mut glyph_x = start_x;
             let mut positioned_glyph_it = glyph_it.enumerate().filter_map(|(index, glyph)| {
                 // TODO: cut off at grapheme boundaries
                 if glyph_x > self.max_width {
@@ -243,7 +301,7 @@ pub fn layout_lines<R>(
             });
 
             if let core::ops::ControlFlow::Break(break_val) =
-                line_callback(&mut positioned_glyph_it, x, y, line, selection)
+                line_callback(&mut positioned_glyph_it, x, y, line, &selection_ranges, bidi_levels)
             {
                 return core::ops::ControlFlow::Break(break_val);
             }
@@ -273,17 +331,26 @@ pub fn layout_lines<R>(
         Ok(baseline_y)
     }
 
-    /// Returns the leading edge of the glyph at the given byte offset
+    /// Returns the leading edge of the glyph at the given byte offset. That's the left edge for
+    /// left-to-right text and the right edge for right-to-left text.
     pub fn cursor_pos_for_byte_offset(&self, byte_offset: usize) -> (Font::Length, Font::Length) {
-        let mut last_glyph_right_edge = Font::Length::zero();
+        let mut last_line_end = Font::Length::zero();
         let mut last_line_y = Font::Length::zero();
 
-        match self.layout_lines(
-            |glyphs, line_x, line_y, line, _| {
-                last_glyph_right_edge = euclid::approxord::min(
-                    self.max_width,
-                    line_x + line.width_including_trailing_whitespace(),
-                );
+        match self.layout_lines_with_bidi_levels(
+            |glyphs, line_x, line_y, line, _, bidi_levels| {
+                last_line_end =
+                    if bidi::is_rtl(bidi_levels.paragraph_level_at(line.byte_range.start)) {
+                        euclid::approxord::max(
+                            Font::Length::zero(),
+                            line_x - line.trailing_whitespace,
+                        )
+                    } else {
+                        euclid::approxord::min(
+                            self.max_width,
+                            line_x + line.width_including_trailing_whitespace(),
+                        )
+                    };
                 last_line_y = line_y;
                 if byte_offset >= line.byte_range.end + line.trailing_whitespace_bytes {
                     return core::ops::ControlFlow::Continue(());
@@ -291,18 +358,27 @@ pub fn cursor_pos_for_byte_offset(&self, byte_offset: usize) -> (Font::Length, F
 
                 for positioned_glyph in glyphs {
                     if positioned_glyph.text_byte_offset == byte_offset {
+                        let leading_edge =
+                            if bidi::is_rtl(line_glyph_level(bidi_levels, line, byte_offset)) {
+                                positioned_glyph.x + positioned_glyph.advance
+                            } else {
+                                positioned_glyph.x
+                            };
                         return core::ops::ControlFlow::Break((
-                            euclid::approxord::min(self.max_width, line_x + positioned_glyph.x),
+                            euclid::approxord::max(
+                                Font::Length::zero(),
+                                euclid::approxord::min(self.max_width, line_x + leading_edge),
+                            ),
                             last_line_y,
                         ));
                     }
                 }
 
-                core::ops::ControlFlow::Break((last_glyph_right_edge, last_line_y))
+                core::ops::ControlFlow::Break((last_line_end, last_line_y))
             },
             None,
         ) {
-            Ok(_) => (last_glyph_right_edge, last_line_y),
+            Ok(_) => (last_line_end, last_line_y),
             Err(position) => position,
         }
     }
@@ -312,8 +388,8 @@ pub fn byte_offset_for_position(&self, (pos_x, pos_y): (Font::Length, Font::Leng
         let mut byte_offset = 0;
         let two = Font::LengthPrimitive::one() + Font::LengthPrimitive::one();
 
-        match self.layout_lines(
-            |glyphs, line_x, line_y, line, _| {
+        match self.layout_lines_with_bidi_levels(
+            |glyphs, line_x, line_y, line, _, bidi_levels| {
                 if pos_y >= line_y + self.layout.font.height() {
                     byte_offset = line.byte_range.end;
                     return core::ops::ControlFlow::Continue(());
@@ -323,21 +399,37 @@ pub fn byte_offset_for_position(&self, (pos_x, pos_y): (Font::Length, Font::Leng
                     return core::ops::ControlFlow::Break(line.byte_range.start);
                 }
 
-                while let Some(positioned_glyph) = glyphs.next() {
-                    if pos_x >= line_x + positioned_glyph.x
-                        && pos_x <= line_x + positioned_glyph.x + positioned_glyph.advance
-                    {
-                        if pos_x < line_x + positioned_glyph.x + positioned_glyph.advance / two {
-                            return core::ops::ControlFlow::Break(
-                                positioned_glyph.text_byte_offset,
-                            );
-                        } else if let Some(next_glyph) = glyphs.next() {
-                            return core::ops::ControlFlow::Break(next_glyph.text_byte_offset);
-                        }
+                let glyphs = glyphs.collect::<Vec<_>>();
+                for glyph in &glyphs {
+                    if pos_x < line_x + glyph.x || pos_x > line_x + glyph.x + glyph.advance {
+                        continue;
+                    }
+                    let is_rtl =
+                        bidi::is_rtl(line_glyph_level(bidi_levels, line, glyph.text_byte_offset));
+                    let on_left_half = pos_x < line_x + glyph.x + glyph.advance / two;
+                    if on_left_half != is_rtl {
+                        return core::ops::ControlFlow::Break(glyph.text_byte_offset);
                     }
+                    // The position is on the trailing half of the glyph, so the offset is the one
+                    // of the logically next glyph.
+                    let next_offset = glyphs
+                        .iter()
+                        .map(|g| g.text_byte_offset)
+                        .filter(|offset| *offset > glyph.text_byte_offset)
+                        .min()
+                        .unwrap_or(line.byte_range.end.max(glyph.text_byte_offset));
+                    return core::ops::ControlFlow::Break(next_offset);
                 }
 
-                core::ops::ControlFlow::Break(line.byte_range.end)
+                // The position is beside the text: on the left it's the start of a left-to-right
+                // paragraph, or the end of a right-to-left paragraph.
+                let is_rtl = bidi::is_rtl(bidi_levels.paragraph_level_at(line.byte_range.start));
+                let on_left = glyphs.first().map_or(false, |glyph| pos_x < line_x + glyph.x);
+                core::ops::ControlFlow::Break(if on_left != is_rtl {
+                    line.byte_range.start
+                } else {
+                    line.byte_range.end
+                })
             },
             None,
         ) {
@@ -347,6 +439,20 @@ pub fn byte_offset_for_position(&self, (pos_x, pos_y): (Font::Length, Font::Leng
     }
 }
 
+/// Returns the bidi level of the character at the given byte offset of the line. The trailing
+/// whitespace of a line gets the level of its paragraph (rule L1 of UAX#9).
+fn line_glyph_level<Length: Default + Clone>(
+    bidi_levels: &BidiLevels,
+    line: &TextLine<Length>,
+    byte_offset: usize,
+) -> u8 {
+    if byte_offset >= line.byte_range.end {
+        bidi_levels.paragraph_level_at(line.byte_range.start)
+    } else {
+        bidi_levels.level_at(byte_offset)
+    }
+}
+
 #[test]
 fn test_no_linebreak_opportunity_at_eot() {
     let mut it = LineBreakIterator::new("Hello World");
@@ -690,3 +796,130 @@ fn test_byte_offset() {
     assert_eq!(paragraph.byte_offset_for_position((45., 10.)), end_offset);
     assert_eq!(paragraph.byte_offset_for_position((0., 20.)), end_offset);
 }
+
+#[test]
+#[cfg_attr(
+    not(feature = "unicode-bidi"),
+    ignore = "Not supported without the unicode-bidi feature"
+)]
+fn test_bidi_reordering() {
+    let font = FixedTestFont;
+    // "abc " followed by the Hebrew letters alef, bet, gimel
+    let text = "abc \u{5d0}\u{5d1}\u{5d2}";
+    let alef_offset = 4;
+    let bet_offset = 6;
+
+    let paragraph = TextParagraphLayout {
+        string: text,
+        layout: TextLayout { font: &font, letter_spacing: None },
+        max_width: 100. * 10.,
+        max_height: 10.,
+        horizontal_alignment: TextHorizontalAlignment::Left,
+        vertical_alignment: TextVerticalAlignment::Top,
+        wrap: TextWrap::NoWrap,
+        overflow: TextOverflow::Clip,
+        single_line: true,
+    };
+
+    let mut offsets = Vec::new();
+    paragraph
+        .layout_lines::<()>(
+            |glyphs, _, _, _, _| {
+                offsets.extend(glyphs.map(|glyph| glyph.text_byte_offset));
+                core::ops::ControlFlow::Continue(())
+            },
+            None,
+        )
+        .unwrap();
+    assert_eq!(offsets, [0, 1, 2, 3, 8, 6, 4]);
+
+    // The leading edge of a right-to-left glyph is its right edge
+    assert_eq!(paragraph.cursor_pos_for_byte_offset(alef_offset), (70., 0.));
+    assert_eq!(paragraph.cursor_pos_for_byte_offset(bet_offset), (60., 0.));
+    assert_eq!(paragraph.cursor_pos_for_byte_offset(text.len()), (70., 0.));
+
+    assert_eq!(paragraph.byte_offset_for_position((68., 0.)), alef_offset);
+    assert_eq!(paragraph.byte_offset_for_position((62., 0.)), bet_offset);
+
+    // Selecting "c " and alef gives a range for each of the runs
+    let mut selection_ranges = Vec::new();
+    paragraph
+        .layout_lines::<()>(
+            |_, _, _, _, selection| {
+                selection_ranges.extend_from_slice(selection);
+                core::ops::ControlFlow::Continue(())
+            },
+            Some(2..bet_offset),
+        )
+        .unwrap();
+    assert_eq!(selection_ranges, [20. ..40., 60. ..70.]);
+}
+
+#[test]
+#[cfg_attr(
+    not(feature = "unicode-bidi"),
+    ignore = "Not supported without the unicode-bidi feature"
+)]
+fn test_bidi_rtl_paragraph() {
+    let font = FixedTestFont;
+    // The Hebrew letters alef, bet followed by " ab"
+    let text = "\u{5d0}\u{5d1} ab";
+
+    let paragraph = TextParagraphLayout {
+        string: text,
+        layout: TextLayout { font: &font, letter_spacing: None },
+        max_width: 100. * 10.,
+        max_height: 10.,
+        horizontal_alignment: TextHorizontalAlignment::Left,
+        vertical_alignment: TextVerticalAlignment::Top,
+        wrap: TextWrap::NoWrap,
+        overflow: TextOverflow::Clip,
+        single_line: true,
+    };
+
+    let mut offsets = Vec::new();
+    paragraph
+        .layout_lines::<()>(
+            |glyphs, _, _, _, _| {
+                offsets.extend(glyphs.map(|glyph| glyph.text_byte_offset));
+                core::ops::ControlFlow::Continue(())
+            },
+            None,
+        )
+        .unwrap();
+    assert_eq!(offsets, [5, 6, 4, 2, 0]);
+
+    // The start of a right-to-left paragraph is on the right, and the end on the left
+    assert_eq!(paragraph.cursor_pos_for_byte_offset(0), (50., 0.));
+    assert_eq!(paragraph.cursor_pos_for_byte_offset(text.len()), (0., 0.));
+    assert_eq!(paragraph.byte_offset_for_position((80., 0.)), 0);
+}
+
+#[test]
+fn test_start_end_alignment() {
+    use crate::items::LayoutDirection;
+    let font = FixedTestFont;
+
+    let line_x = |alignment: TextHorizontalAlignment, direction: LayoutDirection| {
+        let paragraph = TextParagraphLayout {
+            string: "ab",
+            layout: TextLayout { font: &font, letter_spacing: None },
+            max_width: 100.,
+            max_height: 10.,
+            horizontal_alignment: alignment.for_direction(direction),
+            vertical_alignment: TextVerticalAlignment::Top,
+            wrap: TextWrap::NoWrap,
+            overflow: TextOverflow::Clip,
+            single_line: true,
+        };
+        paragraph.cursor_pos_for_byte_offset(0).0
+    };
+
+    assert_eq!(line_x(TextHorizontalAlignment::Start, LayoutDirection::LeftToRight), 0.);
+    assert_eq!(line_x(TextHorizontalAlignment::End, LayoutDirection::LeftToRight), 80.);
+    assert_eq!(line_x(TextHorizontalAlignment::Start, LayoutDirection::RightToLeft), 80.);
+    assert_eq!(line_x(TextHorizontalAlignment::End, LayoutDirection::RightToLeft), 0.);
+    // Left, center and right don't depend on the layout direction
+    assert_eq!(line_x(TextHorizontalAlignment::Right, LayoutDirection::RightToLeft), 80.);
+    assert_eq!(line_x(TextHorizontalAlignment::Center, LayoutDirection::RightToLeft), 40.);
+}
@@ -0,0 +1,141 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! Support for bidirectional text, according to the Unicode Bidirectional Algorithm (UAX#9)
+//!
+//! The levels of the characters are resolved for the whole text by the `unicode-bidi` crate, and
+//! the lines are reordered visually according to the rule L2 of the algorithm.
+//! Without the `unicode-bidi` feature, all text is laid out left-to-right.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// The resolved embedding levels of a text. Odd levels are right-to-left.
+#[derive(Default, Debug)]
+pub struct BidiLevels {
+    /// The level of each byte of the text, or empty if the whole text is left-to-right
+    levels: Vec<u8>,
+    /// The byte range and the base level of each paragraph
+    paragraphs: Vec<(Range<usize>, u8)>,
+}
+
+impl BidiLevels {
+    pub fn new(text: &str) -> Self {
+        #[cfg(feature = "unicode-bidi")]
+        {
+            let info = unicode_bidi::BidiInfo::new(text, None);
+            if info.has_rtl() {
+                return Self {
+                    levels: info.levels.iter().map(|level| level.number()).collect(),
+                    paragraphs: info
+                        .paragraphs
+                        .iter()
+                        .map(|paragraph| (paragraph.range.clone(), paragraph.level.number()))
+                        .collect(),
+                };
+            }
+        }
+        let _ = text;
+        Self::default()
+    }
+
+    /// Returns true if the text contains right-to-left characters that need reordering
+    pub fn has_rtl(&self) -> bool {
+        !self.levels.is_empty()
+    }
+
+    /// Returns the level of the character at the given byte offset
+    pub fn level_at(&self, byte_offset: usize) -> u8 {
+        self.levels.get(byte_offset).copied().unwrap_or(0)
+    }
+
+    /// Returns the base level of the paragraph containing the given byte offset
+    pub fn paragraph_level_at(&self, byte_offset: usize) -> u8 {
+        self.paragraphs
+            .iter()
+            .find(|(range, _)| range.contains(&byte_offset))
+            .or(self.paragraphs.last())
+            .map_or(0, |(_, level)| *level)
+    }
+
+    /// Returns the byte ranges of the runs of the text that have the same level, in logical order.
+    /// These are the boundaries at which the text must be shaped separately.
+    pub fn runs(&self, range: Range<usize>) -> impl Iterator<Item = Range<usize>> + '_ {
+        let mut start = range.start;
+        core::iter::from_fn(move || {
+            if start >= range.end {
+                return None;
+            }
+            let level = self.level_at(start);
+            let end = (start + 1..range.end)
+                .find(|offset| self.level_at(*offset) != level)
+                .unwrap_or(range.end);
+            Some(core::mem::replace(&mut start, end)..end)
+        })
+    }
+}
+
+/// Returns true if text at the given level runs right-to-left
+pub fn is_rtl(level: u8) -> bool {
+    level % 2 == 1
+}
+
+/// Returns the indices of the given levels in visual order, from left to right.
+/// This is the rule L2 of UAX#9: from the highest level to the lowest odd level, any contiguous
+/// sequence of items at that level or higher is reversed.
+pub fn visual_order(levels: &[u8]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..levels.len()).collect();
+    let (Some(highest), Some(lowest)) = (levels.iter().max(), levels.iter().min()) else {
+        return order;
+    };
+    let lowest_odd = lowest | 1;
+    for level in (lowest_odd..=*highest).rev() {
+        let mut index = 0;
+        while index < order.len() {
+            if levels[order[index]] < level {
+                index += 1;
+                continue;
+            }
+            let start = index;
+            while index < order.len() && levels[order[index]] >= level {
+                index += 1;
+            }
+            order[start..index].reverse();
+        }
+    }
+    order
+}
+
+#[test]
+fn test_visual_order() {
+    assert_eq!(visual_order(&[]), Vec::<usize>::new());
+    assert_eq!(visual_order(&[0, 0, 0]), [0, 1, 2]);
+    assert_eq!(visual_order(&[1, 1, 1]), [2, 1, 0]);
+    // Latin text with an embedded right-to-left word
+    assert_eq!(visual_order(&[0, 1, 1, 0]), [0, 2, 1, 3]);
+    // Right-to-left text with an embedded number
+    assert_eq!(visual_order(&[1, 2, 2, 1]), [3, 1, 2, 0]);
+}
+
+#[test]
+#[cfg_attr(
+    not(feature = "unicode-bidi"),
+    ignore = "Not supported without the unicode-bidi feature"
+)]
+fn test_bidi_levels() {
+    let levels = BidiLevels::new("abc");
+    assert!(!levels.has_rtl());
+
+    // "abc" followed by the Hebrew letters alef, bet
+    let text = "abc \u{5d0}\u{5d1}";
+    let levels = BidiLevels::new(text);
+    assert!(levels.has_rtl());
+    assert_eq!(levels.paragraph_level_at(0), 0);
+    assert_eq!(levels.level_at(0), 0);
+    assert_eq!(levels.level_at(4), 1);
+    assert_eq!(levels.runs(0..text.len()).collect::<Vec<_>>(), [0..4, 4..text.len()]);
+
+    let levels = BidiLevels::new("\u{5d0}\u{5d1} abc");
+    assert_eq!(levels.paragraph_level_at(0), 1);
+}
@@ -65,8 +65,7 @@ fn next(&mut self) -> Option<Self::Item> {
         let mut cluster_byte_offset;
         loop {
             let glyph = &self.shaped_text.glyphs[self.glyph_index];
-            // Rustybuzz uses a relative byte offset as cluster index
-            cluster_byte_offset = current_run.byte_range.start + glyph.text_byte_offset;
+            cluster_byte_offset = glyph.text_byte_offset;
             if cluster_byte_offset != self.byte_offset {
                 break;
             }
@@ -15,7 +15,7 @@ pub struct TextLine<Length: Default + Clone> {
     // number of bytes in text after byte_range occupied by trailing whitespace
     pub trailing_whitespace_bytes: usize,
     pub(crate) glyph_range: Range<usize>,
-    trailing_whitespace: Length,
+    pub(crate) trailing_whitespace: Length,
     pub(crate) text_width: Length, // with as occupied by the glyphs
 }
 
@@ -5,6 +5,7 @@
 use alloc::vec::Vec;
 use core::ops::Range;
 
+use super::bidi::BidiLevels;
 use super::TextLayout;
 
 /// This struct describes a glyph from shaping to rendering. This includes the relative shaping
@@ -166,8 +167,10 @@ pub struct TextRun {
 }
 
 pub struct ShapeBuffer<Length> {
+    /// The glyphs in logical order, also for right-to-left runs
     pub glyphs: Vec<Glyph<Length>>,
     pub text_runs: Vec<TextRun>,
+    pub bidi_levels: BidiLevels,
 }
 
 impl<Length> ShapeBuffer<Length> {
@@ -177,11 +180,30 @@ pub fn new<Font>(layout: &TextLayout<Font>, text: &str) -> Self
         Length: Copy + core::ops::AddAssign,
     {
         let mut glyphs = Vec::new();
+        let bidi_levels = BidiLevels::new(text);
         let text_runs = ShapeBoundaries::new(text)
             .scan(0, |run_start, run_end| {
+                let range = core::mem::replace(run_start, run_end)..run_end;
+                Some(bidi_levels.runs(range).collect::<Vec<_>>())
+            })
+            .flatten()
+            .map(|Range { start: run_start, end: run_end }| {
                 let glyphs_start = glyphs.len();
 
-                layout.font.shape_text(&text[*run_start..run_end], &mut glyphs);
+                layout.font.shape_text(&text[run_start..run_end], &mut glyphs);
+                // The shaper uses offsets relative to the run
+                for glyph in &mut glyphs[glyphs_start..] {
+                    glyph.text_byte_offset += run_start;
+                }
+
+                // The shaper returns the glyphs of right-to-left runs in visual order, but the line
+                // breaking works in logical order. The lines are reordered visually after breaking.
+                if glyphs.len() > glyphs_start + 1
+                    && glyphs[glyphs_start].text_byte_offset
+                        > glyphs.last().unwrap().text_byte_offset
+                {
+                    glyphs[glyphs_start..].reverse();
+                }
 
                 if let Some(letter_spacing) = layout.letter_spacing {
                     if glyphs.len() > glyphs_start {
@@ -199,17 +221,14 @@ pub fn new<Font>(layout: &TextLayout<Font>, text: &str) -> Self
                     }
                 }
 
-                let run = TextRun {
-                    byte_range: Range { start: *run_start, end: run_end },
+                TextRun {
+                    byte_range: Range { start: run_start, end: run_end },
                     glyph_range: Range { start: glyphs_start, end: glyphs.len() },
-                };
-                *run_start = run_end;
-
-                Some(run)
+                }
             })
             .collect();
 
-        Self { glyphs, text_runs }
+        Self { glyphs, text_runs, bidi_levels }
     }
 }
 
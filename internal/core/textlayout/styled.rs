@@ -0,0 +1,609 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! Parsing and layout of text made of differently styled spans, used by the `StyledText` element.
+//!
+//! The text is written with a small markup language:
+//!  * `<b>`, `<i>`, `<u>` and `<s>` make the text bold, italic, underlined or struck through.
+//!  * `<span>` sets the `color`, `font-size`, `font-weight` and `font-family` attributes.
+//!  * `<a href="...">` marks a link. It accepts the same attributes as `<span>`.
+//!  * `<br>` is a line break.
+//!  * `&lt;`, `&gt;`, `&amp;`, `&quot;`, `&apos;` and numeric character references are replaced.
+//!
+//! Anything else that looks like a tag is kept as text.
+//!
+//! The layout breaks the text at the line break opportunities of the whole paragraph, and
+//! measures the pieces of each span with the font of that span. The pieces of one line are
+//! aligned on a shared baseline, below the highest ascent of the line.
+
+use super::{BreakOpportunity, LineBreakIterator};
+use crate::graphics::{Color, FontMetrics};
+use crate::items::{TextHorizontalAlignment, TextVerticalAlignment};
+use crate::lengths::{LogicalLength, LogicalPoint, LogicalRect, LogicalSize};
+use crate::{Coord, SharedString};
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// The style of a span, as set by the tags around it.
+/// The fields that are `None` use the value of the `StyledText` element.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SpanStyle {
+    pub font_family: Option<SharedString>,
+    pub font_size: Option<LogicalLength>,
+    pub font_weight: Option<i32>,
+    pub italic: bool,
+    pub color: Option<Color>,
+    pub underline: bool,
+    pub strikethrough: bool,
+    /// The `href` of the link this span is part of
+    pub link: Option<SharedString>,
+}
+
+/// A piece of text with its style
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StyledSpan {
+    pub text: String,
+    pub style: SpanStyle,
+}
+
+enum Tag<'a> {
+    Open { name: &'a str, attributes: Vec<(&'a str, String)>, self_closing: bool },
+    Close(&'a str),
+}
+
+/// Parse the markup of a `StyledText` into spans. Consecutive spans never have the same style.
+pub fn parse_styled_text(markup: &str) -> Vec<StyledSpan> {
+    let mut spans: Vec<StyledSpan> = Vec::new();
+    // The tags that are currently open, with the style they apply
+    let mut stack: Vec<(&str, SpanStyle)> = Vec::new();
+    let mut current = String::new();
+
+    let mut flush = |current: &mut String, stack: &[(&str, SpanStyle)]| {
+        if current.is_empty() {
+            return;
+        }
+        let text = core::mem::take(current);
+        let style = stack.last().map(|(_, style)| style.clone()).unwrap_or_default();
+        match spans.last_mut() {
+            Some(last) if last.style == style => last.text.push_str(&text),
+            _ => spans.push(StyledSpan { text, style }),
+        }
+    };
+
+    let mut rest = markup;
+    while !rest.is_empty() {
+        let pos = rest.find(['<', '&']).unwrap_or(rest.len());
+        current.push_str(&rest[..pos]);
+        rest = &rest[pos..];
+        if rest.is_empty() {
+            break;
+        }
+        if rest.starts_with('&') {
+            let (c, len) = parse_entity(rest).unwrap_or(('&', 1));
+            current.push(c);
+            rest = &rest[len..];
+            continue;
+        }
+
+        let Some((tag, len)) = parse_tag(rest) else {
+            current.push('<');
+            rest = &rest[1..];
+            continue;
+        };
+        match tag {
+            Tag::Open { name: "br", .. } => current.push('\n'),
+            Tag::Open { name, attributes, self_closing } => {
+                let mut style = stack.last().map(|(_, style)| style.clone()).unwrap_or_default();
+                if !apply_tag(&mut style, name, &attributes) {
+                    current.push_str(&rest[..len]);
+                    rest = &rest[len..];
+                    continue;
+                }
+                flush(&mut current, &stack);
+                if !self_closing {
+                    stack.push((name, style));
+                }
+            }
+            Tag::Close(name) => {
+                if let Some(pos) = stack.iter().rposition(|(n, _)| *n == name) {
+                    flush(&mut current, &stack);
+                    stack.truncate(pos);
+                } else if !matches!(name, "br" | "b" | "i" | "u" | "s" | "a" | "span") {
+                    current.push_str(&rest[..len]);
+                }
+            }
+        }
+        rest = &rest[len..];
+    }
+    flush(&mut current, &stack);
+    spans
+}
+
+/// Modify the style for the given tag. Returns false if the tag is not known.
+fn apply_tag(style: &mut SpanStyle, name: &str, attributes: &[(&str, String)]) -> bool {
+    match name {
+        "b" => style.font_weight = Some(700),
+        "i" => style.italic = true,
+        "u" => style.underline = true,
+        "s" => style.strikethrough = true,
+        "a" | "span" => {
+            for (attribute, value) in attributes {
+                match *attribute {
+                    "href" if name == "a" => style.link = Some(value.as_str().into()),
+                    "color" => style.color = parse_color(value).or(style.color),
+                    "font-size" => {
+                        let value = value.trim();
+                        let value = value.strip_suffix("px").unwrap_or(value);
+                        if let Ok(size) = value.trim().parse::<Coord>() {
+                            style.font_size = Some(LogicalLength::new(size));
+                        }
+                    }
+                    "font-weight" => match value.trim() {
+                        "bold" => style.font_weight = Some(700),
+                        "normal" => style.font_weight = Some(400),
+                        value => style.font_weight = value.parse().ok().or(style.font_weight),
+                    },
+                    "font-family" => style.font_family = Some(value.as_str().into()),
+                    _ => (),
+                }
+            }
+            if name == "a" {
+                style.underline = true;
+            }
+        }
+        _ => return false,
+    }
+    true
+}
+
+/// Parse a `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa` color
+fn parse_color(value: &str) -> Option<Color> {
+    let hex = value.trim().strip_prefix('#')?;
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let x = u32::from_str_radix(hex, 16).ok()?;
+    let (r, g, b, a) = match hex.len() {
+        3 => ((x >> 8) * 0x11, ((x >> 4) & 0xf) * 0x11, (x & 0xf) * 0x11, 0xff),
+        4 => ((x >> 12) * 0x11, ((x >> 8) & 0xf) * 0x11, ((x >> 4) & 0xf) * 0x11, (x & 0xf) * 0x11),
+        6 => (x >> 16, (x >> 8) & 0xff, x & 0xff, 0xff),
+        8 => (x >> 24, (x >> 16) & 0xff, (x >> 8) & 0xff, x & 0xff),
+        _ => return None,
+    };
+    Some(Color::from_argb_u8(a as u8, r as u8, g as u8, b as u8))
+}
+
+/// Parse the entity at the start of `s`. Returns the character and the length of the entity.
+fn parse_entity(s: &str) -> Option<(char, usize)> {
+    let end = s.find(';')?;
+    let c = match &s[1..end] {
+        "lt" => '<',
+        "gt" => '>',
+        "amp" => '&',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => '\u{a0}',
+        entity => {
+            let number = entity.strip_prefix('#')?;
+            let code = match number.strip_prefix(|c| c == 'x' || c == 'X') {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => number.parse().ok()?,
+            };
+            char::from_u32(code)?
+        }
+    };
+    Some((c, end + 1))
+}
+
+fn unescape(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(pos) = rest.find('&') {
+        result.push_str(&rest[..pos]);
+        let (c, len) = parse_entity(&rest[pos..]).unwrap_or(('&', 1));
+        result.push(c);
+        rest = &rest[pos + len..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Parse the tag at the start of `s`. Returns the tag and its length.
+fn parse_tag(s: &str) -> Option<(Tag<'_>, usize)> {
+    let bytes = s.as_bytes();
+    let is_name_char = |b: u8| b.is_ascii_alphanumeric() || b == b'-' || b == b'_';
+    let name_end = |start: usize| {
+        (start..bytes.len()).find(|i| !is_name_char(bytes[*i])).unwrap_or(bytes.len())
+    };
+    let skip_spaces = |mut i: usize| {
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+        i
+    };
+
+    if bytes.get(1) == Some(&b'/') {
+        let end = name_end(2);
+        if end == 2 {
+            return None;
+        }
+        let i = skip_spaces(end);
+        return (bytes.get(i) == Some(&b'>')).then(|| (Tag::Close(&s[2..end]), i + 1));
+    }
+
+    if !bytes.get(1)?.is_ascii_alphabetic() {
+        return None;
+    }
+    let end = name_end(1);
+    let name = &s[1..end];
+    let mut attributes = Vec::new();
+    let mut i = end;
+    loop {
+        let before_spaces = i;
+        i = skip_spaces(i);
+        match bytes.get(i)? {
+            b'>' => return Some((Tag::Open { name, attributes, self_closing: false }, i + 1)),
+            b'/' => {
+                return (bytes.get(i + 1) == Some(&b'>'))
+                    .then(|| (Tag::Open { name, attributes, self_closing: true }, i + 2))
+            }
+            _ if i == before_spaces => return None,
+            _ => (),
+        }
+        let attr_end = name_end(i);
+        if attr_end == i {
+            return None;
+        }
+        let attribute = &s[i..attr_end];
+        i = skip_spaces(attr_end);
+        if bytes.get(i) != Some(&b'=') {
+            // Attribute without value
+            attributes.push((attribute, String::new()));
+            i = attr_end;
+            continue;
+        }
+        i = skip_spaces(i + 1);
+        let value = match *bytes.get(i)? {
+            quote @ (b'"' | b'\'') => {
+                let len = s[i + 1..].find(quote as char)?;
+                let value = &s[i + 1..i + 1 + len];
+                i += len + 2;
+                value
+            }
+            _ => {
+                let start = i;
+                while i < bytes.len() && !bytes[i].is_ascii_whitespace() && bytes[i] != b'>' {
+                    i += 1;
+                }
+                &s[start..i]
+            }
+        };
+        attributes.push((attribute, unescape(value)));
+    }
+}
+
+/// A piece of text laid out by [`layout_styled_text`]. All its text has the style of the same span.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StyledTextRun {
+    /// The index of the span in the slice given to [`layout_styled_text`]
+    pub span: usize,
+    pub text: SharedString,
+    /// The geometry of the run, relative to the laid out text
+    pub rect: LogicalRect,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct StyledTextLine {
+    runs: Range<usize>,
+    width: Coord,
+}
+
+/// The result of [`layout_styled_text`]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StyledTextLayout {
+    pub runs: Vec<StyledTextRun>,
+    /// The size of the laid out text
+    pub size: LogicalSize,
+    lines: Vec<StyledTextLine>,
+}
+
+impl StyledTextLayout {
+    /// Move the runs so that the text is aligned in a rectangle of the given size
+    pub fn align(
+        &mut self,
+        size: LogicalSize,
+        horizontal_alignment: TextHorizontalAlignment,
+        vertical_alignment: TextVerticalAlignment,
+    ) {
+        let dy = match vertical_alignment {
+            TextVerticalAlignment::Top => 0 as Coord,
+            TextVerticalAlignment::Center => (size.height - self.size.height) / 2 as Coord,
+            TextVerticalAlignment::Bottom => size.height - self.size.height,
+        };
+        for line in &self.lines {
+            let dx = match horizontal_alignment {
+                TextHorizontalAlignment::Left | TextHorizontalAlignment::Start => 0 as Coord,
+                TextHorizontalAlignment::Center => (size.width - line.width) / 2 as Coord,
+                TextHorizontalAlignment::Right | TextHorizontalAlignment::End => {
+                    size.width - line.width
+                }
+            };
+            for run in &mut self.runs[line.runs.clone()] {
+                run.rect.origin += euclid::vec2(dx, dy);
+            }
+        }
+    }
+
+    /// Returns the run at the given position
+    pub fn run_at(&self, position: LogicalPoint) -> Option<&StyledTextRun> {
+        self.runs.iter().find(|run| run.rect.contains(position))
+    }
+}
+
+struct Piece {
+    span: usize,
+    /// The text without the trailing whitespace
+    text: Range<usize>,
+    width: Coord,
+    trailing_whitespace_width: Coord,
+}
+
+/// Lay out the spans in lines no wider than `max_width`.
+///
+/// `measure` returns the width of a text, on one line, and the metrics of the font of the given style.
+pub fn layout_styled_text(
+    spans: &[StyledSpan],
+    max_width: Option<Coord>,
+    mut measure: impl FnMut(&SpanStyle, &str) -> (Coord, FontMetrics),
+) -> StyledTextLayout {
+    let text: String = spans.iter().map(|span| span.text.as_str()).collect();
+    let mut span_ranges = Vec::with_capacity(spans.len());
+    let mut offset = 0;
+    for span in spans {
+        span_ranges.push(offset..offset + span.text.len());
+        offset += span.text.len();
+    }
+    // The width of a space and the font metrics of each span
+    let metrics: Vec<(Coord, FontMetrics)> = spans
+        .iter()
+        .map(|span| {
+            let (x_width, font_metrics) = measure(&span.style, "x");
+            let space = (measure(&span.style, " x").0 - x_width).max(0 as Coord);
+            (space, font_metrics)
+        })
+        .collect();
+
+    let mut layout = StyledTextLayout::default();
+    let mut line_pieces: Vec<Piece> = Vec::new();
+    let mut line_width = 0 as Coord;
+    // The highest ascent and descent of the pieces of the line
+    let mut line_metrics = FontMetrics::default();
+
+    let emit_line = |layout: &mut StyledTextLayout,
+                     pieces: &mut Vec<Piece>,
+                     line_metrics: FontMetrics| {
+        let y = layout.size.height;
+        let first_run = layout.runs.len();
+        let mut x = 0 as Coord;
+        let mut width = 0 as Coord;
+        // Merge the consecutive pieces of the same span
+        let mut current: Option<(usize, Range<usize>, Coord, Coord)> = None;
+        let push_run = |layout: &mut StyledTextLayout, run: (usize, Range<usize>, Coord, Coord)| {
+            let (span, range, run_x, run_width) = run;
+            if range.start == range.end {
+                return;
+            }
+            let run_metrics = metrics[span].1;
+            layout.runs.push(StyledTextRun {
+                span,
+                text: text[range].into(),
+                rect: LogicalRect::new(
+                    LogicalPoint::new(run_x, y + line_metrics.ascent - run_metrics.ascent),
+                    LogicalSize::new(run_width, run_metrics.ascent + run_metrics.descent),
+                ),
+            });
+        };
+        for piece in pieces.drain(..) {
+            match &mut current {
+                Some((span, range, run_x, run_width)) if *span == piece.span => {
+                    if range.start == range.end {
+                        // Don't start a run with whitespace
+                        *range = piece.text.clone();
+                        *run_x = x;
+                    } else {
+                        range.end = piece.text.end;
+                    }
+                    *run_width = x + piece.width - *run_x;
+                }
+                _ => {
+                    if let Some(run) = current.take() {
+                        push_run(layout, run);
+                    }
+                    current = Some((piece.span, piece.text.clone(), x, piece.width));
+                }
+            }
+            if piece.width > 0 as Coord {
+                width = x + piece.width;
+            }
+            x += piece.width + piece.trailing_whitespace_width;
+        }
+        if let Some(run) = current.take() {
+            push_run(layout, run);
+        }
+        layout.lines.push(StyledTextLine { runs: first_run..layout.runs.len(), width });
+        layout.size.width = layout.size.width.max(width);
+        layout.size.height += line_metrics.ascent + line_metrics.descent;
+    };
+
+    let mut break_opportunities = LineBreakIterator::new(&text)
+        .filter(|(offset, _)| *offset < text.len())
+        .chain(core::iter::once((text.len(), BreakOpportunity::Allowed)));
+    let mut fragment_start = 0;
+    let mut span_index = 0;
+    while fragment_start < text.len() {
+        let Some((fragment_end, opportunity)) = break_opportunities.next() else { break };
+        if fragment_end <= fragment_start {
+            continue;
+        }
+
+        let mut pieces = Vec::new();
+        let mut fragment_metrics = FontMetrics::default();
+        while span_index < spans.len() && span_ranges[span_index].start < fragment_end {
+            let span_range = &span_ranges[span_index];
+            let start = span_range.start.max(fragment_start);
+            let end = span_range.end.min(fragment_end);
+            let piece_text = &text[start..end];
+            let content = piece_text.trim_end();
+            let trailing_whitespace = piece_text[content.len()..]
+                .chars()
+                .filter(|c| !matches!(c, '\n' | '\r' | '\u{2028}' | '\u{2029}'))
+                .count();
+            let (space_width, font_metrics) = metrics[span_index];
+            fragment_metrics = max_metrics(fragment_metrics, font_metrics);
+            pieces.push(Piece {
+                span: span_index,
+                text: start..start + content.len(),
+                width: if content.is_empty() {
+                    0 as Coord
+                } else {
+                    measure(&spans[span_index].style, content).0
+                },
+                trailing_whitespace_width: trailing_whitespace as Coord * space_width,
+            });
+            if span_range.end > fragment_end {
+                break;
+            }
+            span_index += 1;
+        }
+
+        let fragment_width =
+            pieces.iter().map(|p| p.width + p.trailing_whitespace_width).sum::<Coord>()
+                - pieces.last().map_or(0 as Coord, |p| p.trailing_whitespace_width);
+        if let Some(max_width) = max_width {
+            if !line_pieces.is_empty() && line_width + fragment_width > max_width {
+                emit_line(&mut layout, &mut line_pieces, line_metrics);
+                line_width = 0 as Coord;
+                line_metrics = FontMetrics::default();
+            }
+        }
+        line_width +=
+            fragment_width + pieces.last().map_or(0 as Coord, |p| p.trailing_whitespace_width);
+        line_metrics = max_metrics(line_metrics, fragment_metrics);
+        line_pieces.extend(pieces);
+
+        if opportunity == BreakOpportunity::Mandatory {
+            emit_line(&mut layout, &mut line_pieces, line_metrics);
+            line_width = 0 as Coord;
+            line_metrics = FontMetrics::default();
+        }
+        fragment_start = fragment_end;
+    }
+
+    if !line_pieces.is_empty() || layout.lines.is_empty() {
+        if line_metrics == FontMetrics::default() {
+            line_metrics = metrics.last().map_or_else(
+                || measure(&SpanStyle::default(), "x").1,
+                |(_, font_metrics)| *font_metrics,
+            );
+        }
+        emit_line(&mut layout, &mut line_pieces, line_metrics);
+    }
+    layout
+}
+
+/// The metrics of a line that contains text of both fonts
+fn max_metrics(a: FontMetrics, b: FontMetrics) -> FontMetrics {
+    FontMetrics { ascent: a.ascent.max(b.ascent), descent: a.descent.max(b.descent) }
+}
+
+#[test]
+fn test_parse_styled_text() {
+    let spans = parse_styled_text(
+        "Hello <b>bold <i>both</i></b>, <a href=\"https://slint.dev\">a &lt;link&gt;</a><br/>\
+         <span color=\"#ff0000\" font-size=\"20px\">red</span> 1 < 2 <unknown>",
+    );
+    let texts = spans.iter().map(|s| s.text.as_str()).collect::<Vec<_>>();
+    assert_eq!(
+        texts,
+        ["Hello ", "bold ", "both", ", ", "a <link>", "\n", "red", " 1 < 2 <unknown>"]
+    );
+    assert_eq!(spans[0].style, SpanStyle::default());
+    assert_eq!(spans[1].style.font_weight, Some(700));
+    assert!(!spans[1].style.italic);
+    assert_eq!(spans[2].style.font_weight, Some(700));
+    assert!(spans[2].style.italic);
+    assert_eq!(spans[4].style.link.as_deref(), Some("https://slint.dev"));
+    assert!(spans[4].style.underline);
+    assert_eq!(spans[6].style.color, Some(Color::from_rgb_u8(255, 0, 0)));
+    assert_eq!(spans[6].style.font_size, Some(LogicalLength::new(20 as Coord)));
+    assert_eq!(spans[7].style, SpanStyle::default());
+}
+
+#[test]
+fn test_layout_styled_text() {
+    // Every character is 10 wide. Bold text is 20 high with its baseline at 16, and the other
+    // text is 10 high with its baseline at 8.
+    let measure = |style: &SpanStyle, text: &str| {
+        let metrics = if style.font_weight == Some(700) {
+            FontMetrics { ascent: 16 as Coord, descent: 4 as Coord }
+        } else {
+            FontMetrics { ascent: 8 as Coord, descent: 2 as Coord }
+        };
+        (text.chars().count() as Coord * 10 as Coord, metrics)
+    };
+    let spans = parse_styled_text("Hello <b>big</b> world\nnext");
+
+    let layout = layout_styled_text(&spans, None, measure);
+    let runs = layout.runs.iter().map(|r| (r.span, r.text.as_str(), r.rect)).collect::<Vec<_>>();
+    assert_eq!(
+        runs,
+        [
+            (0, "Hello", LogicalRect::new(LogicalPoint::new(0., 8.), LogicalSize::new(50., 10.))),
+            (1, "big", LogicalRect::new(LogicalPoint::new(60., 0.), LogicalSize::new(30., 20.))),
+            (2, "world", LogicalRect::new(LogicalPoint::new(100., 8.), LogicalSize::new(50., 10.))),
+            (2, "next", LogicalRect::new(LogicalPoint::new(0., 20.), LogicalSize::new(40., 10.))),
+        ]
+    );
+    assert_eq!(layout.size, LogicalSize::new(150., 30.));
+
+    let mut layout = layout_styled_text(&spans, Some(100.), measure);
+    let lines = layout.runs.iter().map(|r| (r.text.as_str(), r.rect.origin.y)).collect::<Vec<_>>();
+    assert_eq!(lines, [("Hello", 8.), ("big", 0.), ("world", 20.), ("next", 30.)]);
+    assert_eq!(layout.size, LogicalSize::new(90., 40.));
+
+    layout.align(
+        LogicalSize::new(100., 100.),
+        TextHorizontalAlignment::Right,
+        TextVerticalAlignment::Bottom,
+    );
+    assert_eq!(layout.runs[0].rect.origin, LogicalPoint::new(10., 68.));
+    assert_eq!(layout.runs[2].rect.origin, LogicalPoint::new(50., 80.));
+    assert_eq!(layout.run_at(LogicalPoint::new(55., 85.)).map(|r| r.span), Some(2));
+    assert_eq!(layout.run_at(LogicalPoint::new(5., 85.)), None);
+
+    // The descent of the line is the largest one, even if it's not from the highest text
+    let spans = parse_styled_text("<b>big</b><span font-size=\"8\">low</span>");
+    let layout = layout_styled_text(&spans, None, |style, text| {
+        if style.font_size.is_some() {
+            (text.len() as Coord * 10 as Coord, FontMetrics { ascent: 4., descent: 8. })
+        } else {
+            measure(style, text)
+        }
+    });
+    let runs = layout.runs.iter().map(|r| r.rect).collect::<Vec<_>>();
+    assert_eq!(
+        runs,
+        [
+            LogicalRect::new(LogicalPoint::new(0., 0.), LogicalSize::new(30., 20.)),
+            LogicalRect::new(LogicalPoint::new(30., 12.), LogicalSize::new(30., 12.)),
+        ]
+    );
+    assert_eq!(layout.size, LogicalSize::new(60., 24.));
+
+    let layout = layout_styled_text(&[], Some(100.), measure);
+    assert_eq!(layout.size, LogicalSize::new(0., 10.));
+    assert!(layout.runs.is_empty());
+}
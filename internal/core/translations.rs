@@ -1,10 +1,14 @@
 // Copyright © SixtyFPS GmbH <info@slint.dev>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
 
+use crate::api::SelectBundledTranslationError;
 use crate::SharedString;
 use core::fmt::Display;
 pub use formatter::FormatArgs;
 
+#[cfg(all(not(feature = "std"), feature = "unsafe-single-threaded"))]
+use crate::unsafe_single_threaded::thread_local;
+
 mod formatter {
     use core::fmt::{Display, Formatter, Result};
 
@@ -234,6 +238,146 @@ pub fn gettext_bindtextdomain(_domain: &str, _dirname: std::path::PathBuf) -> st
     Ok(())
 }
 
+/// The state of the translations bundled in the binary by the compiler
+struct BundledTranslations {
+    /// The languages of the bundled translations. The first entry is the language of the original
+    /// strings, and is empty
+    languages: core::cell::RefCell<alloc::vec::Vec<SharedString>>,
+    /// The index, in `languages`, of the selected language
+    current_language: core::pin::Pin<alloc::boxed::Box<crate::Property<usize>>>,
+}
+
+thread_local!(
+    static BUNDLED_TRANSLATIONS: BundledTranslations = BundledTranslations {
+        languages: Default::default(),
+        current_language: alloc::boxed::Box::pin(crate::Property::new_named(
+            0,
+            "i_slint_core::translations::current_language"
+        )),
+    }
+);
+
+/// Called by the generated code to register the languages of the bundled translations.
+/// The first language is the language of the original strings, and is empty.
+pub fn set_bundled_languages(languages: &[&str]) {
+    BUNDLED_TRANSLATIONS.with(|bundled| {
+        let mut current = bundled.languages.borrow_mut();
+        if current.iter().map(|l| l.as_str()).ne(languages.iter().copied()) {
+            *current = languages.iter().map(|l| (*l).into()).collect();
+        }
+    });
+}
+
+/// The index of the selected bundled language. Registers a dependency so that the
+/// translated bindings are re-evaluated when the language changes.
+fn current_bundled_language() -> usize {
+    BUNDLED_TRANSLATIONS.with(|bundled| bundled.current_language.as_ref().get())
+}
+
+/// Select the bundled translation for the given language (see [`crate::api::select_bundled_translation`])
+pub fn select_bundled_translation(language: &str) -> Result<(), SelectBundledTranslationError> {
+    BUNDLED_TRANSLATIONS.with(|bundled| {
+        let languages = bundled.languages.borrow();
+        if languages.is_empty() {
+            return Err(SelectBundledTranslationError::NoTranslationsBundled);
+        }
+        // Try the full locale name first (eg. `de_CH`) then only the language (eg. `de`)
+        let language_only = language.split(['_', '-', '.']).next().unwrap_or_default();
+        let index = languages
+            .iter()
+            .position(|l| l == language)
+            .or_else(|| languages.iter().position(|l| l == language_only))
+            .ok_or_else(|| SelectBundledTranslationError::LanguageNotFound {
+                available_languages: languages.iter().cloned().collect(),
+            })?;
+        bundled.current_language.as_ref().set(index);
+        Ok(())
+    })
+}
+
+/// Translate and format a string bundled by the compiler.
+///
+/// `strs` contains the original string at index 0 and the translation for each of the bundled
+/// languages, or None if the string isn't translated in that language.
+pub fn translate_from_bundle(
+    strs: &[Option<&str>],
+    arguments: &(impl FormatArgs + ?Sized),
+) -> SharedString {
+    translate_from_bundle_impl(|language| strs.get(language).copied().flatten(), arguments)
+}
+
+fn translate_from_bundle_impl<'a>(
+    translation: impl Fn(usize) -> Option<&'a str>,
+    arguments: &(impl FormatArgs + ?Sized),
+) -> SharedString {
+    let translated =
+        translation(current_bundled_language()).or_else(|| translation(0)).unwrap_or_default();
+    let mut output = SharedString::default();
+    use core::fmt::Write;
+    write!(output, "{}", formatter::format(translated, arguments)).unwrap();
+    output
+}
+
+/// Same as [`translate_from_bundle`] for a string with plural forms.
+///
+/// `strs` contains the plural forms of the original string at index 0 and of the translation
+/// for each of the bundled languages. `plural_rules` contains the function returning the index
+/// of the plural form to use for `n`, for each language.
+pub fn translate_from_bundle_with_plural(
+    strs: &[Option<&[&str]>],
+    plural_rules: &[Option<fn(i32) -> usize>],
+    arguments: &(impl FormatArgs + ?Sized),
+    n: i32,
+) -> SharedString {
+    translate_from_bundle_impl(
+        |language| {
+            let forms = strs.get(language).copied().flatten()?;
+            let rule = plural_rules.get(language).copied().flatten()?;
+            forms.get(rule(n)).copied()
+        },
+        &WithPlural(arguments, n),
+    )
+}
+
+#[test]
+fn test_translate_from_bundle() {
+    set_bundled_languages(&["", "de", "fr"]);
+    let strs = [Some("Hello {}"), Some("Hallo {}"), None];
+    let plurals: [Option<&[&str]>; 3] =
+        [Some(&["One file", "{n} files"]), Some(&["Eine Datei", "{n} Dateien"]), None];
+    let rules: [Option<fn(i32) -> usize>; 3] =
+        [Some(|n| (n != 1) as usize), Some(|n| (n != 1) as usize), None];
+    assert_eq!(translate_from_bundle(&strs, &["World"]), "Hello World");
+    assert_eq!(
+        translate_from_bundle_with_plural(&plurals, &rules, &[] as &[SharedString], 3),
+        "3 files"
+    );
+
+    select_bundled_translation("de_CH.UTF-8").unwrap();
+    assert_eq!(translate_from_bundle(&strs, &["Welt"]), "Hallo Welt");
+    assert_eq!(
+        translate_from_bundle_with_plural(&plurals, &rules, &[] as &[SharedString], 1),
+        "Eine Datei"
+    );
+
+    // Untranslated strings fall back to the original
+    select_bundled_translation("fr").unwrap();
+    assert_eq!(translate_from_bundle(&strs, &["World"]), "Hello World");
+    assert_eq!(
+        translate_from_bundle_with_plural(&plurals, &rules, &[] as &[SharedString], 2),
+        "2 files"
+    );
+
+    assert_eq!(
+        select_bundled_translation("it"),
+        Err(SelectBundledTranslationError::LanguageNotFound {
+            available_languages: ["", "de", "fr"].into_iter().map(SharedString::from).collect()
+        })
+    );
+    select_bundled_translation("").unwrap();
+    assert_eq!(translate_from_bundle(&strs, &["World"]), "Hello World");
+}
+
 #[cfg(feature = "ffi")]
 mod ffi {
     #![allow(unsafe_code)]
@@ -255,4 +399,64 @@ pub extern "C" fn slint_translate(
         *to_translate =
             translate(to_translate.as_str(), &context, &domain, arguments.as_slice(), n, &plural)
     }
+
+    fn str_from_slice(slice: &Slice<u8>) -> Option<&str> {
+        // An empty string is an untranslated string
+        (!slice.is_empty()).then(|| core::str::from_utf8(slice.as_slice()).unwrap())
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_translate_set_bundled_languages(languages: Slice<Slice<u8>>) {
+        let languages = languages
+            .iter()
+            .map(|l| core::str::from_utf8(l.as_slice()).unwrap())
+            .collect::<alloc::vec::Vec<_>>();
+        set_bundled_languages(&languages);
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_translate_select_bundled_translation(language: Slice<u8>) -> bool {
+        select_bundled_translation(core::str::from_utf8(language.as_slice()).unwrap()).is_ok()
+    }
+
+    /// `strs` contains the original string followed by the translation of each bundled language,
+    /// or an empty string if it is not translated
+    #[no_mangle]
+    pub extern "C" fn slint_translate_from_bundle(
+        strs: Slice<Slice<u8>>,
+        arguments: Slice<SharedString>,
+        output: &mut SharedString,
+    ) {
+        *output = translate_from_bundle_impl(
+            |language| strs.get(language).and_then(str_from_slice),
+            arguments.as_slice(),
+        );
+    }
+
+    /// `strs` contains the plural forms of the original string followed by the plural forms of
+    /// the translation of each bundled language. The plural forms of the language `i` are at
+    /// `indices[i]..indices[i + 1]`.
+    #[no_mangle]
+    pub extern "C" fn slint_translate_from_bundle_with_plural(
+        strs: Slice<Slice<u8>>,
+        indices: Slice<u32>,
+        plural_rules: Slice<Option<extern "C" fn(i32) -> usize>>,
+        arguments: Slice<SharedString>,
+        n: i32,
+        output: &mut SharedString,
+    ) {
+        *output = translate_from_bundle_impl(
+            |language| {
+                let begin = *indices.get(language)? as usize;
+                let end = *indices.get(language + 1)? as usize;
+                let rule = plural_rules.get(language).copied().flatten()?;
+                let index = rule(n);
+                if begin + index >= end {
+                    return None;
+                }
+                strs.get(begin + index).and_then(str_from_slice)
+            },
+            &WithPlural(arguments.as_slice(), n),
+        );
+    }
 }
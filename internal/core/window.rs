@@ -10,15 +10,18 @@
     CloseRequestResponse, LogicalPosition, PhysicalPosition, PhysicalSize, PlatformError, Window,
     WindowPosition, WindowSize,
 };
-use crate::graphics::Point;
+use crate::graphics::{Point, Rect};
 use crate::input::{
     key_codes, ClickState, InternalKeyboardModifierState, KeyEvent, KeyEventType, MouseEvent,
-    MouseInputState, TextCursorBlinker,
+    MouseInputState, TextCursorBlinker, TouchPoint, TouchState, TouchTarget,
 };
-use crate::item_tree::ItemRc;
+use crate::item_tree::{ItemRc, ItemWeak};
 use crate::item_tree::{ItemTreeRc, ItemTreeRef, ItemTreeVTable, ItemTreeWeak};
-use crate::items::{InputType, ItemRef, MouseCursor};
-use crate::lengths::{LogicalLength, LogicalPoint, LogicalRect, SizeLengths};
+use crate::items::{
+    DragArea, DropEvent, InputType, ItemRef, LayoutDirection, MouseCursor, PointerEventButton,
+    PopupClosePolicy, PopupPlacement, TooltipArea,
+};
+use crate::lengths::{LogicalLength, LogicalPoint, LogicalRect, LogicalSize, SizeLengths};
 use crate::properties::{Property, PropertyTracker};
 use crate::renderer::Renderer;
 use crate::{Callback, Coord, SharedString};
@@ -343,9 +346,26 @@ struct PopupWindow {
     location: PopupWindowLocation,
     /// The component that is responsible for providing the popup content.
     component: ItemTreeRc,
-    /// If true, Slint will close the popup after any mouse click within the popup.
-    /// Set to false and call close() on the PopupWindow to close it manually.
-    close_on_click: bool,
+    /// Defines whether Slint closes the popup after a mouse click within the popup, or outside of it.
+    /// With [`PopupClosePolicy::NoAutoClose`], call close() on the PopupWindow to close it manually.
+    close_policy: PopupClosePolicy,
+}
+
+/// Tracks the `TooltipArea` under the pointer, to show its tooltip after a delay.
+#[derive(Default)]
+struct TooltipState {
+    /// The `TooltipArea` under the pointer
+    hovered: ItemWeak,
+    /// The `TooltipArea` that was found under the pointer while dispatching the current mouse event
+    under_pointer: Option<ItemRc>,
+    /// Set after a click or a key press: the tooltip isn't shown until the pointer leaves the area
+    suppressed: bool,
+    /// True when the area is in a popup shown as a child of the window
+    in_popup: bool,
+    /// The last position of the pointer in the window, or in the popup when `in_popup` is set
+    position: LogicalPoint,
+    /// Shows the tooltip once the pointer stayed over the area for the platform's tooltip delay
+    timer: crate::timers::Timer,
 }
 
 #[pin_project::pin_project]
@@ -361,6 +381,11 @@ struct WindowPinnedFields {
     active: Property<bool>,
     #[pin]
     text_input_focused: Property<bool>,
+    /// The text of the tooltip that is shown, or an empty string
+    #[pin]
+    tooltip_text: Property<SharedString>,
+    #[pin]
+    tooltip_position: Property<LogicalPoint>,
 }
 
 /// Inner datastructure for the [`crate::api::Window`]
@@ -370,6 +395,9 @@ pub struct WindowInner {
     /// When the window is visible, keep a strong reference
     strong_component_ref: RefCell<Option<ItemTreeRc>>,
     mouse_input_state: Cell<MouseInputState>,
+    touch_state: RefCell<TouchState>,
+    /// The data being dragged over the window, and the `DragArea` it comes from, if any
+    drag: RefCell<Option<(DropEvent, ItemWeak)>>,
     pub(crate) modifiers: Cell<InternalKeyboardModifierState>,
 
     /// itemRC will retrieve on wasms
@@ -390,6 +418,7 @@ pub struct WindowInner {
 
     active_popup: RefCell<Option<PopupWindow>>,
     had_popup_on_press: Cell<bool>,
+    tooltip: RefCell<TooltipState>,
     close_requested: Callback<(), CloseRequestResponse>,
     click_state: ClickState,
     pub(crate) ctx: once_cell::unsync::Lazy<crate::SlintContext>,
@@ -429,6 +458,8 @@ pub fn new(window_adapter_weak: Weak<dyn WindowAdapter>) -> Self {
             component: Default::default(),
             strong_component_ref: Default::default(),
             mouse_input_state: Default::default(),
+            touch_state: Default::default(),
+            drag: Default::default(),
             modifiers: Default::default(),
             pinned_fields: Box::pin(WindowPinnedFields {
                 redraw_tracker,
@@ -439,6 +470,14 @@ pub fn new(window_adapter_weak: Weak<dyn WindowAdapter>) -> Self {
                     false,
                     "i_slint_core::Window::text_input_focused",
                 ),
+                tooltip_text: Property::new_named(
+                    Default::default(),
+                    "i_slint_core::Window::tooltip_text",
+                ),
+                tooltip_position: Property::new_named(
+                    Default::default(),
+                    "i_slint_core::Window::tooltip_position",
+                ),
             }),
             #[cfg(feature = "std")]
             fullscreen: Cell::new(std::env::var("SLINT_FULLSCREEN").is_ok()),
@@ -451,6 +490,7 @@ pub fn new(window_adapter_weak: Weak<dyn WindowAdapter>) -> Self {
             cursor_blinker: Default::default(),
             active_popup: Default::default(),
             had_popup_on_press: Default::default(),
+            tooltip: Default::default(),
             close_requested: Default::default(),
             click_state: ClickState::default(),
             prevent_focus_change: Default::default(),
@@ -468,6 +508,8 @@ pub fn set_component(&self, component: &ItemTreeRc) {
         self.close_popup();
         self.focus_item.replace(Default::default());
         self.mouse_input_state.replace(Default::default());
+        self.touch_state.replace(Default::default());
+        self.drag.replace(None);
         self.modifiers.replace(Default::default());
         self.component.replace(ItemTreeRc::downgrade(component));
         self.pinned_fields.window_properties_tracker.set_dirty(); // component changed, layout constraints for sure must be re-calculated
@@ -520,6 +562,17 @@ pub fn process_mouse_input(&self, mut event: MouseEvent) {
         // handle multiple press release
         event = self.click_state.check_repeat(event, self.ctx.0.platform.click_interval());
 
+        if self.drag.borrow().is_some() {
+            event = match event {
+                MouseEvent::Moved { position } => MouseEvent::DragMove { position },
+                MouseEvent::Released { position, button: PointerEventButton::Left, .. } => {
+                    MouseEvent::DragDrop { position }
+                }
+                event => event,
+            };
+        }
+        let drop_event = matches!(event, MouseEvent::DragDrop { .. });
+
         let pressed_event = matches!(event, MouseEvent::Pressed { .. });
         let released_event = matches!(event, MouseEvent::Released { .. });
 
@@ -535,34 +588,22 @@ pub fn process_mouse_input(&self, mut event: MouseEvent) {
             self.had_popup_on_press.set(self.active_popup.borrow().is_some());
         }
 
-        let close_popup_on_click = self.close_popup_on_click();
+        let close_policy = self.close_policy();
         let mut mouse_inside_popup = false;
+        // The position of the popup in the window when the event was dispatched to it
+        let mut popup_origin = None;
+        // false when the event only went to the item that grabs the mouse
+        let mut dispatched = false;
 
         mouse_input_state = if let Some(mut event) =
             crate::input::handle_mouse_grab(event, &window_adapter, &mut mouse_input_state)
         {
-            let (item_tree, offset) = if let Some(PopupWindow {
-                location: PopupWindowLocation::ChildWindow(coordinates),
-                component,
-                ..
-            }) = self.active_popup.borrow().as_ref()
-            {
-                let geom = ItemTreeRc::borrow_pin(component).as_ref().item_geometry(0);
-
-                mouse_inside_popup = event
-                    .position()
-                    .map_or(true, |pos| geom.contains(pos - coordinates.to_vector()));
+            dispatched = true;
+            let target = self.item_tree_at(event.position());
+            mouse_inside_popup = target.as_ref().map_or(false, |(_, _, popup)| *popup);
+            popup_origin = target.as_ref().and_then(|(_, offset, popup)| popup.then_some(*offset));
 
-                if mouse_inside_popup {
-                    (Some(component.clone()), *coordinates)
-                } else {
-                    (None, LogicalPoint::default())
-                }
-            } else {
-                (self.component.borrow().upgrade(), LogicalPoint::default())
-            };
-
-            if let Some(item_tree) = item_tree {
+            if let Some((item_tree, offset, _)) = target {
                 event.translate(-offset.to_vector());
                 let mut new_input_state = crate::input::process_mouse_input(
                     item_tree,
@@ -594,14 +635,330 @@ pub fn process_mouse_input(&self, mut event: MouseEvent) {
 
         self.mouse_input_state.set(mouse_input_state);
 
-        if close_popup_on_click
-            && ((mouse_inside_popup && released_event && self.had_popup_on_press.get())
-                || (!mouse_inside_popup && pressed_event))
-        {
+        if dispatched {
+            self.update_tooltip(event.position(), popup_origin, pressed_event);
+        }
+
+        if drop_event {
+            self.end_drag();
+        }
+
+        let close_popup = match close_policy {
+            Some(PopupClosePolicy::CloseOnClick) => {
+                (mouse_inside_popup && released_event && self.had_popup_on_press.get())
+                    || (!mouse_inside_popup && pressed_event)
+            }
+            Some(PopupClosePolicy::CloseOnClickOutside) => !mouse_inside_popup && pressed_event,
+            Some(PopupClosePolicy::NoAutoClose) | None => false,
+        };
+        if close_popup {
             self.close_popup();
         }
     }
 
+    /// Receive the press of a touch point and pass it to the items of the component.
+    ///
+    /// The first touch point acts as a mouse. A touch point pressed in the same `GestureArea`
+    /// as another one starts a gesture with it, and the other touch points press the items
+    /// under them with their own grab.
+    pub(crate) fn process_touch_pressed(&self, touch_id: u64, position: LogicalPoint) {
+        let mut touch_state = self.touch_state.borrow_mut();
+        touch_state.points.retain(|p| p.id != touch_id);
+        let pressed =
+            MouseEvent::Pressed { position, button: PointerEventButton::Left, click_count: 0 };
+        if !touch_state.has_mouse() {
+            touch_state.points.push(TouchPoint {
+                id: touch_id,
+                position,
+                target: TouchTarget::Mouse,
+            });
+            drop(touch_state);
+            self.process_mouse_input(pressed);
+            return;
+        }
+
+        let partner = if touch_state.has_gesture() {
+            None
+        } else {
+            self.component.borrow().upgrade().and_then(|component| {
+                touch_state
+                    .points
+                    .iter()
+                    .filter(|p| !matches!(p.target, TouchTarget::Gesture))
+                    .find(|p| crate::input::in_same_gesture_area(&component, p.position, position))
+                    .map(|p| p.id)
+            })
+        };
+
+        if let Some(partner) = partner {
+            touch_state.points.push(TouchPoint {
+                id: touch_id,
+                position,
+                target: TouchTarget::Gesture,
+            });
+            let event = touch_state.start_gesture(partner, touch_id);
+            drop(touch_state);
+            self.send_touch_event(partner, event);
+        } else {
+            touch_state.points.push(TouchPoint {
+                id: touch_id,
+                position,
+                target: TouchTarget::Items(Default::default()),
+            });
+            drop(touch_state);
+            self.send_touch_event(touch_id, pressed);
+        }
+    }
+
+    /// Receive the move of a touch point and pass it to the items of the component.
+    pub(crate) fn process_touch_moved(&self, touch_id: u64, position: LogicalPoint) {
+        let gesture_event = {
+            let mut touch_state = self.touch_state.borrow_mut();
+            let Some(point) = touch_state.point_mut(touch_id) else { return };
+            point.position = position;
+            touch_state.update_gesture(touch_id)
+        };
+        match gesture_event {
+            Some((first, event)) => self.send_touch_event(first, event),
+            None => self.send_touch_event(touch_id, MouseEvent::Moved { position }),
+        }
+    }
+
+    /// Receive the release of a touch point and pass it to the items of the component.
+    pub(crate) fn process_touch_released(&self, touch_id: u64, position: LogicalPoint) {
+        let gesture_event = {
+            let mut touch_state = self.touch_state.borrow_mut();
+            let Some(point) = touch_state.point_mut(touch_id) else { return };
+            point.position = position;
+            touch_state.end_gesture(touch_id)
+        };
+        if let Some((first, event)) = gesture_event {
+            self.send_touch_event(first, event);
+        }
+        self.send_touch_event(
+            touch_id,
+            MouseEvent::Released { position, button: PointerEventButton::Left, click_count: 0 },
+        );
+
+        let mut touch_state = self.touch_state.borrow_mut();
+        let Some(index) = touch_state.points.iter().position(|p| p.id == touch_id) else { return };
+        let point = touch_state.points.remove(index);
+        drop(touch_state);
+        if let TouchTarget::Items(mouse_input_state) = point.target {
+            // The finger is lifted: the items under it are no longer hovered
+            crate::input::send_exit_events(
+                &mouse_input_state,
+                &mut MouseInputState::default(),
+                None,
+                &self.window_adapter(),
+            );
+        }
+    }
+
+    /// Send the event to the items of the touch point
+    fn send_touch_event(&self, touch_id: u64, event: MouseEvent) {
+        let mut touch_state = self.touch_state.borrow_mut();
+        let Some(point) = touch_state.point_mut(touch_id) else { return };
+        let mouse_input_state = match &mut point.target {
+            TouchTarget::Mouse => None,
+            TouchTarget::Items(mouse_input_state) => Some(core::mem::take(mouse_input_state)),
+            TouchTarget::Gesture => return,
+        };
+        drop(touch_state);
+
+        let Some(mouse_input_state) = mouse_input_state else {
+            self.process_mouse_input(event);
+            return;
+        };
+        let mouse_input_state = self.process_touch_point_input(event, mouse_input_state);
+        if let Some(point) = self.touch_state.borrow_mut().point_mut(touch_id) {
+            point.target = TouchTarget::Items(mouse_input_state);
+        }
+    }
+
+    /// Dispatch the event of a touch point that doesn't emulate the mouse, with its own
+    /// `mouse_input_state`, and return the new state.
+    fn process_touch_point_input(
+        &self,
+        event: MouseEvent,
+        mut mouse_input_state: MouseInputState,
+    ) -> MouseInputState {
+        let window_adapter = self.window_adapter();
+        let Some(mut event) =
+            crate::input::handle_mouse_grab(event, &window_adapter, &mut mouse_input_state)
+        else {
+            return mouse_input_state;
+        };
+
+        let Some((item_tree, offset, _)) = self.item_tree_at(event.position()) else {
+            let mut new_input_state = MouseInputState::default();
+            crate::input::send_exit_events(
+                &mouse_input_state,
+                &mut new_input_state,
+                event.position(),
+                &window_adapter,
+            );
+            return new_input_state;
+        };
+
+        event.translate(-offset.to_vector());
+        let mut new_input_state =
+            crate::input::process_mouse_input(item_tree, event, &window_adapter, mouse_input_state);
+        new_input_state.offset = offset;
+        // The timer of a delayed event only dispatches the mouse: forward the event right away
+        crate::input::process_delayed_event(&window_adapter, new_input_state)
+    }
+
+    /// Returns the item tree that receives the mouse events at `position`, its position in the
+    /// window, and whether it's the popup. Returns `None` if the position is outside of the popup
+    /// that is open.
+    fn item_tree_at(
+        &self,
+        position: Option<LogicalPoint>,
+    ) -> Option<(ItemTreeRc, LogicalPoint, bool)> {
+        if let Some(PopupWindow {
+            location: PopupWindowLocation::ChildWindow(coordinates),
+            component,
+            ..
+        }) = self.active_popup.borrow().as_ref()
+        {
+            let geom = ItemTreeRc::borrow_pin(component).as_ref().item_geometry(0);
+            let inside = position.map_or(true, |pos| geom.contains(pos - coordinates.to_vector()));
+            return inside.then(|| (component.clone(), *coordinates, true));
+        }
+        self.component
+            .borrow()
+            .upgrade()
+            .map(|component| (component, LogicalPoint::default(), false))
+    }
+
+    /// Start a drag and drop operation carrying the given data.
+    ///
+    /// `source` is the `DragArea` that started the drag, or a null item if the data comes
+    /// from outside of the window. Until the left button is released, the mouse moves are
+    /// sent to the items as [`MouseEvent::DragMove`], and the release as [`MouseEvent::DragDrop`].
+    pub fn start_drag(&self, data: DropEvent, source: ItemWeak) {
+        self.drag.replace(Some((data, source)));
+    }
+
+    /// Returns the data of the drag and drop operation in progress, if any.
+    pub fn drag_data(&self) -> Option<DropEvent> {
+        self.drag.borrow().as_ref().map(|(data, _)| data.clone())
+    }
+
+    /// Ends the drag and drop operation in progress, if any.
+    pub fn end_drag(&self) {
+        let Some((_, source)) = self.drag.take() else { return };
+        if let Some(drag_area) = source.upgrade().as_ref().and_then(|s| s.downcast::<DragArea>()) {
+            DragArea::FIELD_OFFSETS.dragging.apply_pin(drag_area.as_pin_ref()).set(false);
+        }
+    }
+
+    /// Called by a `TooltipArea` that is under the pointer while a mouse event is dispatched.
+    pub(crate) fn set_tooltip_area_under_pointer(&self, area: &ItemRc) {
+        let mut tooltip = self.tooltip.borrow_mut();
+        // The items are visited from the front to the back: an area only replaces the previous
+        // one when it's one of its children, otherwise it's below it.
+        let replace = tooltip.under_pointer.as_ref().map_or(true, |previous| {
+            core::iter::successors(area.parent_item(), ItemRc::parent_item).any(|i| i == *previous)
+        });
+        if replace {
+            tooltip.under_pointer = Some(area.clone());
+        }
+    }
+
+    /// Shows or hides the tooltip after a mouse event was dispatched to the items.
+    ///
+    /// `popup_origin` is the position of the popup when the event was dispatched to a popup
+    /// that is shown as a child of the window.
+    fn update_tooltip(
+        &self,
+        position: Option<LogicalPoint>,
+        popup_origin: Option<LogicalPoint>,
+        pressed: bool,
+    ) {
+        let mut tooltip = self.tooltip.borrow_mut();
+        let under_pointer = tooltip.under_pointer.take();
+        if under_pointer != tooltip.hovered.upgrade() {
+            tooltip.hovered = under_pointer.as_ref().map(ItemRc::downgrade).unwrap_or_default();
+            tooltip.suppressed = false;
+            tooltip.in_popup = popup_origin.is_some();
+            tooltip.timer.stop();
+            self.pinned_fields.tooltip_text.set(Default::default());
+            if under_pointer.is_some() {
+                let window_adapter_weak = self.window_adapter_weak.clone();
+                tooltip.timer.start(
+                    crate::timers::TimerMode::SingleShot,
+                    self.ctx.0.platform.tooltip_delay(),
+                    move || {
+                        if let Some(window_adapter) = window_adapter_weak.upgrade() {
+                            WindowInner::from_pub(window_adapter.window()).show_tooltip();
+                        }
+                    },
+                );
+            }
+        }
+        if let Some(position) = position {
+            tooltip.position = position - popup_origin.unwrap_or_default().to_vector();
+        }
+        if pressed {
+            drop(tooltip);
+            self.suppress_tooltip();
+        }
+    }
+
+    /// Hides the tooltip until the pointer leaves the area.
+    fn suppress_tooltip(&self) {
+        let mut tooltip = self.tooltip.borrow_mut();
+        tooltip.suppressed = true;
+        tooltip.timer.stop();
+        self.pinned_fields.tooltip_text.set(Default::default());
+    }
+
+    fn show_tooltip(&self) {
+        let tooltip = self.tooltip.borrow();
+        if tooltip.suppressed {
+            return;
+        }
+        let Some(area) = tooltip.hovered.upgrade() else { return };
+        let Some(area) = area.downcast::<TooltipArea>() else { return };
+        self.pinned_fields.tooltip_position.set(tooltip.position);
+        self.pinned_fields.tooltip_text.set(area.as_pin_ref().tooltip());
+    }
+
+    /// Returns the text of the tooltip shown in the window, or an empty string when there is none.
+    ///
+    /// When `popup` is true, returns the text of the tooltip shown over the active popup instead.
+    pub fn tooltip_text(&self, popup: bool) -> SharedString {
+        if let Some(popup_window) = self.top_level_popup_window().filter(|_| popup) {
+            return WindowInner::from_pub(popup_window.window()).tooltip_text(false);
+        }
+        let text = self.pinned_fields.as_ref().project_ref().tooltip_text.get();
+        if self.tooltip.borrow().in_popup == popup {
+            text
+        } else {
+            Default::default()
+        }
+    }
+
+    /// Returns the position of the pointer in the window when the tooltip was shown.
+    ///
+    /// When `popup` is true, the position is relative to the active popup.
+    pub fn tooltip_position(&self, popup: bool) -> LogicalPoint {
+        if let Some(popup_window) = self.top_level_popup_window().filter(|_| popup) {
+            return WindowInner::from_pub(popup_window.window()).tooltip_position(false);
+        }
+        self.pinned_fields.as_ref().project_ref().tooltip_position.get()
+    }
+
+    /// Returns the window adapter of the active popup, when it is shown in its own window.
+    fn top_level_popup_window(&self) -> Option<Rc<dyn WindowAdapter>> {
+        match &self.active_popup.borrow().as_ref()?.location {
+            PopupWindowLocation::TopLevel(window_adapter) => Some(window_adapter.clone()),
+            PopupWindowLocation::ChildWindow(_) => None,
+        }
+    }
+
     /// Called by the input code's internal timer to send an event that was delayed
     pub(crate) fn process_delayed_event(&self) {
         self.mouse_input_state.set(crate::input::process_delayed_event(
@@ -617,6 +974,8 @@ pub(crate) fn process_delayed_event(&self) {
     /// * `event`: The key event received by the windowing system.
     /// * `component`: The Slint compiled component that provides the tree of items.
     pub fn process_key_input(&self, mut event: KeyEvent) {
+        self.suppress_tooltip();
+
         if let Some(updated_modifier) = self
             .modifiers
             .get()
@@ -895,17 +1254,18 @@ pub fn dark_color_scheme(&self) -> bool {
             .map_or(false, |x| x.dark_color_scheme())
     }
 
-    /// Show a popup at the given position relative to the item
+    /// Show a popup at the given position relative to the item, or next to the anchor (also
+    /// relative to the item) when the placement is not [`PopupPlacement::Fixed`]
     pub fn show_popup(
         &self,
         popup_componentrc: &ItemTreeRc,
         position: Point,
-        close_on_click: bool,
+        anchor: Rect,
+        placement: PopupPlacement,
+        close_policy: PopupClosePolicy,
         parent_item: &ItemRc,
     ) {
-        let position = parent_item.map_to_window(
-            parent_item.geometry().origin + LogicalPoint::from_untyped(position).to_vector(),
-        );
+        let item_origin = parent_item.geometry().origin;
         let popup_component = ItemTreeRc::borrow_pin(popup_componentrc);
         let popup_root = popup_component.as_ref().get_item_ref(0);
 
@@ -931,7 +1291,26 @@ pub fn show_popup(
         w = w.max(LogicalLength::new(layout_info_h.min)).min(LogicalLength::new(layout_info_h.max));
         h = h.max(LogicalLength::new(layout_info_v.min)).min(LogicalLength::new(layout_info_v.max));
 
-        let size = crate::lengths::LogicalSize::from_lengths(w, h);
+        let size = LogicalSize::from_lengths(w, h);
+
+        let position = if placement == PopupPlacement::Fixed {
+            parent_item
+                .map_to_window(item_origin + LogicalPoint::from_untyped(position).to_vector())
+        } else {
+            let anchor = LogicalRect::new(
+                parent_item.map_to_window(item_origin + anchor.origin.to_vector().cast_unit()),
+                anchor.size.cast_unit(),
+            );
+            // The size of the root item is the size of the window
+            let window_size = self
+                .try_component()
+                .map(|c| ItemTreeRc::borrow_pin(&c).as_ref().item_geometry(0).size)
+                .unwrap_or_default();
+            let direction = self
+                .window_item()
+                .map_or(LayoutDirection::LeftToRight, |w| w.as_pin_ref().layout_direction());
+            place_popup(placement, anchor, size, window_size, direction)
+        };
 
         if let Some(window_item) = ItemRef::downcast_pin(popup_root) {
             let width_property =
@@ -961,7 +1340,7 @@ pub fn show_popup(
         self.active_popup.replace(Some(PopupWindow {
             location,
             component: popup_componentrc.clone(),
-            close_on_click,
+            close_policy,
         }));
     }
 
@@ -993,7 +1372,12 @@ pub fn close_popup(&self) {
 
     /// Returns true if the currently active popup is configured to close on click. None if there is no active popup.
     pub fn close_popup_on_click(&self) -> bool {
-        self.active_popup.borrow().as_ref().map_or(false, |popup| popup.close_on_click)
+        self.close_policy() == Some(PopupClosePolicy::CloseOnClick)
+    }
+
+    /// Returns the close policy of the currently active popup. None if there is no active popup.
+    pub fn close_policy(&self) -> Option<PopupClosePolicy> {
+        self.active_popup.borrow().as_ref().map(|popup| popup.close_policy)
     }
 
     /// Returns the scale factor set on the window, as provided by the windowing system.
@@ -1253,14 +1637,18 @@ pub enum GraphicsAPI {
         handle: *const WindowAdapterRcOpaque,
         popup: &ItemTreeRc,
         position: crate::graphics::Point,
-        close_on_click: bool,
+        anchor: crate::graphics::Rect,
+        placement: PopupPlacement,
+        close_policy: PopupClosePolicy,
         parent_item: &ItemRc,
     ) {
         let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
         WindowInner::from_pub(window_adapter.window()).show_popup(
             popup,
             position,
-            close_on_click,
+            anchor,
+            placement,
+            close_policy,
             parent_item,
         );
     }
@@ -1437,6 +1825,28 @@ fn call(&self) -> CloseRequestResponse {
         window_adapter.internal(crate::InternalToken).map_or(false, |x| x.dark_color_scheme())
     }
 
+    /// Returns the text of the tooltip shown in the window, or over its popup, or an empty string
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_tooltip_text(
+        handle: *const WindowAdapterRcOpaque,
+        popup: bool,
+        out: &mut SharedString,
+    ) {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        *out = WindowInner::from_pub(window_adapter.window()).tooltip_text(popup);
+    }
+
+    /// Returns the position of the pointer when the tooltip was shown
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_tooltip_position(
+        handle: *const WindowAdapterRcOpaque,
+        popup: bool,
+        pos: &mut euclid::default::Point2D<f32>,
+    ) {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        *pos = WindowInner::from_pub(window_adapter.window()).tooltip_position(popup).to_untyped()
+    }
+
     /// Dispatch a key pressed or release event
     #[no_mangle]
     pub unsafe extern "C" fn slint_windowrc_dispatch_key_event(
@@ -1526,6 +1936,111 @@ fn call(&self) -> CloseRequestResponse {
     }
 }
 
+/// Compute the position of a popup of the given size in the window, such that it's placed next to
+/// the `anchor` on the side given by the `placement`, or on the opposite side if there is not enough
+/// room. The popup is then moved to stay inside the window.
+/// In a right-to-left window, the start of the anchor is its right side.
+fn place_popup(
+    placement: PopupPlacement,
+    anchor: LogicalRect,
+    size: LogicalSize,
+    window_size: LogicalSize,
+    direction: LayoutDirection,
+) -> LogicalPoint {
+    let rtl = direction == LayoutDirection::RightToLeft;
+    // Position along one axis, either before `start` or after `end`
+    let position =
+        |prefer_before: bool, start: Coord, end: Coord, size: Coord, available: Coord| {
+            let space_before = start;
+            let space_after = available - end;
+            let before = if prefer_before {
+                size <= space_before || (size > space_after && space_before >= space_after)
+            } else {
+                size > space_after && (size <= space_before || space_before > space_after)
+            };
+            if before {
+                start - size
+            } else {
+                end
+            }
+        };
+    let (x, y) = match placement {
+        PopupPlacement::Fixed => (anchor.min_x(), anchor.min_y()),
+        PopupPlacement::Below | PopupPlacement::Above => (
+            if rtl { anchor.max_x() - size.width } else { anchor.min_x() },
+            position(
+                placement == PopupPlacement::Above,
+                anchor.min_y(),
+                anchor.max_y(),
+                size.height,
+                window_size.height,
+            ),
+        ),
+        PopupPlacement::Start | PopupPlacement::End => (
+            position(
+                (placement == PopupPlacement::Start) != rtl,
+                anchor.min_x(),
+                anchor.max_x(),
+                size.width,
+                window_size.width,
+            ),
+            anchor.min_y(),
+        ),
+    };
+    LogicalPoint::new(
+        x.min(window_size.width - size.width).max(0 as Coord),
+        y.min(window_size.height - size.height).max(0 as Coord),
+    )
+}
+
+#[test]
+fn test_place_popup() {
+    let window_size = LogicalSize::new(200 as Coord, 100 as Coord);
+    let size = LogicalSize::new(50 as Coord, 30 as Coord);
+    let anchor = |x, y| LogicalRect::new(LogicalPoint::new(x, y), LogicalSize::new(20., 10.));
+    let place = |placement, x, y| {
+        let pos =
+            place_popup(placement, anchor(x, y), size, window_size, LayoutDirection::LeftToRight);
+        (pos.x, pos.y)
+    };
+    assert_eq!(place(PopupPlacement::Below, 10., 10.), (10., 20.));
+    // Not enough room below: flip above
+    assert_eq!(place(PopupPlacement::Below, 10., 80.), (10., 50.));
+    assert_eq!(place(PopupPlacement::Above, 10., 50.), (10., 20.));
+    // Not enough room above: flip below
+    assert_eq!(place(PopupPlacement::Above, 10., 10.), (10., 20.));
+    // Clamped to the right edge of the window
+    assert_eq!(place(PopupPlacement::Below, 180., 10.), (150., 20.));
+    assert_eq!(place(PopupPlacement::End, 100., 10.), (120., 10.));
+    // Not enough room after the end: flip to the start, and clamp to the bottom
+    assert_eq!(place(PopupPlacement::End, 170., 90.), (120., 70.));
+    assert_eq!(place(PopupPlacement::Start, 10., 10.), (30., 10.));
+    // Not enough room on either side: use the side with the most room, and stay in the window
+    let large = LogicalSize::new(50 as Coord, 70 as Coord);
+    let pos = place_popup(
+        PopupPlacement::Below,
+        anchor(10., 30.),
+        large,
+        window_size,
+        LayoutDirection::LeftToRight,
+    );
+    assert_eq!((pos.x, pos.y), (10., 30.));
+
+    // Right-to-left: the start of the anchor is its right side
+    let place_rtl = |placement, x, y| {
+        let pos =
+            place_popup(placement, anchor(x, y), size, window_size, LayoutDirection::RightToLeft);
+        (pos.x, pos.y)
+    };
+    assert_eq!(place_rtl(PopupPlacement::Below, 100., 10.), (70., 20.));
+    assert_eq!(place_rtl(PopupPlacement::Start, 100., 10.), (120., 10.));
+    assert_eq!(place_rtl(PopupPlacement::End, 100., 10.), (50., 10.));
+    // Not enough room after the end (on the left): flip to the start
+    assert_eq!(place_rtl(PopupPlacement::End, 10., 10.), (30., 10.));
+    // Clamped to the left edge of the window
+    assert_eq!(place_rtl(PopupPlacement::Below, 10., 10.), (0., 20.));
+}
+
 #[cfg(feature = "software-renderer")]
 #[test]
 fn test_empty_window() {
@@ -1753,6 +1753,107 @@ fn check_model(val: Value, r: &[f64]) {
     check_model(instance.get_property("prop").unwrap(), &[]);
 }
 
+#[test]
+fn element_handle_for_interpreted_component() {
+    use i_slint_backend_testing::ElementHandle;
+    i_slint_backend_testing::init();
+    let mut compiler = ComponentCompiler::default();
+    compiler.set_style("fluent".into());
+    let comp_def = spin_on::spin_on(
+        compiler.build_from_source(
+            r#"
+        component Label inherits Text {}
+        export component App inherits Window {
+            out property <int> clicked;
+            greeting := Label { text: "Hello"; }
+            ta := TouchArea {
+                x: 10px; y: 20px; width: 30px; height: 40px;
+                accessible-role: button;
+                accessible-label: "Click me";
+                clicked => { root.clicked += 1; }
+            }
+        }"#
+            .into(),
+            "".into(),
+        ),
+    )
+    .unwrap();
+    let instance = comp_def.create().unwrap();
+
+    let greeting = ElementHandle::find_by_element_id(&instance, "App::greeting");
+    assert_eq!(greeting.len(), 1);
+    assert_eq!(greeting[0].type_name().unwrap(), "Label");
+    assert_eq!(greeting[0].text().unwrap(), "Hello");
+    let label_root = ElementHandle::find_by_element_id(&instance, "Label::root");
+    assert_eq!(label_root.len(), 1);
+    assert_eq!(label_root[0].type_name().unwrap(), "Text");
+
+    let button = ElementHandle::find_by_accessible_role(
+        &instance,
+        i_slint_core::items::AccessibleRole::Button,
+    );
+    assert_eq!(button.len(), 1);
+    assert_eq!(button[0].id().unwrap(), "App::ta");
+    assert_eq!(button[0].accessible_label().unwrap(), "Click me");
+    assert_eq!(button[0].absolute_position(), i_slint_core::api::LogicalPosition::new(10., 20.));
+    button[0].single_click(i_slint_core::items::PointerEventButton::Left);
+    assert_eq!(instance.get_property("clicked").unwrap(), Value::Number(1.));
+}
+
+#[test]
+fn snapshot_of_interpreted_component() {
+    use i_slint_backend_testing::{compare_with_reference, save_snapshot, ImageComparisonOptions};
+    use i_slint_core::graphics::Rgba8Pixel;
+    i_slint_backend_testing::init_with_software_renderer();
+    let mut compiler = ComponentCompiler::default();
+    compiler.set_style("fluent".into());
+    let comp_def = spin_on::spin_on(
+        compiler.build_from_source(
+            r#"
+        export component App inherits Window {
+            width: 20px;
+            height: 10px;
+            background: blue;
+            in property <color> rect-color: red;
+            Rectangle { x: 0; width: 10px; background: root.rect-color; }
+        }"#
+            .into(),
+            "".into(),
+        ),
+    )
+    .unwrap();
+    let instance = comp_def.create().unwrap();
+    instance.window().set_size(i_slint_core::api::PhysicalSize::new(20, 10));
+
+    let snapshot = i_slint_backend_testing::take_snapshot(instance.window()).unwrap();
+    assert_eq!((snapshot.width(), snapshot.height()), (20, 10));
+    assert_eq!(snapshot.as_slice()[0], Rgba8Pixel::new(255, 0, 0, 255));
+    assert_eq!(snapshot.as_slice()[19], Rgba8Pixel::new(0, 0, 255, 255));
+
+    let dir = std::env::temp_dir().join(format!("slint-snapshot-test-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let reference = dir.join("reference.png");
+    save_snapshot(&snapshot, &reference).unwrap();
+    compare_with_reference(&snapshot, &reference, &Default::default()).unwrap();
+
+    instance
+        .set_property(
+            "rect-color",
+            Value::Brush(i_slint_core::Color::from_rgb_u8(0, 0, 255).into()),
+        )
+        .unwrap();
+    let snapshot = i_slint_backend_testing::take_snapshot(instance.window()).unwrap();
+    let diff = dir.join("diff.png");
+    let options =
+        ImageComparisonOptions { diff_image_path: Some(diff.clone()), ..Default::default() };
+    assert!(compare_with_reference(&snapshot, &reference, &options).is_err());
+    assert!(diff.exists());
+    let options =
+        ImageComparisonOptions { max_different_pixels_percentage: 50., ..Default::default() };
+    compare_with_reference(&snapshot, &reference, &options).unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
 #[test]
 fn lang_type_to_value_type() {
     use std::collections::BTreeMap;
@@ -11,7 +11,9 @@
 use i_slint_compiler::object_tree::ElementRc;
 use i_slint_compiler::{diagnostics::BuildDiagnostics, object_tree::PropertyDeclaration};
 use i_slint_compiler::{generator, object_tree, parser, CompilerConfiguration};
-use i_slint_core::accessibility::AccessibleStringProperty;
+use i_slint_core::accessibility::{
+    AccessibilityAction, AccessibleStringProperty, SupportedAccessibilityAction,
+};
 use i_slint_core::component_factory::ComponentFactory;
 use i_slint_core::item_tree::{
     IndexRange, ItemTree, ItemTreeRef, ItemTreeRefPin, ItemTreeVTable, ItemTreeWeak,
@@ -26,7 +28,7 @@
 use i_slint_core::model::RepeatedItemTree;
 use i_slint_core::model::Repeater;
 use i_slint_core::platform::PlatformError;
-use i_slint_core::properties::InterpolatedPropertyValue;
+use i_slint_core::properties::{AnimationKeyframe, InterpolatedPropertyValue};
 use i_slint_core::rtti::{self, AnimatedBindingKind, FieldOffset, PropertyInfo};
 use i_slint_core::slice::Slice;
 use i_slint_core::window::{WindowAdapterRc, WindowInner};
@@ -236,6 +238,21 @@ fn accessible_string_property(
         self.borrow().as_ref().accessible_string_property(index, what, result)
     }
 
+    fn accessibility_action(self: Pin<&Self>, index: u32, action: &AccessibilityAction) {
+        self.borrow().as_ref().accessibility_action(index, action)
+    }
+
+    fn supported_accessibility_actions(
+        self: Pin<&Self>,
+        index: u32,
+    ) -> SupportedAccessibilityAction {
+        self.borrow().as_ref().supported_accessibility_actions(index)
+    }
+
+    fn item_element_infos(self: Pin<&Self>, index: u32, result: &mut SharedString) -> bool {
+        self.borrow().as_ref().item_element_infos(index, result)
+    }
+
     fn window_adapter(self: Pin<&Self>, do_create: bool, result: &mut Option<WindowAdapterRc>) {
         self.borrow().as_ref().window_adapter(do_create, result);
     }
@@ -835,10 +852,14 @@ pub(crate) fn generate_item_tree<'id>(
                 rtti_for::<ImageItem>(),
                 rtti_for::<ClippedImage>(),
                 rtti_for::<Text>(),
+                rtti_for::<StyledText>(),
                 rtti_for::<Rectangle>(),
                 rtti_for::<BasicBorderRectangle>(),
                 rtti_for::<BorderRectangle>(),
                 rtti_for::<TouchArea>(),
+                rtti_for::<GestureArea>(),
+                rtti_for::<DragArea>(),
+                rtti_for::<DropArea>(),
                 rtti_for::<FocusScope>(),
                 rtti_for::<Path>(),
                 rtti_for::<Flickable>(),
@@ -849,6 +870,7 @@ pub(crate) fn generate_item_tree<'id>(
                 rtti_for::<Rotate>(),
                 rtti_for::<Opacity>(),
                 rtti_for::<Layer>(),
+                rtti_for::<TooltipArea>(),
             ]
             .iter()
             .cloned(),
@@ -1162,6 +1184,9 @@ macro_rules! match_enum_type {
         item_geometry,
         accessible_role,
         accessible_string_property,
+        accessibility_action,
+        supported_accessibility_actions,
+        item_element_infos,
         window_adapter,
         drop_in_place,
         dealloc,
@@ -1248,6 +1273,69 @@ pub fn animation_for_property(
     }
 }
 
+/// Returns a function that computes the keyframes of the animation, if it has keyframes
+pub fn keyframes_for_property(
+    component: InstanceRef,
+    animation: &Option<i_slint_compiler::object_tree::PropertyAnimation>,
+) -> Option<Box<dyn Fn() -> Vec<AnimationKeyframe<Value>>>> {
+    use i_slint_compiler::object_tree::PropertyAnimation as Animation;
+    let has_keyframes = |e: &ElementRc| e.borrow().bindings.contains_key("keyframes");
+    let animation = match animation {
+        Some(Animation::Static(e)) if has_keyframes(e) => Animation::Static(e.clone()),
+        Some(Animation::Transition { animations, state_ref })
+            if animations.iter().any(|a| has_keyframes(&a.animation)) =>
+        {
+            Animation::Transition { animations: animations.clone(), state_ref: state_ref.clone() }
+        }
+        _ => return None,
+    };
+    let component_ptr = component.as_ptr();
+    let vtable = NonNull::from(&component.description.ct).cast();
+    Some(Box::new(move || {
+        generativity::make_guard!(guard);
+        let component = unsafe {
+            InstanceRef::from_pin_ref(
+                Pin::new_unchecked(vtable::VRef::from_raw(
+                    vtable,
+                    NonNull::new_unchecked(component_ptr as *mut u8),
+                )),
+                guard,
+            )
+        };
+        let mut context = eval::EvalLocalContext::from_component_instance(component);
+        let anim_elem = match &animation {
+            Animation::Static(e) => e.clone(),
+            Animation::Transition { animations, state_ref } => {
+                let state = eval::eval_expression(state_ref, &mut context);
+                let state_info: i_slint_core::properties::StateInfo = state.try_into().unwrap();
+                match animations.iter().find(|a| {
+                    (a.is_out && a.state_id == state_info.previous_state)
+                        || (!a.is_out && a.state_id == state_info.current_state)
+                }) {
+                    Some(a) => a.animation.clone(),
+                    None => return Vec::new(),
+                }
+            }
+        };
+        let anim_elem = anim_elem.borrow();
+        let Some(keyframes) = anim_elem.bindings.get("keyframes") else { return Vec::new() };
+        let Value::Model(keyframes) = eval::eval_expression(&keyframes.borrow(), &mut context)
+        else {
+            return Vec::new();
+        };
+        i_slint_core::model::Model::iter(&keyframes)
+            .filter_map(|k| {
+                let Value::Struct(k) = k else { return None };
+                Some(AnimationKeyframe {
+                    position: k.get_field("position")?.clone().try_into().ok()?,
+                    value: k.get_field("value")?.clone(),
+                    easing: k.get_field("easing")?.clone().try_into().ok()?,
+                })
+            })
+            .collect()
+    }))
+}
+
 fn make_callback_eval_closure(
     expr: Expression,
     self_weak: &ErasedItemTreeBoxWeak,
@@ -1451,6 +1539,7 @@ pub fn instantiate(
                 }
 
                 let maybe_animation = animation_for_property(instance_ref, &binding.animation);
+                let keyframes = keyframes_for_property(instance_ref, &binding.animation);
                 let item = Pin::new_unchecked(&*instance_ref.as_ptr().add(*offset));
 
                 if !matches!(binding.expression, Expression::Invalid) {
@@ -1462,13 +1551,18 @@ pub fn instantiate(
                         prop_info.set(item, v, None).unwrap();
                     } else {
                         let e = binding.expression.clone();
-                        prop_info
-                            .set_binding(
+                        let binding = Box::new(make_binding_eval_closure(e, &self_weak));
+                        if let Some(keyframes) = keyframes {
+                            prop_info.set_binding_with_keyframes(
                                 item,
-                                Box::new(make_binding_eval_closure(e, &self_weak)),
+                                binding,
                                 maybe_animation,
+                                keyframes,
                             )
-                            .unwrap();
+                        } else {
+                            prop_info.set_binding(item, binding, maybe_animation)
+                        }
+                        .unwrap();
                     }
                 }
                 for nr in &binding.two_way_bindings {
@@ -1480,6 +1574,7 @@ pub fn instantiate(
                 let item = item_within_component.item_from_item_tree(instance_ref.as_ptr());
                 if let Some(prop_rtti) = item_within_component.rtti.properties.get(prop_name) {
                     let maybe_animation = animation_for_property(instance_ref, &binding.animation);
+                    let keyframes = keyframes_for_property(instance_ref, &binding.animation);
                     for nr in &binding.two_way_bindings {
                         // Safety: The compiler must have ensured that the properties exist and are of the same type
                         prop_rtti.link_two_ways(item, get_property_ptr(nr, instance_ref));
@@ -1500,11 +1595,17 @@ pub fn instantiate(
                                 .unwrap();
                         } else {
                             let e = binding.expression.clone();
-                            prop_rtti.set_binding(
-                                item,
-                                Box::new(make_binding_eval_closure(e, &self_weak)),
-                                maybe_animation,
-                            );
+                            let binding = Box::new(make_binding_eval_closure(e, &self_weak));
+                            if let Some(keyframes) = keyframes {
+                                prop_rtti.set_binding_with_keyframes(
+                                    item,
+                                    binding,
+                                    maybe_animation,
+                                    keyframes,
+                                );
+                            } else {
+                                prop_rtti.set_binding(item, binding, maybe_animation);
+                            }
                         }
                     }
                 } else {
@@ -1880,6 +1981,74 @@ extern "C" fn accessible_string_property(
     }
 }
 
+extern "C" fn accessibility_action(
+    component: ItemTreeRefPin,
+    item_index: u32,
+    action: &AccessibilityAction,
+) {
+    let perform = |prop_name, args: &[Value]| {
+        generativity::make_guard!(guard);
+        let instance_ref = unsafe { InstanceRef::from_pin_ref(component, guard) };
+        let nr = instance_ref.description.original_elements[item_index as usize]
+            .borrow()
+            .accessibility_props
+            .0
+            .get(prop_name)
+            .cloned();
+        if let Some(nr) = nr {
+            let instance_ref = crate::eval::ComponentInstance::InstanceRef(instance_ref);
+            crate::eval::invoke_callback(instance_ref, &nr.element(), nr.name(), args).unwrap();
+        }
+    };
+
+    match action {
+        AccessibilityAction::Default => perform("accessible-action-default", &[]),
+        AccessibilityAction::Decrement => perform("accessible-action-decrement", &[]),
+        AccessibilityAction::Increment => perform("accessible-action-increment", &[]),
+        AccessibilityAction::SetValue(a) => {
+            perform("accessible-action-set-value", &[Value::String(a.clone())])
+        }
+    };
+}
+
+extern "C" fn supported_accessibility_actions(
+    component: ItemTreeRefPin,
+    item_index: u32,
+) -> SupportedAccessibilityAction {
+    generativity::make_guard!(guard);
+    let instance_ref = unsafe { InstanceRef::from_pin_ref(component, guard) };
+    let val = instance_ref.description.original_elements[item_index as usize]
+        .borrow()
+        .accessibility_props
+        .0
+        .keys()
+        .filter_map(|x| x.strip_prefix("accessible-action-"))
+        .fold(SupportedAccessibilityAction::default(), |acc, value| {
+            SupportedAccessibilityAction::from_name(&i_slint_compiler::generator::to_pascal_case(
+                value,
+            ))
+            .unwrap_or_else(|| panic!("Not an accessible action: {value:?}"))
+                | acc
+        });
+    val
+}
+
+extern "C" fn item_element_infos(
+    component: ItemTreeRefPin,
+    item_index: u32,
+    result: &mut SharedString,
+) -> bool {
+    generativity::make_guard!(guard);
+    let instance_ref = unsafe { InstanceRef::from_pin_ref(component, guard) };
+    let element_infos =
+        instance_ref.description.original_elements[item_index as usize].borrow().element_infos();
+    if element_infos.is_empty() {
+        return false;
+    }
+    *result = element_infos.into();
+    true
+}
+
 extern "C" fn window_adapter(
     component: ItemTreeRefPin,
     do_create: bool,
@@ -2074,7 +2243,9 @@ pub fn toplevel_instance<'id2>(
 pub fn show_popup(
     popup: &object_tree::PopupWindow,
     pos: i_slint_core::graphics::Point,
-    close_on_click: bool,
+    anchor: i_slint_core::graphics::Rect,
+    placement: i_slint_core::items::PopupPlacement,
+    close_policy: i_slint_core::items::PopupClosePolicy,
     parent_comp: ErasedItemTreeBoxWeak,
     parent_window_adapter: WindowAdapterRc,
     parent_item: &ItemRc,
@@ -2093,7 +2264,9 @@ pub fn show_popup(
     WindowInner::from_pub(parent_window_adapter.window()).show_popup(
         &vtable::VRc::into_dyn(inst),
         pos,
-        close_on_click,
+        anchor,
+        placement,
+        close_policy,
         parent_item,
     );
 }
@@ -33,6 +33,13 @@ fn set_binding(
         binding: Box<dyn Fn() -> Value>,
         animation: AnimatedBindingKind,
     );
+    fn set_binding_with_keyframes(
+        &self,
+        item: Pin<ItemRef>,
+        binding: Box<dyn Fn() -> Value>,
+        animation: AnimatedBindingKind,
+        keyframes: Box<dyn Fn() -> Vec<corelib::properties::AnimationKeyframe<Value>>>,
+    );
     fn offset(&self) -> usize;
 
     /// Safety: Property2 must be a (pinned) pointer to a `Property<T>`
@@ -62,6 +69,22 @@ fn set_binding(
     ) {
         (*self).set_binding(ItemRef::downcast_pin(item).unwrap(), binding, animation).unwrap();
     }
+    fn set_binding_with_keyframes(
+        &self,
+        item: Pin<ItemRef>,
+        binding: Box<dyn Fn() -> Value>,
+        animation: AnimatedBindingKind,
+        keyframes: Box<dyn Fn() -> Vec<corelib::properties::AnimationKeyframe<Value>>>,
+    ) {
+        (*self)
+            .set_binding_with_keyframes(
+                ItemRef::downcast_pin(item).unwrap(),
+                binding,
+                animation,
+                keyframes,
+            )
+            .unwrap();
+    }
     fn offset(&self) -> usize {
         (*self).offset()
     }
@@ -356,6 +379,10 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
             EasingCurve::CubicBezier(a, b, c, d) => {
                 corelib::animations::EasingCurve::CubicBezier([*a, *b, *c, *d])
             }
+            EasingCurve::Steps(steps) => corelib::animations::EasingCurve::Steps(*steps),
+            EasingCurve::Spring(stiffness, damping, mass) => {
+                corelib::animations::EasingCurve::Spring([*stiffness, *damping, *mass])
+            }
         }),
         Expression::LinearGradient{angle, stops} => {
             let angle = eval_expression(angle, local_context);
@@ -436,6 +463,16 @@ fn call_builtin_function(
                 panic!("Cannot get the window from a global component")
             }
         },
+        BuiltinFunction::GetWindowLayoutDirection => match local_context.component_instance {
+            ComponentInstance::InstanceRef(component) => component
+                .access_window(|window| {
+                    window.window_item().unwrap().as_pin_ref().layout_direction()
+                })
+                .into(),
+            ComponentInstance::GlobalComponent(_) => {
+                panic!("Cannot get the window from a global component")
+            }
+        },
         BuiltinFunction::AnimationTick => {
             Value::Number(i_slint_core::animations::animation_tick() as f64)
         }
@@ -573,6 +610,19 @@ fn call_builtin_function(
                     popup.y.name(),
                 )
                 .unwrap();
+                let anchor = load_property_helper(
+                    local_context.component_instance,
+                    &popup.anchor.element(),
+                    popup.anchor.name(),
+                )
+                .unwrap();
+                let anchor_field = |name| match &anchor {
+                    Value::Struct(s) => s.get_field(name).unwrap().clone().try_into().unwrap(),
+                    _ => unreachable!("The anchor of a popup must be a struct"),
+                };
+                let to_enum = |value: &i_slint_compiler::langtype::EnumerationValue| {
+                    Value::EnumerationValue(value.enumeration.name.clone(), value.to_string())
+                };
 
                 generativity::make_guard!(guard);
                 let enclosing_component =
@@ -592,7 +642,12 @@ fn call_builtin_function(
                         x.try_into().unwrap(),
                         y.try_into().unwrap(),
                     ),
-                    popup.close_on_click,
+                    i_slint_core::graphics::Rect::new(
+                        (anchor_field("x"), anchor_field("y")).into(),
+                        (anchor_field("width"), anchor_field("height")).into(),
+                    ),
+                    to_enum(&popup.placement).try_into().unwrap(),
+                    to_enum(&popup.close_policy).try_into().unwrap(),
                     component.self_weak().get().unwrap().clone(),
                     component.window_adapter(),
                     &parent_item,
@@ -859,6 +914,16 @@ fn call_builtin_function(
                 panic!("First argument not an image");
             }
         }
+        BuiltinFunction::KeyEventMatchesShortcut => {
+            if arguments.len() != 2 {
+                panic!("internal error: incorrect argument count to KeyEventMatchesShortcut")
+            }
+            let event: corelib::items::KeyEvent =
+                eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let shortcut: corelib::items::StandardShortcut =
+                eval_expression(&arguments[1], local_context).try_into().unwrap();
+            Value::Bool(event.shortcut() == Some(shortcut))
+        }
         BuiltinFunction::ArrayLength => {
             if arguments.len() != 1 {
                 panic!("internal error: incorrect argument count to ArrayLength")
@@ -873,6 +938,27 @@ fn call_builtin_function(
                 }
             }
         }
+        BuiltinFunction::ArraySplice => {
+            if arguments.len() != 3 {
+                panic!("internal error: incorrect argument count to ArraySplice")
+            }
+            let (Value::Model(array), Value::Model(tail)) = (
+                eval_expression(&arguments[0], local_context),
+                eval_expression(&arguments[2], local_context),
+            ) else {
+                panic!("First or last argument not an array");
+            };
+            let length: i32 = eval_expression(&arguments[1], local_context).try_into().unwrap();
+            array.model_tracker().track_row_count_changes();
+            tail.model_tracker().track_row_count_changes();
+            Value::Model(ModelRc::new(corelib::model::SharedVectorModel::from(
+                array
+                    .iter()
+                    .take(length.max(0) as usize)
+                    .chain(tail.iter())
+                    .collect::<SharedVector<_>>(),
+            )))
+        }
         BuiltinFunction::Rgb => {
             let r: i32 = eval_expression(&arguments[0], local_context).try_into().unwrap();
             let g: i32 = eval_expression(&arguments[1], local_context).try_into().unwrap();
@@ -895,6 +981,9 @@ fn call_builtin_function(
                 panic!("Cannot get the window from a global component")
             }
         },
+        BuiltinFunction::FirstDayOfWeek => {
+            Value::Number(corelib::date_time::first_day_of_week() as f64)
+        }
         BuiltinFunction::TextInputFocused => match local_context.component_instance {
             ComponentInstance::InstanceRef(component) => {
                 Value::Bool(component.access_window(|window| window.text_input_focused()) as _)
@@ -903,6 +992,24 @@ fn call_builtin_function(
                 panic!("Cannot get the window from a global component")
             }
         },
+        BuiltinFunction::TooltipText => match local_context.component_instance {
+            ComponentInstance::InstanceRef(component) => {
+                let popup = eval_expression(&arguments[0], local_context).try_into().unwrap();
+                Value::String(component.access_window(|window| window.tooltip_text(popup)))
+            }
+            ComponentInstance::GlobalComponent(_) => {
+                panic!("Cannot get the window from a global component")
+            }
+        },
+        BuiltinFunction::TooltipPosition => match local_context.component_instance {
+            ComponentInstance::InstanceRef(component) => {
+                let popup = eval_expression(&arguments[0], local_context).try_into().unwrap();
+                component.access_window(|window| window.tooltip_position(popup)).to_untyped().into()
+            }
+            ComponentInstance::GlobalComponent(_) => {
+                panic!("Cannot get the window from a global component")
+            }
+        },
         BuiltinFunction::SetTextInputFocused => match local_context.component_instance {
             ComponentInstance::InstanceRef(component) => {
                 component.access_window(|window| {
@@ -6,10 +6,12 @@
 use crate::Value;
 use i_slint_compiler::expression_tree::Expression;
 use i_slint_compiler::langtype::Type;
-use i_slint_compiler::layout::{Layout, LayoutConstraints, LayoutGeometry, Orientation};
+use i_slint_compiler::layout::{
+    FlowLayout, Layout, LayoutConstraints, LayoutGeometry, LayoutItem, Orientation,
+};
 use i_slint_compiler::namedreference::NamedReference;
 use i_slint_compiler::object_tree::ElementRc;
-use i_slint_core::items::DialogButtonRole;
+use i_slint_core::items::{DialogButtonRole, LayoutDirection};
 use i_slint_core::layout::{self as core_layout};
 use i_slint_core::model::RepeatedItemTree;
 use i_slint_core::slice::Slice;
@@ -67,6 +69,33 @@ pub(crate) fn compute_layout_info(
             }
             .into()
         }
+        Layout::FlowLayout(flow_layout) => {
+            let (padding, spacing) =
+                padding_and_spacing(&flow_layout.geometry, orientation, &expr_eval);
+            if orientation == flow_layout.orientation {
+                let cells =
+                    layout_cells(&flow_layout.elems, orientation, component, &expr_eval, None);
+                core_layout::flow_layout_info(
+                    Slice::from(cells.as_slice()),
+                    spacing,
+                    &padding,
+                    flow_layout_alignment(flow_layout, component),
+                    flow_layout_wrap(flow_layout, component),
+                )
+            } else {
+                let cross_cells =
+                    layout_cells(&flow_layout.elems, orientation, component, &expr_eval, None);
+                with_flow_layout_data(flow_layout, component, &expr_eval, |data, _| {
+                    core_layout::flow_layout_info_cross(
+                        data,
+                        Slice::from(cross_cells.as_slice()),
+                        spacing,
+                        &padding,
+                    )
+                })
+            }
+            .into()
+        }
     }
 }
 
@@ -82,6 +111,11 @@ pub(crate) fn solve_layout(
     let expr_eval = |nr: &NamedReference| -> f32 {
         eval::load_property(component, &nr.element(), nr.name()).unwrap().try_into().unwrap()
     };
+    let direction = || match orientation {
+        Orientation::Horizontal => component
+            .access_window(|window| window.window_item().unwrap().as_pin_ref().layout_direction()),
+        Orientation::Vertical => LayoutDirection::LeftToRight,
+    };
 
     match lay {
         Layout::GridLayout(grid_layout) => {
@@ -104,6 +138,7 @@ pub(crate) fn solve_layout(
                 size: size_ref.map(expr_eval).unwrap_or(0.),
                 spacing,
                 padding,
+                direction: direction(),
                 cells: Slice::from(cells.as_slice()),
             })
             .into()
@@ -129,12 +164,45 @@ pub(crate) fn solve_layout(
                     spacing,
                     padding,
                     alignment,
+                    direction: direction(),
                     cells: Slice::from(cells.as_slice()),
                 },
                 Slice::from(repeated_indices.as_slice()),
             )
             .into()
         }
+        Layout::FlowLayout(flow_layout) => if orientation == flow_layout.orientation {
+            with_flow_layout_data(flow_layout, component, &expr_eval, |data, repeated_indices| {
+                core_layout::solve_flow_layout(data, repeated_indices)
+            })
+        } else {
+            let cross_cells =
+                layout_cells(&flow_layout.elems, orientation, component, &expr_eval, None);
+            let (padding, spacing) =
+                padding_and_spacing(&flow_layout.geometry, orientation, &expr_eval);
+            let size_ref = flow_layout.geometry.rect.size_reference(orientation);
+            let alignment = flow_layout
+                .cross_alignment
+                .as_ref()
+                .map(|nr| {
+                    eval::load_property(component, &nr.element(), nr.name())
+                        .unwrap()
+                        .try_into()
+                        .unwrap_or_default()
+                })
+                .unwrap_or_default();
+            let cross = core_layout::FlowCrossLayoutData {
+                size: size_ref.map(&expr_eval).unwrap_or(0.),
+                spacing,
+                padding,
+                alignment,
+                cells: Slice::from(cross_cells.as_slice()),
+            };
+            with_flow_layout_data(flow_layout, component, &expr_eval, |data, repeated_indices| {
+                core_layout::solve_flow_layout_cross(data, &cross, repeated_indices)
+            })
+        }
+        .into(),
     }
 }
 
@@ -185,11 +253,35 @@ fn box_layout_data(
     orientation: Orientation,
     component: InstanceRef,
     expr_eval: &impl Fn(&NamedReference) -> f32,
-    mut repeater_indices: Option<&mut Vec<u32>>,
+    repeater_indices: Option<&mut Vec<u32>>,
 ) -> (Vec<core_layout::BoxLayoutCellData>, i_slint_core::items::LayoutAlignment) {
+    let cells =
+        layout_cells(&box_layout.elems, orientation, component, expr_eval, repeater_indices);
+    let alignment = box_layout
+        .geometry
+        .alignment
+        .as_ref()
+        .map(|nr| {
+            eval::load_property(component, &nr.element(), nr.name())
+                .unwrap()
+                .try_into()
+                .unwrap_or_default()
+        })
+        .unwrap_or_default();
+    (cells, alignment)
+}
+
+/// Return the cells of a box or flow layout
+fn layout_cells(
+    elems: &[LayoutItem],
+    orientation: Orientation,
+    component: InstanceRef,
+    expr_eval: &impl Fn(&NamedReference) -> f32,
+    mut repeater_indices: Option<&mut Vec<u32>>,
+) -> Vec<core_layout::BoxLayoutCellData> {
     let window_adapter = component.window_adapter();
-    let mut cells = Vec::with_capacity(box_layout.elems.len());
-    for cell in &box_layout.elems {
+    let mut cells = Vec::with_capacity(elems.len());
+    for cell in elems {
         if cell.element.borrow().repeated.is_some() {
             generativity::make_guard!(guard);
             let rep = crate::dynamic_item_tree::get_repeater_by_name(
@@ -229,18 +321,66 @@ fn box_layout_data(
             cells.push(core_layout::BoxLayoutCellData { constraint: layout_info });
         }
     }
-    let alignment = box_layout
-        .geometry
-        .alignment
-        .as_ref()
-        .map(|nr| {
+    cells
+}
+
+/// Evaluate the properties of a FlowLayout in the direction of the flow, and call the functor with
+/// the resulting FlowLayoutData and the repeater indices
+fn with_flow_layout_data<R>(
+    flow_layout: &FlowLayout,
+    component: InstanceRef,
+    expr_eval: &impl Fn(&NamedReference) -> f32,
+    f: impl FnOnce(&core_layout::FlowLayoutData, Slice<u32>) -> R,
+) -> R {
+    let orientation = flow_layout.orientation;
+    let mut repeater_indices = Vec::new();
+    let cells = layout_cells(
+        &flow_layout.elems,
+        orientation,
+        component,
+        expr_eval,
+        Some(&mut repeater_indices),
+    );
+    let (padding, spacing) = padding_and_spacing(&flow_layout.geometry, orientation, expr_eval);
+    let size_ref = flow_layout.geometry.rect.size_reference(orientation);
+    let direction = match orientation {
+        Orientation::Horizontal => component
+            .access_window(|window| window.window_item().unwrap().as_pin_ref().layout_direction()),
+        Orientation::Vertical => LayoutDirection::LeftToRight,
+    };
+    f(
+        &core_layout::FlowLayoutData {
+            size: size_ref.map(expr_eval).unwrap_or(0.),
+            spacing,
+            padding,
+            alignment: flow_layout_alignment(flow_layout, component),
+            wrap: flow_layout_wrap(flow_layout, component),
+            direction,
+            cells: Slice::from(cells.as_slice()),
+        },
+        Slice::from(repeater_indices.as_slice()),
+    )
+}
+
+fn flow_layout_alignment(
+    flow_layout: &FlowLayout,
+    component: InstanceRef,
+) -> i_slint_core::items::LayoutAlignment {
+    flow_layout.geometry.alignment.as_ref().map_or(
+        i_slint_core::items::LayoutAlignment::Start,
+        |nr| {
             eval::load_property(component, &nr.element(), nr.name())
                 .unwrap()
                 .try_into()
                 .unwrap_or_default()
-        })
-        .unwrap_or_default();
-    (cells, alignment)
+        },
+    )
+}
+
+fn flow_layout_wrap(flow_layout: &FlowLayout, component: InstanceRef) -> bool {
+    flow_layout.wrap.as_ref().map_or(true, |nr| {
+        eval::load_property(component, &nr.element(), nr.name()).unwrap().try_into().unwrap()
+    })
 }
 
 pub(crate) fn fill_layout_info_constraints(
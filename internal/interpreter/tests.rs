@@ -43,3 +43,49 @@ fn reuse_window() {
         instance
     };
 }
+
+#[test]
+fn accessibility_actions() {
+    i_slint_backend_testing::init();
+    use crate::{ComponentCompiler, ComponentHandle, Value};
+    use i_slint_core::accessibility::{AccessibilityAction, SupportedAccessibilityAction};
+    use i_slint_core::item_tree::ItemRc;
+    use i_slint_core::window::WindowInner;
+    let code = r#"
+        component Counter inherits Rectangle {
+            in-out property <int> value;
+            accessible-role: spinbox;
+            accessible-action-increment => { value += 1; }
+            accessible-action-set-value(v) => { value = v.to-float(); }
+        }
+        export component MainWindow inherits Window {
+            in-out property <int> value <=> counter.value;
+            in-out property <bool> pressed;
+            accessible-role: button;
+            accessible-action-default => { pressed = true; }
+            counter := Counter {}
+        }
+    "#;
+    let mut compiler = ComponentCompiler::default();
+    compiler.set_style("fluent".into());
+    let definition = spin_on::spin_on(compiler.build_from_source(code.into(), Default::default()));
+    assert!(compiler.diagnostics().is_empty(), "{:?}", compiler.diagnostics());
+    let instance = definition.unwrap().create().unwrap();
+
+    let root = ItemRc::new(WindowInner::from_pub(instance.window()).component(), 0);
+    assert_eq!(root.supported_accessibility_actions(), SupportedAccessibilityAction::Default);
+    root.accessible_action(&AccessibilityAction::Default);
+    assert_eq!(instance.get_property("pressed").unwrap(), Value::Bool(true));
+
+    let counter = i_slint_core::accessibility::accessible_descendents(&root).next().unwrap();
+    assert_eq!(
+        counter.supported_accessibility_actions(),
+        SupportedAccessibilityAction::Increment | SupportedAccessibilityAction::SetValue
+    );
+    counter.accessible_action(&AccessibilityAction::Increment);
+    assert_eq!(instance.get_property("value").unwrap(), Value::Number(1.));
+    counter.accessible_action(&AccessibilityAction::SetValue("12".into()));
+    assert_eq!(instance.get_property("value").unwrap(), Value::Number(12.));
+    counter.accessible_action(&AccessibilityAction::Decrement);
+    assert_eq!(instance.get_property("value").unwrap(), Value::Number(12.));
+}
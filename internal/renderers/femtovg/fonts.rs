@@ -89,6 +89,13 @@ pub fn height(&self) -> PhysicalLength {
         paint.set_font_size(self.pixel_size.get());
         PhysicalLength::new(self.text_context.measure_font(&paint).unwrap().height())
     }
+
+    pub fn ascent(&self) -> PhysicalLength {
+        let mut paint = femtovg::Paint::default();
+        paint.set_font(&self.fonts);
+        paint.set_font_size(self.pixel_size.get());
+        PhysicalLength::new(self.text_context.measure_font(&paint).unwrap().ascender())
+    }
 }
 
 pub(crate) fn text_size(
@@ -104,6 +111,19 @@ pub(crate) fn text_size(
         / scale_factor
 }
 
+pub(crate) fn font_metrics(
+    font_request: &i_slint_core::graphics::FontRequest,
+    scale_factor: ScaleFactor,
+) -> i_slint_core::graphics::FontMetrics {
+    let font =
+        FONT_CACHE.with(|cache| cache.borrow_mut().font(font_request.clone(), scale_factor, ""));
+    let ascent = font.ascent();
+    i_slint_core::graphics::FontMetrics {
+        ascent: (ascent / scale_factor).get(),
+        descent: ((font.height() - ascent) / scale_factor).get(),
+    }
+}
+
 #[derive(Copy, Clone)]
 struct LoadedFont {
     femtovg_font_id: femtovg::FontId,
@@ -596,11 +616,13 @@ pub(crate) fn layout_text_lines(
     let mut process_line =
         |text: &str, y: PhysicalLength, start: usize, line_metrics: &femtovg::TextMetrics| {
             let x = match horizontal_alignment {
-                TextHorizontalAlignment::Left => PhysicalLength::default(),
+                TextHorizontalAlignment::Left | TextHorizontalAlignment::Start => {
+                    PhysicalLength::default()
+                }
                 TextHorizontalAlignment::Center => {
                     max_width / 2. - max_width.min(PhysicalLength::new(line_metrics.width())) / 2.
                 }
-                TextHorizontalAlignment::Right => {
+                TextHorizontalAlignment::Right | TextHorizontalAlignment::End => {
                     max_width - max_width.min(PhysicalLength::new(line_metrics.width()))
                 }
             };
@@ -322,19 +322,41 @@ fn draw_text(&mut self, text: Pin<&items::Text>, _: &ItemRc, size: LogicalSize)
             Some(paint) => font.init_paint(text.letter_spacing() * self.scale_factor, paint),
             None => return,
         };
+        let stroke_paint = self.text_stroke_paint(
+            text.stroke(),
+            text.stroke_width(),
+            &font,
+            text.letter_spacing(),
+            &rect_to_path((size * self.scale_factor).into()),
+        );
+        let (underline, strikethrough) = (text.font_underline(), text.font_strikethrough());
 
         let mut canvas = self.canvas.borrow_mut();
         fonts::layout_text_lines(
             string,
             &font,
             PhysicalSize::from_lengths(max_width, max_height),
-            (text.horizontal_alignment(), text.vertical_alignment()),
+            (
+                text.resolved_horizontal_alignment(WindowInner::from_pub(self.window)),
+                text.vertical_alignment(),
+            ),
             text.wrap(),
             text.overflow(),
             false,
             &paint,
-            |to_draw, pos, _, _| {
+            |to_draw, pos, _, metrics| {
+                if let Some(stroke_paint) = &stroke_paint {
+                    canvas.stroke_text(pos.x, pos.y, to_draw.trim_end(), stroke_paint).unwrap();
+                }
                 canvas.fill_text(pos.x, pos.y, to_draw.trim_end(), &paint).unwrap();
+                draw_text_decorations(
+                    &mut canvas,
+                    pos,
+                    metrics.width().min(max_width.get() - pos.x),
+                    &paint,
+                    underline,
+                    strikethrough,
+                );
             },
         );
     }
@@ -362,6 +384,8 @@ fn draw_text_input(
                 &text_input.text(),
             )
         });
+        let horizontal_alignment = text_input
+            .resolved_horizontal_alignment(&WindowInner::from_pub(self.window).window_adapter());
 
         let paint = match self
             .brush_to_paint(text_input.color(), &rect_to_path((size * self.scale_factor).into()))
@@ -369,6 +393,15 @@ fn draw_text_input(
             Some(paint) => font.init_paint(text_input.letter_spacing() * self.scale_factor, paint),
             None => return,
         };
+        let stroke_paint = self.text_stroke_paint(
+            text_input.stroke(),
+            text_input.stroke_width(),
+            &font,
+            text_input.letter_spacing(),
+            &rect_to_path((size * self.scale_factor).into()),
+        );
+        let (underline, strikethrough) =
+            (text_input.font_underline(), text_input.font_strikethrough());
 
         let visual_representation = text_input.visual_representation(None);
 
@@ -395,12 +428,15 @@ fn draw_text_input(
             text.as_str(),
             &font,
             PhysicalSize::from_lengths(width, height),
-            (text_input.horizontal_alignment(), text_input.vertical_alignment()),
+            (horizontal_alignment, text_input.vertical_alignment()),
             text_input.wrap(),
             items::TextOverflow::Clip,
             text_input.single_line(),
             &paint,
             |to_draw, pos, start, metrics| {
+                if let Some(stroke_paint) = &stroke_paint {
+                    canvas.stroke_text(pos.x, pos.y, to_draw.trim_end(), stroke_paint).unwrap();
+                }
                 let range = start..(start + to_draw.len());
                 if min_select != max_select
                     && (range.contains(&min_select)
@@ -481,6 +517,14 @@ fn draw_text_input(
                     // no selection on this line
                     canvas.fill_text(pos.x, pos.y, to_draw.trim_end(), &paint).unwrap();
                 };
+                draw_text_decorations(
+                    &mut canvas,
+                    pos,
+                    metrics.width().min(width.get() - pos.x),
+                    &paint,
+                    underline,
+                    strikethrough,
+                );
                 if cursor_visible
                     && (range.contains(&cursor_pos)
                         || (cursor_pos == range.end
@@ -510,10 +554,12 @@ fn draw_text_input(
 
         if let Some(cursor_point) = cursor_point.or_else(|| {
             cursor_visible.then(|| {
-                let x = match text_input.horizontal_alignment() {
-                    TextHorizontalAlignment::Left => PhysicalLength::default(),
+                let x = match horizontal_alignment {
+                    TextHorizontalAlignment::Left | TextHorizontalAlignment::Start => {
+                        PhysicalLength::default()
+                    }
                     TextHorizontalAlignment::Center => width / 2.,
-                    TextHorizontalAlignment::Right => width,
+                    TextHorizontalAlignment::Right | TextHorizontalAlignment::End => width,
                 };
                 PhysicalPoint::from_lengths(x, next_y)
             })
@@ -1517,11 +1563,59 @@ fn brush_to_paint(&self, brush: Brush, path: &femtovg::Path) -> Option<femtovg::
         })
     }
 
+    /// Returns the paint used to outline text drawn with `font`, or None if there is no
+    /// visible stroke. The stroke is centered on the glyph outlines and the fill is drawn on top,
+    /// so the line width is doubled to make `stroke_width` visible outside the glyphs.
+    fn text_stroke_paint(
+        &self,
+        stroke: Brush,
+        stroke_width: LogicalLength,
+        font: &fonts::Font,
+        letter_spacing: LogicalLength,
+        path: &femtovg::Path,
+    ) -> Option<femtovg::Paint> {
+        if stroke_width.get() <= 0. {
+            return None;
+        }
+        let mut paint =
+            font.init_paint(letter_spacing * self.scale_factor, self.brush_to_paint(stroke, path)?);
+        paint.set_line_width((stroke_width * self.scale_factor).get() * 2.);
+        paint.set_line_join(femtovg::LineJoin::Round);
+        Some(paint)
+    }
+
     fn current_render_target(&self) -> femtovg::RenderTarget {
         self.state.last().unwrap().current_render_target
     }
 }
 
+/// Draws the underline and strikethrough of a line of text that is `width` wide and was drawn
+/// at `pos` with `paint`.
+fn draw_text_decorations(
+    canvas: &mut Canvas,
+    pos: PhysicalPoint,
+    width: f32,
+    paint: &femtovg::Paint,
+    underline: bool,
+    strikethrough: bool,
+) {
+    if !(underline || strikethrough) || width <= 0. {
+        return;
+    }
+    let Ok(font_metrics) = canvas.measure_font(paint) else { return };
+    let baseline = font_metrics.ascender();
+    let thickness = (font_metrics.height() / 16.).max(1.);
+    let mut path = femtovg::Path::new();
+    if underline {
+        let offset = (font_metrics.descender().abs() / 3.).max(thickness);
+        path.rect(pos.x, pos.y + baseline + offset, width, thickness);
+    }
+    if strikethrough {
+        path.rect(pos.x, pos.y + baseline - baseline * 0.3, width, thickness);
+    }
+    canvas.fill_path(&path, paint);
+}
+
 pub fn to_femtovg_color(col: &Color) -> femtovg::Color {
     femtovg::Color::rgba(col.red(), col.green(), col.blue(), col.alpha())
 }
@@ -369,11 +369,20 @@ fn text_size(
         crate::fonts::text_size(&font_request, scale_factor, text, max_width)
     }
 
+    fn font_metrics(
+        &self,
+        font_request: i_slint_core::graphics::FontRequest,
+        scale_factor: ScaleFactor,
+    ) -> i_slint_core::graphics::FontMetrics {
+        crate::fonts::font_metrics(&font_request, scale_factor)
+    }
+
     fn text_input_byte_offset_for_position(
         &self,
         text_input: Pin<&i_slint_core::items::TextInput>,
         pos: LogicalPoint,
         font_request: FontRequest,
+        horizontal_alignment: i_slint_core::items::TextHorizontalAlignment,
         scale_factor: ScaleFactor,
     ) -> usize {
         let pos = pos * scale_factor;
@@ -400,7 +409,7 @@ fn text_input_byte_offset_for_position(
             &visual_representation.text,
             &font,
             PhysicalSize::from_lengths(width, height),
-            (text_input.horizontal_alignment(), text_input.vertical_alignment()),
+            (horizontal_alignment, text_input.vertical_alignment()),
             text_input.wrap(),
             i_slint_core::items::TextOverflow::Clip,
             text_input.single_line(),
@@ -428,6 +437,7 @@ fn text_input_cursor_rect_for_byte_offset(
         text_input: Pin<&i_slint_core::items::TextInput>,
         byte_offset: usize,
         font_request: FontRequest,
+        horizontal_alignment: i_slint_core::items::TextHorizontalAlignment,
         scale_factor: ScaleFactor,
     ) -> LogicalRect {
         let text = text_input.text();
@@ -453,7 +463,7 @@ fn text_input_cursor_rect_for_byte_offset(
             text.as_str(),
             &font,
             PhysicalSize::from_lengths(width, height),
-            (text_input.horizontal_alignment(), text_input.vertical_alignment()),
+            (horizontal_alignment, text_input.vertical_alignment()),
             text_input.wrap(),
             i_slint_core::items::TextOverflow::Clip,
             text_input.single_line(),
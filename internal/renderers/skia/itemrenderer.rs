@@ -71,6 +71,29 @@ fn brush_to_paint(
         })
     }
 
+    /// Returns the style to paint the outline of text with, or None if there is no visible stroke.
+    /// The outline is painted before the text itself and is centered on the glyph outlines, so
+    /// the stroke width is doubled to keep `stroke_width` visible outside the glyphs.
+    fn text_stroke_style(
+        &self,
+        stroke: Brush,
+        stroke_width: LogicalLength,
+        width: PhysicalLength,
+        height: PhysicalLength,
+    ) -> Option<skia_safe::textlayout::TextStyle> {
+        if stroke_width.get() <= 0. {
+            return None;
+        }
+        let mut paint = self.brush_to_paint(stroke, width, height)?;
+        paint.set_anti_alias(true);
+        paint.set_style(skia_safe::PaintStyle::Stroke);
+        paint.set_stroke_width((stroke_width * self.scale_factor).get() * 2.);
+        paint.set_stroke_join(skia_safe::PaintJoin::Round);
+        let mut text_style = skia_safe::textlayout::TextStyle::new();
+        text_style.set_foreground_paint(&paint);
+        Some(text_style)
+    }
+
     fn brush_to_shader(
         brush: Brush,
         width: PhysicalLength,
@@ -447,6 +470,8 @@ fn draw_text(
         let string = text.text();
         let string = string.as_str();
         let font_request = text.font_request(WindowInner::from_pub(self.window));
+        let horizontal_alignment =
+            text.resolved_horizontal_alignment(WindowInner::from_pub(self.window));
 
         let paint = match self.brush_to_paint(text.color(), max_width, max_height) {
             Some(paint) => paint,
@@ -455,6 +480,31 @@ fn draw_text(
 
         let mut text_style = skia_safe::textlayout::TextStyle::new();
         text_style.set_foreground_paint(&paint);
+        set_text_decoration(
+            &mut text_style,
+            text.font_underline(),
+            text.font_strikethrough(),
+            text.color().color(),
+        );
+
+        if let Some(stroke_style) =
+            self.text_stroke_style(text.stroke(), text.stroke_width(), max_width, max_height)
+        {
+            let (layout, layout_top_left) = super::textlayout::create_layout(
+                font_request.clone(),
+                self.scale_factor,
+                string,
+                Some(stroke_style),
+                Some(max_width),
+                max_height,
+                horizontal_alignment,
+                text.vertical_alignment(),
+                text.wrap(),
+                text.overflow(),
+                None,
+            );
+            layout.paint(&mut self.canvas, to_skia_point(layout_top_left));
+        }
 
         let (layout, layout_top_left) = super::textlayout::create_layout(
             font_request,
@@ -463,7 +513,7 @@ fn draw_text(
             Some(text_style),
             Some(max_width),
             max_height,
-            text.horizontal_alignment(),
+            horizontal_alignment,
             text.vertical_alignment(),
             text.wrap(),
             text.overflow(),
@@ -488,6 +538,8 @@ fn draw_text_input(
 
         let font_request =
             text_input.font_request(&WindowInner::from_pub(&self.window).window_adapter());
+        let horizontal_alignment = text_input
+            .resolved_horizontal_alignment(&WindowInner::from_pub(&self.window).window_adapter());
 
         let paint = match self.brush_to_paint(text_input.color(), max_width, max_height) {
             Some(paint) => paint,
@@ -496,6 +548,12 @@ fn draw_text_input(
 
         let mut text_style = skia_safe::textlayout::TextStyle::new();
         text_style.set_foreground_paint(&paint);
+        set_text_decoration(
+            &mut text_style,
+            text_input.font_underline(),
+            text_input.font_strikethrough(),
+            text_input.color().color(),
+        );
 
         let visual_representation = text_input.visual_representation(None);
 
@@ -517,6 +575,28 @@ fn draw_text_input(
             None
         };
 
+        if let Some(stroke_style) = self.text_stroke_style(
+            text_input.stroke(),
+            text_input.stroke_width(),
+            max_width,
+            max_height,
+        ) {
+            let (layout, layout_top_left) = super::textlayout::create_layout(
+                font_request.clone(),
+                self.scale_factor,
+                &visual_representation.text,
+                Some(stroke_style),
+                Some(max_width),
+                max_height,
+                horizontal_alignment,
+                text_input.vertical_alignment(),
+                text_input.wrap(),
+                i_slint_core::items::TextOverflow::Clip,
+                None,
+            );
+            layout.paint(&mut self.canvas, to_skia_point(layout_top_left));
+        }
+
         let (layout, layout_top_left) = super::textlayout::create_layout(
             font_request,
             self.scale_factor,
@@ -524,7 +604,7 @@ fn draw_text_input(
             Some(text_style),
             Some(max_width),
             max_height,
-            text_input.horizontal_alignment(),
+            horizontal_alignment,
             text_input.vertical_alignment(),
             text_input.wrap(),
             i_slint_core::items::TextOverflow::Clip,
@@ -539,7 +619,7 @@ fn draw_text_input(
                 cursor_position,
                 layout,
                 text_input.text_cursor_width() * self.scale_factor,
-                text_input.horizontal_alignment(),
+                horizontal_alignment,
             )
             .translate(layout_top_left.to_vector());
 
@@ -913,3 +993,26 @@ fn adjust_rect_and_border_for_inner_drawing(
     rect.origin += PhysicalSize::from_lengths(*border_width / 2., *border_width / 2.);
     rect.size -= PhysicalSize::from_lengths(*border_width, *border_width);
 }
+
+/// Enables the underline and strikethrough decorations of `text_style`, drawn in `color`.
+fn set_text_decoration(
+    text_style: &mut skia_safe::textlayout::TextStyle,
+    underline: bool,
+    strikethrough: bool,
+    color: Color,
+) {
+    let mut ty = skia_safe::textlayout::TextDecoration::NO_DECORATION;
+    if underline {
+        ty |= skia_safe::textlayout::TextDecoration::UNDERLINE;
+    }
+    if strikethrough {
+        ty |= skia_safe::textlayout::TextDecoration::LINE_THROUGH;
+    }
+    if ty.is_empty() {
+        return;
+    }
+    let mut decoration = skia_safe::textlayout::Decoration::default();
+    decoration.ty = ty;
+    decoration.color = to_skia_color(&color);
+    text_style.set_decoration(&decoration);
+}
@@ -363,11 +363,39 @@ fn text_size(
             / scale_factor
     }
 
+    fn font_metrics(
+        &self,
+        font_request: i_slint_core::graphics::FontRequest,
+        scale_factor: ScaleFactor,
+    ) -> i_slint_core::graphics::FontMetrics {
+        let (layout, _) = textlayout::create_layout(
+            font_request,
+            scale_factor,
+            "x",
+            None,
+            None,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            None,
+        );
+        let height = layout.height().ceil();
+        let baseline =
+            layout.get_line_metrics().first().map_or(height, |line| line.baseline as f32);
+        i_slint_core::graphics::FontMetrics {
+            ascent: baseline / scale_factor.get(),
+            descent: (height - baseline) / scale_factor.get(),
+        }
+    }
+
     fn text_input_byte_offset_for_position(
         &self,
         text_input: std::pin::Pin<&i_slint_core::items::TextInput>,
         pos: LogicalPoint,
         font_request: FontRequest,
+        horizontal_alignment: i_slint_core::items::TextHorizontalAlignment,
         scale_factor: ScaleFactor,
     ) -> usize {
         let max_width = text_input.width() * scale_factor;
@@ -387,7 +415,7 @@ fn text_input_byte_offset_for_position(
             None,
             Some(max_width),
             max_height,
-            text_input.horizontal_alignment(),
+            horizontal_alignment,
             text_input.vertical_alignment(),
             text_input.wrap(),
             i_slint_core::items::TextOverflow::Clip,
@@ -416,6 +444,7 @@ fn text_input_cursor_rect_for_byte_offset(
         text_input: std::pin::Pin<&i_slint_core::items::TextInput>,
         byte_offset: usize,
         font_request: FontRequest,
+        horizontal_alignment: i_slint_core::items::TextHorizontalAlignment,
         scale_factor: ScaleFactor,
     ) -> LogicalRect {
         let max_width = text_input.width() * scale_factor;
@@ -435,7 +464,7 @@ fn text_input_cursor_rect_for_byte_offset(
             None,
             Some(max_width),
             max_height,
-            text_input.horizontal_alignment(),
+            horizontal_alignment,
             text_input.vertical_alignment(),
             text_input.wrap(),
             i_slint_core::items::TextOverflow::Clip,
@@ -447,7 +476,7 @@ fn text_input_cursor_rect_for_byte_offset(
             byte_offset,
             layout,
             text_input.text_cursor_width() * scale_factor,
-            text_input.horizontal_alignment(),
+            horizontal_alignment,
         );
 
         physical_cursor_rect.translate(layout_top_left.to_vector()) / scale_factor
@@ -99,9 +99,13 @@ pub fn create_layout(
     }
 
     style.set_text_align(match h_align {
-        items::TextHorizontalAlignment::Left => skia_safe::textlayout::TextAlign::Left,
+        items::TextHorizontalAlignment::Left | items::TextHorizontalAlignment::Start => {
+            skia_safe::textlayout::TextAlign::Left
+        }
         items::TextHorizontalAlignment::Center => skia_safe::textlayout::TextAlign::Center,
-        items::TextHorizontalAlignment::Right => skia_safe::textlayout::TextAlign::Right,
+        items::TextHorizontalAlignment::Right | items::TextHorizontalAlignment::End => {
+            skia_safe::textlayout::TextAlign::Right
+        }
     });
 
     style.set_text_style(&text_style);
@@ -212,9 +216,13 @@ pub fn cursor_rect(
 ) -> PhysicalRect {
     if string.is_empty() {
         let x = match h_align {
-            TextHorizontalAlignment::Left => PhysicalLength::default(),
+            TextHorizontalAlignment::Left | TextHorizontalAlignment::Start => {
+                PhysicalLength::default()
+            }
             TextHorizontalAlignment::Center => PhysicalLength::new(layout.max_width() / 2.),
-            TextHorizontalAlignment::Right => PhysicalLength::new(layout.max_width()),
+            TextHorizontalAlignment::Right | TextHorizontalAlignment::End => {
+                PhysicalLength::new(layout.max_width())
+            }
         };
         return PhysicalRect::new(
             PhysicalPoint::from_lengths(x, PhysicalLength::default()),
@@ -26,8 +26,12 @@ pub fn test(testcase: &test_driver_lib::TestCase) -> Result<(), Box<dyn Error>>
     let mut compiler_config = CompilerConfiguration::new(output_format.clone());
     compiler_config.include_paths = include_paths;
     compiler_config.library_paths = library_paths;
+    if let Some(path) = test_driver_lib::extract_bundle_translations(&source) {
+        compiler_config.translation_domain = Some(testcase.translation_domain());
+        compiler_config.translation_path_bundle = Some(testcase.absolute_path.with_file_name(path));
+    }
     let (root_component, diag, _) =
-        spin_on::spin_on(compile_syntax_node(syntax_node, diag, compiler_config));
+        spin_on::spin_on(compile_syntax_node(syntax_node, diag, compiler_config.clone()));
 
     if diag.has_error() {
         let vec = diag.to_string_vec();
@@ -36,7 +40,7 @@ pub fn test(testcase: &test_driver_lib::TestCase) -> Result<(), Box<dyn Error>>
 
     let mut generated_cpp: Vec<u8> = Vec::new();
 
-    generator::generate(output_format, &mut generated_cpp, &root_component)?;
+    generator::generate(output_format, &mut generated_cpp, &root_component, &compiler_config)?;
 
     if diag.has_error() {
         let vec = diag.to_string_vec();
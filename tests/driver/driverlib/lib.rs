@@ -20,6 +20,11 @@ pub fn identifier(&self) -> String {
             .replace([std::path::MAIN_SEPARATOR, '-'], "_")
     }
 
+    /// The translation domain, used to find the `.po` files of the bundled translations
+    pub fn translation_domain(&self) -> String {
+        self.absolute_path.file_stem().unwrap().to_string_lossy().into_owned()
+    }
+
     /// Returns true if the test case should be ignored for the specified driver.
     pub fn is_ignored(&self, driver: &str) -> bool {
         let source = std::fs::read_to_string(&self.absolute_path).unwrap();
@@ -258,3 +263,25 @@ fn test_extract_cpp_namespace() {
     let r = extract_cpp_namespace(source);
     assert_eq!(r, Some("ui".to_string()));
 }
+
+/// Extract the directory of the translations to bundle from a comment in the source if present.
+/// The translations are loaded from `<path>/<language>/LC_MESSAGES/<file stem>.po`, where the
+/// file stem of the test case is the translation domain.
+pub fn extract_bundle_translations(source: &str) -> Option<&str> {
+    lazy_static::lazy_static! {
+        static ref RX: Regex = Regex::new(r"//bundle-translations:\s*(.+)\s*\n").unwrap();
+    }
+    RX.captures(source).map(|mat| mat.get(1).unwrap().as_str().trim())
+}
+
+#[test]
+fn test_extract_bundle_translations() {
+    assert!(extract_bundle_translations("something").is_none());
+
+    let source = r"
+    //bundle-translations: lang
+    Blah {}
+";
+
+    assert_eq!(extract_bundle_translations(source), Some("lang"));
+}
@@ -61,6 +61,8 @@ fn main() -> std::io::Result<()> {
     //Make sure to use a consistent style
     println!("cargo:rustc-env=SLINT_STYLE=fluent");
     println!("cargo:rustc-env=SLINT_ENABLE_EXPERIMENTAL_FEATURES=1");
+    // For the element handle tests
+    println!("cargo:rustc-env=SLINT_EMIT_DEBUG_INFO=1");
     Ok(())
 }
 
@@ -111,6 +113,21 @@ fn generate_macro(
         output.write_all(b"\"#]\n")?;
     }
 
+    if let Some(path) = test_driver_lib::extract_bundle_translations(source) {
+        let mut abs_path = testcase.absolute_path.clone();
+        abs_path.pop();
+        abs_path.push(path);
+
+        output.write_all(b"#[translation_domain=\"")?;
+        output.write_all(testcase.translation_domain().as_bytes())?;
+        output.write_all(b"\"]\n")?;
+        output.write_all(b"#[bundle_translations=r#\"")?;
+        output.write_all(abs_path.to_string_lossy().as_bytes())?;
+        output.write_all(b"\"#]\n")?;
+
+        println!("cargo:rerun-if-changed={}", abs_path.to_string_lossy());
+    }
+
     let mut abs_path = testcase.absolute_path;
     abs_path.pop();
     output.write_all(b"#[include_path=r#\"")?;
@@ -141,11 +158,16 @@ fn generate_source(
         parser::parse(source.to_owned(), Some(&testcase.absolute_path), None, &mut diag);
     let mut compiler_config = CompilerConfiguration::new(generator::OutputFormat::Rust);
     compiler_config.enable_component_containers = true;
+    compiler_config.debug_info = true;
     compiler_config.include_paths = include_paths;
     compiler_config.library_paths = library_paths;
     compiler_config.style = Some(testcase.requested_style.unwrap_or("fluent").to_string());
+    if let Some(path) = test_driver_lib::extract_bundle_translations(source) {
+        compiler_config.translation_domain = Some(testcase.translation_domain());
+        compiler_config.translation_path_bundle = Some(testcase.absolute_path.with_file_name(path));
+    }
     let (root_component, diag, _) =
-        spin_on::spin_on(compile_syntax_node(syntax_node, diag, compiler_config));
+        spin_on::spin_on(compile_syntax_node(syntax_node, diag, compiler_config.clone()));
 
     if diag.has_error() {
         diag.print_warnings_and_exit_on_error();
@@ -157,6 +179,6 @@ fn generate_source(
         diag.print();
     }
 
-    generator::generate(generator::OutputFormat::Rust, output, &root_component)?;
+    generator::generate(generator::OutputFormat::Rust, output, &root_component, &compiler_config)?;
     Ok(())
 }
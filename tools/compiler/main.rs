@@ -63,9 +63,17 @@ struct Cli {
     #[arg(long = "translation-domain", action)]
     translation_domain: Option<String>,
 
+    /// Bundle the translations from the `.po` files in `<path>/<lang>/LC_MESSAGES/<domain>.po`
+    #[arg(long = "bundle-translations", name = "path", action)]
+    bundle_translations: Option<std::path::PathBuf>,
+
     /// C++ namespace
     #[arg(long = "cpp-namespace", name = "C++ namespace")]
     cpp_namespace: Option<String>,
+
+    /// Generate the id and type name of the elements, to find them in tests
+    #[arg(long = "debug-info", action)]
+    debug_info: bool,
 }
 
 fn main() -> std::io::Result<()> {
@@ -91,6 +99,10 @@ fn main() -> std::io::Result<()> {
 
     let mut compiler_config = CompilerConfiguration::new(format.clone());
     compiler_config.translation_domain = args.translation_domain;
+    compiler_config.debug_info |= args.debug_info;
+    if let Some(path) = args.bundle_translations {
+        compiler_config.translation_path_bundle = Some(path);
+    }
 
     // Override defaults from command line:
     if let Some(embed) = args.embed_resources {
@@ -112,17 +124,19 @@ fn main() -> std::io::Result<()> {
         compiler_config.style = Some(style);
     }
     let syntax_node = syntax_node.expect("diags contained no compilation errors");
-    let (doc, diag, _) = spin_on::spin_on(compile_syntax_node(syntax_node, diag, compiler_config));
+    let (doc, diag, _) =
+        spin_on::spin_on(compile_syntax_node(syntax_node, diag, compiler_config.clone()));
 
     let diag = diag.check_and_exit_on_error();
 
     if args.output == std::path::Path::new("-") {
-        generator::generate(format, &mut std::io::stdout(), &doc)?;
+        generator::generate(format, &mut std::io::stdout(), &doc, &compiler_config)?;
     } else {
         generator::generate(
             format,
             &mut BufWriter::new(std::fs::File::create(&args.output)?),
             &doc,
+            &compiler_config,
         )?;
     }
 
@@ -16,15 +16,19 @@
 
 fn builtin_component_info(name: &str, fills_parent: bool) -> ComponentInformation {
     let (category, is_layout) = match name {
-        "GridLayout" | "HorizontalLayout" | "VerticalLayout" => ("Layout", true),
+        "GridLayout" | "HorizontalLayout" | "VerticalLayout" | "FlowLayout" => ("Layout", true),
         "Dialog" | "Window" | "PopupWindow" => ("Window Management", false),
-        "FocusScope" | "TouchArea" => ("Event Handling", false),
-        "Text" => ("Text Handling", false),
+        "FocusScope" | "TouchArea" | "GestureArea" | "DragArea" | "DropArea" => {
+            ("Event Handling", false)
+        }
+        "Text" | "StyledText" => ("Text Handling", false),
         _ => ("Primitives", false),
     };
 
     let default_properties = match name {
-        "Text" | "TextInput" => vec![PropertyChange::new("text", format!("\"{name}\""))],
+        "Text" | "TextInput" | "StyledText" => {
+            vec![PropertyChange::new("text", format!("\"{name}\""))]
+        }
         "Image" => vec![PropertyChange::new("source", "@image-url(\"EDIT_ME.png\")".to_string())],
         _ => vec![],
     };
@@ -47,7 +51,8 @@ fn std_widgets_info(name: &str, is_global: bool) -> ComponentInformation {
     let (category, is_layout) = match name {
         "GridBox" | "HorizontalBox" | "VerticalBox" => ("Layout", true),
         "LineEdit" | "TextEdit" => ("Text Handling", false),
-        "Button" | "CheckBox" | "ComboBox" | "Slider" | "SpinBox" | "Switch" => ("Input", false),
+        "Button" | "CheckBox" | "ComboBox" | "DatePicker" | "Slider" | "SpinBox" | "Switch"
+        | "TimePicker" => ("Input", false),
         "ProgressIndicator" | "Spinner" => ("Status", false),
         "ListView" | "StandardListView" | "StandardTableView" => ("Views", false),
         _ => ("Widgets", false),
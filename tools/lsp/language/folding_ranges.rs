@@ -0,0 +1,127 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+use super::{uri_to_file, DocumentCache};
+use crate::util;
+
+use i_slint_compiler::parser::{SyntaxKind, TextRange};
+
+use lsp_types::{FoldingRange, FoldingRangeKind};
+
+/// Return the folding ranges of the element blocks, the states and transitions, and the
+/// comments spanning several lines
+pub fn get_folding_ranges(
+    document_cache: &mut DocumentCache,
+    text_document: &lsp_types::TextDocumentIdentifier,
+) -> Option<Vec<FoldingRange>> {
+    let doc = document_cache.documents.get_document(&uri_to_file(&text_document.uri)?)?;
+    let root_node = doc.node.as_ref()?;
+    let sf = &root_node.source_file;
+
+    let mut result = Vec::new();
+    let mut push = |range: TextRange, kind: Option<FoldingRangeKind>| {
+        let range = util::map_range(sf, range);
+        if range.end.line > range.start.line {
+            result.push(FoldingRange {
+                start_line: range.start.line,
+                start_character: Some(range.start.character),
+                end_line: range.end.line,
+                end_character: Some(range.end.character),
+                kind,
+                collapsed_text: None,
+            });
+        }
+    };
+
+    for node in root_node.descendants() {
+        if !matches!(
+            node.kind(),
+            SyntaxKind::Element
+                | SyntaxKind::States
+                | SyntaxKind::State
+                | SyntaxKind::Transitions
+                | SyntaxKind::Transition
+        ) {
+            continue;
+        }
+        // `states` and `transitions` use brackets, the other blocks use braces
+        let mut tokens = node.children_with_tokens().filter_map(|t| t.into_token());
+        let Some(open) =
+            tokens.find(|t| matches!(t.kind(), SyntaxKind::LBrace | SyntaxKind::LBracket))
+        else {
+            continue;
+        };
+        let Some(close) =
+            tokens.filter(|t| matches!(t.kind(), SyntaxKind::RBrace | SyntaxKind::RBracket)).last()
+        else {
+            continue;
+        };
+        push(TextRange::new(open.text_range().start(), close.text_range().end()), None);
+    }
+
+    // Block comments spanning several lines, and runs of consecutive line comments
+    let mut line_comments: Option<TextRange> = None;
+    let mut token = root_node.first_token();
+    while let Some(t) = token {
+        token = t.next_token();
+        match t.kind() {
+            SyntaxKind::Comment if t.text().starts_with("//") => {
+                line_comments = Some(match line_comments {
+                    Some(r) => r.cover(t.text_range()),
+                    None => t.text_range(),
+                });
+                continue;
+            }
+            SyntaxKind::Comment => push(t.text_range(), Some(FoldingRangeKind::Comment)),
+            // A single line break separates consecutive line comments
+            SyntaxKind::Whitespace if t.text().matches('\n').count() <= 1 => continue,
+            _ => (),
+        }
+        if let Some(r) = line_comments.take() {
+            push(r, Some(FoldingRangeKind::Comment));
+        }
+    }
+    if let Some(r) = line_comments {
+        push(r, Some(FoldingRangeKind::Comment));
+    }
+
+    Some(result)
+}
+
+#[test]
+fn test_folding_ranges() {
+    let source = r#"
+/* A comment
+   on several lines */
+export component Test {
+    // first line
+    // second line
+    Rectangle { }
+    Rectangle {
+        background: red;
+    }
+    states [
+        active when true: {
+            x: 10px;
+        }
+    ]
+}"#;
+
+    let (mut dc, uri, _) = crate::language::test::loaded_document_cache(source.into());
+    let ranges = get_folding_ranges(&mut dc, &lsp_types::TextDocumentIdentifier { uri })
+        .unwrap()
+        .into_iter()
+        .map(|r| (r.start_line, r.end_line, r.kind))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        ranges,
+        vec![
+            (3, 15, None),
+            (7, 9, None),
+            (10, 14, None),
+            (11, 13, None),
+            (1, 2, Some(FoldingRangeKind::Comment)),
+            (4, 5, Some(FoldingRangeKind::Comment)),
+        ]
+    );
+}
@@ -8,6 +8,7 @@
 use i_slint_compiler::expression_tree::Expression;
 use i_slint_compiler::langtype::{ElementType, Type};
 use i_slint_compiler::lookup::{LookupObject, LookupResult};
+use i_slint_compiler::object_tree::ElementRc;
 use i_slint_compiler::parser::{syntax_nodes, SyntaxKind, SyntaxNode, SyntaxToken};
 use i_slint_compiler::pathutils::clean_path;
 
@@ -18,6 +19,14 @@ pub fn goto_definition(
     document_cache: &mut DocumentCache,
     token: SyntaxToken,
 ) -> Option<GotoDefinitionResponse> {
+    goto_node(&find_declaration_node(document_cache, token)?)
+}
+
+/// Return the node of the declaration the identifier `token` refers to
+pub fn find_declaration_node(
+    document_cache: &DocumentCache,
+    token: SyntaxToken,
+) -> Option<SyntaxNode> {
     let mut node = token.parent();
     loop {
         if let Some(n) = syntax_nodes::QualifiedName::new(node.clone()) {
@@ -27,8 +36,8 @@ pub fn goto_definition(
                     let qual = i_slint_compiler::object_tree::QualifiedTypeName::from_node(n);
                     let doc = document_cache.documents.get_document(node.source_file.path())?;
                     match doc.local_registry.lookup_qualified(&qual.members) {
-                        Type::Struct { node: Some(node), .. } => goto_node(node.parent().as_ref()?),
-                        Type::Enumeration(e) => goto_node(e.node.as_ref()?),
+                        Type::Struct { node: Some(node), .. } => node.parent(),
+                        Type::Enumeration(e) => Some(e.node.clone()?.into()),
                         _ => None,
                     }
                 }
@@ -37,7 +46,7 @@ pub fn goto_definition(
                     let doc = document_cache.documents.get_document(node.source_file.path())?;
                     match doc.local_registry.lookup_element(&qual.to_string()) {
                         Ok(ElementType::Component(c)) => {
-                            goto_node(&c.root_element.borrow().debug.first()?.0)
+                            Some(c.root_element.borrow().debug.first()?.0.clone().into())
                         }
                         _ => None,
                     }
@@ -46,60 +55,28 @@ pub fn goto_definition(
                     if token.kind() != SyntaxKind::Identifier {
                         return None;
                     }
-                    let lr = with_lookup_ctx(&document_cache.documents, node, |ctx| {
-                        let mut it = n
-                            .children_with_tokens()
-                            .filter_map(|t| t.into_token())
-                            .filter(|t| t.kind() == SyntaxKind::Identifier);
-                        let mut cur_tok = it.next()?;
-                        let first_str =
-                            i_slint_compiler::parser::normalize_identifier(cur_tok.text());
-                        let global = i_slint_compiler::lookup::global_lookup();
-                        let mut expr_it = global.lookup(ctx, &first_str)?;
-                        while cur_tok.token != token.token {
-                            cur_tok = it.next()?;
-                            let str =
-                                i_slint_compiler::parser::normalize_identifier(cur_tok.text());
-                            expr_it = expr_it.lookup(ctx, &str)?;
-                        }
-                        Some(expr_it)
-                    })?;
-                    let gn = match lr? {
+                    match lookup_expression_token(document_cache, &n, &token)? {
                         LookupResult::Expression {
                             expression: Expression::ElementReference(e),
                             ..
-                        } => e.upgrade()?.borrow().debug.first()?.0.clone().into(),
+                        } => Some(e.upgrade()?.borrow().debug.first()?.0.clone().into()),
                         LookupResult::Expression {
                             expression:
                                 Expression::CallbackReference(nr, _)
                                 | Expression::PropertyReference(nr)
                                 | Expression::FunctionReference(nr, _),
                             ..
-                        } => {
-                            let mut el = nr.element();
-                            loop {
-                                if let Some(x) = el.borrow().property_declarations.get(nr.name()) {
-                                    break x.node.clone()?;
-                                }
-                                let base = el.borrow().base_type.clone();
-                                if let ElementType::Component(c) = base {
-                                    el = c.root_element.clone();
-                                } else {
-                                    return None;
-                                }
-                            }
-                        }
+                        } => find_property_declaration(&nr.element(), nr.name()),
                         LookupResult::Expression {
                             expression: Expression::EnumerationValue(v),
                             ..
                         } => {
                             // FIXME: this goes to the enum definition instead of the value definition.
-                            v.enumeration.node.clone()?.into()
+                            Some(v.enumeration.node.clone()?.into())
                         }
-                        LookupResult::Enumeration(e) => e.node.clone()?.into(),
-                        _ => return None,
-                    };
-                    goto_node(&gn)
+                        LookupResult::Enumeration(e) => Some(e.node.clone()?.into()),
+                        _ => None,
+                    }
                 }
                 _ => None,
             };
@@ -108,7 +85,7 @@ pub fn goto_definition(
             let imp_name = i_slint_compiler::typeloader::ImportedName::from_node(n);
             return match doc.local_registry.lookup_element(&imp_name.internal_name) {
                 Ok(ElementType::Component(c)) => {
-                    goto_node(&c.root_element.borrow().debug.first()?.0)
+                    Some(c.root_element.borrow().debug.first()?.0.clone().into())
                 }
                 _ => None,
             };
@@ -121,8 +98,7 @@ pub fn goto_definition(
                 .join(n.child_text(SyntaxKind::StringLiteral)?.trim_matches('\"'));
             let import_file = clean_path(&import_file);
             let doc = document_cache.documents.get_document(&import_file)?;
-            let doc_node = doc.node.clone()?;
-            return goto_node(&doc_node);
+            return Some(doc.node.clone()?.into());
         } else if syntax_nodes::BindingExpression::new(node.clone()).is_some() {
             // don't fallback to the Binding
             return None;
@@ -136,10 +112,9 @@ pub fn goto_definition(
                 (i_slint_compiler::parser::identifier_text(&p.DeclaredIdentifier())? == prop_name)
                     .then_some(p)
             }) {
-                return goto_node(&p);
+                return Some(p.into());
             }
-            let n = find_property_declaration_in_base(document_cache, element, prop_name)?;
-            return goto_node(&n);
+            return find_property_declaration_in_base(document_cache, element, prop_name);
         } else if let Some(n) = syntax_nodes::TwoWayBinding::new(node.clone()) {
             if token.kind() != SyntaxKind::Identifier {
                 return None;
@@ -153,10 +128,9 @@ pub fn goto_definition(
                 (i_slint_compiler::parser::identifier_text(&p.DeclaredIdentifier())? == prop_name)
                     .then_some(p)
             }) {
-                return goto_node(&p);
+                return Some(p.into());
             }
-            let n = find_property_declaration_in_base(document_cache, element, prop_name)?;
-            return goto_node(&n);
+            return find_property_declaration_in_base(document_cache, element, prop_name);
         } else if let Some(n) = syntax_nodes::CallbackConnection::new(node.clone()) {
             if token.kind() != SyntaxKind::Identifier {
                 return None;
@@ -170,15 +144,54 @@ pub fn goto_definition(
                 (i_slint_compiler::parser::identifier_text(&p.DeclaredIdentifier())? == prop_name)
                     .then_some(p)
             }) {
-                return goto_node(&p);
+                return Some(p.into());
             }
-            let n = find_property_declaration_in_base(document_cache, element, prop_name)?;
-            return goto_node(&n);
+            return find_property_declaration_in_base(document_cache, element, prop_name);
         }
         node = node.parent()?;
     }
 }
 
+/// Lookup the identifier `token`, which is part of the qualified name `n` in an expression
+pub fn lookup_expression_token(
+    document_cache: &DocumentCache,
+    n: &syntax_nodes::QualifiedName,
+    token: &SyntaxToken,
+) -> Option<LookupResult> {
+    with_lookup_ctx(&document_cache.documents, n.clone().into(), |ctx| {
+        let mut it = n
+            .children_with_tokens()
+            .filter_map(|t| t.into_token())
+            .filter(|t| t.kind() == SyntaxKind::Identifier);
+        let mut cur_tok = it.next()?;
+        let first_str = i_slint_compiler::parser::normalize_identifier(cur_tok.text());
+        let global = i_slint_compiler::lookup::global_lookup();
+        let mut expr_it = global.lookup(ctx, &first_str)?;
+        while cur_tok.token != token.token {
+            cur_tok = it.next()?;
+            let str = i_slint_compiler::parser::normalize_identifier(cur_tok.text());
+            expr_it = expr_it.lookup(ctx, &str)?;
+        }
+        Some(expr_it)
+    })?
+}
+
+/// Return the node declaring the property, callback or function `name` of the element or of its bases
+pub fn find_property_declaration(element: &ElementRc, name: &str) -> Option<SyntaxNode> {
+    let mut el = element.clone();
+    loop {
+        if let Some(x) = el.borrow().property_declarations.get(name) {
+            return x.node.clone();
+        }
+        let base = el.borrow().base_type.clone();
+        if let ElementType::Component(c) = base {
+            el = c.root_element.clone();
+        } else {
+            return None;
+        }
+    }
+}
+
 /// Try to lookup the property `prop_name` in the base of the given Element
 fn find_property_declaration_in_base(
     document_cache: &DocumentCache,
@@ -0,0 +1,361 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+use super::goto::{find_property_declaration, lookup_expression_token};
+use super::DocumentCache;
+use crate::util;
+
+use i_slint_compiler::expression_tree::Expression;
+use i_slint_compiler::langtype::{ElementType, Type};
+use i_slint_compiler::lookup::LookupResult;
+use i_slint_compiler::object_tree::{ElementRc, PropertyVisibility};
+use i_slint_compiler::parser::{syntax_nodes, SyntaxKind, SyntaxNode, SyntaxToken};
+
+use lsp_types::{Hover, HoverContents, MarkupContent, MarkupKind, Url};
+
+pub fn get_tooltip(document_cache: &mut DocumentCache, token: SyntaxToken) -> Option<Hover> {
+    if token.kind() != SyntaxKind::Identifier {
+        return None;
+    }
+    let node = token.parent();
+    let (signature, declaration) = if let Some(n) = syntax_nodes::QualifiedName::new(node.clone()) {
+        match n.parent()?.kind() {
+            SyntaxKind::Element => {
+                let doc = document_cache.documents.get_document(node.source_file.path())?;
+                let qual = i_slint_compiler::object_tree::QualifiedTypeName::from_node(n);
+                element_type_tooltip(&doc.local_registry.lookup_element(&qual.to_string()).ok()?)
+            }
+            SyntaxKind::Type => {
+                let doc = document_cache.documents.get_document(node.source_file.path())?;
+                let qual = i_slint_compiler::object_tree::QualifiedTypeName::from_node(n);
+                type_tooltip(&doc.local_registry.lookup_qualified(&qual.members))
+            }
+            SyntaxKind::Expression => match lookup_expression_token(document_cache, &n, &token)? {
+                LookupResult::Expression { expression, .. } => {
+                    expression_tooltip(&token, expression)?
+                }
+                LookupResult::Enumeration(e) => {
+                    (format!("enum {}", e.name), e.node.clone().map(Into::into))
+                }
+                LookupResult::Namespace(_) => return None,
+            },
+            _ => return None,
+        }
+    } else if let Some(n) = syntax_nodes::SubElement::new(node.clone()) {
+        let global_tr = document_cache.documents.global_type_registry.borrow();
+        let tr = document_cache
+            .documents
+            .get_document(node.source_file.path())
+            .map(|doc| &doc.local_registry)
+            .unwrap_or(&global_tr);
+        let element_type = util::lookup_current_element_type(n.Element().into(), tr)?;
+        element_tooltip(token.text(), &element_type)
+    } else {
+        let is_declaration = node.kind() == SyntaxKind::DeclaredIdentifier
+            && node.parent().map_or(false, |p| {
+                matches!(
+                    p.kind(),
+                    SyntaxKind::PropertyDeclaration
+                        | SyntaxKind::CallbackDeclaration
+                        | SyntaxKind::Function
+                )
+            });
+        if !is_declaration
+            && !matches!(
+                node.kind(),
+                SyntaxKind::Binding | SyntaxKind::TwoWayBinding | SyntaxKind::CallbackConnection
+            )
+        {
+            return None;
+        }
+        let uri = Url::from_file_path(token.source_file.path()).ok()?;
+        let position = util::map_token(&token)?.start;
+        let element = super::element_at_position(&document_cache.documents, &uri, &position)?;
+        let name = i_slint_compiler::parser::normalize_identifier(token.text());
+        property_tooltip(&element.element, &name)?
+    };
+
+    let mut value = format!("```slint\n{signature}\n```");
+    if let Some(doc) = declaration.as_ref().and_then(doc_comment) {
+        value += "\n\n";
+        value += &doc;
+    }
+    Some(Hover {
+        contents: HoverContents::Markup(MarkupContent { kind: MarkupKind::Markdown, value }),
+        range: util::map_token(&token),
+    })
+}
+
+/// Return the signature and the declaration node of what the expression refers to
+fn expression_tooltip(
+    token: &SyntaxToken,
+    expression: Expression,
+) -> Option<(String, Option<SyntaxNode>)> {
+    match expression {
+        Expression::PropertyReference(nr)
+        | Expression::CallbackReference(nr, _)
+        | Expression::FunctionReference(nr, _) => property_tooltip(&nr.element(), nr.name()),
+        Expression::ElementReference(e) => {
+            let e = e.upgrade()?;
+            let e = e.borrow();
+            Some(element_tooltip(&e.id, &e.base_type))
+        }
+        Expression::BuiltinFunctionReference(f, _) => {
+            Some((function_signature(token.text(), &f.ty(), 0)?, None))
+        }
+        Expression::MemberFunction { member, .. } => match *member {
+            // The first argument is the base of the member function
+            Expression::BuiltinFunctionReference(f, _) => {
+                Some((function_signature(token.text(), &f.ty(), 1)?, None))
+            }
+            _ => None,
+        },
+        Expression::EnumerationValue(v) => Some((
+            format!("{}.{v}", v.enumeration.name),
+            v.enumeration.node.clone().map(Into::into),
+        )),
+        Expression::BuiltinMacroReference(..) => None,
+        expression => {
+            let ty = expression.ty();
+            (ty != Type::Invalid).then(|| (format!("{}: {ty}", token.text()), None))
+        }
+    }
+}
+
+/// Return the signature, including the declared default value, and the declaration node of
+/// the property, callback or function `name` of the element
+fn property_tooltip(element: &ElementRc, name: &str) -> Option<(String, Option<SyntaxNode>)> {
+    let lookup = element.borrow().lookup_property(name);
+    if !lookup.is_valid() {
+        return None;
+    }
+    let name = lookup.resolved_name.as_ref();
+    let declaration = find_property_declaration(element, name);
+    let signature = match &lookup.property_type {
+        Type::Callback { return_type, args } => {
+            let args = args.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ");
+            match return_type {
+                Some(ret) => format!("callback {name}({args}) -> {ret}"),
+                None => format!("callback {name}({args})"),
+            }
+        }
+        Type::Function { return_type, args } => {
+            let names = declaration
+                .clone()
+                .and_then(syntax_nodes::Function::new)
+                .map(|f| {
+                    f.ArgumentDeclaration()
+                        .map(|a| {
+                            i_slint_compiler::parser::identifier_text(&a.DeclaredIdentifier())
+                                .unwrap_or_default()
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default();
+            let args = args
+                .iter()
+                .enumerate()
+                .map(|(i, ty)| match names.get(i) {
+                    Some(n) => format!("{n}: {ty}"),
+                    None => ty.to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            let visibility = match lookup.property_visibility {
+                PropertyVisibility::Public => "public ",
+                PropertyVisibility::Protected => "protected ",
+                _ => "",
+            };
+            match return_type.as_ref() {
+                Type::Void => format!("{visibility}function {name}({args})"),
+                ret => format!("{visibility}function {name}({args}) -> {ret}"),
+            }
+        }
+        ty => {
+            let visibility = match lookup.property_visibility {
+                PropertyVisibility::Input | PropertyVisibility::Constexpr => "in ",
+                PropertyVisibility::Output => "out ",
+                PropertyVisibility::InOut => "in-out ",
+                _ => "",
+            };
+            let mut signature = format!("{visibility}property <{ty}> {name}");
+            if let Some(p) = declaration.clone().and_then(syntax_nodes::PropertyDeclaration::new) {
+                if let Some(b) = p.BindingExpression() {
+                    signature +=
+                        &format!(": {}", b.text().to_string().trim().trim_end_matches(';'));
+                } else if let Some(b) = p.TwoWayBinding() {
+                    signature += &format!(" {}", b.text().to_string().trim().trim_end_matches(';'));
+                }
+            }
+            signature
+        }
+    };
+    Some((signature, declaration))
+}
+
+/// Return the signature of a function of the given type, skipping the first `skip` arguments
+fn function_signature(name: &str, ty: &Type, skip: usize) -> Option<String> {
+    let Type::Function { return_type, args } = ty else { return None };
+    let args = args.iter().skip(skip).map(ToString::to_string).collect::<Vec<_>>().join(", ");
+    Some(match return_type.as_ref() {
+        Type::Void => format!("{name}({args})"),
+        ret => format!("{name}({args}) -> {ret}"),
+    })
+}
+
+fn element_type_tooltip(element_type: &ElementType) -> Option<(String, Option<SyntaxNode>)> {
+    match element_type {
+        ElementType::Component(c) => {
+            let signature = match &c.root_element.borrow().base_type {
+                ElementType::Global => format!("global {}", c.id),
+                ElementType::Builtin(b) if b.name == "Empty" => format!("component {}", c.id),
+                base => format!("component {} inherits {base}", c.id),
+            };
+            Some((signature, c.node.clone()))
+        }
+        ElementType::Builtin(b) => Some((b.name.clone(), None)),
+        _ => None,
+    }
+}
+
+fn element_tooltip(id: &str, element_type: &ElementType) -> (String, Option<SyntaxNode>) {
+    let declaration = match element_type {
+        ElementType::Component(c) => c.node.clone(),
+        _ => None,
+    };
+    (format!("{id} := {element_type}"), declaration)
+}
+
+fn type_tooltip(ty: &Type) -> Option<(String, Option<SyntaxNode>)> {
+    match ty {
+        Type::Struct { name: Some(name), node, .. } => {
+            Some((format!("struct {name}"), node.as_ref().and_then(|n| n.parent())))
+        }
+        Type::Enumeration(e) => Some((format!("enum {}", e.name), e.node.clone().map(Into::into))),
+        Type::Invalid => None,
+        ty => Some((ty.to_string(), None)),
+    }
+}
+
+/// Return the text of the comments directly above the declaration node, without the comment markers
+pub fn doc_comment(node: &SyntaxNode) -> Option<String> {
+    let node =
+        node.parent().filter(|p| p.kind() == SyntaxKind::ExportsList).unwrap_or(node.clone());
+    // The node may start with the white spaces and comments preceding it
+    let mut first_token = node.first_token()?;
+    while matches!(first_token.kind(), SyntaxKind::Whitespace | SyntaxKind::Comment) {
+        first_token = first_token.next_token()?;
+    }
+    let mut comments = Vec::new();
+    let mut token = first_token.prev_token();
+    while let Some(t) = token {
+        match t.kind() {
+            SyntaxKind::Whitespace if t.text().matches('\n').count() <= 1 => {
+                token = t.prev_token();
+            }
+            SyntaxKind::Comment => {
+                let prev = t.prev_token();
+                // A comment at the end of the previous line belongs to the previous line
+                if prev.as_ref().map_or(false, |p| !p.text().contains('\n')) {
+                    break;
+                }
+                comments.push(comment_text(t.text()));
+                token = prev;
+            }
+            _ => break,
+        }
+    }
+    comments.reverse();
+    let doc = comments.join("\n");
+    (!doc.trim().is_empty()).then_some(doc)
+}
+
+fn comment_text(comment: &str) -> String {
+    if let Some(comment) = comment.strip_prefix("//") {
+        comment.trim_start_matches('/').trim().to_string()
+    } else {
+        comment
+            .trim_start_matches("/*")
+            .trim_end_matches("*/")
+            .lines()
+            .map(|l| l.trim().trim_start_matches('*').trim())
+            .collect::<Vec<_>>()
+            .join("\n")
+            .trim()
+            .to_string()
+    }
+}
+
+#[test]
+fn test_tooltip() {
+    fn tooltip(dc: &mut DocumentCache, doc: &syntax_nodes::Document, offset: usize) -> String {
+        let token = crate::language::token_at_offset(doc, offset as u32).unwrap();
+        let hover = get_tooltip(dc, token).unwrap();
+        let HoverContents::Markup(markup) = hover.contents else { panic!("not markup") };
+        markup.value
+    }
+
+    let source = r#"
+/// The Abc component
+export component Abc {
+    // The greeting
+    in property <string> hello: "world";
+    callback clicked(int) -> bool;
+}
+export component Test {
+    function add(a: int, b: int) -> int { return a + b; }
+    abc := Abc {
+        hello: "foo";
+    }
+    Text {
+        text: abc.hello;
+        x: add(1, 2) * 1px;
+        y: max(1px, 2px);
+        opacity: abs(-1);
+    }
+}"#;
+
+    let (mut dc, uri, _) = crate::language::test::loaded_document_cache(source.into());
+    let doc = dc
+        .documents
+        .get_document(&crate::language::uri_to_file(&uri).unwrap())
+        .unwrap()
+        .node
+        .clone()
+        .unwrap();
+
+    let offset = source.find("abc := Abc").unwrap();
+    assert_eq!(
+        tooltip(&mut dc, &doc, offset + 8),
+        "```slint\ncomponent Abc\n```\n\nThe Abc component"
+    );
+    assert_eq!(
+        tooltip(&mut dc, &doc, offset + 1),
+        "```slint\nabc := Abc\n```\n\nThe Abc component"
+    );
+
+    let offset = source.find("text: abc.hello").unwrap();
+    assert_eq!(
+        tooltip(&mut dc, &doc, offset + 12),
+        "```slint\nin property <string> hello: \"world\"\n```\n\nThe greeting"
+    );
+    assert_eq!(tooltip(&mut dc, &doc, offset + 1), "```slint\nin property <string> text\n```");
+
+    let offset = source.find("hello: \"foo\"").unwrap();
+    assert_eq!(
+        tooltip(&mut dc, &doc, offset),
+        "```slint\nin property <string> hello: \"world\"\n```\n\nThe greeting"
+    );
+
+    let offset = source.find("callback clicked").unwrap();
+    assert_eq!(tooltip(&mut dc, &doc, offset + 10), "```slint\ncallback clicked(int) -> bool\n```");
+
+    let offset = source.find("add(1, 2)").unwrap();
+    assert_eq!(
+        tooltip(&mut dc, &doc, offset),
+        "```slint\nfunction add(a: int, b: int) -> int\n```"
+    );
+
+    let offset = source.find("abs(-1)").unwrap();
+    assert_eq!(tooltip(&mut dc, &doc, offset), "```slint\nabs(float) -> float\n```");
+}
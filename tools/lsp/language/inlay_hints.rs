@@ -0,0 +1,193 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+use super::signature_help::{argument_names, lookup_callee};
+use super::{uri_to_file, DocumentCache};
+use crate::util;
+
+use i_slint_compiler::expression_tree::Expression;
+use i_slint_compiler::langtype::Type;
+use i_slint_compiler::parser::{syntax_nodes, SyntaxKind, SyntaxNode};
+
+use lsp_types::{InlayHint, InlayHintKind, InlayHintLabel, Position, Range, Url};
+
+/// Return the inlay hints within the given range of the document:
+///  - the type of the property declarations that don't specify it,
+///  - the value in the default unit of the number literals using another unit,
+///  - the parameter names at the call sites of callbacks and functions.
+pub fn get_inlay_hints(
+    document_cache: &mut DocumentCache,
+    text_document: &lsp_types::TextDocumentIdentifier,
+    range: &Range,
+) -> Option<Vec<InlayHint>> {
+    let doc = document_cache.documents.get_document(&uri_to_file(&text_document.uri)?)?;
+    let root_node = doc.node.as_ref()?;
+    let sf = &root_node.source_file;
+    let in_range = |pos: &Position| *pos >= range.start && *pos <= range.end;
+
+    let mut result = Vec::new();
+    for node in root_node.descendants() {
+        let node = SyntaxNode { node, source_file: sf.clone() };
+        match node.kind() {
+            SyntaxKind::PropertyDeclaration => {
+                let Some(decl) = syntax_nodes::PropertyDeclaration::new(node) else { continue };
+                if decl.Type().is_some() {
+                    continue;
+                }
+                let identifier = decl.DeclaredIdentifier();
+                let position = util::map_position(sf, identifier.text_range().start());
+                if !in_range(&position) {
+                    continue;
+                }
+                let Some(ty) =
+                    property_type(document_cache, &text_document.uri, &position, &identifier)
+                else {
+                    continue;
+                };
+                result.push(hint(position, format!("<{ty}>"), InlayHintKind::TYPE, false, true));
+            }
+            SyntaxKind::Expression => {
+                for token in node.children_with_tokens().filter_map(|t| t.into_token()) {
+                    if token.kind() != SyntaxKind::NumberLiteral {
+                        continue;
+                    }
+                    let position = util::map_position(sf, token.text_range().end());
+                    if !in_range(&position) {
+                        continue;
+                    }
+                    if let Some(label) = resolved_unit(token.text()) {
+                        result.push(hint(position, label, InlayHintKind::TYPE, true, false));
+                    }
+                }
+            }
+            SyntaxKind::FunctionCallExpression => {
+                let names = match lookup_callee(document_cache, &node) {
+                    Some((
+                        _,
+                        Expression::CallbackReference(nr, _) | Expression::FunctionReference(nr, _),
+                    )) => argument_names(&nr).0,
+                    _ => continue,
+                };
+                // The first expression is the callee
+                for (arg, name) in node
+                    .children()
+                    .filter(|n| n.kind() == SyntaxKind::Expression)
+                    .skip(1)
+                    .zip(names)
+                {
+                    let Some(name) = name else { continue };
+                    // Don't repeat the name if it is passed a variable of the same name
+                    if i_slint_compiler::parser::normalize_identifier(arg.text().to_string().trim())
+                        == name
+                    {
+                        continue;
+                    }
+                    let position = util::map_position(sf, arg.text_range().start());
+                    if in_range(&position) {
+                        result.push(hint(
+                            position,
+                            format!("{name}:"),
+                            InlayHintKind::PARAMETER,
+                            false,
+                            true,
+                        ));
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+    Some(result)
+}
+
+fn hint(
+    position: Position,
+    label: String,
+    kind: InlayHintKind,
+    padding_left: bool,
+    padding_right: bool,
+) -> InlayHint {
+    InlayHint {
+        position,
+        label: InlayHintLabel::String(label),
+        kind: Some(kind),
+        text_edits: None,
+        tooltip: None,
+        padding_left: Some(padding_left),
+        padding_right: Some(padding_right),
+        data: None,
+    }
+}
+
+/// Return the type inferred for the declaration of the property named `identifier`
+fn property_type(
+    document_cache: &DocumentCache,
+    uri: &Url,
+    position: &Position,
+    identifier: &syntax_nodes::DeclaredIdentifier,
+) -> Option<Type> {
+    let element = super::element_at_position(&document_cache.documents, uri, position)?;
+    let name = i_slint_compiler::parser::identifier_text(identifier)?;
+    let ty = element.element.borrow().lookup_property(&name).property_type;
+    (!matches!(ty, Type::Invalid | Type::InferredProperty | Type::InferredCallback)).then_some(ty)
+}
+
+/// Return the value of the number literal in the default unit of its type, if it has another unit
+fn resolved_unit(literal: &str) -> Option<String> {
+    let Ok(Expression::NumberLiteral(value, unit)) =
+        i_slint_compiler::literals::parse_number_literal(literal.into())
+    else {
+        return None;
+    };
+    let default_unit = unit.ty().default_unit()?;
+    if unit == default_unit {
+        return None;
+    }
+    let value = (unit.normalize(value) * 100.).round() / 100.;
+    Some(format!("= {value}{default_unit}"))
+}
+
+#[test]
+fn test_inlay_hints() {
+    let source = r#"
+component Abc {
+    in property <int> value;
+    callback clicked(int, string);
+    clicked(count, text) => {}
+}
+export component Test {
+    function add(a: int, b: int) -> int { return a + b; }
+    property value <=> abc.value;
+    abc := Abc {
+        width: 1in;
+        height: 100px;
+    }
+    TouchArea {
+        clicked => {
+            abc.clicked(add(1, 2), "foo");
+        }
+    }
+}"#;
+
+    let (mut dc, uri, _) = crate::language::test::loaded_document_cache(source.into());
+    let range = Range::new(Position::new(0, 0), Position::new(100, 0));
+    let hints = get_inlay_hints(&mut dc, &lsp_types::TextDocumentIdentifier { uri }, &range)
+        .unwrap()
+        .into_iter()
+        .map(|h| {
+            let InlayHintLabel::String(label) = h.label else { panic!("Not a string") };
+            (h.position.line, label)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        hints,
+        vec![
+            (8, "<int>".to_string()),
+            (10, "= 96px".to_string()),
+            (15, "count:".to_string()),
+            (15, "text:".to_string()),
+            (15, "a:".to_string()),
+            (15, "b:".to_string()),
+        ]
+    );
+}
@@ -383,10 +383,10 @@ fn get_properties(element: &common::ElementRcNode) -> Vec<PropertyInformation> {
             group: "accessibility".into(),
         });
         if current_element.borrow().is_binding_set("accessible-role", true) {
-            result.extend(get_reserved_properties(
-                "accessibility",
-                i_slint_compiler::typeregister::RESERVED_ACCESSIBILITY_PROPERTIES,
-            ));
+            let properties = i_slint_compiler::typeregister::reserved_accessibility_properties()
+                .filter(|(_, ty)| ty.is_property_type())
+                .collect::<Vec<_>>();
+            result.extend(get_reserved_properties("accessibility", &properties));
         }
         break;
     }
@@ -0,0 +1,137 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+use super::goto::find_declaration_node;
+use super::DocumentCache;
+use crate::util;
+
+use i_slint_compiler::parser::{normalize_identifier, SyntaxKind, SyntaxNode, SyntaxToken};
+
+use lsp_types::{Location, Url};
+
+/// Find all the identifiers in all loaded documents that refer to the same declaration as `token`
+pub fn find_all_references(
+    document_cache: &mut DocumentCache,
+    token: SyntaxToken,
+    include_declaration: bool,
+) -> Option<Vec<Location>> {
+    if token.kind() != SyntaxKind::Identifier {
+        return None;
+    }
+    let declaration =
+        declared_node(&token).or_else(|| find_declaration_node(document_cache, token.clone()))?;
+    let name = normalize_identifier(token.text());
+
+    let mut result = Vec::new();
+    for (path, doc) in document_cache.documents.all_file_documents() {
+        if path.starts_with("builtin:/") {
+            continue;
+        }
+        let Some(node) = &doc.node else { continue };
+        let Ok(uri) = Url::from_file_path(path) else { continue };
+        let mut current = node.first_token();
+        while let Some(t) = current {
+            current = t.next_token();
+            if t.kind() != SyntaxKind::Identifier || normalize_identifier(t.text()) != name {
+                continue;
+            }
+            let t = SyntaxToken { token: t, source_file: node.source_file.clone() };
+            let (target, is_declaration) = match declared_node(&t) {
+                Some(n) => (Some(n), true),
+                None => (find_declaration_node(document_cache, t.clone()), false),
+            };
+            if target.map_or(false, |n| same_node(&n, &declaration))
+                && (include_declaration || !is_declaration)
+            {
+                if let Some(range) = util::map_token(&t) {
+                    result.push(Location { uri: uri.clone(), range });
+                }
+            }
+        }
+    }
+    Some(result)
+}
+
+/// If the identifier `token` is the name in a declaration, return the node of that declaration,
+/// as returned by `find_declaration_node` for the uses of that name.
+fn declared_node(token: &SyntaxToken) -> Option<SyntaxNode> {
+    let parent = token.parent();
+    match parent.kind() {
+        // `id := Element { ... }`
+        SyntaxKind::SubElement => parent.child_node(SyntaxKind::Element),
+        SyntaxKind::DeclaredIdentifier => {
+            let declaration = parent.parent()?;
+            match declaration.kind() {
+                SyntaxKind::PropertyDeclaration
+                | SyntaxKind::CallbackDeclaration
+                | SyntaxKind::Function
+                | SyntaxKind::StructDeclaration
+                | SyntaxKind::EnumDeclaration => Some(declaration),
+                // Uses of a component refer to its root element
+                SyntaxKind::Component => declaration.child_node(SyntaxKind::Element),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn same_node(a: &SyntaxNode, b: &SyntaxNode) -> bool {
+    a.text_range() == b.text_range() && a.source_file.path() == b.source_file.path()
+}
+
+#[test]
+fn test_find_all_references() {
+    let source = r#"
+component Abc {
+    in property <string> hello;
+    callback clicked;
+}
+export component Test {
+    abc := Abc {
+        hello: "foo";
+        clicked => { abc.hello = "bar"; }
+    }
+    Text {
+        text: abc.hello;
+    }
+    Abc { }
+}"#;
+
+    let (mut dc, uri, _) = crate::language::test::loaded_document_cache(source.into());
+    let doc = dc
+        .documents
+        .get_document(&crate::language::uri_to_file(&uri).unwrap())
+        .unwrap()
+        .node
+        .clone()
+        .unwrap();
+
+    let lines = |locations: Vec<Location>| {
+        assert!(locations.iter().all(|l| l.uri == uri));
+        locations.iter().map(|l| l.range.start.line).collect::<Vec<_>>()
+    };
+
+    // From a use of the property
+    let offset = source.find("text: abc.hello").unwrap() as u32;
+    let token = crate::language::token_at_offset(&doc, offset + 12).unwrap();
+    assert_eq!(token.text(), "hello");
+    let refs = find_all_references(&mut dc, token.clone(), true).unwrap();
+    assert_eq!(lines(refs), vec![2, 7, 8, 11]);
+    let refs = find_all_references(&mut dc, token, false).unwrap();
+    assert_eq!(lines(refs), vec![7, 8, 11]);
+
+    // From the declaration of the component
+    let offset = source.find("component Abc").unwrap() as u32;
+    let token = crate::language::token_at_offset(&doc, offset + 11).unwrap();
+    assert_eq!(token.text(), "Abc");
+    let refs = find_all_references(&mut dc, token, true).unwrap();
+    assert_eq!(lines(refs), vec![1, 6, 13]);
+
+    // From the element id
+    let offset = source.find("abc := Abc").unwrap() as u32;
+    let token = crate::language::token_at_offset(&doc, offset + 1).unwrap();
+    assert_eq!(token.text(), "abc");
+    let refs = find_all_references(&mut dc, token, true).unwrap();
+    assert_eq!(lines(refs), vec![6, 8, 11]);
+}
@@ -0,0 +1,237 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+use super::goto::{find_property_declaration, lookup_expression_token};
+use super::hover::doc_comment;
+use super::DocumentCache;
+
+use i_slint_compiler::expression_tree::Expression;
+use i_slint_compiler::langtype::Type;
+use i_slint_compiler::lookup::LookupResult;
+use i_slint_compiler::namedreference::NamedReference;
+use i_slint_compiler::parser::{syntax_nodes, SyntaxKind, SyntaxNode, SyntaxToken};
+
+use lsp_types::{
+    Documentation, ParameterInformation, ParameterLabel, SignatureHelp, SignatureInformation,
+};
+
+/// Return the signature of the callback or function called at `offset`
+pub fn get_signature_help(
+    document_cache: &mut DocumentCache,
+    token: SyntaxToken,
+    offset: u32,
+) -> Option<SignatureHelp> {
+    // Find the innermost call whose parentheses contain the offset
+    let mut node = token.parent();
+    let (call, lparent) = loop {
+        if node.kind() == SyntaxKind::FunctionCallExpression {
+            let lparent = node
+                .children_with_tokens()
+                .filter_map(|t| t.into_token())
+                .find(|t| t.kind() == SyntaxKind::LParent);
+            if let Some(lparent) = lparent.filter(|t| t.text_range().end() <= offset.into()) {
+                break (node, lparent);
+            }
+        }
+        node = node.parent()?;
+    };
+    let rparent_start = call
+        .children_with_tokens()
+        .filter_map(|t| t.into_token())
+        .find(|t| t.kind() == SyntaxKind::RParent)
+        .map(|t| t.text_range().start());
+    if rparent_start.map_or(false, |end| end < offset.into()) {
+        return None;
+    }
+    let active_parameter = call
+        .children_with_tokens()
+        .filter_map(|t| t.into_token())
+        .filter(|t| {
+            t.kind() == SyntaxKind::Comma
+                && t.text_range().start() > lparent.text_range().start()
+                && t.text_range().start() < offset.into()
+        })
+        .count() as u32;
+
+    let (name, expression) = lookup_callee(document_cache, &call)?;
+    let (args, return_type, declaration) = match expression {
+        Expression::CallbackReference(nr, _) => {
+            let Type::Callback { args, return_type } = nr.ty() else { return None };
+            let (names, declaration) = argument_names(&nr);
+            (named_arguments(&args, &names), return_type.map(|t| *t), declaration)
+        }
+        Expression::FunctionReference(nr, _) => {
+            let Type::Function { args, return_type } = nr.ty() else { return None };
+            let (names, declaration) = argument_names(&nr);
+            (named_arguments(&args, &names), Some(*return_type), declaration)
+        }
+        Expression::BuiltinFunctionReference(f, _) => builtin_function_args(&f.ty(), 0)?,
+        Expression::MemberFunction { member, .. } => match *member {
+            // The first argument is the base of the member function
+            Expression::BuiltinFunctionReference(f, _) => builtin_function_args(&f.ty(), 1)?,
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    let mut label = format!("{name}(");
+    let mut parameters = Vec::new();
+    for (i, arg) in args.iter().enumerate() {
+        if i > 0 {
+            label += ", ";
+        }
+        parameters.push(ParameterInformation {
+            label: ParameterLabel::LabelOffsets([
+                label.len() as u32,
+                (label.len() + arg.len()) as u32,
+            ]),
+            documentation: None,
+        });
+        label += arg;
+    }
+    label += ")";
+    if let Some(ret) = return_type.filter(|t| *t != Type::Void) {
+        label += &format!(" -> {ret}");
+    }
+
+    Some(SignatureHelp {
+        signatures: vec![SignatureInformation {
+            label,
+            documentation: declaration.as_ref().and_then(doc_comment).map(Documentation::String),
+            parameters: Some(parameters),
+            active_parameter: None,
+        }],
+        active_signature: Some(0),
+        active_parameter: Some(active_parameter),
+    })
+}
+
+/// Lookup what is called by the `FunctionCallExpression` node `call`.
+/// Return the name used for the call, and the expression the name resolves to.
+pub fn lookup_callee(
+    document_cache: &DocumentCache,
+    call: &SyntaxNode,
+) -> Option<(String, Expression)> {
+    let callee = syntax_nodes::QualifiedName::new(
+        call.child_node(SyntaxKind::Expression)?.child_node(SyntaxKind::QualifiedName)?,
+    )?;
+    let name_token = callee
+        .children_with_tokens()
+        .filter_map(|t| t.into_token())
+        .filter(|t| t.kind() == SyntaxKind::Identifier)
+        .last()?;
+    let name_token = SyntaxToken { token: name_token, source_file: callee.source_file.clone() };
+    match lookup_expression_token(document_cache, &callee, &name_token)? {
+        LookupResult::Expression { expression, .. } => {
+            Some((name_token.text().to_string(), expression))
+        }
+        _ => None,
+    }
+}
+
+/// Return the names of the arguments of the callback or function, as far as they are known,
+/// and the node of its declaration.
+/// Callback declarations only have types, so the names come from the handler of the callback
+/// in the element that declares it, if any.
+pub fn argument_names(nr: &NamedReference) -> (Vec<Option<String>>, Option<SyntaxNode>) {
+    let declaration = find_property_declaration(&nr.element(), nr.name());
+    let names = declaration.as_ref().and_then(|declaration| {
+        if let Some(f) = syntax_nodes::Function::new(declaration.clone()) {
+            return Some(
+                f.ArgumentDeclaration()
+                    .map(|a| i_slint_compiler::parser::identifier_text(&a.DeclaredIdentifier()))
+                    .collect(),
+            );
+        }
+        let element = syntax_nodes::Element::new(declaration.parent()?)?;
+        let handler = element.CallbackConnection().find(|c| {
+            i_slint_compiler::parser::identifier_text(c).map_or(false, |n| n == nr.name())
+        })?;
+        Some(
+            handler
+                .DeclaredIdentifier()
+                .map(|a| i_slint_compiler::parser::identifier_text(&a))
+                .collect(),
+        )
+    });
+    (names.unwrap_or_default(), declaration)
+}
+
+fn named_arguments(args: &[Type], names: &[Option<String>]) -> Vec<String> {
+    args.iter()
+        .enumerate()
+        .map(|(i, ty)| match names.get(i).cloned().flatten() {
+            Some(n) => format!("{n}: {ty}"),
+            None => ty.to_string(),
+        })
+        .collect()
+}
+
+fn builtin_function_args(
+    ty: &Type,
+    skip: usize,
+) -> Option<(Vec<String>, Option<Type>, Option<SyntaxNode>)> {
+    let Type::Function { args, return_type } = ty else { return None };
+    Some((
+        args.iter().skip(skip).map(ToString::to_string).collect(),
+        Some((**return_type).clone()),
+        None,
+    ))
+}
+
+#[test]
+fn test_signature_help() {
+    let source = r#"
+export component Test {
+    // Add two numbers
+    function add(a: int, b: int) -> int { return a + b; }
+    callback clicked(string, int);
+    Text {
+        x: add(1, 2) * 1px;
+        opacity: abs(0.5);
+    }
+    TouchArea {
+        clicked => { root.clicked("foo", 42); }
+    }
+}"#;
+
+    let (mut dc, uri, _) = crate::language::test::loaded_document_cache(source.into());
+    let doc = dc
+        .documents
+        .get_document(&crate::language::uri_to_file(&uri).unwrap())
+        .unwrap()
+        .node
+        .clone()
+        .unwrap();
+
+    let mut help_at = |offset: usize| {
+        let token = crate::language::token_at_offset(&doc, offset as u32).unwrap();
+        get_signature_help(&mut dc, token, offset as u32)
+    };
+
+    let offset = source.find("add(1, 2)").unwrap();
+    let help = help_at(offset + 4).unwrap();
+    assert_eq!(help.signatures[0].label, "add(a: int, b: int) -> int");
+    assert_eq!(
+        help.signatures[0].documentation,
+        Some(Documentation::String("Add two numbers".into()))
+    );
+    assert_eq!(
+        help.signatures[0].parameters.as_ref().unwrap()[1].label,
+        ParameterLabel::LabelOffsets([12, 18])
+    );
+    assert_eq!(help.active_parameter, Some(0));
+    let help = help_at(offset + 7).unwrap();
+    assert_eq!(help.active_parameter, Some(1));
+    // Not within the parentheses
+    assert!(help_at(offset + 1).is_none());
+
+    let offset = source.find("abs(0.5)").unwrap();
+    let help = help_at(offset + 5).unwrap();
+    assert_eq!(help.signatures[0].label, "abs(float) -> float");
+
+    let offset = source.find("root.clicked(").unwrap();
+    let help = help_at(offset + 21).unwrap();
+    assert_eq!(help.signatures[0].label, "clicked(string, int)");
+    assert_eq!(help.active_parameter, Some(1));
+}